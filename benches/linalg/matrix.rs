@@ -1,4 +1,5 @@
-use rulinalg::matrix::Matrix;
+use rulinalg::matrix::{Matrix, PartialPivLu, Cholesky, Householder};
+use rulinalg::vector::Vector;
 use rulinalg::matrix::slice::{BaseMatrix, BaseMatrixMut};
 use test::Bencher;
 use test::black_box;
@@ -64,6 +65,41 @@ fn mat_mul_128_1000(b: &mut Bencher) {
     b.iter(|| &a * &c)
 }
 
+#[bench]
+fn mat_mul_512_512(b: &mut Bencher) {
+
+    let a = Matrix::new(512, 512, vec![2f64; 262144]);
+    let c = Matrix::new(512, 512, vec![3f64; 262144]);
+
+    b.iter(|| &a * &c)
+}
+
+#[bench]
+fn mat_mul_1024_1024_usize(b: &mut Bencher) {
+
+    let a = Matrix::new(1024, 1024, vec![2usize; 1048576]);
+    let c = Matrix::new(1024, 1024, vec![3usize; 1048576]);
+
+    b.iter(|| &a * &c)
+}
+
+#[bench]
+fn mat_mul_512_512_usize(b: &mut Bencher) {
+
+    let a = Matrix::new(512, 512, vec![2usize; 262144]);
+    let c = Matrix::new(512, 512, vec![3usize; 262144]);
+
+    b.iter(|| &a * &c)
+}
+
+#[bench]
+fn mat_transpose_4096_1024(b: &mut Bencher) {
+
+    let a = Matrix::new(4096, 1024, (0..4096 * 1024).map(|x| x as f64).collect::<Vec<_>>());
+
+    b.iter(|| a.transpose())
+}
+
 #[bench]
 fn mat_elemul_63_1000(b: &mut Bencher) {
 
@@ -147,4 +183,158 @@ fn mat_swap_cols_0_99(b: &mut Bencher) {
     b.iter(|| {
         black_box(m.swap_cols(0, 99));
     });
-}
\ No newline at end of file
+}
+
+#[bench]
+fn util_dot_f64_10000(b: &mut Bencher) {
+    let u = (0..10000).map(|x| x as f64 * 0.5).collect::<Vec<_>>();
+    let v = (0..10000).map(|x| 1.0 / (x as f64 + 1.0)).collect::<Vec<_>>();
+
+    b.iter(|| black_box(rulinalg::utils::dot_f64(&u, &v)))
+}
+
+#[bench]
+fn util_dot_generic_f64_10000(b: &mut Bencher) {
+    let u = (0..10000).map(|x| x as f64 * 0.5).collect::<Vec<_>>();
+    let v = (0..10000).map(|x| 1.0 / (x as f64 + 1.0)).collect::<Vec<_>>();
+
+    b.iter(|| black_box(rulinalg::utils::dot(&u, &v)))
+}
+
+#[bench]
+fn util_dot_simd_f64_10000(b: &mut Bencher) {
+    let u = (0..10000).map(|x| x as f64 * 0.5).collect::<Vec<_>>();
+    let v = (0..10000).map(|x| 1.0 / (x as f64 + 1.0)).collect::<Vec<_>>();
+
+    b.iter(|| black_box(rulinalg::utils::dot_simd(&u, &v)))
+}
+
+/// A 500x500 unit lower triangular matrix.
+fn unit_lower_500() -> Matrix<f64> {
+    Matrix::new(500, 500, (0..250000).map(|k| {
+        let (i, j) = (k / 500, k % 500);
+        if i == j { 1.0 } else if j < i { 1.0 / ((i - j) as f64 + 1.0) } else { 0.0 }
+    }).collect::<Vec<_>>())
+}
+
+#[bench]
+fn lu_solve_unit_lower_500_500(b: &mut Bencher) {
+    // L is unit lower triangular and U = I, so the solve is
+    // dominated by the unit forward substitution.
+    let lu = PartialPivLu::decompose(unit_lower_500()).unwrap();
+    let y = Vector::new(vec![1.0; 500]);
+
+    b.iter(|| black_box(lu.solve(y.clone()).unwrap()))
+}
+
+#[bench]
+fn solve_l_triangular_500_500(b: &mut Bencher) {
+    let l = unit_lower_500();
+    let y = Vector::new(vec![1.0; 500]);
+
+    b.iter(|| black_box(l.solve_l_triangular(y.clone()).unwrap()))
+}
+
+#[bench]
+fn solve_unit_l_triangular_500_500(b: &mut Bencher) {
+    let l = unit_lower_500();
+    let y = Vector::new(vec![1.0; 500]);
+
+    b.iter(|| black_box(l.solve_unit_l_triangular(y.clone())))
+}
+
+#[bench]
+fn solve_u_triangular_500_500(b: &mut Bencher) {
+    let u = unit_lower_500().transpose();
+    let y = Vector::new(vec![1.0; 500]);
+
+    b.iter(|| black_box(u.solve_u_triangular(y.clone()).unwrap()))
+}
+
+#[bench]
+fn solve_unit_u_triangular_500_500(b: &mut Bencher) {
+    let u = unit_lower_500().transpose();
+    let y = Vector::new(vec![1.0; 500]);
+
+    b.iter(|| black_box(u.solve_unit_u_triangular(y.clone())))
+}
+
+#[bench]
+fn mat_mul_strassen_256_256_i64(b: &mut Bencher) {
+    let a = Matrix::new(256, 256, (0..256 * 256).collect::<Vec<i64>>());
+    let c = Matrix::new(256, 256, (0..256 * 256).collect::<Vec<i64>>());
+
+    b.iter(|| a.mul_strassen(&c))
+}
+
+#[bench]
+fn mat_mul_256_256_i64(b: &mut Bencher) {
+    let a = Matrix::new(256, 256, (0..256 * 256).collect::<Vec<i64>>());
+    let c = Matrix::new(256, 256, (0..256 * 256).collect::<Vec<i64>>());
+
+    b.iter(|| &a * &c)
+}
+
+#[bench]
+fn mat_mul_strassen_512_512_i64(b: &mut Bencher) {
+    let a = Matrix::new(512, 512, (0..512 * 512).collect::<Vec<i64>>());
+    let c = Matrix::new(512, 512, (0..512 * 512).collect::<Vec<i64>>());
+
+    b.iter(|| a.mul_strassen(&c))
+}
+
+/// A 1000x1000 symmetric positive definite matrix.
+fn spd_1000() -> Matrix<f64> {
+    let n = 1000;
+    let b = Matrix::new(n, n, (0..n * n).map(|x| ((x * 7 + 3) % 17) as f64 / 17.0 - 0.5)
+                                        .collect::<Vec<_>>());
+    b.transpose() * &b + Matrix::identity(n) * n as f64
+}
+
+#[bench]
+fn cholesky_1000_1000(b: &mut Bencher) {
+    let a = spd_1000();
+
+    b.iter(|| black_box(Cholesky::decompose(a.clone()).unwrap()))
+}
+
+#[cfg(feature = "rayon")]
+#[bench]
+fn cholesky_par_1000_1000(b: &mut Bencher) {
+    let a = spd_1000();
+
+    b.iter(|| black_box(Cholesky::par_decompose(a.clone()).unwrap()))
+}
+
+fn householder_200() -> (Householder<f64>, Matrix<f64>) {
+    let x = Vector::new((0..200).map(|i| ((i * 7) % 11) as f64 - 5.0).collect::<Vec<_>>());
+    let h = Householder::new(&x).unwrap();
+
+    let v = Matrix::new(200, 1, h.vector().to_vec());
+    let q = Matrix::identity(200) - &v * v.transpose() * h.beta();
+    (h, q)
+}
+
+#[bench]
+fn householder_mul_alloc_200_200(b: &mut Bencher) {
+    let (_, q) = householder_200();
+    let a = Matrix::new(200, 200, (0..40000).map(|x| (x % 17) as f64).collect::<Vec<_>>());
+
+    b.iter(|| black_box(&q * &a))
+}
+
+#[bench]
+fn householder_apply_on_left_200_200(b: &mut Bencher) {
+    let (_, q) = householder_200();
+    let mut a = Matrix::new(200, 200, (0..40000).map(|x| (x % 17) as f64).collect::<Vec<_>>());
+
+    b.iter(|| q.apply_on_left(black_box(&mut a)))
+}
+
+#[bench]
+fn householder_apply_left_200_200(b: &mut Bencher) {
+    let (h, _) = householder_200();
+    let mut a = Matrix::new(200, 200, (0..40000).map(|x| (x % 17) as f64).collect::<Vec<_>>());
+
+    b.iter(|| h.apply_left(black_box(&mut a)))
+}
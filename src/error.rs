@@ -10,14 +10,15 @@ use std::marker::{Send, Sync};
 #[derive(Debug)]
 pub struct Error {
     kind: ErrorKind,
-    error: Box<error::Error + Send + Sync>,
+    error: Box<dyn error::Error + Send + Sync>,
+    cause: Option<Box<dyn error::Error + Send + Sync>>,
 }
 
 /// Types of errors produced in the linalg module.
 ///
 /// List intended to grow and so you should
 /// be wary of matching against explicitly.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ErrorKind {
     /// An argument did not uphold a necessary criteria for the function.
     InvalidArg,
@@ -25,19 +26,141 @@ pub enum ErrorKind {
     DecompFailure,
     /// A failure due to some algebraic constraints not being met.
     AlgebraFailure,
+    /// An iterative method did not converge.
+    ConvergenceFailed {
+        /// The number of iterations performed.
+        iterations: usize,
+        /// The residual after the final iteration.
+        residual: f64,
+    },
+    /// A matrix has lower rank than the operation requires.
+    RankDeficient {
+        /// The rank required by the operation.
+        expected_rank: usize,
+        /// The rank of the matrix.
+        actual_rank: usize,
+    },
+    /// The `(rows, cols)` dimensions of an argument are not as required.
+    DimensionMismatch {
+        /// The required dimensions.
+        expected: (usize, usize),
+        /// The dimensions of the argument.
+        actual: (usize, usize),
+    },
+    /// A matrix required to be positive definite is not.
+    Indefinite,
+}
+
+/// Describes the kind of error, including any data it carries.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::error::ErrorKind;
+///
+/// let kind = ErrorKind::DimensionMismatch { expected: (2, 3), actual: (3, 2) };
+/// assert_eq!(kind.to_string(), "dimension mismatch (expected 2x3, found 3x2)");
+/// ```
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::InvalidArg => f.write_str("invalid argument"),
+            ErrorKind::DecompFailure => f.write_str("decomposition failed"),
+            ErrorKind::AlgebraFailure => f.write_str("algebraic constraint not met"),
+            ErrorKind::ConvergenceFailed { iterations, residual } => {
+                write!(f,
+                       "failed to converge after {} iterations (residual {})",
+                       iterations,
+                       residual)
+            }
+            ErrorKind::RankDeficient { expected_rank, actual_rank } => {
+                write!(f,
+                       "rank deficient (expected rank {}, found {})",
+                       expected_rank,
+                       actual_rank)
+            }
+            ErrorKind::DimensionMismatch { expected, actual } => {
+                write!(f,
+                       "dimension mismatch (expected {}x{}, found {}x{})",
+                       expected.0,
+                       expected.1,
+                       actual.0,
+                       actual.1)
+            }
+            ErrorKind::Indefinite => f.write_str("matrix is not positive definite"),
+        }
+    }
 }
 
 impl Error {
     /// Construct a new `Error` of a particular `ErrorKind`.
     pub fn new<E>(kind: ErrorKind, error: E) -> Error
-        where E: Into<Box<error::Error + Send + Sync>>
+        where E: Into<Box<dyn error::Error + Send + Sync>>
+    {
+        Error {
+            kind: kind,
+            error: error.into(),
+            cause: None,
+        }
+    }
+
+    /// Construct a new `Error` of a particular `ErrorKind`
+    /// wrapping an underlying cause.
+    ///
+    /// The cause is exposed through `std::error::Error::source`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error as StdError;
+    /// use rulinalg::error::{Error, ErrorKind};
+    ///
+    /// let parse_err = "x".parse::<f64>().unwrap_err();
+    /// let err = Error::with_cause(ErrorKind::InvalidArg,
+    ///                             "Could not parse matrix entry.",
+    ///                             parse_err);
+    ///
+    /// assert_eq!(err.to_string(), "Could not parse matrix entry.");
+    /// assert!(err.source().is_some());
+    /// ```
+    pub fn with_cause<E, C>(kind: ErrorKind, error: E, cause: C) -> Error
+        where E: Into<Box<dyn error::Error + Send + Sync>>,
+              C: Into<Box<dyn error::Error + Send + Sync>>
     {
         Error {
             kind: kind,
             error: error.into(),
+            cause: Some(cause.into()),
         }
     }
 
+    /// Construct a new `Error` of a particular `ErrorKind`
+    /// wrapping an already boxed lower-level error.
+    ///
+    /// This is equivalent to `with_cause`, for callers which already
+    /// hold a boxed error, for example from another library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error as StdError;
+    /// use rulinalg::error::{Error, ErrorKind};
+    ///
+    /// let io_err: Box<dyn StdError + Send + Sync> = "file truncated".into();
+    /// let err = Error::with_source(ErrorKind::InvalidArg,
+    ///                              "Could not read matrix.",
+    ///                              io_err);
+    ///
+    /// assert_eq!(err.source().unwrap().to_string(), "file truncated");
+    /// assert_eq!(format!("{:#}", err), "Could not read matrix.: file truncated");
+    /// ```
+    pub fn with_source(kind: ErrorKind,
+                       msg: &str,
+                       source: Box<dyn error::Error + Send + Sync>)
+                       -> Error {
+        Error::with_cause(kind, msg, source)
+    }
+
     /// Get the kind of this `Error`.
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
@@ -48,10 +171,138 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         self.error.description()
     }
+
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.cause.as_ref().map(|e| &**e as &(dyn error::Error + 'static))
+    }
 }
 
+/// Writes the error message.
+///
+/// The alternate form `{:#}` also writes the chain of sources,
+/// each separated by `": "`.
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.error.fmt(f)
+        self.error.fmt(f)?;
+
+        if f.alternate() {
+            let mut source = error::Error::source(self);
+            while let Some(e) = source {
+                write!(f, ": {}", e)?;
+                source = e.source();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as StdError;
+    use super::{Error, ErrorKind};
+
+    #[test]
+    fn test_error_without_cause() {
+        let err = Error::new(ErrorKind::InvalidArg, "Bad argument.");
+
+        assert_eq!(err.to_string(), "Bad argument.");
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_error_with_cause() {
+        let parse_err = "abc".parse::<f64>().unwrap_err();
+        let cause_msg = parse_err.to_string();
+        let err = Error::with_cause(ErrorKind::InvalidArg, "Could not parse entry.", parse_err);
+
+        assert_eq!(err.to_string(), "Could not parse entry.");
+        assert_eq!(err.source().unwrap().to_string(), cause_msg);
+
+        let debug = format!("{:?}", err);
+        assert!(debug.contains("InvalidArg"));
+        assert!(debug.contains("cause"));
+    }
+
+    #[test]
+    fn test_error_boxed_dyn() {
+        fn fails() -> Result<(), Box<dyn StdError>> {
+            Err(Error::with_cause(ErrorKind::AlgebraFailure,
+                                  "Outer failure.",
+                                  Error::new(ErrorKind::DecompFailure, "Inner failure.")))?;
+            Ok(())
+        }
+
+        let err = fails().unwrap_err();
+        assert_eq!(err.to_string(), "Outer failure.");
+        assert_eq!(err.source().unwrap().to_string(), "Inner failure.");
+    }
+
+    #[test]
+    fn test_error_kind_variants() {
+        let err = Error::new(ErrorKind::ConvergenceFailed {
+                                 iterations: 50,
+                                 residual: 0.25,
+                             },
+                             "Did not converge.");
+
+        match *err.kind() {
+            ErrorKind::ConvergenceFailed { iterations, residual } => {
+                assert_eq!(iterations, 50);
+                assert_eq!(residual, 0.25);
+            }
+            _ => panic!("Expected ConvergenceFailed error."),
+        }
+        assert_eq!(err.kind().to_string(),
+                   "failed to converge after 50 iterations (residual 0.25)");
+        assert_eq!(err.to_string(), "Did not converge.");
+
+        let rank = ErrorKind::RankDeficient {
+            expected_rank: 3,
+            actual_rank: 2,
+        };
+        assert_eq!(rank.to_string(), "rank deficient (expected rank 3, found 2)");
+        assert_eq!(rank.clone(), rank);
+        assert!(rank != ErrorKind::Indefinite);
+
+        assert_eq!(ErrorKind::Indefinite.to_string(), "matrix is not positive definite");
+        assert_eq!(ErrorKind::InvalidArg.to_string(), "invalid argument");
+    }
+
+    #[test]
+    fn test_error_with_source_chain() {
+        let inner = Error::with_cause(ErrorKind::DecompFailure,
+                                      "Inner failure.",
+                                      "root cause");
+        let err = Error::with_source(ErrorKind::AlgebraFailure,
+                                     "Outer failure.",
+                                     Box::new(inner));
+
+        assert_eq!(*err.kind(), ErrorKind::AlgebraFailure);
+        assert_eq!(err.to_string(), "Outer failure.");
+        assert_eq!(format!("{:#}", err),
+                   "Outer failure.: Inner failure.: root cause");
+
+        let mut chain = vec![];
+        let mut source = err.source();
+        while let Some(e) = source {
+            chain.push(e.to_string());
+            source = e.source();
+        }
+        assert_eq!(chain, vec!["Inner failure.", "root cause"]);
+
+        // The wrapped error can be recovered by downcasting.
+        let inner = err.source().unwrap().downcast_ref::<Error>().unwrap();
+        assert_eq!(*inner.kind(), ErrorKind::DecompFailure);
+    }
+
+    #[test]
+    fn test_error_is_send_sync_static() {
+        fn boxed(e: Error) -> Box<dyn StdError + Send + Sync + 'static> {
+            Box::new(e)
+        }
+
+        let err = boxed(Error::new(ErrorKind::InvalidArg, "Bad argument."));
+        assert_eq!(format!("{:#}", err), "Bad argument.");
+        assert!(err.source().is_none());
     }
 }
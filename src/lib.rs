@@ -18,6 +18,7 @@
 //! - SVD decomposition
 //! - Cholesky decomposition
 //! - Eigenvalue decomposition
+//! - Power and inverse iteration
 //! - Upper Hessenberg decomposition
 //! - Linear system solver
 //! - Other standard transformations, e.g. Transposing, concatenation, etc.
@@ -65,20 +66,58 @@
 //!
 //! Note finally that much of the `Matrix`/`MatrixSlice`/`MatrixSliceMut` functionality is contained behind
 //! the `BaseMatrix`/`BaseMatrixMut` traits. This allows us to be generic over matrices or slices.
+//!
+//! # BLAS
+//!
+//! By default `f32` and `f64` matrix products are computed in pure Rust. With the
+//! `blas` feature enabled they are instead computed by the `sgemm` and `dgemm`
+//! routines of a BLAS library, through the [blas](https://crates.io/crates/blas) crate.
+//! The results agree with the pure Rust products up to floating point rounding.
+//!
+//! The `blas` crate does not choose a BLAS implementation, so one must be linked
+//! into the final binary. The simplest way is to depend on
+//! [blas-src](https://crates.io/crates/blas-src) and select a provider:
+//!
+//! ```toml
+//! [dependencies]
+//! rulinalg = { version = "0.3", features = ["blas"] }
+//! # One of "openblas", "intel-mkl" or "accelerate".
+//! blas-src = { version = "0.10", features = ["openblas"] }
+//! ```
+//!
+//! and then add `extern crate blas_src;` to the crate root. For OpenBLAS this
+//! requires the library to be installed (e.g. `libopenblas-dev` on Debian),
+//! MKL can be downloaded by `intel-mkl-src`, and Accelerate is available on macOS.
+//!
+//! Alternatively an installed library can be linked directly, for instance with
+//! `RUSTFLAGS="-l openblas" cargo test --features blas`.
 
 #![deny(missing_docs)]
 #![warn(missing_debug_implementations)]
 
 extern crate num as libnum;
 extern crate matrixmultiply;
+extern crate num_complex;
+
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "blas")]
+extern crate blas;
+
+#[cfg(test)]
+#[macro_use]
+extern crate quickcheck;
 
 pub mod matrix;
 pub mod convert;
 pub mod macros;
 pub mod error;
+pub mod solvers;
 pub mod utils;
 pub mod vector;
 
+pub use num_complex::Complex;
+
 /// Trait for linear algebra metrics.
 ///
 /// Currently only implements basic euclidean norm.
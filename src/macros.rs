@@ -1,5 +1,12 @@
 //! Macros for the linear algebra modules.
 
+#[macro_use]
+mod matrix_eq;
+
+pub use self::matrix_eq::{ElementwiseComparator, ExactElementwiseComparator,
+                          AbsoluteElementwiseComparator, NanSafeElementwiseComparator,
+                          NanSafeAbsoluteElementwiseComparator, elementwise_matrix_comparison};
+
 macro_rules! count {
     () => (0usize);
     ( $x:tt $($xs:tt)* ) => (1usize + count!($($xs)*));
@@ -0,0 +1,296 @@
+//! Elementwise comparison of matrices, used by `assert_matrix_eq!`.
+//!
+//! A comparator decides whether two elements are equal. The matrices
+//! are compared entry by entry and every mismatch is reported.
+
+use std::fmt;
+
+use matrix::BaseMatrix;
+
+use libnum::Float;
+
+/// The maximum number of mismatched entries listed in a failure message.
+const MAX_REPORTED_MISMATCHES: usize = 10;
+
+/// Decides whether two matrix elements are considered equal.
+pub trait ElementwiseComparator<T> {
+    /// Returns `Ok` if `x` and `y` are equal, and otherwise a
+    /// description of why they differ.
+    fn compare(&self, x: T, y: T) -> Result<(), String>;
+
+    /// Describes the comparison for failure messages.
+    fn description(&self) -> String;
+}
+
+/// Compares elements with `==`.
+#[derive(Debug, Clone, Copy)]
+pub struct ExactElementwiseComparator;
+
+impl<T: Copy + PartialEq + fmt::Debug> ElementwiseComparator<T> for ExactElementwiseComparator {
+    fn compare(&self, x: T, y: T) -> Result<(), String> {
+        if x == y {
+            Ok(())
+        } else {
+            Err(format!("{:?} != {:?}", x, y))
+        }
+    }
+
+    fn description(&self) -> String {
+        "exact equality x == y".to_owned()
+    }
+}
+
+/// Compares elements by the absolute difference `|x - y| <= tol`.
+#[derive(Debug, Clone, Copy)]
+pub struct AbsoluteElementwiseComparator<T> {
+    /// The largest absolute difference accepted.
+    pub tol: T,
+}
+
+impl<T: Float + fmt::Debug> ElementwiseComparator<T> for AbsoluteElementwiseComparator<T> {
+    fn compare(&self, x: T, y: T) -> Result<(), String> {
+        let diff = (x - y).abs();
+        if diff <= self.tol {
+            Ok(())
+        } else {
+            Err(format!("|{:?} - {:?}| = {:?} > {:?}", x, y, diff, self.tol))
+        }
+    }
+
+    fn description(&self) -> String {
+        format!("absolute difference |x - y| <= {:?}", self.tol)
+    }
+}
+
+/// Compares elements with `==`, except that `NaN` is equal to `NaN`.
+///
+/// This is useful for results with `NaN` in known positions.
+#[derive(Debug, Clone, Copy)]
+pub struct NanSafeElementwiseComparator;
+
+impl<T: Float + fmt::Debug> ElementwiseComparator<T> for NanSafeElementwiseComparator {
+    fn compare(&self, x: T, y: T) -> Result<(), String> {
+        if x == y || (x.is_nan() && y.is_nan()) {
+            Ok(())
+        } else {
+            Err(format!("{:?} != {:?}", x, y))
+        }
+    }
+
+    fn description(&self) -> String {
+        "exact equality x == y, with NaN == NaN".to_owned()
+    }
+}
+
+/// Compares elements by the absolute difference `|x - y| <= tol`,
+/// where `NaN` is only equal to `NaN`.
+#[derive(Debug, Clone, Copy)]
+pub struct NanSafeAbsoluteElementwiseComparator<T> {
+    /// The largest absolute difference accepted.
+    pub tol: T,
+}
+
+impl<T: Float + fmt::Debug> ElementwiseComparator<T> for NanSafeAbsoluteElementwiseComparator<T> {
+    fn compare(&self, x: T, y: T) -> Result<(), String> {
+        match (x.is_nan(), y.is_nan()) {
+            (true, true) => Ok(()),
+            (false, false) => AbsoluteElementwiseComparator { tol: self.tol }.compare(x, y),
+            _ => Err(format!("{:?} != {:?} (NaN positions differ)", x, y)),
+        }
+    }
+
+    fn description(&self) -> String {
+        format!("absolute difference |x - y| <= {:?}, with NaN == NaN", self.tol)
+    }
+}
+
+/// Compares two matrices elementwise.
+///
+/// Returns `Ok` if the dimensions agree and every pair of elements is
+/// accepted by the comparator, and otherwise a message describing the
+/// mismatches.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::Matrix;
+/// use rulinalg::macros::{elementwise_matrix_comparison, NanSafeElementwiseComparator};
+/// use std::f64;
+///
+/// let a = Matrix::new(1, 2, vec![1.0, f64::NAN]);
+/// let b = Matrix::new(1, 2, vec![1.0, f64::NAN]);
+///
+/// assert!(elementwise_matrix_comparison(&a, &b, NanSafeElementwiseComparator).is_ok());
+/// ```
+pub fn elementwise_matrix_comparison<T, M1, M2, C>(x: &M1,
+                                                   y: &M2,
+                                                   comp: C)
+                                                   -> Result<(), String>
+    where T: Copy,
+          M1: BaseMatrix<T>,
+          M2: BaseMatrix<T>,
+          C: ElementwiseComparator<T>
+{
+    if x.rows() != y.rows() || x.cols() != y.cols() {
+        return Err(format!("Dimensions differ: {}x{} and {}x{}.",
+                           x.rows(),
+                           x.cols(),
+                           y.rows(),
+                           y.cols()));
+    }
+
+    let mut mismatches = Vec::new();
+    let mut count = 0;
+    for (i, (row_x, row_y)) in x.iter_rows().zip(y.iter_rows()).enumerate() {
+        for (j, (&a, &b)) in row_x.iter().zip(row_y.iter()).enumerate() {
+            if let Err(reason) = comp.compare(a, b) {
+                count += 1;
+                if mismatches.len() < MAX_REPORTED_MISMATCHES {
+                    mismatches.push(format!("  ({}, {}): {}", i, j, reason));
+                }
+            }
+        }
+    }
+
+    if count == 0 {
+        Ok(())
+    } else {
+        Err(format!("{} of {} elements differ using {}:\n{}{}",
+                    count,
+                    x.rows() * x.cols(),
+                    comp.description(),
+                    mismatches.join("\n"),
+                    if count > mismatches.len() { "\n  ..." } else { "" }))
+    }
+}
+
+/// Compares two matrices elementwise, panicking on a mismatch.
+///
+/// The comparison mode is chosen with `comp`:
+///
+/// - `comp = exact` (the default) compares with `==`.
+/// - `comp = abs, tol = $tol` accepts `|x - y| <= tol`.
+/// - `comp = nan_safe` compares with `==`, treating `NaN` as equal to `NaN`.
+/// - `comp = nan_safe_abs, tol = $tol` accepts `|x - y| <= tol`, and
+///   requires `NaN` in the same positions of both matrices.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate rulinalg;
+///
+/// use rulinalg::matrix::Matrix;
+/// use std::f64;
+///
+/// # fn main() {
+/// let a = Matrix::new(2, 2, vec![1.0, f64::NAN, 3.0, 4.0]);
+/// let b = Matrix::new(2, 2, vec![1.0, f64::NAN, 3.0, 4.0 + 1e-14]);
+///
+/// assert_matrix_eq!(a, a, comp = nan_safe);
+/// assert_matrix_eq!(a, b, comp = nan_safe_abs, tol = 1e-12);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// - The dimensions differ, or any pair of elements is not accepted
+/// by the comparator.
+#[macro_export]
+macro_rules! assert_matrix_eq {
+    ($x:expr, $y:expr) => {
+        assert_matrix_eq!($x, $y, comp = exact)
+    };
+    ($x:expr, $y:expr, comp = exact) => {
+        assert_matrix_eq!(@compare $x, $y, $crate::macros::ExactElementwiseComparator)
+    };
+    ($x:expr, $y:expr, comp = abs, tol = $tol:expr) => {
+        assert_matrix_eq!(@compare $x, $y,
+                          $crate::macros::AbsoluteElementwiseComparator { tol: $tol })
+    };
+    ($x:expr, $y:expr, comp = nan_safe) => {
+        assert_matrix_eq!(@compare $x, $y, $crate::macros::NanSafeElementwiseComparator)
+    };
+    ($x:expr, $y:expr, comp = nan_safe_abs, tol = $tol:expr) => {
+        assert_matrix_eq!(@compare $x, $y,
+                          $crate::macros::NanSafeAbsoluteElementwiseComparator { tol: $tol })
+    };
+    (@compare $x:expr, $y:expr, $comp:expr) => {
+        if let Err(msg) = $crate::macros::elementwise_matrix_comparison(&$x, &$y, $comp) {
+            panic!("assert_matrix_eq!({}, {}) failed.\n{}",
+                   stringify!($x),
+                   stringify!($y),
+                   msg);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use matrix::{Matrix, MatrixSlice};
+    use super::{elementwise_matrix_comparison, ExactElementwiseComparator,
+                NanSafeElementwiseComparator, NanSafeAbsoluteElementwiseComparator};
+
+    #[test]
+    fn test_nan_safe_matching_positions() {
+        let a = Matrix::new(2, 2, vec![1.0, f64::NAN, f64::NAN, 4.0]);
+        let b = a.clone();
+
+        assert!(elementwise_matrix_comparison(&a, &b, NanSafeElementwiseComparator).is_ok());
+        assert!(elementwise_matrix_comparison(&a, &b, ExactElementwiseComparator).is_err());
+        assert_matrix_eq!(a, b, comp = nan_safe);
+        assert_matrix_eq!(a, b, comp = nan_safe_abs, tol = 0.0);
+    }
+
+    #[test]
+    fn test_nan_safe_nan_against_finite() {
+        let a = Matrix::new(1, 3, vec![1.0, f64::NAN, 3.0]);
+        let b = Matrix::new(1, 3, vec![1.0, 2.0, 3.0]);
+
+        let msg = elementwise_matrix_comparison(&a, &b, NanSafeElementwiseComparator)
+            .unwrap_err();
+        assert!(msg.contains("(0, 1)"));
+        assert!(elementwise_matrix_comparison(&b, &a, NanSafeElementwiseComparator).is_err());
+
+        let abs = NanSafeAbsoluteElementwiseComparator { tol: 1e10 };
+        assert!(elementwise_matrix_comparison(&a, &b, abs).is_err());
+    }
+
+    #[test]
+    fn test_nan_safe_abs_tolerance() {
+        let a = Matrix::new(1, 3, vec![1.0, f64::NAN, 3.0]);
+        let b = Matrix::new(1, 3, vec![1.0 + 1e-10, f64::NAN, 3.0]);
+
+        assert_matrix_eq!(a, b, comp = nan_safe_abs, tol = 1e-8);
+        let tight = NanSafeAbsoluteElementwiseComparator { tol: 1e-12 };
+        assert!(elementwise_matrix_comparison(&a, &b, tight).is_err());
+    }
+
+    #[test]
+    fn test_comparison_dimensions_and_slices() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        assert!(elementwise_matrix_comparison(&a, &b, ExactElementwiseComparator).is_err());
+
+        let s = MatrixSlice::from_matrix(&a, [0, 1], 2, 2);
+        assert_matrix_eq!(s, Matrix::new(2, 2, vec![2, 3, 5, 6]));
+        assert_matrix_eq!(a, a.clone(), comp = exact);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_matrix_eq_nan_mismatch_panics() {
+        let a = Matrix::new(1, 2, vec![f64::NAN, 1.0]);
+        let b = Matrix::new(1, 2, vec![0.0, 1.0]);
+        assert_matrix_eq!(a, b, comp = nan_safe);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_matrix_eq_abs_panics() {
+        let a = Matrix::new(1, 1, vec![1.0]);
+        let b = Matrix::new(1, 1, vec![1.1]);
+        assert_matrix_eq!(a, b, comp = abs, tol = 1e-3);
+    }
+}
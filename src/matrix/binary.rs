@@ -0,0 +1,262 @@
+//! Raw binary serialization of matrices.
+//!
+//! The format is a fixed 24 byte header followed by the elements:
+//!
+//! | Offset | Size | Contents                                              |
+//! |--------|------|-------------------------------------------------------|
+//! | 0      | 4    | Magic bytes `b"RLMX"`                                 |
+//! | 4      | 1    | Format version, currently `1`                         |
+//! | 5      | 1    | Endianness marker, `b'<'` for little-endian           |
+//! | 6      | 1    | Element size in bytes, `4` for `f32` or `8` for `f64` |
+//! | 7      | 1    | Reserved, always `0`                                  |
+//! | 8      | 8    | Number of rows as a little-endian `u64`               |
+//! | 16     | 8    | Number of columns as a little-endian `u64`            |
+//! | 24     | ...  | `rows * cols` IEEE 754 elements in row-major order    |
+//!
+//! All multi-byte values are little-endian. With NumPy for example,
+//! the data of a file `f` written from a `Matrix<f64>` can be read with
+//! `np.fromfile(f, dtype='<f8', offset=24).reshape(rows, cols)`.
+
+use std::any::{Any, TypeId};
+use std::io::{self, Read, Write};
+use std::mem;
+
+use libnum::{Float, cast};
+
+use matrix::Matrix;
+use error::{Error, ErrorKind};
+
+const MAGIC: &[u8; 4] = b"RLMX";
+const VERSION: u8 = 1;
+const LITTLE_ENDIAN: u8 = b'<';
+
+/// Returns the element size used in the format, if `T` is supported.
+fn element_size<T: Any>() -> Option<u8> {
+    if TypeId::of::<T>() == TypeId::of::<f32>() {
+        Some(4)
+    } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+        Some(8)
+    } else {
+        None
+    }
+}
+
+fn read_error(msg: &'static str, cause: io::Error) -> Error {
+    Error::with_cause(ErrorKind::InvalidArg, msg, cause)
+}
+
+impl<T: Any + Float> Matrix<T> {
+    /// Writes the matrix in the raw binary format.
+    ///
+    /// See the [module documentation](binary/index.html) for a
+    /// description of the format. Only `f32` and `f64` matrices
+    /// are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    ///
+    /// let mut buf = Vec::new();
+    /// a.write_binary(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf.len(), 24 + 4 * 8);
+    /// assert_eq!(Matrix::<f64>::read_binary(&buf[..]).unwrap(), a);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The element type is not `f32` or `f64`.
+    /// - Writing to `w` fails.
+    pub fn write_binary<W: Write>(&self, mut w: W) -> Result<(), io::Error> {
+        let size = element_size::<T>().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput,
+                           "Only f32 and f64 matrices can be written in binary format.")
+        })?;
+
+        let mut header = [0u8; 24];
+        header[..4].copy_from_slice(MAGIC);
+        header[4] = VERSION;
+        header[5] = LITTLE_ENDIAN;
+        header[6] = size;
+        header[8..16].copy_from_slice(&(self.rows as u64).to_le_bytes());
+        header[16..24].copy_from_slice(&(self.cols as u64).to_le_bytes());
+        w.write_all(&header)?;
+
+        let mut buf = Vec::with_capacity(self.data.len() * size as usize);
+        for &x in &self.data {
+            if size == 4 {
+                let x: f32 = cast(x).unwrap();
+                buf.extend_from_slice(&x.to_le_bytes());
+            } else {
+                let x: f64 = cast(x).unwrap();
+                buf.extend_from_slice(&x.to_le_bytes());
+            }
+        }
+
+        w.write_all(&buf)
+    }
+
+    /// Reads a matrix written in the raw binary format.
+    ///
+    /// The element type of the file must match `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(1, 3, vec![1.0f32, 2.0, 3.0]);
+    ///
+    /// let mut buf = Vec::new();
+    /// a.write_binary(&mut buf).unwrap();
+    ///
+    /// // The header must match the element type
+    /// assert!(Matrix::<f64>::read_binary(&buf[..]).is_err());
+    /// assert_eq!(Matrix::<f32>::read_binary(&buf[..]).unwrap(), a);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The magic bytes, version or endianness marker are not recognized.
+    /// - The element size in the header does not match `T`.
+    /// - The input ends before all elements are read.
+    pub fn read_binary<R: Read>(mut r: R) -> Result<Matrix<T>, Error> {
+        let size = element_size::<T>().ok_or_else(|| {
+            Error::new(ErrorKind::InvalidArg,
+                       "Only f32 and f64 matrices can be read in binary format.")
+        })?;
+
+        let mut header = [0u8; 24];
+        r.read_exact(&mut header)
+            .map_err(|e| read_error("Could not read binary matrix header.", e))?;
+
+        if &header[..4] != MAGIC {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Input is not a binary matrix (magic bytes do not match)."));
+        }
+        if header[4] != VERSION {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Unsupported binary matrix format version."));
+        }
+        if header[5] != LITTLE_ENDIAN {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Unsupported endianness in binary matrix."));
+        }
+        if header[6] != size {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Element size of binary matrix does not match the matrix type."));
+        }
+
+        let mut dim = [0u8; 8];
+        dim.copy_from_slice(&header[8..16]);
+        let rows = u64::from_le_bytes(dim) as usize;
+        dim.copy_from_slice(&header[16..24]);
+        let cols = u64::from_le_bytes(dim) as usize;
+
+        let len = rows.checked_mul(cols)
+            .and_then(|n| n.checked_mul(size as usize))
+            .ok_or_else(|| Error::new(ErrorKind::InvalidArg, "Binary matrix dimensions overflow."))?;
+
+        // Read through `take` so a corrupt header cannot force a huge allocation.
+        let mut bytes = Vec::new();
+        r.take(len as u64)
+            .read_to_end(&mut bytes)
+            .map_err(|e| read_error("Could not read binary matrix data.", e))?;
+        if bytes.len() != len {
+            return Err(Error::new(ErrorKind::InvalidArg, "Binary matrix data is truncated."));
+        }
+
+        let data: Vec<T> = if size == 4 {
+            bytes.chunks(mem::size_of::<f32>())
+                .map(|c| {
+                    let mut b = [0u8; 4];
+                    b.copy_from_slice(c);
+                    cast::<f32, T>(f32::from_le_bytes(b)).unwrap()
+                })
+                .collect()
+        } else {
+            bytes.chunks(mem::size_of::<f64>())
+                .map(|c| {
+                    let mut b = [0u8; 8];
+                    b.copy_from_slice(c);
+                    cast::<f64, T>(f64::from_le_bytes(b)).unwrap()
+                })
+                .collect()
+        };
+
+        Ok(Matrix::new(rows, cols, data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::Matrix;
+
+    fn round_trip<T: ::std::any::Any + ::libnum::Float + ::std::fmt::Debug>(a: &Matrix<T>) -> Matrix<T> {
+        let mut buf = Vec::new();
+        a.write_binary(&mut buf).unwrap();
+        Matrix::read_binary(&buf[..]).unwrap()
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let a = Matrix::new(3, 2, vec![1.5f64, -0.0, ::std::f64::MAX, 1e-300, 3.25, -7.0]);
+        assert_eq!(round_trip(&a), a);
+
+        let b = Matrix::new(2, 3, vec![1.5f32, -2.0, ::std::f32::MIN_POSITIVE, 0.1, 3.0, 4.0]);
+        assert_eq!(round_trip(&b), b);
+
+        let empty = Matrix::<f64>::new(0, 4, vec![]);
+        assert_eq!(round_trip(&empty), empty);
+
+        let nan = round_trip(&Matrix::new(1, 1, vec![::std::f64::NAN]));
+        assert!(nan[[0, 0]].is_nan());
+    }
+
+    #[test]
+    fn test_binary_header_layout() {
+        let a = Matrix::new(2, 3, vec![1.0f64; 6]);
+        let mut buf = Vec::new();
+        a.write_binary(&mut buf).unwrap();
+
+        assert_eq!(&buf[..8], b"RLMX\x01<\x08\x00");
+        assert_eq!(&buf[8..16], &[2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&buf[16..24], &[3, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&buf[24..32], &1.0f64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_binary_truncated() {
+        let a = Matrix::new(2, 2, vec![1.0f64, 2.0, 3.0, 4.0]);
+        let mut buf = Vec::new();
+        a.write_binary(&mut buf).unwrap();
+
+        assert!(Matrix::<f64>::read_binary(&buf[..buf.len() - 1]).is_err());
+        assert!(Matrix::<f64>::read_binary(&buf[..10]).is_err());
+        assert!(Matrix::<f64>::read_binary(&buf[..0]).is_err());
+    }
+
+    #[test]
+    fn test_binary_bad_header() {
+        let a = Matrix::new(1, 2, vec![1.0f64, 2.0]);
+        let mut buf = Vec::new();
+        a.write_binary(&mut buf).unwrap();
+
+        let mut bad_magic = buf.clone();
+        bad_magic[0] = b'X';
+        assert!(Matrix::<f64>::read_binary(&bad_magic[..]).is_err());
+
+        let mut bad_version = buf.clone();
+        bad_version[4] = 2;
+        assert!(Matrix::<f64>::read_binary(&bad_version[..]).is_err());
+
+        let mut bad_endian = buf.clone();
+        bad_endian[5] = b'>';
+        assert!(Matrix::<f64>::read_binary(&bad_endian[..]).is_err());
+
+        assert!(Matrix::<f32>::read_binary(&buf[..]).is_err());
+    }
+}
@@ -0,0 +1,479 @@
+//! Matrices stored in column-major order.
+//!
+//! A column-major matrix has the same memory layout as the row-major
+//! storage of its transpose. The types here wrap a `Matrix` or
+//! `MatrixSlice` holding that transpose, so converting between the two
+//! orders by transposing is free.
+
+use std::any::Any;
+use std::ops::{Add, Mul, Index, IndexMut};
+use std::slice;
+use libnum::Zero;
+
+use matrix::{Matrix, MatrixSlice, BaseMatrix, BaseMatrixMut};
+use matrix::impl_ops::check_index;
+use utils;
+
+/// A matrix stored in column-major order.
+///
+/// Each column is contiguous in memory. This matches the layout used
+/// by Fortran and most LAPACK-style libraries.
+///
+/// `BaseMatrix` and `BaseMatrixMut` describe row-major storage with
+/// contiguous rows, so they are not implemented here. Their methods
+/// can be used on the row-major transpose given by `transpose_view`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, ColumnMajorMatrix};
+///
+/// let a = ColumnMajorMatrix::new(2, 3, vec![1, 4, 2, 5, 3, 6]);
+///
+/// assert_eq!(a[[0, 2]], 3);
+/// assert_eq!(a.col(1), &[2, 5]);
+/// assert_eq!(a.to_row_major(), Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnMajorMatrix<T> {
+    // The row-major transpose, sharing the column-major data layout.
+    t: Matrix<T>,
+}
+
+/// A borrowed block of a column-major matrix.
+///
+/// Each column of the block is contiguous, with consecutive columns
+/// a fixed stride apart.
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnMajorSlice<'a, T: 'a> {
+    t: MatrixSlice<'a, T>,
+}
+
+impl<T> ColumnMajorMatrix<T> {
+    /// Constructor for ColumnMajorMatrix from column-major data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::ColumnMajorMatrix;
+    ///
+    /// let a = ColumnMajorMatrix::new(2, 2, vec![1.0, 3.0, 2.0, 4.0]);
+    /// assert_eq!(a[[0, 1]], 2.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The data length is not `rows * cols`.
+    pub fn new<U: Into<Vec<T>>>(rows: usize, cols: usize, data: U) -> ColumnMajorMatrix<T> {
+        ColumnMajorMatrix { t: Matrix::new(cols, rows, data) }
+    }
+
+    /// Interprets the row-major storage of `m` as the column-major transpose of `m`.
+    ///
+    /// This takes `O(1)` time as no data is moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, ColumnMajorMatrix, BaseMatrix};
+    ///
+    /// let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let t = ColumnMajorMatrix::from_transpose(m.clone());
+    ///
+    /// assert_eq!(t.to_row_major(), m.transpose());
+    /// ```
+    pub fn from_transpose(m: Matrix<T>) -> ColumnMajorMatrix<T> {
+        ColumnMajorMatrix { t: m }
+    }
+
+    /// Consumes the matrix and returns its transpose in row-major order.
+    ///
+    /// This takes `O(1)` time as no data is moved.
+    pub fn into_transpose(self) -> Matrix<T> {
+        self.t
+    }
+
+    /// Returns a row-major view of the transpose of the matrix.
+    ///
+    /// This takes `O(1)` time as no data is moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{ColumnMajorMatrix, BaseMatrix};
+    ///
+    /// let a = ColumnMajorMatrix::new(2, 3, vec![1, 4, 2, 5, 3, 6]);
+    /// let t = a.transpose_view();
+    ///
+    /// assert_eq!(t.rows(), 3);
+    /// assert_eq!(t[[2, 1]], 6);
+    /// ```
+    pub fn transpose_view(&self) -> MatrixSlice<'_, T> {
+        self.t.as_slice()
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.t.cols()
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.t.rows()
+    }
+
+    /// Returns a non-mutable reference to the column-major data.
+    pub fn data(&self) -> &Vec<T> {
+        self.t.data()
+    }
+
+    /// Consumes the matrix and returns the column-major data.
+    pub fn into_vec(self) -> Vec<T> {
+        self.t.into_vec()
+    }
+
+    /// Returns column `j` as a contiguous slice.
+    ///
+    /// # Panics
+    ///
+    /// - `j` is out of bounds.
+    pub fn col(&self, j: usize) -> &[T] {
+        assert!(j < self.cols(), "Column index is greater than column dimension.");
+        unsafe { self.t.get_row_unchecked(j) }
+    }
+
+    /// Returns column `j` as a contiguous mutable slice.
+    ///
+    /// # Panics
+    ///
+    /// - `j` is out of bounds.
+    pub fn col_mut(&mut self, j: usize) -> &mut [T] {
+        assert!(j < self.cols(), "Column index is greater than column dimension.");
+        unsafe { self.t.get_row_unchecked_mut(j) }
+    }
+
+    /// Returns a view of the whole matrix.
+    pub fn as_slice(&self) -> ColumnMajorSlice<'_, T> {
+        ColumnMajorSlice { t: self.t.as_slice() }
+    }
+
+    /// Returns a view of the `rows x cols` block starting at `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, ColumnMajorMatrix};
+    ///
+    /// let a = Matrix::new(3, 3, (0..9).collect::<Vec<_>>()).to_column_major();
+    /// let b = a.sub_slice([1, 0], 2, 2);
+    ///
+    /// assert_eq!(b.to_row_major(), Matrix::new(2, 2, vec![3, 4, 6, 7]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The block exceeds the matrix dimensions.
+    pub fn sub_slice(&self, start: [usize; 2], rows: usize, cols: usize) -> ColumnMajorSlice<'_, T> {
+        self.as_slice().sub_slice(start, rows, cols)
+    }
+}
+
+impl<T: Copy> ColumnMajorMatrix<T> {
+    /// Copies the matrix into row-major order.
+    pub fn to_row_major(&self) -> Matrix<T> {
+        self.t.transpose()
+    }
+}
+
+impl<'a, T> ColumnMajorSlice<'a, T> {
+    /// Returns the number of rows in the slice.
+    pub fn rows(&self) -> usize {
+        self.t.cols()
+    }
+
+    /// Returns the number of columns in the slice.
+    pub fn cols(&self) -> usize {
+        self.t.rows()
+    }
+
+    /// Returns the distance in memory between the start of consecutive columns.
+    pub fn col_stride(&self) -> usize {
+        self.t.row_stride()
+    }
+
+    /// Returns a row-major view of the transpose of the slice.
+    pub fn transpose_view(&self) -> MatrixSlice<'a, T> {
+        unsafe {
+            MatrixSlice::from_raw_parts(self.t.as_ptr(),
+                                        self.t.rows(),
+                                        self.t.cols(),
+                                        self.t.row_stride())
+        }
+    }
+
+    /// Returns column `j` as a contiguous slice.
+    ///
+    /// # Panics
+    ///
+    /// - `j` is out of bounds.
+    pub fn col(&self, j: usize) -> &'a [T] {
+        assert!(j < self.cols(), "Column index is greater than column dimension.");
+        unsafe {
+            slice::from_raw_parts(self.t.as_ptr().offset((j * self.t.row_stride()) as isize),
+                                  self.t.cols())
+        }
+    }
+
+    /// Returns a view of the `rows x cols` block starting at `start`.
+    ///
+    /// # Panics
+    ///
+    /// - The block exceeds the slice dimensions.
+    pub fn sub_slice(&self, start: [usize; 2], rows: usize, cols: usize) -> ColumnMajorSlice<'a, T> {
+        ColumnMajorSlice { t: self.t.sub_slice([start[1], start[0]], cols, rows) }
+    }
+}
+
+impl<'a, T: Copy> ColumnMajorSlice<'a, T> {
+    /// Copies the slice into a row-major `Matrix`.
+    pub fn to_row_major(&self) -> Matrix<T> {
+        self.t.transpose()
+    }
+
+    /// Copies the slice into a `ColumnMajorMatrix`.
+    pub fn to_column_major(&self) -> ColumnMajorMatrix<T> {
+        ColumnMajorMatrix { t: self.t.into_matrix() }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Returns a column-major view of the transpose of the matrix.
+    ///
+    /// This takes `O(1)` time as no data is moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let t = a.transpose_view();
+    ///
+    /// assert_eq!(t.rows(), 3);
+    /// assert_eq!(t.col(1), &[4, 5, 6]);
+    /// ```
+    pub fn transpose_view(&self) -> ColumnMajorSlice<'_, T> {
+        ColumnMajorSlice { t: self.as_slice() }
+    }
+}
+
+impl<T: Copy> Matrix<T> {
+    /// Copies the matrix into column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let c = a.to_column_major();
+    ///
+    /// assert_eq!(*c.data(), vec![1, 3, 2, 4]);
+    /// assert_eq!(c.to_row_major(), a);
+    /// ```
+    pub fn to_column_major(&self) -> ColumnMajorMatrix<T> {
+        ColumnMajorMatrix { t: self.transpose() }
+    }
+}
+
+/// Indexes column-major matrix.
+///
+/// Takes row index first then column.
+impl<T> Index<[usize; 2]> for ColumnMajorMatrix<T> {
+    type Output = T;
+
+    fn index(&self, idx: [usize; 2]) -> &T {
+        check_index(idx, self.rows(), self.cols());
+        unsafe { self.t.get_unchecked([idx[1], idx[0]]) }
+    }
+}
+
+/// Indexes mutable column-major matrix.
+///
+/// Takes row index first then column.
+impl<T> IndexMut<[usize; 2]> for ColumnMajorMatrix<T> {
+    fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
+        check_index(idx, self.rows(), self.cols());
+        unsafe { self.t.get_unchecked_mut([idx[1], idx[0]]) }
+    }
+}
+
+/// Indexes column-major slice.
+///
+/// Takes row index first then column.
+impl<'a, T> Index<[usize; 2]> for ColumnMajorSlice<'a, T> {
+    type Output = T;
+
+    fn index(&self, idx: [usize; 2]) -> &T {
+        check_index(idx, self.rows(), self.cols());
+        unsafe { self.t.get_unchecked([idx[1], idx[0]]) }
+    }
+}
+
+/// Multiplies a row-major matrix by a column-major matrix.
+///
+/// Each entry is the dot product of a contiguous row and a contiguous column.
+impl<'a, 'b, T> Mul<&'b ColumnMajorMatrix<T>> for &'a Matrix<T>
+    where T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, m: &ColumnMajorMatrix<T>) -> Matrix<T> {
+        assert!(self.cols() == m.rows(), "Matrix dimensions do not agree.");
+
+        let mut data = Vec::with_capacity(self.rows() * m.cols());
+        for row in self.iter_rows() {
+            for j in 0..m.cols() {
+                data.push(utils::dot(row, m.col(j)));
+            }
+        }
+
+        Matrix::new(self.rows(), m.cols(), data)
+    }
+}
+
+/// Multiplies a column-major matrix by a row-major matrix.
+///
+/// The product is accumulated as a sum of outer products of the
+/// contiguous columns of `self` and rows of `m`.
+impl<'a, 'b, T> Mul<&'b Matrix<T>> for &'a ColumnMajorMatrix<T>
+    where T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, m: &Matrix<T>) -> Matrix<T> {
+        assert!(self.cols() == m.rows(), "Matrix dimensions do not agree.");
+
+        let mut out = Matrix::zeros(self.rows(), m.cols());
+        for (k, m_row) in m.iter_rows().enumerate() {
+            for (out_row, &a_ik) in out.iter_rows_mut().zip(self.col(k)) {
+                utils::in_place_vec_bin_op(out_row, m_row, |x, &y| *x = *x + a_ik * y);
+            }
+        }
+
+        out
+    }
+}
+
+/// Multiplies two column-major matrices.
+///
+/// Computed as the row-major product `(AB)ᵀ = BᵀAᵀ`.
+impl<'a, 'b, T> Mul<&'b ColumnMajorMatrix<T>> for &'a ColumnMajorMatrix<T>
+    where T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> + Any
+{
+    type Output = ColumnMajorMatrix<T>;
+
+    fn mul(self, m: &ColumnMajorMatrix<T>) -> ColumnMajorMatrix<T> {
+        assert!(self.cols() == m.rows(), "Matrix dimensions do not agree.");
+        ColumnMajorMatrix { t: &m.t * &self.t }
+    }
+}
+
+macro_rules! impl_mul_by_value (
+    ($lhs:ty, $rhs:ty, $out:ty $(, $extra:path)*) => (
+
+/// Multiplies matrices of mixed storage order.
+impl<T> Mul<$rhs> for $lhs
+    where T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T> $(+ $extra)*
+{
+    type Output = $out;
+
+    fn mul(self, m: $rhs) -> $out {
+        &self * &m
+    }
+}
+    );
+);
+
+impl_mul_by_value!(Matrix<T>, ColumnMajorMatrix<T>, Matrix<T>);
+impl_mul_by_value!(ColumnMajorMatrix<T>, Matrix<T>, Matrix<T>);
+impl_mul_by_value!(ColumnMajorMatrix<T>, ColumnMajorMatrix<T>, ColumnMajorMatrix<T>, Any);
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, BaseMatrix};
+    use super::ColumnMajorMatrix;
+
+    #[test]
+    fn test_column_major_round_trip() {
+        let a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        let c = a.to_column_major();
+
+        assert_eq!(c.rows(), 3);
+        assert_eq!(c.cols(), 2);
+        assert_eq!(*c.data(), vec![1, 3, 5, 2, 4, 6]);
+        assert_eq!(c.to_row_major(), a);
+
+        // Transposing between the orders reuses the buffer.
+        let ptr = a.data().as_ptr();
+        let t = ColumnMajorMatrix::from_transpose(a.clone());
+        assert_eq!(t.to_row_major(), a.transpose());
+
+        let back = ColumnMajorMatrix::from_transpose(a).into_transpose();
+        assert_eq!(back.data().as_ptr(), ptr);
+        assert_eq!(back, Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(c.transpose_view().into_matrix(), back.transpose());
+        assert_eq!(back.transpose_view().to_column_major().into_transpose(), back);
+    }
+
+    #[test]
+    fn test_column_major_index() {
+        let mut c = ColumnMajorMatrix::new(2, 3, vec![1, 4, 2, 5, 3, 6]);
+
+        assert_eq!(c[[1, 0]], 4);
+        assert_eq!(c.col(2), &[3, 6]);
+
+        c[[0, 1]] = 7;
+        c.col_mut(0)[1] = 8;
+        assert_eq!(c.to_row_major(), Matrix::new(2, 3, vec![1, 7, 3, 8, 5, 6]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_major_index_out_of_bounds() {
+        let c = ColumnMajorMatrix::new(2, 3, vec![0; 6]);
+        let _ = c[[2, 0]];
+    }
+
+    #[test]
+    fn test_mixed_order_mul() {
+        let a = Matrix::new(3, 4, (0..12).map(|x| x as f64 - 5.0).collect::<Vec<_>>());
+        let b = Matrix::new(4, 2, (0..8).map(|x| (x * x) as f64).collect::<Vec<_>>());
+        let expected = &a * &b;
+
+        let a_c = a.to_column_major();
+        let b_c = b.to_column_major();
+
+        assert_eq!(&a * &b_c, expected);
+        assert_eq!(&a_c * &b, expected);
+        assert_eq!((&a_c * &b_c).to_row_major(), expected);
+        assert_eq!(a * b_c, expected);
+    }
+
+    #[test]
+    fn test_column_major_slice() {
+        let a = Matrix::new(4, 4, (0..16).collect::<Vec<_>>());
+        let c = a.to_column_major();
+
+        let s = c.sub_slice([1, 2], 3, 2);
+        assert_eq!(s.rows(), 3);
+        assert_eq!(s.cols(), 2);
+        assert_eq!(s.col_stride(), 4);
+        assert_eq!(s[[0, 1]], 7);
+        assert_eq!(s.col(0), &[6, 10, 14]);
+        assert_eq!(s.to_row_major(), Matrix::new(3, 2, vec![6, 7, 10, 11, 14, 15]));
+
+        let inner = s.sub_slice([1, 1], 2, 1);
+        assert_eq!(inner.to_column_major().into_vec(), vec![11, 15]);
+    }
+}
@@ -0,0 +1,116 @@
+//! Operations specific to complex matrices.
+
+use std::ops::Neg;
+use libnum::Num;
+use num_complex::Complex;
+
+use matrix::{Matrix, BaseMatrix};
+
+impl<T: Copy + Num + Neg<Output = T>> Matrix<Complex<T>> {
+    /// Returns the element-wise complex conjugate of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::Complex;
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(1, 2, vec![Complex::new(1, 2), Complex::new(3, -4)]);
+    ///
+    /// assert_eq!(*a.conj().data(), vec![Complex::new(1, -2), Complex::new(3, 4)]);
+    /// ```
+    pub fn conj(&self) -> Matrix<Complex<T>> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|z| z.conj()).collect(),
+        }
+    }
+
+    /// Returns the adjoint (conjugate transpose) of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::Complex;
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(1, 2, vec![Complex::new(1, 2), Complex::new(3, -4)]);
+    /// let a_adj = a.adjoint();
+    ///
+    /// assert_eq!(a_adj.rows(), 2);
+    /// assert_eq!(*a_adj.data(), vec![Complex::new(1, -2), Complex::new(3, 4)]);
+    /// ```
+    pub fn adjoint(&self) -> Matrix<Complex<T>> {
+        let mut adj = self.transpose();
+        for z in adj.data.iter_mut() {
+            *z = z.conj();
+        }
+        adj
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex;
+    use matrix::{Matrix, BaseMatrix, Cholesky};
+    use error::ErrorKind;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn test_conj() {
+        let a = Matrix::new(2, 2, vec![c(1., 1.), c(0., -2.), c(3., 0.), c(-1., 4.)]);
+        let expected = Matrix::new(2, 2, vec![c(1., -1.), c(0., 2.), c(3., 0.), c(-1., -4.)]);
+
+        assert_eq!(a.conj(), expected);
+    }
+
+    #[test]
+    fn test_adjoint_adjoint() {
+        let a = Matrix::new(2, 3, vec![c(1., 1.), c(0., -2.), c(3., 0.),
+                                       c(-1., 4.), c(2., 2.), c(0., 5.)]);
+
+        assert_eq!(a.adjoint().rows(), 3);
+        assert_eq!(a.adjoint()[[2, 1]], c(0., -5.));
+        assert_eq!(a.adjoint().adjoint(), a);
+    }
+
+    #[test]
+    fn test_unitary_adjoint() {
+        let s = 0.5f64.sqrt();
+        let u = Matrix::new(2, 2, vec![c(s, 0.), c(0., s), c(0., s), c(s, 0.)]);
+
+        let id = &u * &u.adjoint();
+
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((id[[i, j]] - c(expected, 0.)).norm() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermitian_cholesky() {
+        let a = Matrix::new(2, 2, vec![c(2., 0.), c(1., 2.), c(1., -2.), c(3., 0.)]);
+        let l = Cholesky::decompose_hermitian(a.clone()).unwrap().unpack();
+
+        assert_eq!(l[[0, 1]], c(0., 0.));
+        assert_eq!(l[[1, 1]].im, 0.);
+
+        let reconstructed = &l * &l.adjoint();
+        for (x, y) in reconstructed.data().iter().zip(a.data().iter()) {
+            assert!((x - y).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_hermitian_cholesky_not_positive_definite() {
+        let a = Matrix::new(2, 2, vec![c(1., 0.), c(0., 2.), c(0., -2.), c(1., 0.)]);
+        let err = Cholesky::decompose_hermitian(a).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Indefinite);
+    }
+}
@@ -0,0 +1,811 @@
+use std::any::Any;
+
+use matrix::{Matrix, BaseMatrix, SymmetricMatrix, transpose_back_substitution_matrix};
+use vector::Vector;
+use error::{Error, ErrorKind};
+use Metric;
+use utils;
+use super::{check_finite_lower, lower_triangle, cholesky_in_place};
+
+use libnum::Float;
+use num_complex::Complex;
+
+/// Cholesky decomposition of a positive definite matrix.
+///
+/// Stores the lower triangular factor `L` such that `A = L Lᵀ`.
+/// The equivalent upper triangular factor is `R = Lᵀ`, with `A = Rᵀ R`,
+/// which is given by `unpack_upper` or computed by `decompose_upper`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, Cholesky};
+/// use rulinalg::vector::Vector;
+///
+/// let a = Matrix::new(2, 2, vec![4.0f64, 2.0, 2.0, 3.0]);
+/// let chol = Cholesky::decompose(a).unwrap();
+///
+/// let x = chol.solve(Vector::new(vec![2.0, 1.0])).unwrap();
+/// assert!((x[0] - 0.5).abs() < 1e-12);
+/// assert!(x[1].abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cholesky<T> {
+    l: Matrix<T>,
+}
+
+impl<T> Cholesky<T> {
+    /// Returns a reference to the lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// Consumes the decomposition and returns the lower triangular factor `L`.
+    pub fn unpack(self) -> Matrix<T> {
+        self.l
+    }
+}
+
+impl<T: Copy> Cholesky<T> {
+    /// Consumes the decomposition and returns the upper triangular factor `R`.
+    ///
+    /// The upper factor is `R = Lᵀ`, so that `A = Rᵀ R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 5.0]);
+    /// let r = Cholesky::decompose(a.clone()).unwrap().unpack_upper();
+    ///
+    /// assert_eq!(*r.data(), vec![2.0, 1.0, 0.0, 2.0]);
+    /// assert_eq!(r.transpose() * r, a);
+    /// ```
+    pub fn unpack_upper(self) -> Matrix<T> {
+        self.l.transpose()
+    }
+}
+
+impl<T: Any + Float> Cholesky<T> {
+    /// Computes the Cholesky decomposition of the given matrix.
+    ///
+    /// Only the lower triangular part of the matrix is read, so the
+    /// upper triangle may hold any values, including NaN.
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - The lower triangle contains a NaN or infinite value.
+    /// - Matrix is not positive definite.
+    pub fn decompose(matrix: Matrix<T>) -> Result<Cholesky<T>, Error> {
+        Cholesky::decompose_from(&matrix)
+    }
+
+    /// Computes the Cholesky decomposition of a matrix or matrix slice.
+    ///
+    /// Only the lower triangle is copied into the storage for the
+    /// factor, so a block of a larger matrix can be factored without
+    /// first copying it into a new `Matrix`. The factor is identical
+    /// to the one given by `decompose`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, MatrixSlice, Cholesky};
+    ///
+    /// let a = Matrix::new(3, 3, vec![9.0, 0.0, 0.0,
+    ///                                0.0, 4.0, 2.0,
+    ///                                0.0, 2.0, 5.0]);
+    /// let block = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+    /// let chol = Cholesky::decompose_from(&block).unwrap();
+    ///
+    /// assert_eq!(*chol.l().data(), vec![2.0, 0.0, 1.0, 2.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - The lower triangle contains a NaN or infinite value.
+    /// - Matrix is not positive definite.
+    pub fn decompose_from<M: BaseMatrix<T>>(matrix: &M) -> Result<Cholesky<T>, Error> {
+        assert!(matrix.rows() == matrix.cols(),
+                "Matrix must be square for Cholesky decomposition.");
+        check_finite_lower(matrix)?;
+
+        let n = matrix.rows();
+        let mut l = lower_triangle(matrix);
+        cholesky_in_place(&mut l, n, |i| i * n)?;
+
+        Ok(Cholesky { l: Matrix::new(n, n, l) })
+    }
+
+    /// Computes the Cholesky decomposition without consuming the matrix.
+    ///
+    /// The factor is identical to the one given by `decompose`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 5.0]);
+    /// let chol = Cholesky::decompose_ref(&a).unwrap();
+    ///
+    /// assert_eq!(chol.reconstruct(), a);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - The lower triangle contains a NaN or infinite value.
+    /// - Matrix is not positive definite.
+    pub fn decompose_ref(matrix: &Matrix<T>) -> Result<Cholesky<T>, Error> {
+        Cholesky::decompose_from(matrix)
+    }
+
+    /// Computes the upper triangular Cholesky factor `R` with `A = Rᵀ R`.
+    ///
+    /// This gives the same matrix as `unpack_upper`, but computes `R`
+    /// directly. Each step updates whole rows of the trailing
+    /// submatrix, which suits the row-major storage.
+    ///
+    /// Only the upper triangular part of the matrix is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 5.0]);
+    /// let r = Cholesky::decompose_upper(a.clone()).unwrap();
+    ///
+    /// assert_eq!(r.transpose() * r, a);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - Matrix is not positive definite.
+    pub fn decompose_upper(matrix: Matrix<T>) -> Result<Matrix<T>, Error> {
+        assert!(matrix.rows() == matrix.cols(),
+                "Matrix must be square for Cholesky decomposition.");
+
+        let n = matrix.rows();
+        let mut r = matrix.into_vec();
+
+        for k in 0..n {
+            let (head, trailing) = r.split_at_mut((k + 1) * n);
+            let row_k = &mut head[k * n..];
+
+            let diag = row_k[k];
+            if !(diag > T::zero()) {
+                return Err(Error::new(ErrorKind::Indefinite,
+                                      "Matrix is not positive definite."));
+            }
+
+            let r_kk = diag.sqrt();
+            row_k[k] = r_kk;
+            for x in &mut row_k[..k] {
+                *x = T::zero();
+            }
+            for x in &mut row_k[k + 1..] {
+                *x = *x / r_kk;
+            }
+
+            // Subtract the outer product of row k from the trailing rows.
+            for (offset, row_i) in trailing.chunks_mut(n).enumerate() {
+                let i = k + 1 + offset;
+                let r_ki = row_k[i];
+                for (x, &r_kj) in row_i[i..].iter_mut().zip(&row_k[i..]) {
+                    *x = *x - r_ki * r_kj;
+                }
+            }
+        }
+
+        Ok(Matrix::new(n, n, r))
+    }
+
+    /// Solves the linear system `Ax = b` using the decomposition.
+    ///
+    /// # Panics
+    ///
+    /// - The size of `b` does not match the dimension of the matrix.
+    ///
+    /// # Failures
+    ///
+    /// - The factor `L` is singular.
+    pub fn solve(&self, b: Vector<T>) -> Result<Vector<T>, Error> {
+        assert!(b.size() == self.l.rows(),
+                "Vector size must match the dimension of the decomposed matrix.");
+
+        let n = b.size();
+        let y = self.l.solve_l_triangular(b)?;
+        let x = transpose_back_substitution_matrix(&self.l, Matrix::new(n, 1, y.into_vec()))?;
+        Ok(Vector::new(x.into_vec()))
+    }
+
+    /// Solves `Ax = b` using the decomposition followed by iterative refinement.
+    ///
+    /// After the initial solve, the residual `r = b - Ax` is computed
+    /// with compensated summation using the original matrix `a`, and
+    /// the correction `A dx = r` is solved with the decomposition.
+    /// This repeats until `||r|| < tol * ||b||` or `max_iter`
+    /// refinement steps are taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Cholesky};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 3.0]);
+    /// let chol = Cholesky::decompose(a.clone()).unwrap();
+    ///
+    /// let x = chol.solve_refined(&a, Vector::new(vec![2.0, 1.0]), 1e-14, 3).unwrap();
+    /// assert_eq!(x, Vector::new(vec![0.5, 0.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The dimensions of `a` or `b` do not match the decomposed matrix.
+    ///
+    /// # Failures
+    ///
+    /// - The factor `L` is singular.
+    pub fn solve_refined(&self,
+                         a: &Matrix<T>,
+                         b: Vector<T>,
+                         tol: T,
+                         max_iter: usize)
+                         -> Result<Vector<T>, Error> {
+        assert!(a.rows() == self.l.rows() && a.cols() == self.l.cols(),
+                "Matrix dimensions must match the decomposed matrix.");
+
+        let mut x = self.solve(b.clone())?;
+        let threshold = tol * b.norm();
+
+        for _ in 0..max_iter {
+            // The residual suffers from cancellation as x converges,
+            // so it is accumulated with compensated dot products.
+            let r = Vector::new(a.iter_rows()
+                .zip(b.iter())
+                .map(|(row, &b_i)| b_i - utils::dot_compensated(row, x.data()))
+                .collect::<Vec<_>>());
+            if r.norm() < threshold {
+                break;
+            }
+            x = x + self.solve(r)?;
+        }
+
+        Ok(x)
+    }
+
+    /// Reconstructs the decomposed matrix as `L Lᵀ`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 5.0]);
+    /// let chol = Cholesky::decompose(a.clone()).unwrap();
+    ///
+    /// assert_eq!(chol.reconstruct(), a);
+    /// ```
+    pub fn reconstruct(&self) -> Matrix<T> {
+        &self.l * self.l.transpose()
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    ///
+    /// This is `Π l_ii²`. For large matrices the product may overflow,
+    /// in which case `logdet` should be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0f64, 2.0, 2.0, 3.0]);
+    /// let det = Cholesky::decompose(a).unwrap().det();
+    ///
+    /// assert!((det - 8.0).abs() < 1e-12);
+    /// ```
+    pub fn det(&self) -> T {
+        let mut prod = T::one();
+        for i in 0..self.l.rows() {
+            prod = prod * self.l[[i, i]];
+        }
+        prod * prod
+    }
+
+    /// Computes the log of the determinant of the decomposed matrix.
+    ///
+    /// This is `2 Σ ln(l_ii)`, which does not overflow where the
+    /// determinant itself would. The determinant of a positive
+    /// definite matrix is always positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0f64, 2.0, 2.0, 3.0]);
+    /// let logdet = Cholesky::decompose(a).unwrap().logdet();
+    ///
+    /// assert!((logdet - 8.0f64.ln()).abs() < 1e-12);
+    /// ```
+    pub fn logdet(&self) -> T {
+        let mut sum = T::zero();
+        for i in 0..self.l.rows() {
+            sum = sum + self.l[[i, i]].ln();
+        }
+        sum + sum
+    }
+}
+
+impl<T: Any + Float> SymmetricMatrix<T> {
+    /// Computes the Cholesky decomposition of the matrix.
+    ///
+    /// The factorization runs on a copy of the packed lower triangle,
+    /// so the dense matrix is never formed. The factor is identical to
+    /// the one given by `Cholesky::decompose` on the dense matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::SymmetricMatrix;
+    ///
+    /// let sym = SymmetricMatrix::new(2, vec![4.0, 2.0, 5.0]);
+    /// let chol = sym.cholesky().unwrap();
+    ///
+    /// assert_eq!(*chol.l().data(), vec![2.0, 0.0, 1.0, 2.0]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Matrix is not positive definite.
+    pub fn cholesky(&self) -> Result<Cholesky<T>, Error> {
+        let n = self.size();
+        let mut packed = self.data().clone();
+        cholesky_in_place(&mut packed, n, |i| i * (i + 1) / 2)?;
+
+        let mut l = vec![T::zero(); n * n];
+        let mut offset = 0;
+        for i in 0..n {
+            l[i * n..i * n + i + 1].copy_from_slice(&packed[offset..offset + i + 1]);
+            offset += i + 1;
+        }
+
+        Ok(Cholesky { l: Matrix::new(n, n, l) })
+    }
+}
+
+/// The minimum number of multiplications given to each parallel task
+/// when updating a column in `par_decompose`.
+#[cfg(feature = "rayon")]
+const PAR_CHOLESKY_GRAIN: usize = 1 << 12;
+
+#[cfg(feature = "rayon")]
+impl<T: Any + Float + Send + Sync> Cholesky<T> {
+    /// Computes the Cholesky decomposition using multiple threads.
+    ///
+    /// The factor is computed one column at a time, with the entries
+    /// below the diagonal updated in parallel. Each entry is computed
+    /// with the same operations as in `decompose`, so for a positive
+    /// definite matrix the factors are identical.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 5.0]);
+    /// let chol = Cholesky::par_decompose(a.clone()).unwrap();
+    ///
+    /// assert_eq!(chol.l(), Cholesky::decompose(a).unwrap().l());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - The lower triangle contains a NaN or infinite value.
+    /// - Matrix is not positive definite.
+    pub fn par_decompose(matrix: Matrix<T>) -> Result<Cholesky<T>, Error> {
+        use std::cmp;
+        use rayon::prelude::*;
+
+        assert!(matrix.rows() == matrix.cols(),
+                "Matrix must be square for Cholesky decomposition.");
+        check_finite_lower(&matrix)?;
+
+        let n = matrix.rows();
+        let mut l = matrix.into_vec();
+
+        for i in 0..n {
+            for x in &mut l[i * n + i + 1..(i + 1) * n] {
+                *x = T::zero();
+            }
+        }
+
+        for j in 0..n {
+            let (head, tail) = l.split_at_mut((j + 1) * n);
+            let row_j = &mut head[j * n..j * n + j + 1];
+
+            let d = (row_j[j] - utils::dot_simd(&row_j[..j], &row_j[..j])).sqrt();
+            if !(d > T::zero()) {
+                return Err(Error::new(ErrorKind::Indefinite,
+                                      "Matrix is not positive definite."));
+            }
+            row_j[j] = d;
+
+            let row_j = &row_j[..j];
+            let finite = tail.par_chunks_mut(n)
+                .with_min_len(cmp::max(1, PAR_CHOLESKY_GRAIN / (j + 1)))
+                .all(|row_i| {
+                    let p = (row_i[j] - utils::dot_simd(&row_i[..j], row_j)) / d;
+                    row_i[j] = p;
+                    p.is_finite()
+                });
+
+            if !finite {
+                return Err(Error::new(ErrorKind::Indefinite,
+                                      "Matrix is not positive definite."));
+            }
+        }
+
+        Ok(Cholesky { l: Matrix::new(n, n, l) })
+    }
+}
+
+impl<T: Any + Float> Cholesky<Complex<T>> {
+    /// Computes the Cholesky decomposition of a Hermitian positive definite matrix.
+    ///
+    /// The factor `L` satisfies `A = L Lᴴ`, where `Lᴴ` is the conjugate transpose.
+    /// Only the lower triangular part of the matrix is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::Complex;
+    /// use rulinalg::matrix::{Matrix, Cholesky};
+    ///
+    /// let a = Matrix::new(2, 2, vec![Complex::new(2.0, 0.0), Complex::new(1.0, 2.0),
+    ///                                Complex::new(1.0, -2.0), Complex::new(3.0, 0.0)]);
+    ///
+    /// let chol = Cholesky::decompose_hermitian(a).unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - Matrix is not positive definite.
+    pub fn decompose_hermitian(matrix: Matrix<Complex<T>>) -> Result<Cholesky<Complex<T>>, Error> {
+        assert!(matrix.rows() == matrix.cols(),
+                "Matrix must be square for Cholesky decomposition.");
+
+        let n = matrix.rows();
+        let mut l = Matrix::<Complex<T>>::zeros(n, n);
+
+        for j in 0..n {
+            let mut diag = matrix[[j, j]].re;
+            for k in 0..j {
+                diag = diag - l[[j, k]].norm_sqr();
+            }
+
+            if !(diag > T::zero()) {
+                return Err(Error::new(ErrorKind::Indefinite,
+                                      "Matrix is not positive definite."));
+            }
+
+            let l_jj = diag.sqrt();
+            l[[j, j]] = Complex::new(l_jj, T::zero());
+
+            for i in j + 1..n {
+                let mut sum = matrix[[i, j]];
+                for k in 0..j {
+                    sum = sum - l[[i, k]] * l[[j, k]].conj();
+                }
+                l[[i, j]] = sum.unscale(l_jj);
+            }
+        }
+
+        Ok(Cholesky { l: l })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, BaseMatrix};
+    use vector::Vector;
+    use Metric;
+    use error::ErrorKind;
+    use super::Cholesky;
+
+    #[test]
+    fn test_cholesky_decompose() {
+        let a = Matrix::new(3, 3, vec![4.0, 12.0, -16.0,
+                                       12.0, 37.0, -43.0,
+                                       -16.0, -43.0, 98.0]);
+        let chol = Cholesky::decompose(a.clone()).unwrap();
+
+        let l = chol.l();
+        assert_eq!(*l.data(), vec![2.0, 0.0, 0.0,
+                                   6.0, 1.0, 0.0,
+                                   -8.0, 5.0, 3.0]);
+
+        let reconstructed = l * l.transpose();
+        assert_eq!(reconstructed, a);
+    }
+
+    #[test]
+    fn test_cholesky_decompose_ref() {
+        let a = Matrix::new(3, 3, vec![4.0, 12.0, -16.0,
+                                       12.0, 37.0, -43.0,
+                                       -16.0, -43.0, 98.0]);
+        let copy = a.clone();
+
+        let by_ref = Cholesky::decompose_ref(&a).unwrap();
+        assert_eq!(a, copy);
+
+        let by_value = Cholesky::decompose(a).unwrap();
+        assert_eq!(by_ref.l(), by_value.l());
+
+        let not_pd = Matrix::new(3, 3, vec![1.0, 2.0, 0.0,
+                                            2.0, 1.0, 0.0,
+                                            0.0, 0.0, 1.0]);
+        let err = Cholesky::decompose_ref(&not_pd).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Indefinite);
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let a = Matrix::new(3, 3, vec![4.0, 12.0, -16.0,
+                                       12.0, 37.0, -43.0,
+                                       -16.0, -43.0, 98.0]);
+        let b = Vector::new(vec![1.0, 2.0, 3.0]);
+
+        let x = Cholesky::decompose(a.clone()).unwrap().solve(b.clone()).unwrap();
+        let residual: Vector<f64> = &a * &x - b;
+
+        for r in residual.iter() {
+            assert!(r.abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_cholesky_solve_refined() {
+        // Factorize a Hilbert matrix rounded to single precision, so the
+        // initial solve is inaccurate and refinement has work to do.
+        let n = 5;
+        let a = Matrix::new(n, n, (0..n * n)
+            .map(|k| 1.0 / ((k / n + k % n + 1) as f64))
+            .collect::<Vec<_>>());
+        let chol = Cholesky::decompose(a.map(|x| x as f32 as f64)).unwrap();
+
+        let x_true = Vector::new(vec![1.0; n]);
+        let b = &a * &x_true;
+
+        let x0 = chol.solve_refined(&a, b.clone(), 0.0, 0).unwrap();
+        let x2 = chol.solve_refined(&a, b.clone(), 0.0, 2).unwrap();
+
+        let err0 = (&x0 - &x_true).norm();
+        let err2 = (&x2 - &x_true).norm();
+        assert!(err0 > 1e-4);
+        assert!(err2 < err0 * 1e-4);
+    }
+
+    #[test]
+    fn test_cholesky_solve_refined_early_exit() {
+        let a = Matrix::new(3, 3, vec![4.0, 12.0, -16.0,
+                                       12.0, 37.0, -43.0,
+                                       -16.0, -43.0, 98.0]);
+        let b = Vector::new(vec![1.0, 2.0, 3.0]);
+        let chol = Cholesky::decompose(a.clone()).unwrap();
+
+        // The initial solution already meets the tolerance, so no
+        // refinement step is taken.
+        let x = chol.solve(b.clone()).unwrap();
+        let refined = chol.solve_refined(&a, b, 1e-6, 100).unwrap();
+        assert_eq!(refined, x);
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        let a = Matrix::new(3, 3, vec![1.0, 2.0, 0.0,
+                                       2.0, 1.0, 0.0,
+                                       0.0, 0.0, 1.0]);
+        let err = Cholesky::decompose(a).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Indefinite);
+    }
+
+    #[test]
+    fn test_cholesky_logdet() {
+        let a = Matrix::new(3, 3, vec![4.0, 12.0, -16.0,
+                                       12.0, 37.0, -43.0,
+                                       -16.0, -43.0, 98.0]);
+        let logdet: f64 = Cholesky::decompose(a.clone()).unwrap().logdet();
+
+        assert!((logdet.exp() - a.det()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_cholesky_det() {
+        let a = Matrix::new(3, 3, vec![4.0, 12.0, -16.0,
+                                       12.0, 37.0, -43.0,
+                                       -16.0, -43.0, 98.0]);
+        let chol: Cholesky<f64> = Cholesky::decompose(a).unwrap();
+
+        assert!((chol.det() - 36.0).abs() < 1e-10);
+        assert!((chol.det().ln() - chol.logdet()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cholesky_reconstruct() {
+        let a = Matrix::new(4, 4, vec![10.0f64, 1.0, 2.0, -1.0,
+                                       1.0, 8.0, 0.5, 0.0,
+                                       2.0, 0.5, 6.0, 1.5,
+                                       -1.0, 0.0, 1.5, 5.0]);
+        let b = Cholesky::decompose(a.clone()).unwrap().reconstruct();
+
+        for (x, y) in b.data().iter().zip(a.data()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_decompose_matches_serial() {
+        for &n in &[10, 25, 50, 100, 200] {
+            // BᵀB + nI is symmetric positive definite.
+            let b = Matrix::new(n, n, (0..n * n).map(|x| ((x * 7 + 3) % 17) as f64 / 17.0 - 0.5)
+                                                .collect::<Vec<_>>());
+            let a = b.transpose() * &b + Matrix::<f64>::identity(n) * n as f64;
+
+            let serial = Cholesky::decompose(a.clone()).unwrap();
+            let parallel = Cholesky::par_decompose(a).unwrap();
+            assert_eq!(serial.l(), parallel.l());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_decompose_not_positive_definite() {
+        let a = Matrix::new(3, 3, vec![1.0, 2.0, 0.0,
+                                       2.0, 1.0, 0.0,
+                                       0.0, 0.0, 1.0]);
+        let err = Cholesky::par_decompose(a).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Indefinite);
+
+        let a = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, -1.0]);
+        let err = Cholesky::par_decompose(a).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Indefinite);
+    }
+
+    #[test]
+    fn test_decompose_from_matches_decompose() {
+        let a = Matrix::new(4, 4, vec![10.0f64, 1.0, 2.0, -1.0,
+                                       1.0, 8.0, 0.5, 0.0,
+                                       2.0, 0.5, 6.0, 1.5,
+                                       -1.0, 0.0, 1.5, 5.0]);
+
+        let from = Cholesky::decompose_from(&a).unwrap();
+        let owned = Cholesky::decompose(a.clone()).unwrap();
+        assert_eq!(from.l(), owned.l());
+        assert_eq!(from.l(), &a.cholesky().unwrap());
+    }
+
+    #[test]
+    fn test_decompose_from_slice() {
+        use matrix::MatrixSlice;
+
+        // Embed a positive definite block inside a larger matrix whose
+        // other entries would not be positive definite.
+        let block = Matrix::new(3, 3, vec![4.0, 2.0, -2.0,
+                                           2.0, 10.0, 2.0,
+                                           -2.0, 2.0, 6.0]);
+        let mut big = Matrix::new(5, 6, vec![-1.0; 30]);
+        for i in 0..3 {
+            for j in 0..3 {
+                big[[i + 1, j + 2]] = block[[i, j]];
+            }
+        }
+
+        let slice = MatrixSlice::from_matrix(&big, [1, 2], 3, 3);
+        let from_slice = Cholesky::decompose_from(&slice).unwrap();
+        let expected = Cholesky::decompose(block.clone()).unwrap();
+
+        assert_eq!(from_slice.l(), expected.l());
+        assert_eq!(from_slice.reconstruct(), block);
+    }
+
+    #[test]
+    fn test_cholesky_upper() {
+        let a = Matrix::new(4, 4, vec![10.0f64, 1.0, 2.0, -1.0,
+                                       1.0, 8.0, 0.5, 0.0,
+                                       2.0, 0.5, 6.0, 1.5,
+                                       -1.0, 0.0, 1.5, 5.0]);
+
+        let chol = Cholesky::decompose(a.clone()).unwrap();
+        let l = chol.clone().unpack();
+        let r = chol.unpack_upper();
+        assert_eq!(r, l.transpose());
+
+        let direct = Cholesky::decompose_upper(a.clone()).unwrap();
+        assert!(direct.is_upper_triangular());
+
+        let reconstructed = direct.transpose() * &direct;
+        for (x, y) in reconstructed.data().iter().zip(a.data()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+        for (x, y) in direct.data().iter().zip(r.data()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cholesky_non_finite() {
+        use std::f64;
+
+        let mut a = Matrix::<f64>::identity(4) * 2.0;
+        a[[3, 1]] = f64::NAN;
+
+        let err = Cholesky::decompose(a.clone()).unwrap_err();
+        match *err.kind() {
+            ErrorKind::InvalidArg => {}
+            _ => panic!("Expected InvalidArg error."),
+        }
+        assert_eq!(err.to_string(), "Matrix has a non-finite value at (3, 1).");
+
+        a[[3, 1]] = 0.0;
+        a[[2, 2]] = f64::INFINITY;
+        let err = Cholesky::decompose_ref(&a).unwrap_err();
+        assert_eq!(err.to_string(), "Matrix has a non-finite value at (2, 2).");
+    }
+
+    #[test]
+    fn test_cholesky_ignores_upper_triangle() {
+        use std::f64;
+
+        let a = Matrix::new(3, 3, vec![4.0, f64::NAN, f64::NEG_INFINITY,
+                                       2.0, 5.0, f64::NAN,
+                                       0.0, 1.0, 3.0]);
+        let expected = Cholesky::decompose(Matrix::new(3, 3, vec![4.0, 2.0, 0.0,
+                                                                  2.0, 5.0, 1.0,
+                                                                  0.0, 1.0, 3.0]))
+            .unwrap();
+
+        assert_eq!(Cholesky::decompose_ref(&a).unwrap().l(), expected.l());
+        assert_eq!(Cholesky::decompose(a).unwrap().l(), expected.l());
+    }
+
+    #[test]
+    fn test_cholesky_upper_not_positive_definite() {
+        let a = Matrix::new(3, 3, vec![1.0, 2.0, 0.0,
+                                       2.0, 1.0, 0.0,
+                                       0.0, 0.0, 1.0]);
+        let err = Cholesky::decompose_upper(a).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Indefinite);
+    }
+}
@@ -0,0 +1,434 @@
+use std::any::Any;
+
+use matrix::{Matrix, BaseMatrix, back_substitution, unit_forward_substitution, parity};
+use vector::Vector;
+use error::{Error, ErrorKind};
+use super::check_finite;
+use utils;
+
+use libnum::Float;
+
+/// LU decomposition with partial pivoting.
+///
+/// Stores the factors of `PA = LU`, where `P` is a permutation
+/// matrix, `L` is unit lower triangular and `U` is upper triangular.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, PartialPivLu};
+/// use rulinalg::vector::Vector;
+///
+/// let a = Matrix::new(2, 2, vec![2.0f64, 3.0, 1.0, 2.0]);
+/// let lu = PartialPivLu::decompose(a).unwrap();
+///
+/// let x = lu.solve(Vector::new(vec![13.0, 8.0])).unwrap();
+/// assert!((x[0] - 2.0).abs() < 1e-12);
+/// assert!((x[1] - 3.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartialPivLu<T> {
+    l: Matrix<T>,
+    u: Matrix<T>,
+    p: Matrix<T>,
+}
+
+impl<T> PartialPivLu<T> {
+    /// Returns a reference to the unit lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// Returns a reference to the upper triangular factor `U`.
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// Returns a reference to the permutation matrix `P`.
+    pub fn p(&self) -> &Matrix<T> {
+        &self.p
+    }
+
+    /// Consumes the decomposition and returns `(L, U, P)`.
+    pub fn unpack(self) -> (Matrix<T>, Matrix<T>, Matrix<T>) {
+        (self.l, self.u, self.p)
+    }
+}
+
+impl<T: Any + Float> PartialPivLu<T> {
+    /// Computes the LU decomposition of the given matrix.
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - The matrix contains a NaN or infinite value.
+    /// - Matrix cannot be LUP decomposed.
+    pub fn decompose(matrix: Matrix<T>) -> Result<PartialPivLu<T>, Error> {
+        check_finite(&matrix)?;
+        let (l, u, p) = matrix.lup_decomp()?;
+
+        Ok(PartialPivLu {
+            l: l,
+            u: u,
+            p: p,
+        })
+    }
+
+    /// Solves the linear system `Ax = b` using the decomposition.
+    ///
+    /// # Panics
+    ///
+    /// - The size of `b` does not match the dimension of the matrix.
+    ///
+    /// # Failures
+    ///
+    /// - The matrix is singular.
+    pub fn solve(&self, b: Vector<T>) -> Result<Vector<T>, Error> {
+        assert!(b.size() == self.l.rows(),
+                "Vector size must match the dimension of the decomposed matrix.");
+
+        let y = unit_forward_substitution(&self.l, &self.p * b);
+        back_substitution(&self.u, y)
+    }
+
+    /// Solves the linear system `Ax = b` with iterative refinement.
+    ///
+    /// After the initial solve, the residual `r = b - Ax` is computed
+    /// in the original matrix `a` and the correction from solving
+    /// `A dx = r` is added to `x`. This is repeated `iters` times.
+    /// The residual is accumulated with a compensated dot product,
+    /// which lets refinement recover accuracy lost to ill-conditioning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, PartialPivLu};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![2.0f64, 3.0, 1.0, 2.0]);
+    /// let lu = PartialPivLu::decompose(a.clone()).unwrap();
+    ///
+    /// let x = lu.solve_refined(&a, Vector::new(vec![13.0, 8.0]), 2).unwrap();
+    /// assert!((x[0] - 2.0).abs() < 1e-15);
+    /// assert!((x[1] - 3.0).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `a` does not have the dimensions of the decomposed matrix.
+    /// - The size of `b` does not match the dimension of the matrix.
+    ///
+    /// # Failures
+    ///
+    /// - The matrix is singular.
+    pub fn solve_refined(&self, a: &Matrix<T>, b: Vector<T>, iters: usize) -> Result<Vector<T>, Error> {
+        let n = self.l.rows();
+        assert!(a.rows() == n && a.cols() == n,
+                "Matrix must have the dimensions of the decomposed matrix.");
+
+        let mut x = self.solve(b.clone())?;
+
+        // Each row of `a` is extended by `b[i]` and `x` by `-1`, so that
+        // the compensated dot product gives the residual in one sum.
+        let mut row = Vec::with_capacity(n + 1);
+        let mut neg_x = Vec::with_capacity(n + 1);
+        for _ in 0..iters {
+            neg_x.clear();
+            neg_x.extend(x.iter().map(|&x_i| -x_i));
+            neg_x.push(T::one());
+
+            let r = a.iter_rows()
+                .zip(b.iter())
+                .map(|(a_row, &b_i)| {
+                    row.clear();
+                    row.extend_from_slice(a_row);
+                    row.push(b_i);
+                    utils::dot_compensated(&row, &neg_x)
+                })
+                .collect::<Vec<_>>();
+
+            x = x + self.solve(Vector::new(r))?;
+        }
+
+        Ok(x)
+    }
+
+    /// Solves the transposed linear system `Aᵀx = b` using the decomposition.
+    ///
+    /// Since `Aᵀ = UᵀLᵀP`, this solves `Uᵀz = b` and `Lᵀw = z`
+    /// and then applies the inverse permutation to `w`. The factors
+    /// are read row by row, so no transpose is formed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, PartialPivLu};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![2.0f64, 1.0, 3.0, 2.0]);
+    /// let lu = PartialPivLu::decompose(a).unwrap();
+    ///
+    /// // Aᵀ = [[2, 3], [1, 2]]
+    /// let x = lu.solve_transpose(Vector::new(vec![13.0, 8.0])).unwrap();
+    /// assert!((x[0] - 2.0).abs() < 1e-12);
+    /// assert!((x[1] - 3.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The size of `b` does not match the dimension of the matrix.
+    ///
+    /// # Failures
+    ///
+    /// - The matrix is singular.
+    pub fn solve_transpose(&self, b: Vector<T>) -> Result<Vector<T>, Error> {
+        let n = self.l.rows();
+        assert!(b.size() == n,
+                "Vector size must match the dimension of the decomposed matrix.");
+
+        let mut z = b.into_vec();
+
+        // Forward substitution with Uᵀ, eliminating using the rows of U.
+        for (i, u_row) in self.u.iter_rows().enumerate() {
+            let diag = u_row[i];
+            if diag.abs() < T::min_positive_value() + T::min_positive_value() {
+                return Err(Error::new(ErrorKind::AlgebraFailure,
+                                      "Linear system cannot be solved (matrix is singular)."));
+            }
+
+            z[i] = z[i] / diag;
+            let z_i = z[i];
+            for (z_j, &u_ij) in z[i + 1..].iter_mut().zip(&u_row[i + 1..]) {
+                *z_j = *z_j - u_ij * z_i;
+            }
+        }
+
+        // Back substitution with Lᵀ, eliminating using the rows of L.
+        for i in (0..n).rev() {
+            let l_row = &self.l.data()[i * n..(i + 1) * n];
+            z[i] = z[i] / l_row[i];
+            let z_i = z[i];
+            for (z_j, &l_ij) in z[..i].iter_mut().zip(&l_row[..i]) {
+                *z_j = *z_j - l_ij * z_i;
+            }
+        }
+
+        // x = Pᵀw, where row i of P moves entry j to position i.
+        let mut x = vec![T::zero(); n];
+        for (i, p_row) in self.p.iter_rows().enumerate() {
+            for (j, &p_ij) in p_row.iter().enumerate() {
+                if p_ij == T::one() {
+                    x[j] = z[i];
+                }
+            }
+        }
+
+        Ok(Vector::new(x))
+    }
+
+    /// Reconstructs the decomposed matrix as `PᵀLU`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, PartialPivLu};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let lu = PartialPivLu::decompose(a.clone()).unwrap();
+    ///
+    /// assert_eq!(lu.reconstruct(), a);
+    /// ```
+    pub fn reconstruct(&self) -> Matrix<T> {
+        self.p.transpose() * (&self.l * &self.u)
+    }
+
+    /// Computes the log of the absolute determinant and its sign.
+    ///
+    /// Returns `(ln|det(A)|, sign)` where `sign` is `1`, `-1` or `0`.
+    /// The logs of the diagonal entries of `U` are summed, so this
+    /// does not overflow for large matrices where `det` would.
+    /// A singular matrix gives `(-inf, 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, PartialPivLu};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0f64, 2.0, 3.0, 4.0]);
+    /// let (logdet, sign) = PartialPivLu::decompose(a).unwrap().logdet();
+    ///
+    /// assert_eq!(sign, -1);
+    /// assert!((logdet - 2.0f64.ln()).abs() < 1e-12);
+    /// ```
+    pub fn logdet(&self) -> (T, i8) {
+        let mut logdet = T::zero();
+        let mut sign = if parity(&self.p) < T::zero() { -1 } else { 1 };
+
+        for i in 0..self.u.rows() {
+            let u_ii = self.u[[i, i]];
+            if u_ii == T::zero() {
+                return (T::neg_infinity(), 0);
+            }
+            if u_ii < T::zero() {
+                sign = -sign;
+            }
+            logdet = logdet + u_ii.abs().ln();
+        }
+
+        (logdet, sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, BaseMatrix};
+    use vector::Vector;
+    use error::ErrorKind;
+    use super::PartialPivLu;
+
+    fn assert_vec_close(x: &Vector<f64>, y: &Vector<f64>) {
+        assert_eq!(x.size(), y.size());
+        for (a, b) in x.iter().zip(y.iter()) {
+            assert!((a - b).abs() < 1e-10, "{} != {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let a = Matrix::new(3, 3, vec![1.0, 2.0, 0.0,
+                                       0.0, 3.0, 4.0,
+                                       5.0, 1.0, 2.0]);
+        let b = Vector::new(vec![1.0, -2.0, 3.0]);
+
+        let x = PartialPivLu::decompose(a.clone()).unwrap().solve(b.clone()).unwrap();
+        assert_vec_close(&(&a * x), &b);
+    }
+
+    #[test]
+    fn test_lu_solve_refined_hilbert() {
+        use utils;
+
+        let n = 10;
+        let h = Matrix::new(n, n, (0..n * n)
+            .map(|k| 1.0 / ((k / n + k % n + 1) as f64))
+            .collect::<Vec<_>>());
+        let b = Vector::new((0..n).map(|i| (i as f64 + 1.0).sqrt()).collect::<Vec<_>>());
+
+        // Residual computed accurately, so it is not hidden by rounding.
+        let residual = |x: &Vector<f64>| {
+            h.iter_rows()
+                .zip(b.iter())
+                .map(|(row, &b_i)| {
+                    let mut ext = row.to_vec();
+                    ext.push(b_i);
+                    let mut neg_x = x.iter().map(|x_i| -x_i).collect::<Vec<_>>();
+                    neg_x.push(1.0);
+                    utils::dot_compensated(&ext, &neg_x).powi(2)
+                })
+                .sum::<f64>()
+                .sqrt()
+        };
+
+        let lu = PartialPivLu::decompose(h.clone()).unwrap();
+        let x0 = lu.solve(b.clone()).unwrap();
+        let x1 = lu.solve_refined(&h, b.clone(), 3).unwrap();
+
+        assert!(residual(&x1) < 0.1 * residual(&x0));
+        assert_eq!(lu.solve_refined(&h, b.clone(), 0).unwrap(), x0);
+    }
+
+    #[test]
+    fn test_lu_solve_transpose() {
+        let systems = vec![
+            Matrix::new(3, 3, vec![1.0, 2.0, 0.0,
+                                   0.0, 3.0, 4.0,
+                                   5.0, 1.0, 2.0]),
+            Matrix::new(4, 4, vec![4.0, -1.0, 2.0, 0.5,
+                                   1.0, 3.0, 0.0, -2.0,
+                                   -2.0, 1.0, 5.0, 1.0,
+                                   0.0, 2.0, 1.0, 6.0]),
+            Matrix::new(2, 2, vec![0.0, 1.0, 2.0, 3.0]),
+        ];
+
+        for a in systems {
+            let n = a.rows();
+            let b = Vector::new((0..n).map(|i| i as f64 - 1.5).collect::<Vec<_>>());
+
+            let lu = PartialPivLu::decompose(a.clone()).unwrap();
+            let x = lu.solve_transpose(b.clone()).unwrap();
+
+            let expected = a.transpose().solve(b.clone()).unwrap();
+            assert_vec_close(&x, &expected);
+            assert_vec_close(&(a.transpose() * x), &b);
+        }
+    }
+
+    #[test]
+    fn test_lu_solve_transpose_singular() {
+        // The last pivot is nonzero, so the decomposition succeeds,
+        // but it is too small to divide by.
+        let a = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 1e-310]);
+        let lu = PartialPivLu::decompose(a).unwrap();
+
+        let err = lu.solve_transpose(Vector::new(vec![1.0, 1.0])).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::AlgebraFailure);
+    }
+
+    #[test]
+    fn test_lu_logdet() {
+        let systems = vec![
+            Matrix::new(3, 3, vec![1.0, 2.0, 0.0,
+                                   0.0, 3.0, 4.0,
+                                   5.0, 1.0, 2.0]),
+            Matrix::new(4, 4, vec![4.0, -1.0, 2.0, 0.5,
+                                   1.0, 3.0, 0.0, -2.0,
+                                   -2.0, 1.0, 5.0, 1.0,
+                                   0.0, 2.0, 1.0, 6.0]),
+            Matrix::new(2, 2, vec![0.0, 1.0, 2.0, 3.0]),
+        ];
+
+        for a in systems {
+            let (logdet, sign): (f64, i8) = PartialPivLu::decompose(a.clone()).unwrap().logdet();
+            let det = a.det();
+
+            assert!((logdet.exp() * sign as f64 - det).abs() < 1e-10 * det.abs());
+        }
+    }
+
+    #[test]
+    fn test_lu_logdet_large() {
+        // det = 10^400 overflows f64, but its log does not.
+        let a = Matrix::<f64>::identity(200) * 100.0;
+        let (logdet, sign) = PartialPivLu::decompose(a).unwrap().logdet();
+
+        assert_eq!(sign, 1);
+        assert!((logdet - 200.0 * 100f64.ln()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lu_non_finite() {
+        use std::f64;
+
+        let mut a = Matrix::<f64>::identity(3);
+        a[[1, 2]] = f64::INFINITY;
+
+        let err = PartialPivLu::decompose(a).unwrap_err();
+        assert_eq!(err.to_string(), "Matrix has a non-finite value at (1, 2).");
+    }
+
+    #[test]
+    fn test_lu_reconstruct() {
+        let a = Matrix::new(4, 4, vec![4.0f64, -1.0, 2.0, 0.5,
+                                       1.0, 3.0, 0.0, -2.0,
+                                       -2.0, 1.0, 5.0, 1.0,
+                                       0.0, 2.0, 1.0, 6.0]);
+        let b = PartialPivLu::decompose(a.clone()).unwrap().reconstruct();
+
+        for (x, y) in b.data().iter().zip(a.data()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+}
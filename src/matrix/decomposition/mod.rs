@@ -15,8 +15,9 @@ use std::cmp;
 use std::ops::{Mul, Add, Div, Sub, Neg};
 use std::slice;
 
-use matrix::{Matrix, MatrixSlice, MatrixSliceMut, BaseMatrix, BaseMatrixMut};
-use vector::Vector;
+use matrix::{Matrix, MatrixSlice, MatrixSliceMut, BaseMatrix, BaseMatrixMut, Givens};
+use matrix::gaxpy_rows;
+use matrix::householder::householder_vector;
 use Metric;
 use utils;
 use error::{Error, ErrorKind};
@@ -24,6 +25,103 @@ use error::{Error, ErrorKind};
 use libnum::{One, Zero, Float, Signed};
 use libnum::{cast, abs};
 
+mod cholesky;
+mod lu;
+
+pub use self::cholesky::Cholesky;
+pub use self::lu::PartialPivLu;
+
+/// Returns an `InvalidArg` error naming the first NaN or infinite
+/// element of the matrix, if there is one.
+///
+/// Decompositions check their input with this so that bad data is
+/// reported directly rather than as a failure of the algorithm.
+fn check_finite<T: Float, M: BaseMatrix<T>>(m: &M) -> Result<(), Error> {
+    match m.find_non_finite() {
+        Some((i, j)) => Err(Error::new(ErrorKind::InvalidArg,
+                                       format!("Matrix has a non-finite value at ({}, {}).", i, j))),
+        None => Ok(()),
+    }
+}
+
+/// Returns an `InvalidArg` error naming the first NaN or infinite
+/// element on or below the diagonal, if there is one.
+///
+/// Used by decompositions which never read the upper triangle.
+fn check_finite_lower<T: Float, M: BaseMatrix<T>>(m: &M) -> Result<(), Error> {
+    for (i, row) in m.iter_rows().enumerate() {
+        if let Some(j) = row[..cmp::min(i + 1, row.len())].iter().position(|x| !x.is_finite()) {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  format!("Matrix has a non-finite value at ({}, {}).", i, j)));
+        }
+    }
+    Ok(())
+}
+
+/// Copies the lower triangular part of a square matrix, with zeros above the diagonal.
+fn lower_triangle<T: Copy + Zero, M: BaseMatrix<T>>(m: &M) -> Vec<T> {
+    let n = m.rows();
+    let mut l = vec![T::zero(); n * n];
+
+    for (i, row) in m.iter_rows().enumerate() {
+        l[i * n..i * n + i + 1].copy_from_slice(&row[..i + 1]);
+    }
+    l
+}
+
+/// Overwrites the lower triangle `l` of an `n x n` matrix with its Cholesky factor.
+///
+/// Row `i` of the lower triangle is stored in `l` at `row_start(i)`, so
+/// both dense (`i * n`) and packed (`i * (i + 1) / 2`) storage can be
+/// factored without conversion. The rows must be disjoint and in order.
+///
+/// The columns are computed in turn. Column `j` on and below the diagonal
+/// is updated to `a_ij - L_i · L_j`, a gaxpy with the rows of the earlier
+/// columns, and then divided by `l_jj`.
+fn cholesky_in_place<T, F>(l: &mut [T], n: usize, row_start: F) -> Result<(), Error>
+    where T: Any + Float,
+          F: Fn(usize) -> usize
+{
+    if n == 0 {
+        return Ok(());
+    }
+    assert!(row_start(n - 1) + n <= l.len(), "Lower triangle exceeds the buffer.");
+
+    let mut l_j = Vec::with_capacity(n);
+
+    for j in 0..n {
+        let start_j = row_start(j);
+        l_j.clear();
+        l_j.extend_from_slice(&l[start_j..start_j + j]);
+
+        // The rows are disjoint, so each may be borrowed mutably at once.
+        let ptr = l.as_mut_ptr();
+        let rows = (j..n).map(|i| unsafe {
+            let row = slice::from_raw_parts_mut(ptr.offset(row_start(i) as isize), j + 1);
+            let (head, tail) = row.split_at_mut(j);
+            (&*head, &mut tail[0])
+        });
+        gaxpy_rows(rows, &l_j, -T::one());
+
+        let d = l[start_j + j].sqrt();
+        if !(d > T::zero()) {
+            return Err(Error::new(ErrorKind::Indefinite,
+                                  "Matrix is not positive definite."));
+        }
+        l[start_j + j] = d;
+
+        for i in j + 1..n {
+            let l_ij = &mut l[row_start(i) + j];
+            *l_ij = *l_ij / d;
+            if !l_ij.is_finite() {
+                return Err(Error::new(ErrorKind::Indefinite,
+                                      "Matrix is not positive definite."));
+            }
+        }
+    }
+    Ok(())
+}
+
 impl<T: Any + Float> Matrix<T> {
     /// Cholesky decomposition
     ///
@@ -50,42 +148,14 @@ impl<T: Any + Float> Matrix<T> {
         assert!(self.rows == self.cols,
                 "Matrix must be square for Cholesky decomposition.");
 
-        let mut new_data = Vec::<T>::with_capacity(self.rows() * self.cols());
-
-        for i in 0..self.rows() {
-
-            for j in 0..self.cols() {
-
-                if j > i {
-                    new_data.push(T::zero());
-                    continue;
-                }
-
-                let mut sum = T::zero();
-                for k in 0..j {
-                    sum = sum + (new_data[i * self.cols() + k] * new_data[j * self.cols() + k]);
-                }
-
-                if j == i {
-                    new_data.push((self[[i, i]] - sum).sqrt());
-                } else {
-                    let p = (self[[i, j]] - sum) / new_data[j * self.cols + j];
-
-                    if !p.is_finite() {
-                        return Err(Error::new(ErrorKind::DecompFailure,
-                                              "Matrix is not positive definite."));
-                    } else {
-
-                    }
-                    new_data.push(p);
-                }
-            }
-        }
+        let mut l = lower_triangle(self);
+        let n = self.rows;
+        cholesky_in_place(&mut l, n, |i| i * n)?;
 
         Ok(Matrix {
-            rows: self.rows(),
-            cols: self.cols(),
-            data: new_data,
+            rows: self.rows,
+            cols: self.cols,
+            data: l,
         })
     }
 
@@ -93,57 +163,38 @@ impl<T: Any + Float> Matrix<T> {
     ///
     /// Returns a tuple (c, s).
     fn givens_rot(a: T, b: T) -> (T, T) {
-        let r = a.hypot(b);
+        let g = Givens::new(a, b);
 
-        (a / r, -b / r)
+        (g.c(), g.s())
     }
 
-    fn make_householder(column: &[T]) -> Result<Matrix<T>, Error> {
-        let size = column.len();
-
-        if size == 0 {
+    /// Computes the Householder vector, with first entry one, and its
+    /// scaling for the reflector zeroing `column` below the first entry.
+    fn householder_parts(column: &[T]) -> Result<(Vec<T>, T), Error> {
+        if column.is_empty() {
             return Err(Error::new(ErrorKind::InvalidArg,
                                   "Column for householder transform cannot be empty."));
         }
 
-        let denom = column[0] + column[0].signum() * utils::dot(column, column).sqrt();
-
-        if denom == T::zero() {
-            return Err(Error::new(ErrorKind::DecompFailure,
-                                  "Cannot produce househoulder transform from column as first \
-                                   entry is 0."));
-        }
+        householder_vector(column).ok_or_else(|| {
+            Error::new(ErrorKind::DecompFailure,
+                       "Cannot produce househoulder transform from column as first \
+                        entry is 0.")
+        })
+    }
 
-        let mut v = column.into_iter().map(|&x| x / denom).collect::<Vec<T>>();
-        // Ensure first element is fixed to 1.
-        v[0] = T::one();
-        let v = Vector::new(v);
-        let v_norm_sq = v.dot(&v);
+    fn make_householder(column: &[T]) -> Result<Matrix<T>, Error> {
+        let size = column.len();
+        let (v, beta) = Matrix::householder_parts(column)?;
 
-        let v_vert = Matrix::new(size, 1, v.data().clone());
-        let v_hor = Matrix::new(1, size, v.into_vec());
-        Ok(Matrix::<T>::identity(size) - (v_vert * v_hor) * ((T::one() + T::one()) / v_norm_sq))
+        let v_vert = Matrix::new(size, 1, v.clone());
+        let v_hor = Matrix::new(1, size, v);
+        Ok(Matrix::<T>::identity(size) - (v_vert * v_hor) * beta)
     }
 
     fn make_householder_vec(column: &[T]) -> Result<Matrix<T>, Error> {
         let size = column.len();
-
-        if size == 0 {
-            return Err(Error::new(ErrorKind::InvalidArg,
-                                  "Column for householder transform cannot be empty."));
-        }
-
-        let denom = column[0] + column[0].signum() * utils::dot(column, column).sqrt();
-
-        if denom == T::zero() {
-            return Err(Error::new(ErrorKind::DecompFailure,
-                                  "Cannot produce househoulder transform from column as first \
-                                   entry is 0."));
-        }
-
-        let mut v = column.into_iter().map(|&x| x / denom).collect::<Vec<T>>();
-        // Ensure first element is fixed to 1.
-        v[0] = T::one();
+        let (v, _) = Matrix::householder_parts(column)?;
         let v = Matrix::new(size, 1, v);
 
         Ok(&v / v.norm())
@@ -407,6 +458,67 @@ impl<T: Any + Float + Signed> Matrix<T> {
 
     }
 
+    /// The nuclear norm of the matrix.
+    ///
+    /// Returns the sum of the singular values, computed with the SVD.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![3.0, 4.0, -4.0, 3.0]);
+    /// let norm = a.nuclear_norm().unwrap();
+    ///
+    /// assert!((norm - 10.0f64).abs() < 1e-10);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The SVD could not be computed.
+    pub fn nuclear_norm(&self) -> Result<T, Error> {
+        let (sigma, _, _) = self.clone().svd()?;
+        let k = cmp::min(sigma.rows(), sigma.cols());
+
+        Ok((0..k).fold(T::zero(), |sum, i| sum + sigma[[i, i]].abs()))
+    }
+
+    /// The proximal operator of the nuclear norm.
+    ///
+    /// Computes the SVD, soft-thresholds the singular values by
+    /// `lambda` and reconstructs the matrix. Singular values below
+    /// `lambda` are removed, so this reduces the rank of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// // Singular values 2 and 0.5
+    /// let a = Matrix::new(2, 2, vec![1.25, 0.75, 0.75, 1.25]);
+    /// let b = a.nuclear_prox(1.0).unwrap();
+    ///
+    /// // Only the singular value 1 remains
+    /// for x in b.data() {
+    ///     assert!((x - 0.5f64).abs() < 1e-10);
+    /// }
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The SVD could not be computed.
+    pub fn nuclear_prox(&self, lambda: T) -> Result<Matrix<T>, Error> {
+        let (sigma, u, v) = self.clone().svd()?;
+
+        // Keep only the diagonal; singular values may carry a sign here.
+        let mut diag = Matrix::zeros(sigma.rows(), sigma.cols());
+        for i in 0..cmp::min(sigma.rows(), sigma.cols()) {
+            diag[[i, i]] = sigma[[i, i]];
+        }
+
+        Ok(u * diag.soft_threshold(lambda) * v.transpose())
+    }
+
     /// This function is unsafe as it makes assumptions about the dimensions
     /// of the inputs matrices and does not check them. As a result if misused
     /// this function can call `get_unchecked` on invalid indices.
@@ -1063,6 +1175,7 @@ impl<T> Matrix<T> where T: Any + Copy + One + Zero + Neg<Output=T> +
 mod tests {
     use matrix::{Matrix, BaseMatrix};
     use vector::Vector;
+    use Metric;
 
     fn validate_bidiag(mat: &Matrix<f64>,
                        b: &Matrix<f64>,
@@ -1166,6 +1279,40 @@ mod tests {
         validate_svd(&mat, &b, &u, &v);
     }
 
+    #[test]
+    fn test_nuclear_norm() {
+        // A scaled orthogonal matrix has equal singular values.
+        let a = Matrix::new(2, 2, vec![3.0f64, 4.0, -4.0, 3.0]);
+        assert!((a.nuclear_norm().unwrap() - 10.0).abs() < 1e-10);
+
+        let b = Matrix::new(5, 3, vec![1f64, 2.0, 3.0, 4.0, 5.0, 2.0, 4.0, 1.0, 2.0, 1.0, 3.0, 1.0,
+                                       7.0, 1.0, 1.0]);
+        let (sigma, _, _) = b.clone().svd().unwrap();
+        let expected = (0..3).fold(0.0, |sum, i| sum + sigma[[i, i]].abs());
+
+        assert!((b.nuclear_norm().unwrap() - expected).abs() < 1e-10);
+        assert!((b.transpose().nuclear_norm().unwrap() - expected).abs() < 1e-8);
+        assert!(expected >= b.norm());
+    }
+
+    #[test]
+    fn test_nuclear_prox_rank_deficient() {
+        // Rank 2, with all singular values bounded by the Frobenius norm < 1.
+        let a = Matrix::new(4, 3, vec![0.1f64, 0.2, 0.3,
+                                       0.2, 0.4, 0.6,
+                                       -0.1, 0.0, 0.1,
+                                       0.0, 0.1, 0.2]);
+        assert!(a.norm() < 1.0);
+
+        let prox = a.nuclear_prox(1.0).unwrap();
+        assert_eq!(prox.rows(), 4);
+        assert_eq!(prox.cols(), 3);
+        assert!(prox.data().iter().all(|x| x.abs() < 1e-10));
+
+        let same = a.nuclear_prox(0.0).unwrap();
+        assert!(same.data().iter().zip(a.data()).all(|(x, y)| (x - y).abs() < 1e-10));
+    }
+
     #[test]
     fn test_1_by_1_matrix_eigenvalues() {
         let a = Matrix::new(1, 1, vec![3.]);
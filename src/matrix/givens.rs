@@ -0,0 +1,203 @@
+//! Givens rotations.
+//!
+//! A Givens rotation acts on two coordinates at a time and is used to
+//! zero out single entries of a matrix without disturbing the others.
+
+use matrix::BaseMatrixMut;
+
+use libnum::Float;
+
+/// A Givens rotation `G = [c -s; s c]`.
+///
+/// The rotation built by `Givens::new(a, b)` maps the pair `(a, b)`
+/// to `(r, 0)`, where `r = sqrt(a² + b²)`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, Givens};
+///
+/// let mut a = Matrix::new(2, 2, vec![3.0f64, 1.0, 4.0, 2.0]);
+///
+/// // Zero out the entry below the diagonal.
+/// let g = Givens::new(a[[0, 0]], a[[1, 0]]);
+/// g.apply_to_rows(&mut a, 0, 1);
+///
+/// assert!((a[[0, 0]] - 5.0).abs() < 1e-12);
+/// assert!(a[[1, 0]].abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Givens<T> {
+    c: T,
+    s: T,
+}
+
+impl<T: Float> Givens<T> {
+    /// Computes the rotation which zeros `b` in the pair `(a, b)`.
+    ///
+    /// If both `a` and `b` are zero the identity rotation is returned.
+    pub fn new(a: T, b: T) -> Givens<T> {
+        let r = a.hypot(b);
+
+        if r == T::zero() {
+            Givens {
+                c: T::one(),
+                s: T::zero(),
+            }
+        } else {
+            Givens {
+                c: a / r,
+                s: -b / r,
+            }
+        }
+    }
+
+    /// The cosine of the rotation angle.
+    pub fn c(&self) -> T {
+        self.c
+    }
+
+    /// The sine of the rotation angle.
+    pub fn s(&self) -> T {
+        self.s
+    }
+
+    /// Rotates rows `i` and `j` of `m` in place, computing `G * m`
+    /// restricted to those two rows.
+    ///
+    /// Row `i` plays the role of `a` and row `j` the role of `b`.
+    ///
+    /// # Panics
+    ///
+    /// - `i` or `j` is out of bounds.
+    /// - `i` and `j` are equal.
+    pub fn apply_to_rows<M: BaseMatrixMut<T>>(&self, m: &mut M, i: usize, j: usize) {
+        assert!(i < m.rows() && j < m.rows(), "Row index out of bounds.");
+        assert!(i != j, "Cannot rotate a row with itself.");
+
+        for k in 0..m.cols() {
+            unsafe {
+                let x = *m.get_unchecked([i, k]);
+                let y = *m.get_unchecked([j, k]);
+                *m.get_unchecked_mut([i, k]) = self.c * x - self.s * y;
+                *m.get_unchecked_mut([j, k]) = self.s * x + self.c * y;
+            }
+        }
+    }
+
+    /// Rotates columns `i` and `j` of `m` in place, computing `m * Gᵀ`
+    /// restricted to those two columns.
+    ///
+    /// Column `i` plays the role of `a` and column `j` the role of `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Givens};
+    ///
+    /// let mut a = Matrix::new(1, 3, vec![3.0f64, 7.0, 4.0]);
+    ///
+    /// let g = Givens::new(a[[0, 0]], a[[0, 2]]);
+    /// g.apply_to_cols(&mut a, 0, 2);
+    ///
+    /// assert!((a[[0, 0]] - 5.0).abs() < 1e-12);
+    /// assert_eq!(a[[0, 1]], 7.0);
+    /// assert!(a[[0, 2]].abs() < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `i` or `j` is out of bounds.
+    /// - `i` and `j` are equal.
+    pub fn apply_to_cols<M: BaseMatrixMut<T>>(&self, m: &mut M, i: usize, j: usize) {
+        assert!(i < m.cols() && j < m.cols(), "Column index out of bounds.");
+        assert!(i != j, "Cannot rotate a column with itself.");
+
+        for k in 0..m.rows() {
+            unsafe {
+                let x = *m.get_unchecked([k, i]);
+                let y = *m.get_unchecked([k, j]);
+                *m.get_unchecked_mut([k, i]) = self.c * x - self.s * y;
+                *m.get_unchecked_mut([k, j]) = self.s * x + self.c * y;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, BaseMatrix, MatrixSliceMut};
+    use super::Givens;
+
+    #[test]
+    fn test_givens_unit_norm() {
+        for &(a, b) in &[(3.0f64, 4.0), (-1.0, 2.5), (1e-300, 1e-300), (0.0, -7.0), (2.0, 0.0)] {
+            let g = Givens::new(a, b);
+            assert!((g.c() * g.c() + g.s() * g.s() - 1.0).abs() < 1e-14);
+        }
+
+        let g = Givens::new(0.0f64, 0.0);
+        assert_eq!((g.c(), g.s()), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_givens_zeros_row_entry() {
+        let a = Matrix::new(3, 3, vec![4.0f64, 1.0, 2.0, 1.0, 5.0, 3.0, 3.0, 2.0, 6.0]);
+        let mut b = a.clone();
+
+        let g = Givens::new(b[[0, 0]], b[[2, 0]]);
+        g.apply_to_rows(&mut b, 0, 2);
+
+        assert!(b[[2, 0]].abs() < 1e-14);
+        assert!((b[[0, 0]] - 5.0).abs() < 1e-14);
+        // The untouched row and the column norms are preserved.
+        assert_eq!(b.get_row(1), a.get_row(1));
+        for j in 0..3 {
+            let before = a[[0, j]] * a[[0, j]] + a[[2, j]] * a[[2, j]];
+            let after = b[[0, j]] * b[[0, j]] + b[[2, j]] * b[[2, j]];
+            assert!((before - after).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_givens_zeros_col_entry() {
+        let mut a = Matrix::new(2, 3, vec![1.0f64, -2.0, 2.0, 0.5, 0.0, 1.0]);
+
+        let g = Givens::new(a[[0, 1]], a[[0, 2]]);
+        g.apply_to_cols(&mut a, 1, 2);
+
+        assert!(a[[0, 2]].abs() < 1e-14);
+        assert!((a[[0, 1]] - 8.0f64.sqrt()).abs() < 1e-14);
+        assert_eq!(a[[0, 0]], 1.0);
+        assert_eq!(a[[1, 0]], 0.5);
+    }
+
+    #[test]
+    fn test_givens_on_slice() {
+        let mut a = Matrix::new(3, 3, vec![1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+
+        {
+            let mut s = MatrixSliceMut::from_matrix(&mut a, [1, 1], 2, 2);
+            let g = Givens::new(5.0, 8.0);
+            g.apply_to_rows(&mut s, 0, 1);
+        }
+
+        assert!(a[[2, 1]].abs() < 1e-14);
+        assert_eq!(a[[2, 0]], 7.0);
+        assert_eq!(a.get_row(0), Some(&[1.0, 2.0, 3.0][..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_givens_same_row() {
+        let mut a = Matrix::<f64>::identity(2);
+        Givens::new(1.0, 1.0).apply_to_rows(&mut a, 1, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_givens_col_out_of_bounds() {
+        let mut a = Matrix::<f64>::identity(2);
+        Givens::new(1.0, 1.0).apply_to_cols(&mut a, 0, 2);
+    }
+}
@@ -0,0 +1,278 @@
+//! Householder reflectors.
+//!
+//! A Householder reflector zeros every entry of a vector below the
+//! first, and is applied to a matrix as a rank-one update.
+
+use matrix::BaseMatrixMut;
+use vector::Vector;
+use error::{Error, ErrorKind};
+use utils;
+
+use libnum::Float;
+
+/// A Householder reflector `H = I - βvvᵀ`.
+///
+/// The reflector built by `Householder::new(x)` maps `x` to a multiple of
+/// the first unit vector. It is symmetric and orthogonal, so it is its
+/// own inverse.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, Householder};
+/// use rulinalg::vector::Vector;
+///
+/// let mut a = Matrix::new(3, 2, vec![2.0f64, 1.0, 1.0, 3.0, 2.0, 0.0]);
+///
+/// // Zero out the first column below the diagonal.
+/// let h = Householder::new(&Vector::new(vec![2.0, 1.0, 2.0])).unwrap();
+/// h.apply_left(&mut a);
+///
+/// assert!((a[[0, 0]].abs() - 3.0).abs() < 1e-12);
+/// assert!(a[[1, 0]].abs() < 1e-12);
+/// assert!(a[[2, 0]].abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Householder<T> {
+    v: Vec<T>,
+    beta: T,
+}
+
+impl<T: Float> Householder<T> {
+    /// Computes the reflector mapping `x` to a multiple of the first
+    /// unit vector.
+    ///
+    /// The vector `v` is scaled so that its first entry is one. If `x` is
+    /// zero the identity, with `β = 0`, is returned.
+    ///
+    /// # Failures
+    ///
+    /// - The vector `x` is empty.
+    pub fn new(x: &Vector<T>) -> Result<Householder<T>, Error> {
+        let x = x.data();
+
+        if x.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Vector for householder transform cannot be empty."));
+        }
+
+        match householder_vector(x) {
+            Some((v, beta)) => Ok(Householder { v: v, beta: beta }),
+            None => {
+                let mut v = vec![T::zero(); x.len()];
+                v[0] = T::one();
+                Ok(Householder {
+                    v: v,
+                    beta: T::zero(),
+                })
+            }
+        }
+    }
+
+    /// The Householder vector `v`, with first entry one.
+    pub fn vector(&self) -> &[T] {
+        &self.v
+    }
+
+    /// The scaling factor `β`.
+    pub fn beta(&self) -> T {
+        self.beta
+    }
+
+    /// Overwrites `m` with `H * m`.
+    ///
+    /// This is computed as `m - βv(vᵀm)` without forming `H`.
+    ///
+    /// # Panics
+    ///
+    /// - The rows of `m` do not match the length of `v`.
+    pub fn apply_left<M: BaseMatrixMut<T>>(&self, m: &mut M) {
+        assert!(m.rows() == self.v.len(), "Matrix dimensions do not agree.");
+
+        // w = vᵀm, accumulated row by row.
+        let mut w = vec![T::zero(); m.cols()];
+        for (row, &vi) in m.iter_rows().zip(self.v.iter()) {
+            utils::in_place_vec_bin_op(&mut w, row, |wj, &mij| *wj = *wj + vi * mij);
+        }
+
+        for (row, &vi) in m.iter_rows_mut().zip(self.v.iter()) {
+            let scale = self.beta * vi;
+            utils::in_place_vec_bin_op(row, &w, |mij, &wj| *mij = *mij - scale * wj);
+        }
+    }
+
+    /// Overwrites `m` with `m * H`.
+    ///
+    /// This is computed as `m - β(mv)vᵀ` without forming `H`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Householder};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(1, 2, vec![3.0f64, 4.0]);
+    ///
+    /// let h = Householder::new(&Vector::new(vec![3.0, 4.0])).unwrap();
+    /// h.apply_right(&mut a);
+    ///
+    /// assert!((a[[0, 0]] + 5.0).abs() < 1e-12);
+    /// assert!(a[[0, 1]].abs() < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The columns of `m` do not match the length of `v`.
+    pub fn apply_right<M: BaseMatrixMut<T>>(&self, m: &mut M) {
+        assert!(m.cols() == self.v.len(), "Matrix dimensions do not agree.");
+
+        for row in m.iter_rows_mut() {
+            let scale = self.beta * utils::dot(row, &self.v);
+            utils::in_place_vec_bin_op(row, &self.v, |mij, &vj| *mij = *mij - scale * vj);
+        }
+    }
+}
+
+/// Computes the vector `v`, with first entry one, and the scaling `β`
+/// of the reflector mapping `x` to a multiple of the first unit vector.
+///
+/// The norm of `x` is computed after dividing by its largest absolute
+/// entry, so it neither overflows nor underflows. Returns `None` if `x`
+/// is zero. Also used by the decompositions, which build reflectors
+/// from slices.
+pub fn householder_vector<T: Float>(x: &[T]) -> Option<(Vec<T>, T)> {
+    let scale = x.iter().fold(T::zero(), |m, &xi| m.max(xi.abs()));
+    if scale == T::zero() {
+        return None;
+    }
+
+    let scaled_sq = x.iter().fold(T::zero(), |s, &xi| {
+        let y = xi / scale;
+        s + y * y
+    });
+    let norm = scale * scaled_sq.sqrt();
+
+    // Adding the norm with the sign of x[0] avoids cancellation.
+    let denom = x[0] + x[0].signum() * norm;
+    let mut v = x.iter().map(|&xi| xi / denom).collect::<Vec<T>>();
+    v[0] = T::one();
+
+    let beta = (T::one() + T::one()) / utils::dot(&v, &v);
+    Some((v, beta))
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, BaseMatrix, MatrixSliceMut};
+    use vector::Vector;
+    use super::Householder;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-12, "{} != {}", x, y);
+        }
+    }
+
+    fn dense(h: &Householder<f64>) -> Matrix<f64> {
+        let n = h.vector().len();
+        let v = Matrix::new(n, 1, h.vector().to_vec());
+        Matrix::identity(n) - &v * v.transpose() * h.beta()
+    }
+
+    #[test]
+    fn test_householder_zeros_subcolumn() {
+        let mut a = Matrix::new(4, 3, vec![1.0f64, 2.0, 0.5,
+                                           -2.0, 1.0, 3.0,
+                                           2.0, 0.0, 1.0,
+                                           4.0, -1.0, 2.0]);
+
+        let h = Householder::new(&Vector::new(vec![1.0, -2.0, 2.0, 4.0])).unwrap();
+        h.apply_left(&mut a);
+
+        assert!((a[[0, 0]] + 5.0).abs() < 1e-12);
+        for i in 1..4 {
+            assert!(a[[i, 0]].abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_householder_matches_dense() {
+        let h = Householder::new(&Vector::new(vec![0.5, 3.0, -1.0])).unwrap();
+        let q = dense(&h);
+        let a = Matrix::new(3, 3, vec![1.0, -2.0, 0.5, 3.0, 1.0, 2.0, -1.0, 4.0, 0.0]);
+
+        let mut b = a.clone();
+        h.apply_left(&mut b);
+        assert_close(b.data(), (&q * &a).data());
+
+        let mut c = a.clone();
+        h.apply_right(&mut c);
+        assert_close(c.data(), (&a * &q).data());
+        assert_close((&q * &q).data(), Matrix::<f64>::identity(3).data());
+    }
+
+    #[test]
+    fn test_householder_is_own_inverse() {
+        let h = Householder::new(&Vector::new(vec![-2.0, 1.0, 0.0, 3.0])).unwrap();
+        let a = Matrix::new(4, 4, (0..16).map(|x| (x * x % 7) as f64 - 3.0).collect::<Vec<_>>());
+
+        let mut b = a.clone();
+        h.apply_left(&mut b);
+        h.apply_left(&mut b);
+        assert_close(b.data(), a.data());
+
+        h.apply_right(&mut b);
+        h.apply_right(&mut b);
+        assert_close(b.data(), a.data());
+    }
+
+    #[test]
+    fn test_householder_on_slice() {
+        let mut a = Matrix::new(3, 3, vec![9.0f64, 9.0, 9.0, 9.0, 3.0, 1.0, 9.0, 4.0, 2.0]);
+
+        {
+            let mut block = MatrixSliceMut::from_matrix(&mut a, [1, 1], 2, 2);
+            let h = Householder::new(&Vector::new(vec![3.0, 4.0])).unwrap();
+            h.apply_left(&mut block);
+        }
+
+        assert!((a[[1, 1]] + 5.0).abs() < 1e-12);
+        assert!(a[[2, 1]].abs() < 1e-12);
+        assert_eq!(a.get_row(0), Some(&[9.0, 9.0, 9.0][..]));
+        assert_eq!(a[[2, 0]], 9.0);
+    }
+
+    #[test]
+    fn test_householder_zero_vector() {
+        let h = Householder::new(&Vector::new(vec![0.0, 0.0])).unwrap();
+        assert_eq!(h.beta(), 0.0);
+
+        let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        h.apply_left(&mut a);
+        assert_eq!(a, Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+
+        assert!(Householder::<f64>::new(&Vector::new(vec![])).is_err());
+    }
+
+    #[test]
+    fn test_householder_extreme_scale() {
+        for &scale in &[1e200f64, 1e-200] {
+            let x = vec![3.0 * scale, 4.0 * scale];
+            let h = Householder::new(&Vector::new(x.clone())).unwrap();
+
+            let mut a = Matrix::new(2, 1, x);
+            h.apply_left(&mut a);
+
+            assert!((a[[0, 0]] / scale + 5.0).abs() < 1e-12);
+            assert!((a[[1, 0]] / scale).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_householder_wrong_size() {
+        let h = Householder::new(&Vector::new(vec![1.0, 1.0])).unwrap();
+        h.apply_right(&mut Matrix::<f64>::zeros(2, 3));
+    }
+}
@@ -10,6 +10,19 @@ use std::ops::{Mul, Add, Div, Sub, Index, IndexMut, Neg};
 use std::ops::{MulAssign, AddAssign, SubAssign, DivAssign};
 use libnum::Zero;
 
+/// Panics with the index and dimensions if `idx` is out of bounds.
+///
+/// Also used to index the column-major matrix types.
+#[inline]
+pub fn check_index(idx: [usize; 2], rows: usize, cols: usize) {
+    assert!(idx[0] < rows && idx[1] < cols,
+            "Index [{}, {}] is out of bounds for a {}x{} matrix.",
+            idx[0],
+            idx[1],
+            rows,
+            cols);
+}
+
 /// Indexes matrix.
 ///
 /// Takes row index first then column.
@@ -17,10 +30,7 @@ impl<T> Index<[usize; 2]> for Matrix<T> {
     type Output = T;
 
     fn index(&self, idx: [usize; 2]) -> &T {
-        assert!(idx[0] < self.rows,
-                "Row index is greater than row dimension.");
-        assert!(idx[1] < self.cols,
-                "Column index is greater than column dimension.");
+        check_index(idx, self.rows, self.cols);
 
         unsafe { &*(self.get_unchecked(idx)) }
     }
@@ -35,10 +45,7 @@ impl<'a, T> Index<[usize; 2]> for $slice_type<'a, T> {
     type Output = T;
 
     fn index(&self, idx: [usize; 2]) -> &T {
-        assert!(idx[0] < self.rows,
-                "Row index is greater than row dimension.");
-        assert!(idx[1] < self.cols,
-                "Column index is greater than column dimension.");
+        check_index(idx, self.rows, self.cols);
 
         unsafe {
             &*(self.get_unchecked(idx))
@@ -56,10 +63,7 @@ impl_index_slice!(MatrixSliceMut, "mutable matrix slice.");
 /// Takes row index first then column.
 impl<'a, T> IndexMut<[usize; 2]> for MatrixSliceMut<'a, T> {
     fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
-        assert!(idx[0] < self.rows,
-                "Row index is greater than row dimension.");
-        assert!(idx[1] < self.cols,
-                "Column index is greater than column dimension.");
+        check_index(idx, self.rows, self.cols);
 
         unsafe { &mut *(self.ptr.offset((idx[0] * self.row_stride + idx[1]) as isize)) }
     }
@@ -70,10 +74,7 @@ impl<'a, T> IndexMut<[usize; 2]> for MatrixSliceMut<'a, T> {
 /// Takes row index first then column.
 impl<T> IndexMut<[usize; 2]> for Matrix<T> {
     fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
-        assert!(idx[0] < self.rows,
-                "Row index is greater than row dimension.");
-        assert!(idx[1] < self.cols,
-                "Column index is greater than column dimension.");
+        check_index(idx, self.rows, self.cols);
         let self_cols = self.cols;
         unsafe { self.data.get_unchecked_mut(idx[0] * self_cols + idx[1]) }
     }
@@ -611,6 +612,9 @@ macro_rules! impl_op_assign_mat (
 /// assignment between two matrices.
 impl<T : Copy + $trt<T, Output=T>> $assign_trt<Matrix<T>> for Matrix<T> {
     fn $op_assign(&mut self, _rhs: Matrix<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         utils::in_place_vec_bin_op(&mut self.data, &_rhs.data, |x, &y| {*x = (*x).$op(y) });
     }
 }
@@ -620,6 +624,9 @@ impl<T : Copy + $trt<T, Output=T>> $assign_trt<Matrix<T>> for Matrix<T> {
 /// assignment between two matrices.
 impl<'a, T : Copy + $trt<T, Output=T>> $assign_trt<&'a Matrix<T>> for Matrix<T> {
     fn $op_assign(&mut self, _rhs: &Matrix<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         utils::in_place_vec_bin_op(&mut self.data, &_rhs.data, |x, &y| {*x = (*x).$op(y) });
     }
 }
@@ -639,6 +646,9 @@ impl<'a, T> $assign_trt<Matrix<T>> for MatrixSliceMut<'a, T>
     where T : Copy + $trt<T, Output=T>
 {
     fn $op_assign(&mut self, _rhs: Matrix<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         for (slice_row, target_row) in self.iter_rows_mut().zip(_rhs.iter_rows()) {
             utils::in_place_vec_bin_op(slice_row, target_row, |x, &y| {*x = (*x).$op(y) });
         }
@@ -652,6 +662,9 @@ impl<'a, 'b, T> $assign_trt<&'b Matrix<T>> for MatrixSliceMut<'a, T>
     where T : Copy + $trt<T, Output=T>
 {
     fn $op_assign(&mut self, _rhs: &Matrix<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         for (slice_row, target_row) in self.iter_rows_mut()
                                         .zip(_rhs.iter_rows()) {
             utils::in_place_vec_bin_op(slice_row,
@@ -678,6 +691,9 @@ impl<'a, 'b, T> $assign_trt<$target_slice<'b, T>> for MatrixSliceMut<'a, T>
     where T : Copy + $trt<T, Output=T>
 {
     fn $op_assign(&mut self, _rhs: $target_slice<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         for (slice_row, target_row) in self.iter_rows_mut()
                                             .zip(_rhs.iter_rows()) {
             utils::in_place_vec_bin_op(slice_row,
@@ -694,6 +710,9 @@ impl<'a, 'b, 'c, T> $assign_trt<&'c $target_slice<'b, T>> for MatrixSliceMut<'a,
     where T : Copy + $trt<T, Output=T>
 {
     fn $op_assign(&mut self, _rhs: &$target_slice<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         for (slice_row, target_row) in self.iter_rows_mut()
                                             .zip(_rhs.iter_rows()) {
             utils::in_place_vec_bin_op(slice_row,
@@ -718,6 +737,9 @@ macro_rules! impl_op_assign_mat_slice (
 /// assignment between two matrices.
 impl<'a, T : Copy + $trt<T, Output=T>> $assign_trt<$target_mat<'a, T>> for Matrix<T> {
     fn $op_assign(&mut self, _rhs: $target_mat<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         for (slice_row, target_row) in self.iter_rows_mut().zip(_rhs.iter_rows()) {
             utils::in_place_vec_bin_op(slice_row, target_row, |x, &y| {*x = (*x).$op(y) });
         }
@@ -729,6 +751,9 @@ impl<'a, T : Copy + $trt<T, Output=T>> $assign_trt<$target_mat<'a, T>> for Matri
 /// assignment between two matrices.
 impl<'a, 'b, T : Copy + $trt<T, Output=T>> $assign_trt<&'b $target_mat<'a, T>> for Matrix<T> {
     fn $op_assign(&mut self, _rhs: &$target_mat<T>) {
+        assert!(self.rows() == _rhs.rows(), "Row dimensions do not agree.");
+        assert!(self.cols() == _rhs.cols(), "Column dimensions do not agree.");
+
         for (slice_row, target_row) in self.iter_rows_mut().zip(_rhs.iter_rows()) {
             utils::in_place_vec_bin_op(slice_row, target_row, |x, &y| {*x = (*x).$op(y) });
         }
@@ -1548,4 +1573,13 @@ mod tests {
         }
         assert_eq!(a.into_vec(), res_data.clone());
     }
+
+    #[test]
+    #[should_panic]
+    fn slice_add_assign_dimension_mismatch() {
+        let mut a = Matrix::new(3, 3, vec![1.0; 9]);
+        let b = Matrix::new(3, 3, vec![1.0; 9]);
+        let mut a_slice = MatrixSliceMut::from_matrix(&mut a, [0, 0], 2, 2);
+        a_slice += &b;
+    }
 }
@@ -0,0 +1,345 @@
+//! Reading and writing matrices in the NumPy `.npy` format.
+//!
+//! Only two-dimensional `f32` and `f64` arrays are supported, in either
+//! C (row-major) or Fortran (column-major) order. One-dimensional
+//! arrays are read as a single row. Matrices are always written as
+//! little-endian C-order arrays with a version 1.0 header, which can
+//! be loaded with `numpy.load`.
+//!
+//! See the [format description](https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html)
+//! for details.
+
+use std::any::{Any, TypeId};
+use std::io::{self, Read, Write};
+
+use libnum::{Float, cast};
+
+use matrix::{Matrix, BaseMatrix};
+use error::{Error, ErrorKind};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// The header alignment required by the format.
+const ALIGN: usize = 64;
+
+fn io_error(msg: &'static str, cause: io::Error) -> Error {
+    Error::with_cause(ErrorKind::InvalidArg, msg, cause)
+}
+
+fn header_error(msg: &'static str) -> Error {
+    Error::new(ErrorKind::InvalidArg, msg)
+}
+
+/// Returns the text following `'key':` in the header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("'{}':", key);
+    header.find(&pattern).map(|i| header[i + pattern.len()..].trim_start())
+}
+
+/// Parses the shape tuple, e.g. `(3, 4)`, into rows and columns.
+fn parse_shape(value: &str) -> Result<(usize, usize), Error> {
+    let end = value.find(')').ok_or_else(|| header_error("Malformed shape in npy header."))?;
+    if !value.starts_with('(') {
+        return Err(header_error("Malformed shape in npy header."));
+    }
+
+    let dims = value[1..end]
+        .split(',')
+        .map(|d| d.trim())
+        .filter(|d| !d.is_empty())
+        .map(|d| d.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| Error::with_cause(ErrorKind::InvalidArg, "Malformed shape in npy header.", e))?;
+
+    match dims.len() {
+        1 => Ok((1, dims[0])),
+        2 => Ok((dims[0], dims[1])),
+        _ => Err(header_error("Only one and two dimensional npy arrays are supported.")),
+    }
+}
+
+impl<T: Any + Float> Matrix<T> {
+    /// Reads a matrix from the NumPy `.npy` format.
+    ///
+    /// The dtype of the file must match `T`, i.e. `f4` for `f32`
+    /// and `f8` for `f64`. Either byte order is accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    ///
+    /// let mut buf = Vec::new();
+    /// a.to_npy(&mut buf).unwrap();
+    ///
+    /// assert_eq!(Matrix::<f64>::from_npy(&buf[..]).unwrap(), a);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The magic string or header is malformed.
+    /// - The dtype does not match `T`, or the array is not one or two dimensional.
+    /// - The input ends before all elements are read.
+    pub fn from_npy<R: Read>(mut r: R) -> Result<Matrix<T>, Error> {
+        let mut preamble = [0u8; 8];
+        r.read_exact(&mut preamble).map_err(|e| io_error("Could not read npy header.", e))?;
+
+        if &preamble[..6] != MAGIC {
+            return Err(header_error("Input is not an npy file (magic string does not match)."));
+        }
+
+        let header_len = match preamble[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                r.read_exact(&mut len).map_err(|e| io_error("Could not read npy header.", e))?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                r.read_exact(&mut len).map_err(|e| io_error("Could not read npy header.", e))?;
+                u32::from_le_bytes(len) as usize
+            }
+            _ => return Err(header_error("Unsupported npy format version.")),
+        };
+
+        // Read through `take` so a corrupt header length cannot force a huge allocation.
+        let mut header = Vec::new();
+        r.by_ref()
+            .take(header_len as u64)
+            .read_to_end(&mut header)
+            .map_err(|e| io_error("Could not read npy header.", e))?;
+        if header.len() != header_len {
+            return Err(header_error("Npy header is truncated."));
+        }
+        let header = String::from_utf8(header)
+            .map_err(|e| Error::with_cause(ErrorKind::InvalidArg, "Npy header is not valid text.", e))?;
+
+        let descr = header_value(&header, "descr")
+            .and_then(|v| v.get(1..4))
+            .ok_or_else(|| header_error("Missing dtype in npy header."))?;
+        let (little_endian, size) = match descr {
+            "<f4" => (true, 4),
+            ">f4" => (false, 4),
+            "<f8" => (true, 8),
+            ">f8" => (false, 8),
+            _ => return Err(header_error("Unsupported dtype in npy file, expected f4 or f8.")),
+        };
+
+        let expected = if TypeId::of::<T>() == TypeId::of::<f32>() {
+            4
+        } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+            8
+        } else {
+            return Err(header_error("Only f32 and f64 matrices can be read from npy files."));
+        };
+        if size != expected {
+            return Err(header_error("Dtype of npy file does not match the matrix type."));
+        }
+
+        let fortran_order = match header_value(&header, "fortran_order") {
+            Some(v) if v.starts_with("True") => true,
+            Some(v) if v.starts_with("False") => false,
+            _ => return Err(header_error("Missing fortran_order in npy header.")),
+        };
+
+        let (rows, cols) = header_value(&header, "shape")
+            .ok_or_else(|| header_error("Missing shape in npy header."))
+            .and_then(parse_shape)?;
+
+        let len = rows.checked_mul(cols)
+            .and_then(|n| n.checked_mul(size))
+            .ok_or_else(|| header_error("Npy array dimensions overflow."))?;
+
+        let mut bytes = Vec::new();
+        r.take(len as u64)
+            .read_to_end(&mut bytes)
+            .map_err(|e| io_error("Could not read npy data.", e))?;
+        if bytes.len() != len {
+            return Err(header_error("Npy data is truncated."));
+        }
+
+        let data: Vec<T> = bytes.chunks(size)
+            .map(|c| if size == 4 {
+                let mut b = [0u8; 4];
+                b.copy_from_slice(c);
+                let x = if little_endian { f32::from_le_bytes(b) } else { f32::from_be_bytes(b) };
+                cast::<f32, T>(x).unwrap()
+            } else {
+                let mut b = [0u8; 8];
+                b.copy_from_slice(c);
+                let x = if little_endian { f64::from_le_bytes(b) } else { f64::from_be_bytes(b) };
+                cast::<f64, T>(x).unwrap()
+            })
+            .collect();
+
+        if fortran_order {
+            Ok(Matrix::new(cols, rows, data).transpose())
+        } else {
+            Ok(Matrix::new(rows, cols, data))
+        }
+    }
+
+    /// Writes the matrix in the NumPy `.npy` format.
+    ///
+    /// The data is written as a little-endian C-order array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(1, 2, vec![1.0f32, 2.0]);
+    ///
+    /// let mut buf = Vec::new();
+    /// a.to_npy(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..6], b"\x93NUMPY");
+    /// assert_eq!(buf.len() % 64, 8);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The element type is not `f32` or `f64`.
+    /// - Writing to `w` fails.
+    pub fn to_npy<W: Write>(&self, mut w: W) -> Result<(), Error> {
+        let descr = if TypeId::of::<T>() == TypeId::of::<f32>() {
+            "<f4"
+        } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+            "<f8"
+        } else {
+            return Err(header_error("Only f32 and f64 matrices can be written to npy files."));
+        };
+
+        let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+                                 descr,
+                                 self.rows,
+                                 self.cols);
+        // Pad with spaces so the data starts on an aligned offset.
+        while (MAGIC.len() + 4 + header.len() + 1) % ALIGN != 0 {
+            header.push(' ');
+        }
+        header.push('\n');
+
+        let mut buf = Vec::with_capacity(MAGIC.len() + 4 + header.len() + self.data.len() * 8);
+        buf.extend_from_slice(MAGIC);
+        buf.extend_from_slice(&[1, 0]);
+        buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        buf.extend_from_slice(header.as_bytes());
+
+        for &x in &self.data {
+            if descr == "<f4" {
+                buf.extend_from_slice(&cast::<T, f32>(x).unwrap().to_le_bytes());
+            } else {
+                buf.extend_from_slice(&cast::<T, f64>(x).unwrap().to_le_bytes());
+            }
+        }
+
+        w.write_all(&buf).map_err(|e| io_error("Could not write npy file.", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error::ErrorKind;
+    use matrix::Matrix;
+
+    /// Builds an npy file with the given header dictionary, padded as NumPy does.
+    fn npy_file(dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut header = dict.to_string();
+        while (10 + header.len() + 1) % 64 != 0 {
+            header.push(' ');
+        }
+        header.push('\n');
+
+        let mut buf = b"\x93NUMPY\x01\x00".to_vec();
+        buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        buf.extend_from_slice(header.as_bytes());
+        buf.extend_from_slice(data);
+        buf
+    }
+
+    fn f64_bytes(xs: &[f64]) -> Vec<u8> {
+        xs.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let a = Matrix::new(3, 2, vec![1.5f64, -2.0, 0.0, 1e-300, 3.25, -7.0]);
+        let mut buf = Vec::new();
+        a.to_npy(&mut buf).unwrap();
+        assert_eq!(Matrix::<f64>::from_npy(&buf[..]).unwrap(), a);
+
+        let b = Matrix::new(2, 2, vec![1.5f32, -2.0, 0.1, 4.0]);
+        let mut buf = Vec::new();
+        b.to_npy(&mut buf).unwrap();
+        assert_eq!(Matrix::<f32>::from_npy(&buf[..]).unwrap(), b);
+    }
+
+    #[test]
+    fn test_npy_written_header() {
+        let a = Matrix::new(2, 3, vec![1.0f64; 6]);
+        let mut buf = Vec::new();
+        a.to_npy(&mut buf).unwrap();
+
+        // This is byte for byte what `numpy.save` writes for `np.ones((2, 3))`.
+        let expected = npy_file("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+                                &f64_bytes(&[1.0; 6]));
+        assert_eq!(buf, expected);
+        assert_eq!((buf.len() - 6 * 8) % 64, 0);
+    }
+
+    #[test]
+    fn test_npy_read_numpy_files() {
+        let c_order = npy_file("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }",
+                               &f64_bytes(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+        let a = Matrix::<f64>::from_npy(&c_order[..]).unwrap();
+        assert_eq!(a, Matrix::new(2, 3, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+
+        let f_order = npy_file("{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }",
+                               &f64_bytes(&[0.0, 3.0, 1.0, 4.0, 2.0, 5.0]));
+        assert_eq!(Matrix::<f64>::from_npy(&f_order[..]).unwrap(), a);
+
+        let big_endian: Vec<u8> = [1.0f32, 2.0].iter().flat_map(|x| x.to_be_bytes().to_vec()).collect();
+        let be = npy_file("{'descr': '>f4', 'fortran_order': False, 'shape': (2,), }",
+                          &big_endian);
+        assert_eq!(Matrix::<f32>::from_npy(&be[..]).unwrap(),
+                   Matrix::new(1, 2, vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_npy_errors() {
+        let data = f64_bytes(&[1.0, 2.0]);
+
+        let int_dtype = npy_file("{'descr': '<i8', 'fortran_order': False, 'shape': (1, 2), }",
+                                 &data);
+        assert!(Matrix::<f64>::from_npy(&int_dtype[..]).is_err());
+
+        let good = npy_file("{'descr': '<f8', 'fortran_order': False, 'shape': (1, 2), }", &data);
+        assert!(Matrix::<f32>::from_npy(&good[..]).is_err());
+        assert!(Matrix::<f64>::from_npy(&good[..good.len() - 1]).is_err());
+
+        let mut bad_magic = good.clone();
+        bad_magic[1] = b'X';
+        assert!(Matrix::<f64>::from_npy(&bad_magic[..]).is_err());
+
+        let bad_shape = npy_file("{'descr': '<f8', 'fortran_order': False, 'shape': (1, x), }",
+                                 &data);
+        assert!(Matrix::<f64>::from_npy(&bad_shape[..]).is_err());
+
+        let three_dim = npy_file("{'descr': '<f8', 'fortran_order': False, 'shape': (1, 1, 2), }",
+                                 &data);
+        assert!(Matrix::<f64>::from_npy(&three_dim[..]).is_err());
+
+        let no_order = npy_file("{'descr': '<f8', 'shape': (1, 2), }", &data);
+        assert!(Matrix::<f64>::from_npy(&no_order[..]).is_err());
+
+        // A version 2 header claiming ~4 GiB of header text.
+        let mut huge_header = b"\x93NUMPY\x02\x00".to_vec();
+        huge_header.extend_from_slice(&[0xff, 0xff, 0xff, 0xff]);
+        huge_header.extend_from_slice(b"{'descr': '<f8', ");
+        let err = Matrix::<f64>::from_npy(&huge_header[..]).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::InvalidArg);
+    }
+}
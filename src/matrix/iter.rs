@@ -1,8 +1,13 @@
 use std::iter::{ExactSizeIterator, FromIterator};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Index, IndexMut};
 use std::slice;
 
 use super::{Matrix, MatrixSlice, MatrixSliceMut, Rows, RowsMut};
+use super::{Cols, ColsMut, Column, ColumnMut, Diagonal, DiagonalMut};
 use super::slice::{BaseMatrix, BaseMatrixMut, SliceIter, SliceIterMut};
+use vector::Vector;
 
 macro_rules! impl_iter_rows (
     ($rows:ident, $row_type:ty, $slice_from_parts:ident) => (
@@ -73,6 +78,204 @@ impl_iter_rows!(RowsMut, &'a mut [T], from_raw_parts_mut);
 impl<'a, T> ExactSizeIterator for Rows<'a, T> {}
 impl<'a, T> ExactSizeIterator for RowsMut<'a, T> {}
 
+macro_rules! impl_iter_cols (
+    ($cols:ident, $col_type:ident) => (
+
+/// Iterates over the columns in the matrix.
+impl<'a, T> Iterator for $cols<'a, T> {
+    type Item = $col_type<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+// Check if we have reached the end
+        if self.col_pos < self.slice_cols {
+            let col: $col_type<'a, T>;
+            unsafe {
+// The column starts at the top of the slice, offset by the column position
+                col = $col_type {
+                    ptr: self.slice_start.offset(self.col_pos as isize),
+                    rows: self.slice_rows,
+                    row_stride: self.row_stride,
+                    marker: PhantomData,
+                };
+            }
+
+            self.col_pos += 1;
+            Some(col)
+        } else {
+            None
+        }
+    }
+
+    fn count(self) -> usize {
+        self.slice_cols - self.col_pos
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slice_cols - self.col_pos, Some(self.slice_cols - self.col_pos))
+    }
+}
+    );
+);
+
+impl_iter_cols!(Cols, Column);
+impl_iter_cols!(ColsMut, ColumnMut);
+
+impl<'a, T> ExactSizeIterator for Cols<'a, T> {}
+impl<'a, T> ExactSizeIterator for ColsMut<'a, T> {}
+
+macro_rules! impl_iter_diag (
+    ($diag:ident, $diag_type:ty) => (
+
+/// Iterates over a diagonal in the matrix.
+impl<'a, T> Iterator for $diag<'a, T> {
+    type Item = $diag_type;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.diag_pos < self.diag_len {
+            unsafe {
+                let iter_ptr = self.ptr.offset((self.diag_pos * self.stride) as isize);
+
+                self.diag_pos += 1;
+                Some(mem::transmute(iter_ptr))
+            }
+        } else {
+            None
+        }
+    }
+
+    fn count(self) -> usize {
+        self.diag_len - self.diag_pos
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.diag_len - self.diag_pos, Some(self.diag_len - self.diag_pos))
+    }
+}
+    );
+);
+
+impl_iter_diag!(Diagonal, &'a T);
+impl_iter_diag!(DiagonalMut, &'a mut T);
+
+impl<'a, T> ExactSizeIterator for Diagonal<'a, T> {}
+impl<'a, T> ExactSizeIterator for DiagonalMut<'a, T> {}
+
+macro_rules! impl_column (
+    ($col_type:ident) => (
+
+impl<'a, T> $col_type<'a, T> {
+    /// The number of entries in the column.
+    pub fn len(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns `true` if the column has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Returns an iterator over the column entries, from top to bottom.
+    pub fn iter(&self) -> SliceIter<'_, T> {
+        unsafe {
+            MatrixSlice::from_raw_parts(self.ptr as *const T, self.rows, 1, self.row_stride).iter()
+        }
+    }
+
+    /// Copies the column into a new `Vector`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let col = a.iter_cols().nth(1).unwrap().to_vector();
+    ///
+    /// assert_eq!(*col.data(), vec![2.0, 4.0]);
+    /// ```
+    pub fn to_vector(&self) -> Vector<T>
+        where T: Copy
+    {
+        Vector::new(self.iter().cloned().collect::<Vec<T>>())
+    }
+}
+
+/// Indexes the column.
+impl<'a, T> Index<usize> for $col_type<'a, T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        assert!(idx < self.rows, "Index is greater than column length.");
+
+        unsafe { &*(self.ptr.offset((idx * self.row_stride) as isize)) }
+    }
+}
+    );
+);
+
+impl_column!(Column);
+impl_column!(ColumnMut);
+
+impl<'a, T> ColumnMut<'a, T> {
+    /// Returns a mutable iterator over the column entries, from top to bottom.
+    ///
+    /// The iterator borrows the column, so two iterators over the
+    /// same column cannot be alive at once:
+    ///
+    /// ```compile_fail
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![0; 4]);
+    /// let mut col = a.iter_cols_mut().next().unwrap();
+    ///
+    /// let first = col.iter_mut().next().unwrap();
+    /// let again = col.iter_mut().next().unwrap();
+    /// *first = 1;
+    /// *again = 2;
+    /// ```
+    pub fn iter_mut(&mut self) -> SliceIterMut<'_, T> {
+        unsafe {
+            MatrixSliceMut::from_raw_parts(self.ptr, self.rows, 1, self.row_stride).iter_mut()
+        }
+    }
+
+    /// Overwrites the column with the contents of `src`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// a.iter_cols_mut().next().unwrap().copy_from_slice(&[0, 0]);
+    /// assert_eq!(a.into_vec(), vec![0, 2, 0, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The length of `src` is not equal to the column length.
+    pub fn copy_from_slice(&mut self, src: &[T])
+        where T: Copy
+    {
+        assert!(src.len() == self.rows,
+                "Source slice length does not match column length.");
+
+        for (x, y) in self.iter_mut().zip(src.iter()) {
+            *x = *y;
+        }
+    }
+}
+
+/// Indexes the mutable column.
+impl<'a, T> IndexMut<usize> for ColumnMut<'a, T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        assert!(idx < self.rows, "Index is greater than column length.");
+
+        unsafe { &mut *(self.ptr.offset((idx * self.row_stride) as isize)) }
+    }
+}
+
 /// Creates a `Matrix` from an iterator over slices.
 ///
 /// Each of the slices produced by the iterator will become a row in the matrix.
@@ -346,6 +549,82 @@ mod tests {
 
     }
 
+    #[test]
+    fn test_matrix_cols() {
+        let a = Matrix::new(3, 4, (0..12).collect::<Vec<usize>>());
+
+        let col_sums = a.iter_cols()
+            .map(|col| col.iter().fold(0, |sum, x| sum + x))
+            .collect::<Vec<_>>();
+
+        assert_eq!(col_sums, *a.sum_rows().data());
+        assert_eq!(a.iter_cols().count(), 4);
+
+        let col = a.iter_cols().nth(2).unwrap();
+        assert_eq!(col.len(), 3);
+        assert_eq!(col[0], 2);
+        assert_eq!(col[1], 6);
+        assert_eq!(col[2], 10);
+        assert_eq!(*col.to_vector().data(), vec![2, 6, 10]);
+    }
+
+    #[test]
+    fn test_matrix_slice_cols() {
+        let a = Matrix::new(3, 3, (0..9).collect::<Vec<usize>>());
+        let b = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+
+        let data = [[4, 7], [5, 8]];
+
+        for (i, col) in b.iter_cols().enumerate() {
+            assert_eq!(data[i], *col.iter().cloned().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_matrix_cols_mut() {
+        let mut a = Matrix::new(3, 3, (0..9).collect::<Vec<usize>>());
+
+        for (i, mut col) in a.iter_cols_mut().enumerate() {
+            col.copy_from_slice(&[i, i + 1, i + 2]);
+        }
+
+        assert_eq!(a.into_vec(), vec![0, 1, 2, 1, 2, 3, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_matrix_slice_cols_mut() {
+        let mut a = Matrix::new(3, 3, (0..9).collect::<Vec<usize>>());
+
+        {
+            let mut b = MatrixSliceMut::from_matrix(&mut a, [0, 1], 2, 2);
+
+            for mut col in b.iter_cols_mut() {
+                for x in col.iter_mut() {
+                    *x = 0;
+                }
+                col[1] = 1;
+            }
+        }
+
+        assert_eq!(a.into_vec(), vec![0, 0, 0, 3, 1, 1, 6, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_copy_from_slice_bad_len() {
+        let mut a = Matrix::new(3, 3, (0..9).collect::<Vec<usize>>());
+
+        a.iter_cols_mut().next().unwrap().copy_from_slice(&[1, 2]);
+    }
+
+    #[test]
+    fn test_matrix_cols_empty() {
+        let a = Matrix::<f64>::new(0, 3, vec![]);
+
+        assert_eq!(a.iter_cols().count(), 3);
+        assert!(a.iter_cols().all(|col| col.is_empty()));
+    }
+
     #[test]
     fn into_iter_compile() { 
         let a = Matrix::new(3, 3, vec![2.0; 9]); 
@@ -0,0 +1,220 @@
+//! Boolean masks produced by elementwise comparisons.
+//!
+//! Masks are plain `Matrix<bool>` values, typically created through
+//! the comparison methods on `BaseMatrix` such as `gt` and `lt_scalar`.
+
+use super::{Matrix, BaseMatrix};
+
+impl Matrix<bool> {
+    /// Returns `true` if any element of the mask is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, -2.0, 3.0, 0.5]);
+    ///
+    /// assert!(a.lt_scalar(0.0).any());
+    /// assert!(!a.gt_scalar(5.0).any());
+    /// ```
+    pub fn any(&self) -> bool {
+        self.data.iter().any(|&x| x)
+    }
+
+    /// Returns `true` if every element of the mask is `true`.
+    ///
+    /// An empty mask gives `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 0.5]);
+    ///
+    /// assert!(a.gt_scalar(0.0).all());
+    /// ```
+    pub fn all(&self) -> bool {
+        self.data.iter().all(|&x| x)
+    }
+
+    /// Returns the number of `true` elements in the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 5, 2, 7, 0, 9]);
+    ///
+    /// assert_eq!(a.ge_scalar(5).count_true(), 3);
+    /// ```
+    pub fn count_true(&self) -> usize {
+        self.data.iter().filter(|&&x| x).count()
+    }
+
+    /// Computes the elementwise logical and of two masks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(1, 4, vec![-1.0, 0.5, 2.0, 4.0]);
+    /// let inside = a.gt_scalar(0.0).and(&a.lt_scalar(3.0));
+    ///
+    /// assert_eq!(*inside.data(), vec![false, true, true, false]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The masks have different dimensions.
+    pub fn and(&self, m: &Matrix<bool>) -> Matrix<bool> {
+        self.zip_map(m, |x, y| x && y)
+    }
+
+    /// Computes the elementwise logical or of two masks.
+    ///
+    /// # Panics
+    ///
+    /// - The masks have different dimensions.
+    pub fn or(&self, m: &Matrix<bool>) -> Matrix<bool> {
+        self.zip_map(m, |x, y| x || y)
+    }
+
+    /// Computes the elementwise logical negation of the mask.
+    pub fn not(&self) -> Matrix<bool> {
+        self.map(|x| !x)
+    }
+}
+
+/// Chooses elements from `a` where `mask` is `true` and from `b` otherwise.
+///
+/// This is an elementwise ternary operation, the matrix analogue of
+/// `if mask { a } else { b }`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{self, Matrix, BaseMatrix};
+///
+/// let a = Matrix::new(2, 2, vec![1.0, -2.0, -3.0, 4.0]);
+/// let zeros = Matrix::zeros(2, 2);
+///
+/// // Clamp the negative entries to zero.
+/// let relu = matrix::select(&a.gt_scalar(0.0), &a, &zeros);
+///
+/// assert_eq!(relu, Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 4.0]));
+/// ```
+///
+/// # Panics
+///
+/// - The mask, `a` and `b` do not all have the same dimensions.
+pub fn select<T, MA, MB>(mask: &Matrix<bool>, a: &MA, b: &MB) -> Matrix<T>
+    where T: Copy,
+          MA: BaseMatrix<T>,
+          MB: BaseMatrix<T>
+{
+    assert!(mask.rows() == a.rows() && mask.rows() == b.rows(),
+            "Matrix row counts not equal.");
+    assert!(mask.cols() == a.cols() && mask.cols() == b.cols(),
+            "Matrix column counts not equal.");
+
+    let mut data = Vec::with_capacity(mask.rows() * mask.cols());
+    for ((m_r, a_r), b_r) in mask.iter_rows().zip(a.iter_rows()).zip(b.iter_rows()) {
+        data.extend(m_r.iter().zip(a_r).zip(b_r).map(|((&m, &x), &y)| if m { x } else { y }));
+    }
+
+    Matrix::new(mask.rows(), mask.cols(), data)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use matrix::{Matrix, BaseMatrix};
+    use super::select;
+
+    #[test]
+    fn test_threshold() {
+        let a = Matrix::new(3, 2, vec![0.1, 0.9, 0.5, 0.7, 0.2, 0.6]);
+        let mask = a.ge_scalar(0.5);
+
+        assert_eq!(*mask.data(), vec![false, true, true, true, false, true]);
+        assert_eq!(mask.count_true(), 4);
+        assert!(mask.any());
+        assert!(!mask.all());
+        assert_eq!(a.lt_scalar(0.5), mask.not());
+    }
+
+    #[test]
+    fn test_matrix_comparisons() {
+        let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+        let b = Matrix::new(2, 2, vec![4, 2, 1, 4]);
+
+        assert_eq!(*a.gt(&b).data(), vec![false, false, true, false]);
+        assert_eq!(*a.lt(&b).data(), vec![true, false, false, false]);
+        assert_eq!(*a.ge(&b).data(), vec![false, true, true, true]);
+        assert_eq!(*a.le(&b).data(), vec![true, true, false, true]);
+        assert_eq!(*a.eq_elem(&b).data(), vec![false, true, false, true]);
+        assert_eq!(*a.eq_scalar(4).data(), vec![false, false, false, true]);
+    }
+
+    #[test]
+    fn test_combine_masks() {
+        let a = Matrix::new(1, 5, vec![-2, -1, 0, 1, 2]);
+        let neg = a.lt_scalar(0);
+        let pos = a.gt_scalar(0);
+
+        assert_eq!(*neg.and(&pos).data(), vec![false; 5]);
+        assert_eq!(*neg.or(&pos).data(), vec![true, true, false, true, true]);
+        assert_eq!(neg.or(&pos).not(), a.eq_scalar(0));
+    }
+
+    #[test]
+    fn test_select() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b = Matrix::new(2, 3, vec![-1, -2, -3, -4, -5, -6]);
+        let mask = Matrix::new(2, 3, vec![true, false, true, false, true, false]);
+
+        let c = select(&mask, &a, &b);
+        assert_eq!(c, Matrix::new(2, 3, vec![1, -2, 3, -4, 5, -6]));
+
+        let c = select(&mask, &a.sub_slice([0, 0], 2, 3), &b);
+        assert_eq!(c, Matrix::new(2, 3, vec![1, -2, 3, -4, 5, -6]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_dimension_mismatch() {
+        let mask = Matrix::new(2, 2, vec![true; 4]);
+        let a = Matrix::new(2, 2, vec![1; 4]);
+        let b = Matrix::new(2, 3, vec![1; 6]);
+
+        let _ = select(&mask, &a, &b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_compare_dimension_mismatch() {
+        let a = Matrix::new(2, 2, vec![1; 4]);
+        let b = Matrix::new(3, 2, vec![1; 6]);
+
+        let _ = a.gt(&b);
+    }
+
+    #[test]
+    fn test_nan_comparisons() {
+        let a = Matrix::new(1, 3, vec![f64::NAN, 1.0, f64::NAN]);
+        let b = Matrix::new(1, 3, vec![0.0, f64::NAN, f64::NAN]);
+
+        assert!(!a.gt(&b).any());
+        assert!(!a.lt(&b).any());
+        assert!(!a.ge(&b).any());
+        assert!(!a.le(&b).any());
+        assert!(!a.eq_elem(&b).any());
+        assert!(!a.eq_elem(&a).all());
+        assert_eq!(a.ge_scalar(0.0).count_true(), 1);
+        assert_eq!(a.lt_scalar(f64::NAN).count_true(), 0);
+    }
+}
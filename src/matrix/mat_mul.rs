@@ -1,99 +1,491 @@
-use super::{Matrix, MatrixSlice, MatrixSliceMut, BaseMatrix};
+use super::{Matrix, MatrixSlice, MatrixSliceMut, BaseMatrix, BaseMatrixMut};
 
 use std::any::{Any, TypeId};
+use std::cmp;
 use std::ops::{Add, Mul};
 
-use libnum::Zero;
+use libnum::{Signed, Zero};
 use matrixmultiply;
 
+#[cfg(feature = "rayon")]
+use rayon;
+#[cfg(feature = "blas")]
+use blas;
+
 /// Return `true` if `A` and `B` are the same type
 fn same_type<A: Any, B: Any>() -> bool {
     TypeId::of::<A>() == TypeId::of::<B>()
 }
 
-macro_rules! mat_mul_general (
-    ($mat:ident) => (
+/// The tile size used by the generic multiplication kernel.
+const MUL_BLOCK: usize = 64;
+
+/// The minimum number of scalar multiplications (`p * q * r`) before
+/// a product is split across threads.
+///
+/// Below this the cost of spawning work outweighs the gain.
+#[cfg(feature = "rayon")]
+const PARALLEL_MUL_THRESHOLD: usize = 1 << 18;
+
+/// Return `true` if `T` is a primitive numeric type.
+///
+/// These are known to be `Send + Sync`, which the generic
+/// multiplication bounds do not otherwise guarantee.
+#[cfg(feature = "rayon")]
+fn is_primitive<T: Any>() -> bool {
+    same_type::<T, f32>() || same_type::<T, f64>() ||
+    same_type::<T, i8>() || same_type::<T, i16>() || same_type::<T, i32>() ||
+    same_type::<T, i64>() || same_type::<T, isize>() ||
+    same_type::<T, u8>() || same_type::<T, u16>() || same_type::<T, u32>() ||
+    same_type::<T, u64>() || same_type::<T, usize>()
+}
 
-    fn mul(self, m: &$mat<T>) -> Matrix<T> {
-        assert!(self.cols == m.rows, "Matrix dimensions do not agree.");
+/// Computes `rows` rows of the product of `a` (`rows x q`) and `b` (`q x r`).
+///
+/// The result is written to `out`, which must hold `rows * r` zeroed elements.
+unsafe fn mul_rows<T>(a: *const T, a_stride: usize,
+                      b: *const T, b_stride: usize,
+                      rows: usize, q: usize, r: usize,
+                      out: *mut T)
+    where T: Any + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    if same_type::<T, f32>() {
+        #[cfg(feature = "blas")]
+        blas_sgemm(a as *const _, a_stride, b as *const _, b_stride, rows, q, r, out as *mut _);
+        #[cfg(not(feature = "blas"))]
+        matrixmultiply::sgemm(
+            rows, q, r,
+            1f32,
+            a as *const _,
+            a_stride as isize, 1,
+            b as *const _,
+            b_stride as isize, 1,
+            0f32,
+            out as *mut _,
+            r as isize, 1
+            );
+    } else if same_type::<T, f64>() {
+        #[cfg(feature = "blas")]
+        blas_dgemm(a as *const _, a_stride, b as *const _, b_stride, rows, q, r, out as *mut _);
+        #[cfg(not(feature = "blas"))]
+        matrixmultiply::dgemm(
+            rows, q, r,
+            1f64,
+            a as *const _,
+            a_stride as isize, 1,
+            b as *const _,
+            b_stride as isize, 1,
+            0f64,
+            out as *mut _,
+            r as isize, 1
+            );
+    } else {
+        // Work on MUL_BLOCK x MUL_BLOCK tiles of `b` so that they stay in cache
+        // while each row of `a` passes over them. For every output element the
+        // products are still accumulated in increasing `k`.
+        for kk in (0..q).step_by(MUL_BLOCK)
+        {
+            let k_end = cmp::min(kk + MUL_BLOCK, q);
 
-        let p = self.rows;
-        let q = self.cols;
-        let r = m.cols;
+            for jj in (0..r).step_by(MUL_BLOCK)
+            {
+                let j_end = cmp::min(jj + MUL_BLOCK, r);
 
-        if same_type::<T, f32>() {
-            let mut new_data = Vec::with_capacity(p * r);
-
-            unsafe {
-                new_data.set_len(p * r);
-
-                matrixmultiply::sgemm(
-                    p, q, r,
-                    1f32,
-                    self.as_ptr() as *const _,
-                    self.row_stride() as isize, 1,
-                    m.as_ptr() as *const _,
-                    m.row_stride() as isize, 1,
-                    0f32,
-                    new_data.as_mut_ptr() as *mut _,
-                    r as isize, 1
-                    );
+                for i in 0..rows
+                {
+                    for k in kk..k_end
+                    {
+                        let a_ik = *a.offset((i * a_stride + k) as isize);
+                        for j in jj..j_end
+                        {
+                            let out_ij = out.offset((i * r + j) as isize);
+                            *out_ij = *out_ij + a_ik * *b.offset((k * b_stride + j) as isize);
+                        }
+                    }
+                }
             }
+        }
+    }
+}
 
-            Matrix {
-                rows: p,
-                cols: r,
-                data: new_data
-            }
-        } else if same_type::<T, f64>() {
-            let mut new_data = Vec::with_capacity(p * r);
-
-            unsafe {
-                new_data.set_len(p * r);
-
-                matrixmultiply::dgemm(
-                    p, q, r,
-                    1f64,
-                    self.as_ptr() as *const _,
-                    self.row_stride() as isize, 1,
-                    m.as_ptr() as *const _,
-                    m.row_stride() as isize, 1,
-                    0f64,
-                    new_data.as_mut_ptr() as *mut _,
-                    r as isize, 1
-                    );
-            }
+macro_rules! impl_blas_gemm (
+    ($(#[$attr:meta])* fn $name:ident, $gemm:path, $t:ty) => (
 
-            Matrix {
-                rows: p,
-                cols: r,
-                data: new_data
-            }
+$(#[$attr])*
+#[cfg(feature = "blas")]
+unsafe fn $name(a: *const $t, a_stride: usize,
+                b: *const $t, b_stride: usize,
+                rows: usize, q: usize, r: usize,
+                out: *mut $t) {
+    use std::slice;
 
-        } else {
-            let mut new_data = vec![T::zero(); p * r];
+    if rows == 0 || q == 0 || r == 0 {
+        // `out` is already zeroed.
+        return;
+    }
 
-            unsafe {
-                for i in 0..p
-                {
-                    for k in 0..q
-                    {
-                        for j in 0..r
-                        {
-                            new_data[i*r + j] = *new_data.get_unchecked(i*r + j) +
-                                                *self.get_unchecked([i,k]) *
-                                                *m.get_unchecked([k,j]);
+    assert!(cmp::max(a_stride, b_stride) <= i32::max_value() as usize &&
+            cmp::max(rows, r) <= i32::max_value() as usize,
+            "Matrix dimensions are too large for BLAS.");
+
+    let a = slice::from_raw_parts(a, (rows - 1) * a_stride + q);
+    let b = slice::from_raw_parts(b, (q - 1) * b_stride + r);
+    let out = slice::from_raw_parts_mut(out, rows * r);
+
+    // BLAS works on column-major matrices, in which the row-major `A`
+    // and `B` are stored as their transposes. So `Cᵀ = Bᵀ Aᵀ` is computed.
+    $gemm(b'N', b'N',
+          r as i32, rows as i32, q as i32,
+          1.0,
+          b, b_stride as i32,
+          a, a_stride as i32,
+          0.0,
+          out, r as i32);
+}
+    );
+);
+
+impl_blas_gemm!(
+    /// Computes the product as in `mul_rows` using the BLAS `sgemm` routine.
+    fn blas_sgemm, blas::sgemm, f32);
+
+impl_blas_gemm!(
+    /// Computes the product as in `mul_rows` using the BLAS `dgemm` routine.
+    fn blas_dgemm, blas::dgemm, f64);
+
+/// A raw pointer which may be shared between threads.
+///
+/// Only used for primitive types, with each thread writing to disjoint rows.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy)]
+struct SharedPtr<T>(*mut T);
+
+#[cfg(feature = "rayon")]
+unsafe impl<T> Send for SharedPtr<T> {}
+#[cfg(feature = "rayon")]
+unsafe impl<T> Sync for SharedPtr<T> {}
+
+/// Computes the product as in `mul_rows`, splitting the rows across threads.
+#[cfg(feature = "rayon")]
+unsafe fn par_mul_rows<T>(a: *const T, a_stride: usize,
+                          b: *const T, b_stride: usize,
+                          p: usize, q: usize, r: usize,
+                          out: *mut T)
+    where T: Any + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    use rayon::prelude::*;
+
+    let block_rows = cmp::max(1, p / (4 * rayon::current_num_threads()));
+    let blocks = (p + block_rows - 1) / block_rows;
+
+    let a = SharedPtr(a as *mut T);
+    let b = SharedPtr(b as *mut T);
+    let out = SharedPtr(out);
+
+    (0..blocks).into_par_iter().for_each(|block| {
+        let start = block * block_rows;
+        let rows = cmp::min(block_rows, p - start);
+
+        mul_rows(a.0.offset((start * a_stride) as isize) as *const T, a_stride,
+                 b.0 as *const T, b_stride,
+                 rows, q, r,
+                 out.0.offset((start * r) as isize));
+    });
+}
+
+/// Computes the `p x r` product of `a` and `b` into `out`.
+///
+/// With the `rayon` feature, large products of primitive types
+/// are computed in parallel over blocks of rows.
+unsafe fn mul_into<T>(a: *const T, a_stride: usize,
+                      b: *const T, b_stride: usize,
+                      p: usize, q: usize, r: usize,
+                      out: *mut T)
+    where T: Any + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    #[cfg(feature = "rayon")]
+    {
+        if p > 1 && p * q * r >= PARALLEL_MUL_THRESHOLD && is_primitive::<T>() {
+            return par_mul_rows(a, a_stride, b, b_stride, p, q, r, out);
+        }
+    }
+
+    mul_rows(a, a_stride, b, b_stride, p, q, r, out)
+}
+
+/// Computes `out = A B` for `f32` and `f64` with arbitrary strides.
+///
+/// Returns `false` without touching `out` if `T` is not a float type.
+unsafe fn float_gemm_strided<T: Any>(p: usize, q: usize, r: usize,
+                                     a: *const T, rsa: usize, csa: usize,
+                                     b: *const T, rsb: usize, csb: usize,
+                                     out: *mut T) -> bool {
+    if same_type::<T, f32>() {
+        matrixmultiply::sgemm(p, q, r,
+                              1f32,
+                              a as *const _, rsa as isize, csa as isize,
+                              b as *const _, rsb as isize, csb as isize,
+                              0f32,
+                              out as *mut _, r as isize, 1);
+        true
+    } else if same_type::<T, f64>() {
+        matrixmultiply::dgemm(p, q, r,
+                              1f64,
+                              a as *const _, rsa as isize, csa as isize,
+                              b as *const _, rsb as isize, csb as isize,
+                              0f64,
+                              out as *mut _, r as isize, 1);
+        true
+    } else {
+        false
+    }
+}
+
+impl<T> Matrix<T>
+    where T: Any + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    /// Computes `selfᵀ * b` without forming the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    ///
+    /// // The normal equations matrix AᵀA
+    /// let ata = a.mul_transpose_a(&a);
+    /// assert_eq!(ata, a.transpose() * &a);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrices do not have the same number of rows.
+    pub fn mul_transpose_a(&self, b: &Matrix<T>) -> Matrix<T> {
+        assert!(self.rows == b.rows, "Matrix dimensions do not agree.");
+
+        let p = self.cols;
+        let q = self.rows;
+        let r = b.cols;
+
+        let mut new_data = vec![T::zero(); p * r];
+
+        unsafe {
+            if !float_gemm_strided(p, q, r,
+                                   self.data.as_ptr(), 1, self.cols,
+                                   b.data.as_ptr(), b.cols, 1,
+                                   new_data.as_mut_ptr()) {
+                // Accumulate the outer products of the rows of `self` and `b`,
+                // reading both matrices row by row.
+                for k in 0..q {
+                    let a_row = &self.data[k * p..(k + 1) * p];
+                    let b_row = &b.data[k * r..(k + 1) * r];
+
+                    for (i, &a_ki) in a_row.iter().enumerate() {
+                        let out_row = &mut new_data[i * r..(i + 1) * r];
+                        for (out_ij, &b_kj) in out_row.iter_mut().zip(b_row) {
+                            *out_ij = *out_ij + a_ki * b_kj;
                         }
                     }
                 }
             }
+        }
+
+        Matrix {
+            rows: p,
+            cols: r,
+            data: new_data,
+        }
+    }
+
+    /// Computes `self * bᵀ` without forming the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    ///
+    /// let aat = a.mul_transpose_b(&a);
+    /// assert_eq!(aat, &a * a.transpose());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrices do not have the same number of columns.
+    pub fn mul_transpose_b(&self, b: &Matrix<T>) -> Matrix<T> {
+        assert!(self.cols == b.cols, "Matrix dimensions do not agree.");
 
-            Matrix {
-                rows: self.rows,
-                cols: m.cols,
-                data: new_data
+        let p = self.rows;
+        let q = self.cols;
+        let r = b.rows;
+
+        let mut new_data = vec![T::zero(); p * r];
+
+        unsafe {
+            if !float_gemm_strided(p, q, r,
+                                   self.data.as_ptr(), self.cols, 1,
+                                   b.data.as_ptr(), 1, b.cols,
+                                   new_data.as_mut_ptr()) && q > 0 {
+                // Each entry is the dot product of two contiguous rows.
+                for (i, a_row) in self.data.chunks(q).take(p).enumerate() {
+                    for (j, b_row) in b.data.chunks(q).take(r).enumerate() {
+                        new_data[i * r + j] = a_row.iter()
+                            .zip(b_row)
+                            .fold(T::zero(), |sum, (&x, &y)| sum + x * y);
+                    }
+                }
             }
         }
+
+        Matrix {
+            rows: p,
+            cols: r,
+            data: new_data,
+        }
+    }
+}
+
+/// The dimension at or below which `mul_strassen` uses standard multiplication.
+pub const STRASSEN_THRESHOLD: usize = 128;
+
+impl<T> Matrix<T>
+    where T: Any + Copy + Signed
+{
+    /// Multiplies two matrices using Strassen's algorithm.
+    ///
+    /// Performs seven half-size products per level in place of eight,
+    /// giving `O(n^2.807)` scaling. Subproblems of dimension at most
+    /// `STRASSEN_THRESHOLD` use standard multiplication. The operands
+    /// are zero-padded to a common square size, and odd sizes are padded
+    /// by one during the recursion.
+    ///
+    /// For floating point types the result may differ from `self * rhs`
+    /// by rounding, as the products are summed in a different order.
+    /// The element type must be signed, as the intermediate sums take
+    /// differences of the entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let b = Matrix::new(3, 2, vec![1, 0, 0, 1, 1, 1]);
+    ///
+    /// assert_eq!(a.mul_strassen(&b), &a * &b);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix dimensions do not agree.
+    pub fn mul_strassen(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.mul_strassen_with_threshold(rhs, STRASSEN_THRESHOLD)
+    }
+
+    /// Multiplies two matrices using Strassen's algorithm with the given crossover.
+    ///
+    /// As `mul_strassen`, but subproblems of dimension at most `threshold`
+    /// use standard multiplication. A `threshold` of zero is treated as one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(3, 3, (0..9).collect::<Vec<i64>>());
+    ///
+    /// assert_eq!(a.mul_strassen_with_threshold(&a, 1), &a * &a);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix dimensions do not agree.
+    pub fn mul_strassen_with_threshold(&self, rhs: &Matrix<T>, threshold: usize) -> Matrix<T> {
+        assert!(self.cols == rhs.rows, "Matrix dimensions do not agree.");
+
+        let n = cmp::max(self.rows, cmp::max(self.cols, rhs.cols));
+        if n <= threshold {
+            return self * rhs;
+        }
+
+        let out = strassen(&pad_square(self, n), &pad_square(rhs, n), cmp::max(threshold, 1));
+        out.submatrix(0..self.rows, 0..rhs.cols)
+    }
+}
+
+/// Copies `m` into the top left corner of an `n x n` zero matrix.
+fn pad_square<T: Copy + Zero>(m: &Matrix<T>, n: usize) -> Matrix<T> {
+    if m.rows == n && m.cols == n {
+        return m.clone();
+    }
+
+    let mut padded = Matrix::zeros(n, n);
+    padded.set_submatrix(0, 0, m);
+    padded
+}
+
+/// Strassen multiplication of two `n x n` matrices.
+fn strassen<T>(a: &Matrix<T>, b: &Matrix<T>, threshold: usize) -> Matrix<T>
+    where T: Any + Copy + Signed
+{
+    let n = a.rows;
+    if n <= threshold {
+        return a * b;
+    }
+
+    if n % 2 == 1 {
+        let out = strassen(&pad_square(a, n + 1), &pad_square(b, n + 1), threshold);
+        return out.submatrix(0..n, 0..n);
+    }
+
+    let h = n / 2;
+    let quarter = |m: &Matrix<T>, i: usize, j: usize| m.submatrix(i * h..(i + 1) * h, j * h..(j + 1) * h);
+
+    let (a11, a12, a21, a22) = (quarter(a, 0, 0), quarter(a, 0, 1), quarter(a, 1, 0), quarter(a, 1, 1));
+    let (b11, b12, b21, b22) = (quarter(b, 0, 0), quarter(b, 0, 1), quarter(b, 1, 0), quarter(b, 1, 1));
+
+    let m1 = strassen(&(&a11 + &a22), &(&b11 + &b22), threshold);
+    let m2 = strassen(&(&a21 + &a22), &b11, threshold);
+    let m3 = strassen(&a11, &(&b12 - &b22), threshold);
+    let m4 = strassen(&a22, &(&b21 - &b11), threshold);
+    let m5 = strassen(&(&a11 + &a12), &b22, threshold);
+    let m6 = strassen(&(&a21 - &a11), &(&b11 + &b12), threshold);
+    let m7 = strassen(&(&a12 - &a22), &(&b21 + &b22), threshold);
+
+    let mut c = Matrix::zeros(n, n);
+    c.set_submatrix(0, 0, &(&(&m1 + &m4) - &(&m5 - &m7)));
+    c.set_submatrix(0, h, &(&m3 + &m5));
+    c.set_submatrix(h, 0, &(&m2 + &m4));
+    c.set_submatrix(h, h, &(&(&m1 - &m2) + &(&m3 + &m6)));
+    c
+}
+
+macro_rules! mat_mul_general (
+    ($mat:ident) => (
+
+    fn mul(self, m: &$mat<T>) -> Matrix<T> {
+        assert!(self.cols == m.rows, "Matrix dimensions do not agree.");
+
+        let p = self.rows;
+        let q = self.cols;
+        let r = m.cols;
+
+        let mut new_data = vec![T::zero(); p * r];
+
+        unsafe {
+            mul_into(self.as_ptr(), self.row_stride(),
+                     m.as_ptr(), m.row_stride(),
+                     p, q, r,
+                     new_data.as_mut_ptr());
+        }
+
+        Matrix {
+            rows: p,
+            cols: r,
+            data: new_data
+        }
     }
 
     );
@@ -415,4 +807,175 @@ mod tests {
         assert_eq!(e[[1, 0]], 19);
         assert_eq!(e[[1, 1]], 28);
     }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_mul_matches_serial() {
+        use super::{mul_rows, par_mul_rows};
+
+        let n = 512;
+
+        let a = Matrix::new(n, n, (0..n * n).map(|x| x % 13).collect::<Vec<usize>>());
+        let b = Matrix::new(n, n, (0..n * n).map(|x| x % 7).collect::<Vec<usize>>());
+
+        let mut serial = vec![0usize; n * n];
+        let mut parallel = vec![0usize; n * n];
+        unsafe {
+            mul_rows(a.as_ptr(), n, b.as_ptr(), n, n, n, n, serial.as_mut_ptr());
+            par_mul_rows(a.as_ptr(), n, b.as_ptr(), n, n, n, n, parallel.as_mut_ptr());
+        }
+        assert_eq!(serial, parallel);
+        assert_eq!((&a * &b).into_vec(), serial);
+
+        let a = Matrix::new(n, n, (0..n * n).map(|x| (x % 13) as f64 / 7.0).collect::<Vec<_>>());
+        let b = Matrix::new(n, n, (0..n * n).map(|x| (x % 7) as f64 / 3.0).collect::<Vec<_>>());
+
+        let mut serial = vec![0f64; n * n];
+        let mut parallel = vec![0f64; n * n];
+        unsafe {
+            mul_rows(a.as_ptr(), n, b.as_ptr(), n, n, n, n, serial.as_mut_ptr());
+            par_mul_rows(a.as_ptr(), n, b.as_ptr(), n, n, n, n, parallel.as_mut_ptr());
+        }
+        for (x, y) in serial.iter().zip(parallel.iter()) {
+            assert!((x - y).abs() <= 1e-10 * x.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn float_mul_matches_generic() {
+        // Integral values are multiplied exactly by every kernel, so the
+        // float path (BLAS with the `blas` feature) must match the generic one.
+        let (p, q, r) = (37, 53, 29);
+
+        let a = Matrix::new(p, q, (0..p * q).map(|x| ((x * 7 + 3) % 11) as i64 - 5).collect::<Vec<_>>());
+        let b = Matrix::new(q, r, (0..q * r).map(|x| ((x * 5 + 1) % 13) as i64 - 6).collect::<Vec<_>>());
+        let expected = &a * &b;
+
+        let a64 = Matrix::new(p, q, a.data().iter().map(|&x| x as f64).collect::<Vec<_>>());
+        let b64 = Matrix::new(q, r, b.data().iter().map(|&x| x as f64).collect::<Vec<_>>());
+        assert_eq!(*(&a64 * &b64).data(),
+                   expected.data().iter().map(|&x| x as f64).collect::<Vec<_>>());
+
+        let a32 = Matrix::new(p, q, a.data().iter().map(|&x| x as f32).collect::<Vec<_>>());
+        let b32 = Matrix::new(q, r, b.data().iter().map(|&x| x as f32).collect::<Vec<_>>());
+        assert_eq!(*(&a32 * &b32).data(),
+                   expected.data().iter().map(|&x| x as f32).collect::<Vec<_>>());
+
+        // Strided slices of the matrices.
+        let sa = MatrixSlice::from_matrix(&a64, [3, 5], 20, 30);
+        let sb = MatrixSlice::from_matrix(&b64, [2, 4], 30, 17);
+        let c = sa * sb;
+        for i in 0..20 {
+            for j in 0..17 {
+                let e = (0..30).fold(0, |s, k| s + a[[i + 3, k + 5]] * b[[k + 2, j + 4]]);
+                assert_eq!(c[[i, j]], e as f64);
+            }
+        }
+
+        // Empty inner dimension.
+        let e = Matrix::<f64>::new(3, 0, vec![]) * Matrix::<f64>::new(0, 2, vec![]);
+        assert_eq!(e, Matrix::zeros(3, 2));
+    }
+
+    #[test]
+    fn blocked_mul_matches_naive() {
+        let (p, q, r) = (100, 130, 90);
+
+        let a = Matrix::new(p, q, (0..p * q).map(|x| ((x * 7 + 3) % 11) as i64).collect::<Vec<_>>());
+        let b = Matrix::new(q, r, (0..q * r).map(|x| ((x * 5 + 1) % 13) as i64).collect::<Vec<_>>());
+
+        let mut naive = vec![0i64; p * r];
+        for i in 0..p {
+            for j in 0..r {
+                for k in 0..q {
+                    naive[i * r + j] += a[[i, k]] * b[[k, j]];
+                }
+            }
+        }
+
+        assert_eq!((&a * &b).into_vec(), naive);
+
+        let a_slice = MatrixSlice::from_matrix(&a, [1, 2], 50, 100);
+        let b_slice = MatrixSlice::from_matrix(&b, [3, 1], 100, 70);
+        let c = a_slice * b_slice;
+        for i in 0..50 {
+            for j in 0..70 {
+                let expected = (0..100).fold(0, |s, k| s + a[[i + 1, k + 2]] * b[[k + 3, j + 1]]);
+                assert_eq!(c[[i, j]], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_transpose_a_matches_transpose() {
+        let a = Matrix::new(4, 3, (0..12).map(|x| ((x * 7 + 2) % 9) as f64).collect::<Vec<_>>());
+        let b = Matrix::new(4, 5, (0..20).map(|x| ((x * 3 + 1) % 11) as f64).collect::<Vec<_>>());
+
+        assert_eq!(a.mul_transpose_a(&b), a.transpose() * &b);
+        assert_eq!(a.mul_transpose_a(&a), a.transpose() * &a);
+
+        let a = Matrix::new(4, 3, (0..12).map(|x| (x * 7 + 2) % 9).collect::<Vec<i32>>());
+        let b = Matrix::new(4, 5, (0..20).map(|x| (x * 3 + 1) % 11).collect::<Vec<i32>>());
+
+        assert_eq!(a.mul_transpose_a(&b), a.transpose() * &b);
+    }
+
+    #[test]
+    fn mul_transpose_b_matches_transpose() {
+        let a = Matrix::new(3, 4, (0..12).map(|x| ((x * 7 + 2) % 9) as f32).collect::<Vec<_>>());
+        let b = Matrix::new(5, 4, (0..20).map(|x| ((x * 3 + 1) % 11) as f32).collect::<Vec<_>>());
+
+        assert_eq!(a.mul_transpose_b(&b), &a * b.transpose());
+        assert_eq!(a.mul_transpose_b(&a), &a * a.transpose());
+
+        let a = Matrix::new(3, 4, (0..12).map(|x| (x * 7 + 2) % 9).collect::<Vec<u64>>());
+        let b = Matrix::new(5, 4, (0..20).map(|x| (x * 3 + 1) % 11).collect::<Vec<u64>>());
+
+        assert_eq!(a.mul_transpose_b(&b), &a * b.transpose());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_transpose_a_bad_dims() {
+        let a = Matrix::new(3, 2, vec![0.0; 6]);
+        let b = Matrix::new(2, 3, vec![0.0; 6]);
+
+        let _ = a.mul_transpose_a(&b);
+    }
+
+    #[test]
+    fn test_mul_strassen_exact() {
+        // Integer entries make the result independent of summation order.
+        for &(p, q, r) in &[(1, 1, 1), (4, 4, 4), (5, 5, 5), (17, 17, 17), (33, 20, 9), (8, 31, 16)] {
+            let a = Matrix::new(p, q, (0..p * q).map(|x| (x % 11) as i64 - 5).collect::<Vec<_>>());
+            let b = Matrix::new(q, r, (0..q * r).map(|x| (x % 7) as i64 - 3).collect::<Vec<_>>());
+            let expected = &a * &b;
+
+            for &threshold in &[0, 1, 2, 3, 8] {
+                assert_eq!(a.mul_strassen_with_threshold(&b, threshold), expected);
+            }
+            assert_eq!(a.mul_strassen(&b), expected);
+        }
+    }
+
+    #[test]
+    fn test_mul_strassen_float() {
+        let n = 70;
+        let a = Matrix::new(n, n, (0..n * n).map(|x| ((x * 37) % 101) as f64 / 7.0 - 6.0).collect::<Vec<_>>());
+        let b = Matrix::new(n, n, (0..n * n).map(|x| ((x * 13) % 89) as f64 / 3.0 - 14.0).collect::<Vec<_>>());
+
+        let expected = &a * &b;
+        let c = a.mul_strassen_with_threshold(&b, 16);
+
+        for (x, y) in c.data().iter().zip(expected.data()) {
+            assert!((x - y).abs() < 1e-9 * (1.0 + y.abs()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_strassen_bad_dims() {
+        let a = Matrix::new(2, 3, vec![0; 6]);
+        let _ = a.mul_strassen(&a);
+    }
 }
@@ -7,8 +7,12 @@
 //! via `BaseMatrix` and `BaseMatrixMut` trait.
 
 use std::any::Any;
+use std::cmp;
+use std::cmp::Ordering;
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Add, Mul};
+use libnum;
 use libnum::{One, Zero, Float, FromPrimitive};
 
 use Metric;
@@ -16,13 +20,33 @@ use error::{Error, ErrorKind};
 use utils;
 use vector::Vector;
 
+mod column_major;
+mod complex;
 mod decomposition;
+mod givens;
+mod householder;
 mod impl_ops;
 mod mat_mul;
 mod iter;
+mod mask;
+mod permutation_matrix;
+mod symmetric;
+mod triangular;
+pub mod binary;
+pub mod io;
 pub mod slice;
+pub mod sparse;
 
 pub use self::slice::{BaseMatrix, BaseMatrixMut};
+pub use self::column_major::{ColumnMajorMatrix, ColumnMajorSlice};
+pub use self::decomposition::{Cholesky, PartialPivLu};
+pub use self::givens::Givens;
+pub use self::householder::Householder;
+pub use self::mask::select;
+pub use self::mat_mul::STRASSEN_THRESHOLD;
+pub use self::permutation_matrix::PermutationMatrix;
+pub use self::symmetric::SymmetricMatrix;
+pub use self::triangular::TriangularMatrix;
 
 /// Matrix dimensions
 #[derive(Debug, Clone, Copy)]
@@ -95,6 +119,69 @@ pub struct RowsMut<'a, T: 'a> {
     _marker: PhantomData<&'a mut T>,
 }
 
+/// Column iterator.
+#[derive(Debug)]
+pub struct Cols<'a, T: 'a> {
+    slice_start: *const T,
+    col_pos: usize,
+    slice_rows: usize,
+    slice_cols: usize,
+    row_stride: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+/// Mutable column iterator.
+#[derive(Debug)]
+pub struct ColsMut<'a, T: 'a> {
+    slice_start: *mut T,
+    col_pos: usize,
+    slice_rows: usize,
+    slice_cols: usize,
+    row_stride: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+/// A view of a single column of a matrix.
+///
+/// The column data is strided in memory, so unlike the rows
+/// it cannot be represented by a plain slice.
+#[derive(Debug, Clone, Copy)]
+pub struct Column<'a, T: 'a> {
+    ptr: *const T,
+    rows: usize,
+    row_stride: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// A mutable view of a single column of a matrix.
+#[derive(Debug)]
+pub struct ColumnMut<'a, T: 'a> {
+    ptr: *mut T,
+    rows: usize,
+    row_stride: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+/// Diagonal iterator.
+#[derive(Debug)]
+pub struct Diagonal<'a, T: 'a> {
+    ptr: *const T,
+    diag_pos: usize,
+    diag_len: usize,
+    stride: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+/// Mutable diagonal iterator.
+#[derive(Debug)]
+pub struct DiagonalMut<'a, T: 'a> {
+    ptr: *mut T,
+    diag_pos: usize,
+    diag_len: usize,
+    stride: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
 impl<T> Matrix<T> {
     /// Constructor for Matrix struct.
     ///
@@ -126,6 +213,105 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Constructs a matrix from an iterator over rows.
+    ///
+    /// The number of columns is taken from the first row. An empty
+    /// iterator gives a `0 x 0` matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let mat = Matrix::from_rows(rows).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - A row differs in length from the first row.
+    pub fn from_rows<I, R>(rows: I) -> Result<Matrix<T>, Error>
+        where I: IntoIterator<Item = R>,
+              R: IntoIterator<Item = T>
+    {
+        let mut data = Vec::new();
+        let mut n_rows = 0;
+        let mut n_cols = 0;
+
+        for row in rows {
+            let start = data.len();
+            data.extend(row);
+            let len = data.len() - start;
+
+            if n_rows == 0 {
+                n_cols = len;
+            } else if len != n_cols {
+                return Err(Error::new(ErrorKind::DimensionMismatch {
+                                          expected: (1, n_cols),
+                                          actual: (1, len),
+                                      },
+                                      format!("Row {} has length {} but expected {}.",
+                                              n_rows,
+                                              len,
+                                              n_cols)));
+            }
+            n_rows += 1;
+        }
+
+        Ok(Matrix {
+            rows: n_rows,
+            cols: n_cols,
+            data: data,
+        })
+    }
+
+    /// Constructs a matrix from a vector of rows.
+    ///
+    /// This is `from_rows` specialised to `Vec<Vec<T>>`. An empty
+    /// outer vector gives a `0 x 0` matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let mat = Matrix::from_vec_of_vecs(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::new(2, 2, vec![1, 2, 3, 4]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The inner vectors do not all have the same length.
+    pub fn from_vec_of_vecs(vecs: Vec<Vec<T>>) -> Result<Matrix<T>, Error> {
+        Matrix::from_rows(vecs)
+    }
+
+    /// Copies the rows of the matrix into a vector of vectors.
+    ///
+    /// A matrix with no rows gives an empty vector, so the number of
+    /// columns is lost for `0 x n` matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let mat = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(mat.to_vec_of_vecs(), vec![vec![1, 2], vec![3, 4]]);
+    /// ```
+    pub fn to_vec_of_vecs(&self) -> Vec<Vec<T>>
+        where T: Clone
+    {
+        if self.cols == 0 {
+            return vec![Vec::new(); self.rows];
+        }
+        self.data.chunks(self.cols).map(|row| row.to_vec()).collect()
+    }
+
     /// Returns a non-mutable reference to the underlying data.
     pub fn data(&self) -> &Vec<T> {
         &self.data
@@ -140,114 +326,1332 @@ impl<T> Matrix<T> {
     pub fn into_vec(self) -> Vec<T> {
         self.data
     }
-}
 
-impl<T: Clone> Clone for Matrix<T> {
-    /// Clones the Matrix.
-    fn clone(&self) -> Matrix<T> {
+    /// Consumes a single row or column matrix and returns it as a `Vector`.
+    ///
+    /// The data is moved, not copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(3, 1, vec![1, 2, 3]);
+    /// assert_eq!(a.into_vector().unwrap(), Vector::new(vec![1, 2, 3]));
+    ///
+    /// let b = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// assert!(b.into_vector().is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Neither dimension of the matrix is 1.
+    pub fn into_vector(self) -> Result<Vector<T>, Error> {
+        if self.rows != 1 && self.cols != 1 {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  format!("Cannot convert a {}x{} matrix to a vector.",
+                                          self.rows,
+                                          self.cols)));
+        }
+
+        Ok(Vector::new(self.data))
+    }
+
+    /// Changes the dimensions of the matrix, keeping the row-major data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let b = a.reshape(3, 2).unwrap();
+    ///
+    /// assert_eq!(b.rows(), 3);
+    /// assert_eq!(b[[1, 0]], 3);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `rows * cols` differs from the number of elements in the matrix.
+    pub fn reshape(self, rows: usize, cols: usize) -> Result<Matrix<T>, Error> {
+        if rows.checked_mul(cols) != Some(self.data.len()) {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "New dimensions do not match the number of elements."));
+        }
+
+        Ok(Matrix {
+            rows: rows,
+            cols: cols,
+            data: self.data,
+        })
+    }
+
+    /// Returns a view of the matrix data with new dimensions.
+    ///
+    /// The view reads the row-major data of the matrix as a
+    /// `rows x cols` matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let view = a.view_reshape(3, 2).unwrap();
+    ///
+    /// assert_eq!(view.rows(), 3);
+    /// assert_eq!(view[[1, 0]], 3);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `rows * cols` differs from the number of elements in the matrix.
+    pub fn view_reshape(&self, rows: usize, cols: usize) -> Result<MatrixSlice<'_, T>, Error> {
+        if rows.checked_mul(cols) != Some(self.data.len()) {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "New dimensions do not match the number of elements."));
+        }
+
+        unsafe { Ok(MatrixSlice::from_raw_parts(self.data.as_ptr(), rows, cols, cols)) }
+    }
+
+    /// Consumes the Matrix and applies a function to each element,
+    /// producing a matrix of a possibly different type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1u8, 2, 3, 4]);
+    /// let b = a.map_into(|x| x as f64 / 4.0);
+    ///
+    /// assert_eq!(*b.data(), vec![0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn map_into<U, F>(self, f: F) -> Matrix<U>
+        where F: FnMut(T) -> U
+    {
         Matrix {
             rows: self.rows,
             cols: self.cols,
-            data: self.data.clone(),
+            data: self.data.into_iter().map(f).collect(),
         }
     }
+
+    /// Consumes the Matrix and converts each element to type `U`.
+    ///
+    /// Only lossless conversions implementing `From` are accepted. Use
+    /// `map_into` for other conversions, such as `as` casts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1i32, 2, 3, 4]);
+    /// let b: Matrix<f64> = a.cast();
+    ///
+    /// assert_eq!(*b.data(), vec![1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn cast<U: From<T>>(self) -> Matrix<U> {
+        self.map_into(U::from)
+    }
 }
 
-impl<T: Clone + Zero> Matrix<T> {
-    /// Constructs matrix of all zeros.
+macro_rules! impl_round_to_int (
+    ($t:ty) => (
+
+/// Rounding conversions to integer matrices.
+///
+/// Values outside the range of `i64` saturate to `i64::MIN` or
+/// `i64::MAX`, and NaN is converted to `0`.
+impl Matrix<$t> {
+    /// Rounds each element to the nearest integer, with ties to even.
     ///
-    /// Requires both the row and the column dimensions.
+    /// A plain `as` cast truncates towards zero, so a value computed
+    /// as `2.9999999` would become `2`. This rounds it to `3` instead.
     ///
     /// # Examples
     ///
     /// ```
     /// use rulinalg::matrix::Matrix;
     ///
-    /// let mat = Matrix::<f64>::zeros(2,3);
+    #[doc = concat!("let a = Matrix::new(2, 2, vec![2.5", stringify!($t), ", 3.5, -0.5, 2.9999]);")]
+    /// let b = a.round_to_i64();
+    ///
+    /// assert_eq!(*b.data(), vec![2, 4, 0, 3]);
     /// ```
-    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+    pub fn round_to_i64(&self) -> Matrix<i64> {
+        self.map_to_i64(<$t>::round_ties_even)
+    }
+
+    /// Rounds each element down to the nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    #[doc = concat!("let a = Matrix::new(1, 3, vec![2.5", stringify!($t), ", -0.5, 3.0]);")]
+    ///
+    /// assert_eq!(*a.floor_to_i64().data(), vec![2, -1, 3]);
+    /// ```
+    pub fn floor_to_i64(&self) -> Matrix<i64> {
+        self.map_to_i64(<$t>::floor)
+    }
+
+    /// Rounds each element up to the nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    #[doc = concat!("let a = Matrix::new(1, 3, vec![2.5", stringify!($t), ", -0.5, 3.0]);")]
+    ///
+    /// assert_eq!(*a.ceil_to_i64().data(), vec![3, 0, 3]);
+    /// ```
+    pub fn ceil_to_i64(&self) -> Matrix<i64> {
+        self.map_to_i64(<$t>::ceil)
+    }
+
+    fn map_to_i64<F: Fn($t) -> $t>(&self, f: F) -> Matrix<i64> {
         Matrix {
-            cols: cols,
-            rows: rows,
-            data: vec![T::zero(); cols*rows],
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&x| f(x) as i64).collect(),
+        }
+    }
+}
+    );
+);
+
+impl_round_to_int!(f32);
+impl_round_to_int!(f64);
+
+impl<T: Copy> Matrix<T> {
+    /// Transposes the matrix in place.
+    ///
+    /// Square matrices are transposed by swapping elements across the
+    /// diagonal, without allocating. Other matrices are copied into a
+    /// new buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// a.transpose_in_place();
+    /// assert_eq!(a.data(), &vec![1, 3, 2, 4]);
+    ///
+    /// let mut b = Matrix::new(1, 3, vec![1, 2, 3]);
+    /// b.transpose_in_place();
+    /// assert_eq!(b.rows(), 3);
+    /// ```
+    pub fn transpose_in_place(&mut self) {
+        if self.rows == self.cols {
+            let n = self.rows;
+            for i in 0..n {
+                for j in 0..i {
+                    self.data.swap(i * n + j, j * n + i);
+                }
+            }
+        } else {
+            *self = self.transpose();
+        }
+    }
+
+    /// Returns a new matrix with `row` inserted before row `k`.
+    ///
+    /// Passing `k == self.rows()` appends the row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 5, 6]);
+    /// let b = a.insert_row(1, &Vector::new(vec![3, 4])).unwrap();
+    ///
+    /// assert_eq!(*b.data(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `k` is greater than the number of rows.
+    /// - The size of `row` does not match the number of columns.
+    pub fn insert_row(&self, k: usize, row: &Vector<T>) -> Result<Matrix<T>, Error> {
+        if k > self.rows {
+            return Err(Error::new(ErrorKind::InvalidArg, "Row index is out of bounds."));
+        }
+        if row.size() != self.cols {
+            return Err(Error::new(ErrorKind::DimensionMismatch {
+                                      expected: (1, self.cols),
+                                      actual: (1, row.size()),
+                                  },
+                                  "Row size does not match the number of columns."));
+        }
+
+        let mut data = Vec::with_capacity(self.data.len() + self.cols);
+        data.extend_from_slice(&self.data[..k * self.cols]);
+        data.extend_from_slice(row.data());
+        data.extend_from_slice(&self.data[k * self.cols..]);
+
+        Ok(Matrix {
+            rows: self.rows + 1,
+            cols: self.cols,
+            data: data,
+        })
+    }
+
+    /// Returns a new matrix with `col` inserted before column `k`.
+    ///
+    /// Passing `k == self.cols()` appends the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 3, 4, 6]);
+    /// let b = a.insert_col(1, &Vector::new(vec![2, 5])).unwrap();
+    ///
+    /// assert_eq!(*b.data(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `k` is greater than the number of columns.
+    /// - The size of `col` does not match the number of rows.
+    pub fn insert_col(&self, k: usize, col: &Vector<T>) -> Result<Matrix<T>, Error> {
+        if k > self.cols {
+            return Err(Error::new(ErrorKind::InvalidArg, "Column index is out of bounds."));
+        }
+        if col.size() != self.rows {
+            return Err(Error::new(ErrorKind::DimensionMismatch {
+                                      expected: (self.rows, 1),
+                                      actual: (col.size(), 1),
+                                  },
+                                  "Column size does not match the number of rows."));
+        }
+
+        let mut data = Vec::with_capacity(self.data.len() + self.rows);
+        for (i, &x) in col.data().iter().enumerate() {
+            let row = &self.data[i * self.cols..(i + 1) * self.cols];
+            data.extend_from_slice(&row[..k]);
+            data.push(x);
+            data.extend_from_slice(&row[k..]);
+        }
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols + 1,
+            data: data,
+        })
+    }
+
+    /// Returns a new matrix with row `k` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let b = a.delete_row(1);
+    ///
+    /// assert_eq!(*b.data(), vec![1, 2, 5, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `k` is not a valid row index.
+    pub fn delete_row(&self, k: usize) -> Matrix<T> {
+        assert!(k < self.rows, "Row index is greater than row dimension.");
+
+        let mut data = Vec::with_capacity(self.data.len() - self.cols);
+        data.extend_from_slice(&self.data[..k * self.cols]);
+        data.extend_from_slice(&self.data[(k + 1) * self.cols..]);
+
+        Matrix {
+            rows: self.rows - 1,
+            cols: self.cols,
+            data: data,
+        }
+    }
+
+    /// Returns a new matrix with column `k` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let b = a.delete_col(1);
+    ///
+    /// assert_eq!(*b.data(), vec![1, 3, 4, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `k` is not a valid column index.
+    pub fn delete_col(&self, k: usize) -> Matrix<T> {
+        assert!(k < self.cols, "Column index is greater than column dimension.");
+
+        let mut data = Vec::with_capacity(self.data.len() - self.rows);
+        for row in self.data.chunks(self.cols) {
+            data.extend_from_slice(&row[..k]);
+            data.extend_from_slice(&row[k + 1..]);
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols - 1,
+            data: data,
+        }
+    }
+
+    /// Repeats the matrix `row_times` times vertically and
+    /// `col_times` times horizontally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(1, 2, vec![1, 2]);
+    /// let b = a.tile(2, 2);
+    ///
+    /// assert_eq!(*b.data(), vec![1, 2, 1, 2,
+    ///                            1, 2, 1, 2]);
+    /// ```
+    pub fn tile(&self, row_times: usize, col_times: usize) -> Matrix<T> {
+        let rows = self.rows * row_times;
+        let cols = self.cols * col_times;
+        let mut data = Vec::with_capacity(rows * cols);
+
+        if rows * cols > 0 {
+            // Build the first band of tiles, then copy it for the remaining bands.
+            for row in self.data.chunks(self.cols) {
+                for _ in 0..col_times {
+                    data.extend_from_slice(row);
+                }
+            }
+
+            let band = data.len();
+            for _ in 1..row_times {
+                data.extend_from_within(..band);
+            }
+        }
+
+        Matrix {
+            rows: rows,
+            cols: cols,
+            data: data,
+        }
+    }
+
+    /// Repeats the matrix in a grid of `row_times x col_times` blocks.
+    ///
+    /// This is an alias for `tile`.
+    pub fn repmat(&self, row_times: usize, col_times: usize) -> Matrix<T> {
+        self.tile(row_times, col_times)
+    }
+
+    /// Returns the elements of the matrix as a vector in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// assert_eq!(a.flatten().into_vec(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn flatten(&self) -> Vector<T> {
+        Vector::new(self.data.clone())
+    }
+
+    /// Returns the elements of the matrix as a vector in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// assert_eq!(a.flatten_col_major().into_vec(), vec![1, 3, 2, 4]);
+    /// ```
+    pub fn flatten_col_major(&self) -> Vector<T> {
+        Vector::new(self.transpose().into_vec())
+    }
+
+    /// Constructs a matrix from a vector holding the elements in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let v = Vector::new(vec![1, 2, 3, 4, 5, 6]);
+    /// let a = Matrix::from_vector_row_major(v, 2, 3).unwrap();
+    ///
+    /// assert_eq!(a[[1, 0]], 4);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The size of the vector is not `rows * cols`.
+    pub fn from_vector_row_major(v: Vector<T>, rows: usize, cols: usize) -> Result<Matrix<T>, Error> {
+        if v.size() != rows * cols {
+            return Err(Error::new(ErrorKind::DimensionMismatch {
+                                      expected: (rows * cols, 1),
+                                      actual: (v.size(), 1),
+                                  },
+                                  "Vector size does not match the given dimensions."));
+        }
+
+        Ok(Matrix::new(rows, cols, v.into_vec()))
+    }
+
+    /// Constructs a matrix from a vector holding the elements in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let v = Vector::new(vec![1, 2, 3, 4, 5, 6]);
+    /// let a = Matrix::from_vector_col_major(v, 2, 3).unwrap();
+    ///
+    /// assert_eq!(a[[1, 0]], 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The size of the vector is not `rows * cols`.
+    pub fn from_vector_col_major(v: Vector<T>, rows: usize, cols: usize) -> Result<Matrix<T>, Error> {
+        if v.size() != rows * cols {
+            return Err(Error::new(ErrorKind::DimensionMismatch {
+                                      expected: (rows * cols, 1),
+                                      actual: (v.size(), 1),
+                                  },
+                                  "Vector size does not match the given dimensions."));
+        }
+
+        Ok(Matrix::new(cols, rows, v.into_vec()).transpose())
+    }
+}
+
+impl<T: Copy + PartialOrd> Matrix<T> {
+    /// Sorts the rows so that the given column is ascending.
+    ///
+    /// The sort is stable, and rows with a NaN key are moved to
+    /// the end in their original order. Returns the permutation
+    /// `P` such that `P * A` is the sorted matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let mut a = Matrix::new(3, 2, vec![3.0, 0.0,
+    ///                                    1.0, 1.0,
+    ///                                    2.0, 2.0]);
+    /// a.sort_rows_by_column(0);
+    ///
+    /// assert_eq!(*a.data(), vec![1.0, 1.0, 2.0, 2.0, 3.0, 0.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The column index is out of bounds.
+    pub fn sort_rows_by_column(&mut self, col: usize) -> PermutationMatrix<T> {
+        self.sort_rows_by_column_by(col, utils::nan_last_cmp)
+    }
+}
+
+impl<T: Copy> Matrix<T> {
+    /// Sorts the rows by the given column using a comparator.
+    ///
+    /// The sort is stable. Returns the permutation `P` such that
+    /// `P * A` is the sorted matrix.
+    ///
+    /// The rows are moved with at most `rows - 1` swaps after
+    /// sorting the row indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let mut a = Matrix::new(3, 1, vec![1, 3, 2]);
+    /// a.sort_rows_by_column_by(0, |x, y| y.cmp(x));
+    ///
+    /// assert_eq!(*a.data(), vec![3, 2, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The column index is out of bounds.
+    pub fn sort_rows_by_column_by<F>(&mut self, col: usize, mut compare: F) -> PermutationMatrix<T>
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        assert!(col < self.cols, "Column index is greater than column dimension.");
+
+        let mut order = (0..self.rows).collect::<Vec<_>>();
+        {
+            let data = &self.data;
+            let cols = self.cols;
+            order.sort_by(|&i, &j| compare(&data[i * cols + col], &data[j * cols + col]));
+        }
+
+        // Row `order[k]` moves to row `k`.
+        let mut perm = vec![0; self.rows];
+        for (k, &i) in order.iter().enumerate() {
+            perm[i] = k;
+        }
+
+        let p = PermutationMatrix::from_array(perm.clone())
+            .expect("Sorted indices always form a permutation.");
+
+        for i in 0..self.rows {
+            while perm[i] != i {
+                let j = perm[i];
+                self.swap_rows(i, j);
+                perm.swap(i, j);
+            }
+        }
+
+        p
+    }
+}
+
+impl<T: Clone> Clone for Matrix<T> {
+    /// Clones the Matrix.
+    fn clone(&self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Zero> Matrix<T> {
+    /// Constructs matrix of all zeros.
+    ///
+    /// Requires both the row and the column dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let mat = Matrix::<f64>::zeros(2,3);
+    /// ```
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix {
+            cols: cols,
+            rows: rows,
+            data: vec![T::zero(); cols*rows],
+        }
+    }
+
+    /// Constructs matrix with given diagonal.
+    ///
+    /// Requires slice of diagonal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let mat = Matrix::from_diag(&vec![1.0,2.0,3.0,4.0]);
+    /// ```
+    pub fn from_diag(diag: &[T]) -> Matrix<T> {
+        let size = diag.len();
+        let mut data = vec![T::zero(); size * size];
+
+        for (i, item) in diag.into_iter().enumerate().take(size) {
+            data[i * (size + 1)] = item.clone();
+        }
+
+        Matrix {
+            cols: size,
+            rows: size,
+            data: data,
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> Matrix<T> {
+    /// Constructs the Toeplitz matrix with the given first column and row.
+    ///
+    /// Every diagonal of a Toeplitz matrix is constant, so entry
+    /// `[i, j]` is `first_col[i - j]` below the diagonal and
+    /// `first_row[j - i]` above it. The result has `first_col.size()`
+    /// rows and `first_row.size()` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let col = Vector::new(vec![1, 2, 3]);
+    /// let row = Vector::new(vec![1, 4, 5, 6]);
+    /// let t = Matrix::toeplitz(&col, &row).unwrap();
+    ///
+    /// assert_eq!(t, Matrix::new(3, 4, vec![1, 4, 5, 6,
+    ///                                      2, 1, 4, 5,
+    ///                                      3, 2, 1, 4]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The first entries of `first_col` and `first_row` differ.
+    pub fn toeplitz(first_col: &Vector<T>, first_row: &Vector<T>) -> Result<Matrix<T>, Error> {
+        check_toeplitz_corner(first_col, first_row)?;
+
+        let (rows, cols) = (first_col.size(), first_row.size());
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            data.extend((0..cols).map(|j| toeplitz_entry(first_col, first_row, i, j)));
+        }
+
+        Ok(Matrix {
+            rows: rows,
+            cols: cols,
+            data: data,
+        })
+    }
+
+    /// Multiplies the Toeplitz matrix given by `first_col` and `first_row` with `x`.
+    ///
+    /// Gives the same result as `Matrix::toeplitz(first_col, first_row)? * x`
+    /// in `O(mn)` time, without forming the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// // Convolution with the kernel [1, 2, 3].
+    /// let col = Vector::new(vec![1.0, 2.0, 3.0, 0.0]);
+    /// let row = Vector::new(vec![1.0, 0.0]);
+    /// let y = Matrix::toeplitz_matvec(&col, &row, &Vector::new(vec![1.0, 1.0])).unwrap();
+    ///
+    /// assert_eq!(y, Vector::new(vec![1.0, 3.0, 5.0, 3.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The size of `x` does not match the size of `first_row`.
+    ///
+    /// # Failures
+    ///
+    /// - The first entries of `first_col` and `first_row` differ.
+    pub fn toeplitz_matvec(first_col: &Vector<T>,
+                           first_row: &Vector<T>,
+                           x: &Vector<T>)
+                           -> Result<Vector<T>, Error>
+        where T: Zero + Add<T, Output = T> + Mul<T, Output = T>
+    {
+        check_toeplitz_corner(first_col, first_row)?;
+        assert!(x.size() == first_row.size(),
+                "Matrix and Vector dimensions do not agree.");
+
+        let (c, r, x) = (first_col.data(), first_row.data(), x.data());
+        let y = (0..c.len())
+            .map(|i| {
+                // Below and on the diagonal entry [i, j] is c[i - j],
+                // above it r[j - i].
+                let split = cmp::min(i + 1, x.len());
+                let lower = (0..split).fold(T::zero(), |s, j| s + c[i - j] * x[j]);
+                (split..x.len()).fold(lower, |s, j| s + r[j - i] * x[j])
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Vector::new(y))
+    }
+}
+
+/// Checks that the first column and row of a Toeplitz matrix share their corner.
+fn check_toeplitz_corner<T: PartialEq>(first_col: &Vector<T>, first_row: &Vector<T>) -> Result<(), Error> {
+    match (first_col.data().first(), first_row.data().first()) {
+        (Some(c), Some(r)) if c != r => {
+            Err(Error::new(ErrorKind::InvalidArg,
+                           "First column and first row of a Toeplitz matrix must share their \
+                            first entry."))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns entry `[i, j]` of a Toeplitz matrix.
+fn toeplitz_entry<T: Copy>(first_col: &Vector<T>, first_row: &Vector<T>, i: usize, j: usize) -> T {
+    if i >= j {
+        first_col[i - j]
+    } else {
+        first_row[j - i]
+    }
+}
+
+impl<T: Clone + One> Matrix<T> {
+    /// Constructs matrix of all ones.
+    ///
+    /// Requires both the row and the column dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let mat = Matrix::<f64>::ones(2,3);
+    /// ```
+    pub fn ones(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix {
+            cols: cols,
+            rows: rows,
+            data: vec![T::one(); cols*rows],
+        }
+    }
+}
+
+impl<T: Clone + Zero + One> Matrix<T> {
+    /// Constructs the identity matrix.
+    ///
+    /// Requires the size of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let I = Matrix::<f64>::identity(4);
+    /// ```
+    pub fn identity(size: usize) -> Matrix<T> {
+        let mut data = vec![T::zero(); size * size];
+
+        for i in 0..size {
+            data[(i * (size + 1)) as usize] = T::one();
+        }
+
+        Matrix {
+            cols: size,
+            rows: size,
+            data: data,
+        }
+    }
+}
+
+impl<T: Copy + Add<T, Output = T>> Matrix<T> {
+    /// The cumulative sum of the matrix along the specified axis.
+    ///
+    /// - Axis Row - Running sums down each column.
+    /// - Axis Col - Running sums along each row.
+    ///
+    /// Overflow follows the semantics of the `+` operator for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Axes};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// let c = a.cumsum(Axes::Row);
+    /// assert_eq!(*c.data(), vec![1, 2, 4, 6]);
+    ///
+    /// let d = a.cumsum(Axes::Col);
+    /// assert_eq!(*d.data(), vec![1, 3, 3, 7]);
+    /// ```
+    pub fn cumsum(&self, axis: Axes) -> Matrix<T> {
+        let mut data = self.data.clone();
+
+        if self.cols > 0 {
+            match axis {
+                Axes::Row => {
+                    // Combine each row with the previous one as a whole.
+                    for i in 1..self.rows {
+                        let (prev, rest) = data.split_at_mut(i * self.cols);
+                        utils::in_place_vec_bin_op(&mut rest[..self.cols],
+                                                   &prev[(i - 1) * self.cols..],
+                                                   |x, &y| *x = y + *x);
+                    }
+                }
+                Axes::Col => {
+                    for row in data.chunks_mut(self.cols) {
+                        for j in 1..row.len() {
+                            row[j] = row[j - 1] + row[j];
+                        }
+                    }
+                }
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: data,
+        }
+    }
+
+    /// The cumulative sum down each column of the matrix.
+    ///
+    /// Entry `[i, j]` of the result is the sum of entries `[0, j]`
+    /// through `[i, j]`. This is `cumsum(Axes::Row)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(a.cumsum_cols(), Matrix::new(3, 2, vec![1, 2, 4, 6, 9, 12]));
+    /// ```
+    pub fn cumsum_cols(&self) -> Matrix<T> {
+        self.cumsum(Axes::Row)
+    }
+
+    /// The cumulative sum along each row of the matrix.
+    ///
+    /// Entry `[i, j]` of the result is the sum of entries `[i, 0]`
+    /// through `[i, j]`. This is `cumsum(Axes::Col)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(a.cumsum_rows(), Matrix::new(2, 3, vec![1, 3, 6, 4, 9, 15]));
+    /// ```
+    pub fn cumsum_rows(&self) -> Matrix<T> {
+        self.cumsum(Axes::Col)
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Matrix<T> {
+    /// The cumulative product of the matrix along the specified axis.
+    ///
+    /// - Axis Row - Running products down each column.
+    /// - Axis Col - Running products along each row.
+    ///
+    /// Overflow follows the semantics of the `*` operator for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, Axes};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// let c = a.cumprod(Axes::Row);
+    /// assert_eq!(*c.data(), vec![1, 2, 3, 8]);
+    ///
+    /// let d = a.cumprod(Axes::Col);
+    /// assert_eq!(*d.data(), vec![1, 2, 3, 12]);
+    /// ```
+    pub fn cumprod(&self, axis: Axes) -> Matrix<T> {
+        let mut data = self.data.clone();
+
+        if self.cols > 0 {
+            match axis {
+                Axes::Row => {
+                    // Combine each row with the previous one as a whole.
+                    for i in 1..self.rows {
+                        let (prev, rest) = data.split_at_mut(i * self.cols);
+                        utils::in_place_vec_bin_op(&mut rest[..self.cols],
+                                                   &prev[(i - 1) * self.cols..],
+                                                   |x, &y| *x = y * *x);
+                    }
+                }
+                Axes::Col => {
+                    for row in data.chunks_mut(self.cols) {
+                        for j in 1..row.len() {
+                            row[j] = row[j - 1] * row[j];
+                        }
+                    }
+                }
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: data,
+        }
+    }
+
+    /// Multiplies row `i` of the matrix by `scales[i]`.
+    ///
+    /// This is the product `diag(scales) * self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// a.scale_rows_in_place(&Vector::new(vec![2, 10]));
+    ///
+    /// assert_eq!(*a.data(), vec![2, 4, 30, 40]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The size of `scales` is not the number of rows.
+    pub fn scale_rows_in_place(&mut self, scales: &Vector<T>) {
+        assert!(scales.size() == self.rows, "Vector size must match the number of rows.");
+
+        if self.cols > 0 {
+            for (row, &s) in self.data.chunks_mut(self.cols).zip(scales.data()) {
+                for x in row {
+                    *x = *x * s;
+                }
+            }
+        }
+    }
+
+    /// Multiplies column `j` of the matrix by `scales[j]`.
+    ///
+    /// This is the product `self * diag(scales)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// a.scale_cols_in_place(&Vector::new(vec![2, 10]));
+    ///
+    /// assert_eq!(*a.data(), vec![2, 20, 6, 40]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The size of `scales` is not the number of columns.
+    pub fn scale_cols_in_place(&mut self, scales: &Vector<T>) {
+        assert!(scales.size() == self.cols, "Vector size must match the number of columns.");
+
+        if self.cols > 0 {
+            for row in self.data.chunks_mut(self.cols) {
+                utils::in_place_vec_bin_op(row, scales.data(), |x, &s| *x = *x * s);
+            }
+        }
+    }
+}
+
+/// The number of columns `apply_on_left` copies out at a time.
+const APPLY_BLOCK_COLS: usize = 64;
+
+impl<T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>> Matrix<T> {
+    /// Computes the diagonal of `self * other` without forming the product.
+    ///
+    /// Each entry is `Σ_k self[i, k] * other[k, i]`, taking `O(n²)`
+    /// time rather than the `O(n³)` of the full product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let b = Matrix::new(2, 2, vec![5, 6, 7, 8]);
+    ///
+    /// assert_eq!(*a.diag_of_product(&b).data(), vec![19, 50]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The columns of `self` do not match the rows of `other`.
+    pub fn diag_of_product(&self, other: &Matrix<T>) -> Vector<T> {
+        assert!(self.cols == other.rows, "Matrix dimensions do not agree.");
+
+        let n = cmp::min(self.rows, other.cols);
+        let mut diag = Vec::with_capacity(n);
+
+        for i in 0..n {
+            let row = &self.data[i * self.cols..(i + 1) * self.cols];
+            let mut sum = T::zero();
+            for (k, &a_ik) in row.iter().enumerate() {
+                sum = sum + a_ik * other.data[k * other.cols + i];
+            }
+            diag.push(sum);
+        }
+
+        Vector::new(diag)
+    }
+
+    /// Computes the diagonal of `selfᵀ * self`.
+    ///
+    /// These are the squared euclidean norms of the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(*a.diag_of_ata().data(), vec![10, 20]);
+    /// ```
+    pub fn diag_of_ata(&self) -> Vector<T> {
+        let mut diag = vec![T::zero(); self.cols];
+
+        if self.cols > 0 {
+            for row in self.data.chunks(self.cols) {
+                utils::in_place_vec_bin_op(&mut diag, row, |d, &x| *d = *d + x * x);
+            }
+        }
+
+        Vector::new(diag)
+    }
+
+    /// Computes the trace of `self * other` without forming the product.
+    ///
+    /// This is `Σ_ij self[i, j] * other[j, i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let b = Matrix::new(3, 2, vec![1, 0, 0, 1, 1, 1]);
+    ///
+    /// assert_eq!(a.trace_of_product(&b), 15);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The dimensions of `other` are not the transpose of those of `self`.
+    pub fn trace_of_product(&self, other: &Matrix<T>) -> T {
+        assert!(self.cols == other.rows && self.rows == other.cols,
+                "Matrix dimensions do not agree.");
+
+        let mut sum = T::zero();
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                sum = sum + self.data[i * self.cols + j] * other.data[j * other.cols + i];
+            }
+        }
+
+        sum
+    }
+
+    /// Overwrites `other` with `self * other`.
+    ///
+    /// The columns of `other` are copied out a block at a time, so the
+    /// scratch space is bounded and `other` is only accessed along its
+    /// rows. This is useful for accumulating products of transforms into
+    /// an existing matrix or slice. A reflector is much cheaper to apply
+    /// with `Householder::apply_left` than as a dense matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![0, 1, 1, 1]);
+    /// let mut b = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let expected = &a * &b;
+    ///
+    /// a.apply_on_left(&mut b);
+    /// assert_eq!(b, expected);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `self` is not square.
+    /// - The size of `self` does not match the rows of `other`.
+    pub fn apply_on_left<M: BaseMatrixMut<T>>(&self, other: &mut M) {
+        assert!(self.rows == self.cols, "Matrix must be square.");
+        assert!(self.cols == other.rows(), "Matrix dimensions do not agree.");
+
+        let n = self.rows;
+        let cols = other.cols();
+        if n == 0 {
+            return;
+        }
+        let mut scratch = Vec::with_capacity(n * cmp::min(cols, APPLY_BLOCK_COLS));
+
+        let mut start = 0;
+        while start < cols {
+            let end = cmp::min(start + APPLY_BLOCK_COLS, cols);
+            let width = end - start;
+
+            // Column c of the block is stored contiguously at `c * n`.
+            scratch.clear();
+            scratch.resize(n * width, T::zero());
+            for (k, row) in other.iter_rows().enumerate() {
+                for (c, &x) in row[start..end].iter().enumerate() {
+                    scratch[c * n + k] = x;
+                }
+            }
+
+            for (row, self_row) in other.iter_rows_mut().zip(self.data.chunks(n)) {
+                for (x, col) in row[start..end].iter_mut().zip(scratch.chunks(n)) {
+                    *x = utils::dot(self_row, col);
+                }
+            }
+
+            start = end;
+        }
+    }
+
+    /// Overwrites `other` with `other * self`.
+    ///
+    /// Each row of `other` is multiplied through a single scratch row,
+    /// so no matrix is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![0, 1, 1, 1]);
+    /// let mut b = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// let expected = &b * &a;
+    ///
+    /// a.apply_on_right(&mut b);
+    /// assert_eq!(b, expected);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `self` is not square.
+    /// - The size of `self` does not match the columns of `other`.
+    pub fn apply_on_right<M: BaseMatrixMut<T>>(&self, other: &mut M) {
+        assert!(self.rows == self.cols, "Matrix must be square.");
+        assert!(other.cols() == self.rows, "Matrix dimensions do not agree.");
+
+        let n = self.rows;
+        let mut scratch = Vec::with_capacity(n);
+
+        for row in other.iter_rows_mut() {
+            scratch.clear();
+            scratch.extend_from_slice(row);
+
+            // The new row is the combination of the rows of `self`
+            // weighted by the old entries.
+            for x in row.iter_mut() {
+                *x = T::zero();
+            }
+            for (k, &w) in scratch.iter().enumerate() {
+                let self_row = &self.data[k * n..(k + 1) * n];
+                utils::in_place_vec_bin_op(row, self_row, |x, &y| *x = *x + w * y);
+            }
         }
     }
+}
 
-    /// Constructs matrix with given diagonal.
+impl<T: Float> Matrix<T> {
+    /// Scales each row to have unit infinity norm.
     ///
-    /// Requires slice of diagonal elements.
+    /// Returns the scaled matrix `diag(r) * self` and the scale
+    /// factors `r`, where `r[i]` is the reciprocal of the largest
+    /// absolute value in row `i`. Rows of zeros are left unscaled
+    /// with a factor of one.
     ///
     /// # Examples
     ///
     /// ```
     /// use rulinalg::matrix::Matrix;
     ///
-    /// let mat = Matrix::from_diag(&vec![1.0,2.0,3.0,4.0]);
+    /// let a = Matrix::new(2, 2, vec![1.0, -4.0, 0.5, 0.25]);
+    /// let (b, r) = a.row_scale();
+    ///
+    /// assert_eq!(*b.data(), vec![0.25, -1.0, 1.0, 0.5]);
+    /// assert_eq!(*r.data(), vec![0.25, 2.0]);
     /// ```
-    pub fn from_diag(diag: &[T]) -> Matrix<T> {
-        let size = diag.len();
-        let mut data = vec![T::zero(); size * size];
-
-        for (i, item) in diag.into_iter().enumerate().take(size) {
-            data[i * (size + 1)] = item.clone();
-        }
-
-        Matrix {
-            cols: size,
-            rows: size,
-            data: data,
-        }
+    pub fn row_scale(&self) -> (Matrix<T>, Vector<T>) {
+        let scales = self.iter_rows()
+            .map(|row| inf_norm_reciprocal(row.iter()))
+            .collect::<Vec<_>>();
+        let scales = Vector::new(scales);
+
+        let mut scaled = self.clone();
+        scaled.scale_rows_in_place(&scales);
+        (scaled, scales)
     }
-}
 
-impl<T: Clone + One> Matrix<T> {
-    /// Constructs matrix of all ones.
+    /// Scales each column to have unit infinity norm.
     ///
-    /// Requires both the row and the column dimensions.
+    /// Returns the scaled matrix `self * diag(c)` and the scale
+    /// factors `c`, where `c[j]` is the reciprocal of the largest
+    /// absolute value in column `j`. Columns of zeros are left
+    /// unscaled with a factor of one.
     ///
     /// # Examples
     ///
     /// ```
     /// use rulinalg::matrix::Matrix;
     ///
-    /// let mat = Matrix::<f64>::ones(2,3);
+    /// let a = Matrix::new(2, 2, vec![1.0, -4.0, 0.5, 0.25]);
+    /// let (b, c) = a.col_scale();
+    ///
+    /// assert_eq!(*b.data(), vec![1.0, -1.0, 0.5, 0.0625]);
+    /// assert_eq!(*c.data(), vec![1.0, 0.25]);
     /// ```
-    pub fn ones(rows: usize, cols: usize) -> Matrix<T> {
-        Matrix {
-            cols: cols,
-            rows: rows,
-            data: vec![T::one(); cols*rows],
-        }
+    pub fn col_scale(&self) -> (Matrix<T>, Vector<T>) {
+        let scales = self.iter_cols()
+            .map(|col| inf_norm_reciprocal(col.iter()))
+            .collect::<Vec<_>>();
+        let scales = Vector::new(scales);
+
+        let mut scaled = self.clone();
+        scaled.scale_cols_in_place(&scales);
+        (scaled, scales)
     }
-}
 
-impl<T: Clone + Zero + One> Matrix<T> {
-    /// Constructs the identity matrix.
+    /// Equilibrates the rows and then the columns of the matrix.
     ///
-    /// Requires the size of the matrix.
+    /// Returns `(diag(r) * self * diag(c), r, c)`. Afterwards every
+    /// non-zero row and column has an infinity norm of one. The
+    /// original matrix can be recovered by dividing row `i` by `r[i]`
+    /// and column `j` by `c[j]`.
     ///
     /// # Examples
     ///
     /// ```
     /// use rulinalg::matrix::Matrix;
     ///
-    /// let I = Matrix::<f64>::identity(4);
+    /// let a = Matrix::new(2, 2, vec![1e6f64, 2e6, 3.0, 1e-3]);
+    /// let (b, r, c) = a.equilibrate();
+    ///
+    /// for i in 0..2 {
+    ///     assert!((b[[i, 0]].abs().max(b[[i, 1]].abs()) - 1.0).abs() < 1e-12);
+    /// }
     /// ```
-    pub fn identity(size: usize) -> Matrix<T> {
-        let mut data = vec![T::zero(); size * size];
-
-        for i in 0..size {
-            data[(i * (size + 1)) as usize] = T::one();
-        }
-
-        Matrix {
-            cols: size,
-            rows: size,
-            data: data,
-        }
+    pub fn equilibrate(&self) -> (Matrix<T>, Vector<T>, Vector<T>) {
+        let (rows_scaled, r) = self.row_scale();
+        let (scaled, c) = rows_scaled.col_scale();
+        (scaled, r, c)
     }
 }
 
+/// Returns the reciprocal of the largest absolute value, or one if all are zero.
+fn inf_norm_reciprocal<'a, T: Float + 'a, I: Iterator<Item = &'a T>>(xs: I) -> T {
+    let max = xs.fold(T::zero(), |m, x| m.max(x.abs()));
+    if max > T::zero() { max.recip() } else { T::one() }
+}
+
 impl<T: Float + FromPrimitive> Matrix<T> {
     /// The mean of the matrix along the specified axis.
     ///
@@ -393,10 +1797,44 @@ impl<T: Any + Float> Matrix<T> {
     pub fn solve(&self, y: Vector<T>) -> Result<Vector<T>, Error> {
         let (l, u, p) = try!(self.lup_decomp());
 
-        let b = try!(forward_substitution(&l, p * y));
+        let b = unit_forward_substitution(&l, p * y);
         back_substitution(&u, b)
     }
 
+    /// Solves the equation `Ax = b` and returns `x` with the residual `b - Ax`.
+    ///
+    /// The residual gives a direct measure of the quality of the solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2,2, vec![2.0,3.0,1.0,2.0]);
+    /// let b = Vector::new(vec![13.0,8.0]);
+    ///
+    /// let (x, r) = a.solve_with_residual(&b).unwrap();
+    ///
+    /// assert_eq!(*x.data(), vec![2.0, 3.0]);
+    /// assert_eq!(*r.data(), vec![0.0, 0.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix column count and vector size are different.
+    /// - The matrix is not square.
+    ///
+    /// # Failures
+    ///
+    /// - The matrix cannot be decomposed into an LUP form to solve.
+    /// - There is no valid solution as the matrix is singular.
+    pub fn solve_with_residual(&self, b: &Vector<T>) -> Result<(Vector<T>, Vector<T>), Error> {
+        let x = self.solve(b.clone())?;
+        let r = b - self * &x;
+        Ok((x, r))
+    }
+
     /// Computes the inverse of the matrix.
     ///
     /// # Examples
@@ -423,43 +1861,63 @@ impl<T: Any + Float> Matrix<T> {
     pub fn inverse(&self) -> Result<Matrix<T>, Error> {
         assert!(self.rows == self.cols, "Matrix is not square.");
 
-        let mut inv_t_data = Vec::<T>::new();
-        let (l, u, p) = try!(self.lup_decomp().map_err(|_| {
+        self.try_inverse()
+    }
+
+    /// Computes the inverse of the matrix without panicking.
+    ///
+    /// The matrix is treated as singular if a pivot of its LUP
+    /// decomposition is negligible compared to the largest pivot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2,2, vec![1.,2.,2.,4.]);
+    /// assert!(a.try_inverse().is_err());
+    ///
+    /// let b = Matrix::new(2,2, vec![2.,3.,1.,2.]);
+    /// let inv = b.try_inverse().unwrap();
+    ///
+    /// assert_eq!(*inv.data(), vec![2.0,-3.0,-1.0,2.0]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The matrix is not square.
+    /// - The matrix could not be LUP decomposed.
+    /// - The matrix is singular or near-singular.
+    pub fn try_inverse(&self) -> Result<Matrix<T>, Error> {
+        if self.rows != self.cols {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Matrix must be square to compute its inverse."));
+        }
+
+        let (l, u, p) = self.lup_decomp().map_err(|_| {
             Error::new(ErrorKind::DecompFailure,
                        "Could not compute LUP factorization for inverse.")
-        }));
-
-        let mut d = T::one();
+        })?;
 
-        unsafe {
-            for i in 0..l.cols {
-                d = d * *l.get_unchecked([i, i]);
-                d = d * *u.get_unchecked([i, i]);
-            }
-        }
+        let pivots = u.diag();
+        let max_pivot = pivots.iter().fold(T::zero(), |m, x| m.max(x.abs()));
+        let tol = max_pivot * T::epsilon() * libnum::cast(self.rows).unwrap();
 
-        if d == T::zero() {
+        if max_pivot == T::zero() || pivots.iter().any(|x| !(x.abs() > tol)) {
             return Err(Error::new(ErrorKind::DecompFailure,
                                   "Matrix is singular and cannot be inverted."));
         }
 
-        for i in 0..self.rows {
-            let mut id_col = vec![T::zero(); self.cols];
-            id_col[i] = T::one();
-
-            let b = forward_substitution(&l, &p * Vector::new(id_col))
-                .expect("Matrix is singular AND has non-zero determinant!?");
-            inv_t_data.append(&mut back_substitution(&u, b)
-                .expect("Matrix is singular AND has non-zero determinant!?")
-                .into_vec());
-
-        }
-
-        Ok(Matrix::new(self.rows, self.cols, inv_t_data).transpose())
+        let b = forward_substitution_matrix(&l, p)?;
+        back_substitution_matrix(&u, b)
     }
 
     /// Computes the determinant of the matrix.
     ///
+    /// Matrices larger than `3 x 3` which are exactly symmetric are first
+    /// tried with a Cholesky decomposition, which costs half as much as LU.
+    /// If the matrix is not positive definite the LU decomposition is used.
+    ///
     /// # Examples
     ///
     /// ```
@@ -503,6 +1961,12 @@ impl<T: Any + Float> Matrix<T> {
             (self[[0, 1]] * self[[1, 0]] * self[[2, 2]]) -
             (self[[0, 2]] * self[[1, 1]] * self[[2, 0]])
         } else {
+            if self.is_symmetric() {
+                if let Ok(chol) = Cholesky::decompose_from(self) {
+                    return chol.det();
+                }
+            }
+
             let (l, u, p) = self.lup_decomp().expect("Could not compute LUP decomposition.");
 
             let mut d = T::one();
@@ -519,6 +1983,76 @@ impl<T: Any + Float> Matrix<T> {
             sgn * d
         }
     }
+
+    /// Soft-thresholds each element of the matrix.
+    ///
+    /// Computes `sign(x) * max(|x| - lambda, 0)` element-wise. This
+    /// is the proximal operator of the element-wise L1 norm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![-3.0, 0.5, 2.0, 1.0]);
+    ///
+    /// assert_eq!(a.soft_threshold(1.0), Matrix::new(2, 2, vec![-2.0, 0.0, 1.0, 0.0]));
+    /// ```
+    pub fn soft_threshold(&self, lambda: T) -> Matrix<T> {
+        Matrix::new(self.rows, self.cols, utils::soft_threshold(&self.data, lambda))
+    }
+}
+
+/// Computes `y += alpha * A x` in place.
+///
+/// This is the generalized saxpy (gaxpy) operation. Each entry of `y`
+/// is updated using one dot product with a row of `A`, so no
+/// temporary vector is allocated.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{self, Matrix};
+/// use rulinalg::vector::Vector;
+///
+/// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+/// let x = Vector::new(vec![1.0, 1.0]);
+/// let mut y = Vector::new(vec![1.0, -1.0]);
+///
+/// matrix::gaxpy(&a, &x, &mut y, -1.0);
+/// assert_eq!(y.into_vec(), vec![-2.0, -8.0]);
+/// ```
+///
+/// # Panics
+///
+/// - The size of `x` does not match the number of columns of `A`.
+/// - The size of `y` does not match the number of rows of `A`.
+pub fn gaxpy<T>(a: &Matrix<T>, x: &Vector<T>, y: &mut Vector<T>, alpha: T)
+    where T: Any + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    assert!(a.cols == x.size(), "Matrix and Vector dimensions do not agree.");
+    assert!(a.rows == y.size(), "Matrix and Vector dimensions do not agree.");
+
+    if a.cols == 0 {
+        return;
+    }
+
+    gaxpy_rows(a.data.chunks(a.cols).zip(y.mut_data().iter_mut()), x.data(), alpha);
+}
+
+/// Computes `y_i += alpha * (a_i · x)` for each row `a_i` paired with
+/// an entry `y_i`.
+///
+/// This is the kernel of `gaxpy`. The rows and the entries of `y` are
+/// passed as pairs so that both may be borrowed from one buffer, as in
+/// the Cholesky decomposition.
+fn gaxpy_rows<'a, T, I>(rows: I, x: &[T], alpha: T)
+    where T: 'a + Any + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>,
+          I: Iterator<Item = (&'a [T], &'a mut T)>
+{
+    for (row, y_i) in rows {
+        *y_i = *y_i + alpha * utils::dot_simd(row, x);
+    }
 }
 
 impl<T: Float> Metric<T> for Matrix<T> {
@@ -594,27 +2128,30 @@ impl<'a, T: Float> Metric<T> for MatrixSliceMut<'a, T> {
 
 impl<T: fmt::Display> fmt::Display for Matrix<T> {
     /// Formats the Matrix for display.
+    ///
+    /// Each column is right-aligned to the width of its widest entry.
+    /// The precision flag, as in `{:.3}`, is applied to every entry.
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let mut max_datum_width = 0;
-        for datum in &self.data {
+        let mut widths = vec![0; self.cols];
+        for (index, datum) in self.data.iter().enumerate() {
             let datum_width = match f.precision() {
-                Some(places) => format!("{:.1$}", datum, places).len(),
-                None => format!("{}", datum).len(),
+                Some(places) => format!("{:.1$}", datum, places).chars().count(),
+                None => format!("{}", datum).chars().count(),
             };
-            if datum_width > max_datum_width {
-                max_datum_width = datum_width;
+            let width = &mut widths[index % self.cols];
+            if datum_width > *width {
+                *width = datum_width;
             }
         }
-        let width = max_datum_width;
 
         fn write_row<T: fmt::Display>(f: &mut fmt::Formatter,
                                       row: &[T],
                                       left_delimiter: &str,
                                       right_delimiter: &str,
-                                      width: usize)
+                                      widths: &[usize])
                                       -> Result<(), fmt::Error> {
             try!(write!(f, "{}", left_delimiter));
-            for (index, datum) in row.iter().enumerate() {
+            for (index, (datum, &width)) in row.iter().zip(widths).enumerate() {
                 match f.precision() {
                     Some(places) => {
                         try!(write!(f, "{:1$.2$}", datum, width, places));
@@ -631,27 +2168,27 @@ impl<T: fmt::Display> fmt::Display for Matrix<T> {
         }
 
         match self.rows {
-            1 => write_row(f, &self.data, "[", "]", width),
+            1 => write_row(f, &self.data, "[", "]", &widths),
             _ => {
                 try!(write_row(f,
                                &self.data[0..self.cols],
                                "⎡", // \u{23a1} LEFT SQUARE BRACKET UPPER CORNER
                                "⎤", // \u{23a4} RIGHT SQUARE BRACKET UPPER CORNER
-                               width));
+                               &widths));
                 try!(f.write_str("\n"));
                 for row_index in 1..self.rows - 1 {
                     try!(write_row(f,
                                    &self.data[row_index * self.cols..(row_index + 1) * self.cols],
                                    "⎢", // \u{23a2} LEFT SQUARE BRACKET EXTENSION
                                    "⎥", // \u{23a5} RIGHT SQUARE BRACKET EXTENSION
-                                   width));
+                                   &widths));
                     try!(f.write_str("\n"));
                 }
                 write_row(f,
                           &self.data[(self.rows - 1) * self.cols..self.rows * self.cols],
                           "⎣", // \u{23a3} LEFT SQUARE BRACKET LOWER CORNER
                           "⎦", // \u{23a6} RIGHT SQUARE BRACKET LOWER CORNER
-                          width)
+                          &widths)
             }
         }
 
@@ -716,6 +2253,151 @@ fn forward_substitution<T, M>(m: &M, y: Vector<T>) -> Result<Vector<T>, Error>
     Ok(Vector::new(x))
 }
 
+/// Forward substitution with a unit lower triangular matrix.
+///
+/// The diagonal of `m` is assumed to be one and is never read,
+/// so no division takes place and the system is never singular.
+fn unit_forward_substitution<T, M>(m: &M, y: Vector<T>) -> Vector<T>
+    where T: Any + Float,
+          M: BaseMatrix<T>,
+{
+    let mut x = y.into_vec();
+
+    for i in 1..x.len() {
+        let row = unsafe { m.get_row_unchecked(i) };
+        let (solved, rest) = x.split_at_mut(i);
+        rest[0] = rest[0] - utils::dot(&row[..i], solved);
+    }
+
+    Vector::new(x)
+}
+
+/// Back substitution with a unit upper triangular matrix.
+///
+/// The diagonal of `m` is assumed to be one and is never read.
+fn unit_back_substitution<T, M>(m: &M, y: Vector<T>) -> Vector<T>
+    where T: Any + Float,
+          M: BaseMatrix<T>,
+{
+    let mut x = y.into_vec();
+    let n = x.len();
+
+    for i in (0..n).rev() {
+        let row = unsafe { m.get_row_unchecked(i) };
+        let (head, solved) = x.split_at_mut(i + 1);
+        head[i] = head[i] - utils::dot(&row[i + 1..n], solved);
+    }
+
+    Vector::new(x)
+}
+
+/// Checks that `m` is a square triangular factor matching the rows of `b`.
+fn check_substitution_dims<T, M>(m: &M, b: &Matrix<T>)
+    where M: BaseMatrix<T>
+{
+    assert!(m.rows() == m.cols(), "Triangular matrix must be square.");
+    assert!(m.rows() == b.rows(),
+            "Matrix and right-hand side dimensions do not agree.");
+}
+
+/// Returns the `i`th diagonal element, or an error if it is (numerically) zero.
+fn nonsingular_diag<T, M>(m: &M, i: usize) -> Result<T, Error>
+    where T: Any + Float,
+          M: BaseMatrix<T>,
+{
+    let diag = unsafe { *m.get_unchecked([i, i]) };
+    if diag.abs() < T::min_positive_value() + T::min_positive_value() {
+        Err(Error::new(ErrorKind::AlgebraFailure,
+                       "Linear system cannot be solved (matrix is singular)."))
+    } else {
+        Ok(diag)
+    }
+}
+
+/// Forward substitution for multiple right-hand sides.
+///
+/// Solves `LX = B` for lower triangular `L`, overwriting `B` row by row.
+fn forward_substitution_matrix<T, M>(m: &M, mut b: Matrix<T>) -> Result<Matrix<T>, Error>
+    where T: Any + Float,
+          M: BaseMatrix<T>,
+{
+    check_substitution_dims(m, &b);
+    let cols = b.cols;
+
+    for i in 0..b.rows {
+        let diag = nonsingular_diag(m, i)?;
+        let (above, rest) = b.data.split_at_mut(i * cols);
+        let row_i = &mut rest[..cols];
+
+        for (j, row_j) in above.chunks(cols.max(1)).enumerate() {
+            let l_ij = unsafe { *m.get_unchecked([i, j]) };
+            utils::in_place_vec_bin_op(row_i, row_j, |x, &y| *x = *x - l_ij * y);
+        }
+
+        for x in row_i.iter_mut() {
+            *x = *x / diag;
+        }
+    }
+
+    Ok(b)
+}
+
+/// Back substitution for multiple right-hand sides.
+///
+/// Solves `UX = B` for upper triangular `U`, overwriting `B` row by row.
+fn back_substitution_matrix<T, M>(m: &M, mut b: Matrix<T>) -> Result<Matrix<T>, Error>
+    where T: Any + Float,
+          M: BaseMatrix<T>,
+{
+    check_substitution_dims(m, &b);
+    let cols = b.cols;
+
+    for i in (0..b.rows).rev() {
+        let diag = nonsingular_diag(m, i)?;
+        let (head, below) = b.data.split_at_mut((i + 1) * cols);
+        let row_i = &mut head[i * cols..];
+
+        for (k, row_j) in below.chunks(cols.max(1)).enumerate() {
+            let u_ij = unsafe { *m.get_unchecked([i, i + 1 + k]) };
+            utils::in_place_vec_bin_op(row_i, row_j, |x, &y| *x = *x - u_ij * y);
+        }
+
+        for x in row_i.iter_mut() {
+            *x = *x / diag;
+        }
+    }
+
+    Ok(b)
+}
+
+/// Back substitution with the transpose of a lower triangular matrix.
+///
+/// Solves `LᵀX = B` without forming `Lᵀ`, overwriting `B` row by row.
+fn transpose_back_substitution_matrix<T, M>(m: &M, mut b: Matrix<T>) -> Result<Matrix<T>, Error>
+    where T: Any + Float,
+          M: BaseMatrix<T>,
+{
+    check_substitution_dims(m, &b);
+    let cols = b.cols;
+
+    for i in (0..b.rows).rev() {
+        let diag = nonsingular_diag(m, i)?;
+        let (head, below) = b.data.split_at_mut((i + 1) * cols);
+        let row_i = &mut head[i * cols..];
+
+        for (k, row_j) in below.chunks(cols.max(1)).enumerate() {
+            let l_ji = unsafe { *m.get_unchecked([i + 1 + k, i]) };
+            utils::in_place_vec_bin_op(row_i, row_j, |x, &y| *x = *x - l_ji * y);
+        }
+
+        for x in row_i.iter_mut() {
+            *x = *x / diag;
+        }
+    }
+
+    Ok(b)
+}
+
 /// Computes the parity of a permutation matrix.
 fn parity<T, M>(m: &M) -> T
     where T: Any + Float,
@@ -747,9 +2429,10 @@ fn parity<T, M>(m: &M) -> T
 #[cfg(test)]
 mod tests {
     use super::super::vector::Vector;
-    use super::Matrix;
-    use super::slice::BaseMatrix;
+    use super::{Matrix, Axes};
+    use super::slice::{BaseMatrix, BaseMatrixMut};
     use libnum::abs;
+    use error::ErrorKind;
 
     #[test]
     fn test_new_mat() {
@@ -795,11 +2478,22 @@ mod tests {
                                         3,
                                         vec![3.14, 2.718, 1.414, 2.503, 4.669, 1.202, 1.618,
                                              0.5772, 1.3, 2.68545, 1.282, 10000.]);
-        let second_exp = "⎡   3.14   2.718   1.414⎤\n⎢  2.503   4.669   1.202⎥\n⎢  \
-                        1.618  0.5772     1.3⎥\n⎣2.68545   1.282   10000⎦";
+        let second_exp = "⎡   3.14  2.718 1.414⎤\n⎢  2.503  4.669 1.202⎥\n⎢  \
+                        1.618 0.5772   1.3⎥\n⎣2.68545  1.282 10000⎦";
         assert_eq!(second_exp, format!("{}", second_matrix));
     }
 
+    #[test]
+    fn test_display_column_alignment() {
+        let a = Matrix::new(3, 3, vec![1, -200, 3, 40, 5, 6, 7, 8, 9000]);
+        let expectation = "⎡ 1 -200    3⎤\n⎢40    5    6⎥\n⎣ 7    8 9000⎦";
+        assert_eq!(expectation, format!("{}", a));
+
+        let b = Matrix::new(2, 2, vec![-1.5, 100.25, 2.0, 0.125]);
+        let expectation = "⎡-1.500 100.250⎤\n⎣ 2.000   0.125⎦";
+        assert_eq!(expectation, format!("{:.3}", b));
+    }
+
     #[test]
     fn test_single_row_display_formatting() {
         let one_row_matrix = Matrix::new(1, 4, vec![1, 2, 3, 4]);
@@ -836,21 +2530,135 @@ mod tests {
     }
 
     #[test]
-    fn test_matrix_select_rows() {
-        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-
-        let b = a.select_rows(&[0, 2, 3]);
+    fn test_matrix_select_rows() {
+        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+
+        let b = a.select_rows(&[0, 2, 3]);
+
+        assert_eq!(b.into_vec(), vec![0, 1, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_matrix_select_cols() {
+        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+
+        let b = a.select_cols(&[1]);
+
+        assert_eq!(b.into_vec(), vec![1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_solve_with_residual() {
+        use utils;
+
+        let a = Matrix::new(3, 3, vec![4.0f64, 1.0, 0.0, 1.0, 5.0, 2.0, 0.0, 2.0, 6.0]);
+        let b = Vector::new(vec![1.0, -2.0, 3.0]);
+
+        let (x, r) = a.solve_with_residual(&b).unwrap();
+        assert_eq!(x, a.solve(b.clone()).unwrap());
+        assert!(r.norm_2() < 1e-14);
+
+        for i in 0..3 {
+            assert!((r[i] - (b[i] - utils::dot(a.get_row(i).unwrap(), x.data()))).abs() < 1e-14);
+        }
+
+        // The 10x10 Hilbert matrix is ill-conditioned enough that the
+        // computed solution leaves a rounding residual behind.
+        let n = 10;
+        let h = Matrix::new(n, n, (0..n * n)
+            .map(|k| 1.0 / ((k / n + k % n + 1) as f64))
+            .collect::<Vec<_>>());
+        let b = Vector::new((1..n + 1).map(|i| i as f64).collect::<Vec<_>>());
+
+        let (x, r) = h.solve_with_residual(&b).unwrap();
+        assert!(r.norm_2() > 0.0);
+
+        for i in 0..n {
+            let expected = b[i] - utils::dot(h.get_row(i).unwrap(), x.data());
+            assert!((r[i] - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_elem_max_min_interleaved() {
+        let a = Matrix::new(2, 3, vec![1.0, 8.0, 3.0, 9.0, 2.0, 7.0]);
+        let b = Matrix::new(2, 3, vec![6.0, 2.0, 5.0, 1.0, 4.0, 0.0]);
+
+        assert_eq!(a.elem_max(&b), Matrix::new(2, 3, vec![6.0, 8.0, 5.0, 9.0, 4.0, 7.0]));
+        assert_eq!(a.elem_min(&b), Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 1.0, 2.0, 0.0]));
+
+        let mut c = a.clone();
+        c.elem_max_mut(&b);
+        assert_eq!(c, a.elem_max(&b));
+        c.elem_min_mut(&a);
+        assert_eq!(c, a);
+
+        // Works across slices too.
+        let s = b.sub_slice([0, 1], 2, 2);
+        assert_eq!(a.sub_slice([0, 0], 2, 2).elem_max(&s),
+                   Matrix::new(2, 2, vec![2.0, 8.0, 9.0, 2.0]));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let a = Matrix::new(2, 2, vec![-3.0, 0.25, 9.0, ::std::f64::NAN]);
+
+        assert_eq!(*a.clamp(0.0, 1.0).data(), vec![0.0, 0.25, 1.0, 0.0]);
+
+        let mut b = a.clone();
+        b.clamp_mut(-1.0, 2.0);
+        assert_eq!(*b.data(), vec![-1.0, 0.25, 2.0, -1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_inverted_bounds() {
+        let mut a = Matrix::new(1, 2, vec![0.0, 1.0]);
+        a.clamp_mut(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_elem_max_dimension_mismatch() {
+        let a = Matrix::new(2, 2, vec![0.0; 4]);
+        let _ = a.elem_max(&Matrix::new(2, 1, vec![0.0; 2]));
+    }
+
+    #[test]
+    fn test_matrix_select_rows_reorder() {
+        let a = Matrix::new(3, 2, (0..6).collect::<Vec<usize>>());
+
+        assert_eq!(a.select_rows(&[1, 0]), Matrix::new(2, 2, vec![2, 3, 0, 1]));
+        assert_eq!(a.select_rows(&[2, 2, 0]),
+                   Matrix::new(3, 2, vec![4, 5, 4, 5, 0, 1]));
+        assert_eq!(a.select_rows(&(0..3).collect::<Vec<_>>()), a);
+        assert_eq!(a.select_cols(&[1, 1, 0]),
+                   Matrix::new(3, 3, vec![1, 1, 0, 3, 3, 2, 5, 5, 4]));
+    }
 
-        assert_eq!(b.into_vec(), vec![0, 1, 4, 5, 6, 7]);
+    #[test]
+    #[should_panic]
+    fn test_matrix_select_rows_out_of_bounds() {
+        let a = Matrix::new(3, 2, (0..6).collect::<Vec<usize>>());
+        let _ = a.select_rows(&[0, 3]);
     }
 
     #[test]
-    fn test_matrix_select_cols() {
-        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+    fn test_matrix_set_rows_and_cols() {
+        let mut a = Matrix::new(3, 2, (0..6).collect::<Vec<usize>>());
+        let b = a.select_rows(&[1, 0]);
 
-        let b = a.select_cols(&[1]);
+        a.set_rows(&[0, 1], &b).unwrap();
+        assert_eq!(a, Matrix::new(3, 2, vec![2, 3, 0, 1, 4, 5]));
 
-        assert_eq!(b.into_vec(), vec![1, 3, 5, 7]);
+        a.set_cols(&[1], &Matrix::new(3, 1, vec![7, 8, 9])).unwrap();
+        assert_eq!(a, Matrix::new(3, 2, vec![2, 7, 0, 8, 4, 9]));
+
+        assert!(a.set_rows(&[3], &Matrix::new(1, 2, vec![0, 0])).is_err());
+        assert!(a.set_rows(&[0, 1], &Matrix::new(1, 2, vec![0, 0])).is_err());
+        assert!(a.set_cols(&[2], &Matrix::new(3, 1, vec![0, 0, 0])).is_err());
+        assert!(a.set_cols(&[0], &Matrix::new(2, 1, vec![0, 0])).is_err());
+        assert_eq!(a, Matrix::new(3, 2, vec![2, 7, 0, 8, 4, 9]));
     }
 
     #[test]
@@ -914,6 +2722,43 @@ mod tests {
         assert_eq!(x[1], 2.);
     }
 
+    #[test]
+    fn matrix_try_inverse_singular() {
+        let a = Matrix::new(2, 2, vec![1., 2., 2., 4.]);
+        assert!(a.try_inverse().is_err());
+
+        let b = Matrix::new(3, 3, vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        assert!(b.try_inverse().is_err());
+
+        let c = Matrix::new(2, 3, vec![1., 0., 0., 0., 1., 0.]);
+        assert!(c.try_inverse().is_err());
+    }
+
+    #[test]
+    fn matrix_try_inverse() {
+        // Deterministic pseudo-random entries, made diagonally dominant.
+        let n = 6;
+        let mut state = 12345u32;
+        let data = (0..n * n)
+            .map(|i| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                let x = (state >> 16) as f64 / 65536.0 - 0.5;
+                if i % (n + 1) == 0 { x + n as f64 } else { x }
+            })
+            .collect::<Vec<_>>();
+        let a = Matrix::new(n, n, data);
+
+        let inv = a.try_inverse().unwrap();
+        let id = &a * &inv;
+
+        for i in 0..n {
+            for j in 0..n {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(abs(id[[i, j]] - expected) < 1e-12);
+            }
+        }
+    }
+
     #[test]
     fn create_mat_zeros() {
         let a = Matrix::<f32>::zeros(10, 10);
@@ -1006,4 +2851,702 @@ mod tests {
         let d_col = d.variance(Axes::Col);
         assert!(d_col.is_err());
     }
+
+    #[test]
+    fn test_flatten_roundtrip() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        let row_major = a.flatten();
+        assert_eq!(*row_major.data(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(Matrix::from_vector_row_major(row_major, 2, 3).unwrap(), a);
+
+        let col_major = a.flatten_col_major();
+        assert_eq!(*col_major.data(), vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(Matrix::from_vector_col_major(col_major, 2, 3).unwrap(), a);
+    }
+
+    #[test]
+    fn test_from_vector_bad_size() {
+        let v = Vector::new(vec![1.0; 5]);
+
+        assert!(Matrix::from_vector_row_major(v.clone(), 2, 3).is_err());
+        assert!(Matrix::from_vector_col_major(v, 3, 2).is_err());
+    }
+
+    #[test]
+    fn test_flatten_identity() {
+        let id = Matrix::<i32>::identity(3);
+
+        let expected = vec![1, 0, 0, 0, 1, 0, 0, 0, 1];
+        assert_eq!(*id.flatten().data(), expected);
+        assert_eq!(*id.flatten_col_major().data(), expected);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let a = Matrix::new(1, 6, vec![1, 2, 3, 4, 5, 6]);
+
+        // A row vector reshaped to a column has the data of its transpose.
+        let b = a.clone().reshape(6, 1).unwrap();
+        assert_eq!(b.data(), a.transpose().data());
+
+        let c = b.reshape(2, 3).unwrap();
+        assert_eq!(*c.data(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(c[[1, 0]], 4);
+
+        let same = c.clone().reshape(2, 3).unwrap();
+        assert_eq!(same, c);
+    }
+
+    #[test]
+    fn test_cast_and_map_into() {
+        let a = Matrix::new(2, 3, vec![1i32, -2, 3, 4, 0, 6]);
+
+        let b: Matrix<f64> = a.clone().cast();
+        assert_eq!((b.rows(), b.cols()), (2, 3));
+        assert_eq!(*b.data(), vec![1.0, -2.0, 3.0, 4.0, 0.0, 6.0]);
+
+        let c = Matrix::new(1, 2, vec![0.5f32, 1e-3]).cast::<f64>();
+        assert_eq!(*c.data(), vec![0.5f32 as f64, 1e-3f32 as f64]);
+
+        let sq = a.map_into(|x| x * x);
+        assert_eq!((sq.rows(), sq.cols()), (2, 3));
+        assert_eq!(*sq.data(), vec![1, 4, 9, 16, 0, 36]);
+    }
+
+    #[test]
+    fn test_round_to_i64_half_to_even() {
+        let a = Matrix::new(2, 4, vec![0.5f64, 1.5, 2.5, -0.5, -1.5, -2.5, 2.4999, 2.5001]);
+        let r = a.round_to_i64();
+
+        assert_eq!((r.rows(), r.cols()), (2, 4));
+        assert_eq!(*r.data(), vec![0, 2, 2, 0, -2, -2, 2, 3]);
+
+        let b = Matrix::new(3, 1, vec![0.5f32, 1.5, -2.5]);
+        assert_eq!(*b.round_to_i64().data(), vec![0, 2, -2]);
+    }
+
+    #[test]
+    fn test_floor_ceil_to_i64() {
+        let a = Matrix::new(3, 2, vec![1.5f64, -1.5, 2.0, -2.0, 0.9999, -0.0001]);
+
+        let f = a.floor_to_i64();
+        assert_eq!((f.rows(), f.cols()), (3, 2));
+        assert_eq!(*f.data(), vec![1, -2, 2, -2, 0, -1]);
+
+        let c = a.ceil_to_i64();
+        assert_eq!((c.rows(), c.cols()), (3, 2));
+        assert_eq!(*c.data(), vec![2, -1, 2, -2, 1, 0]);
+
+        let b = Matrix::new(1, 2, vec![2.5f32, -2.5]);
+        assert_eq!(*b.floor_to_i64().data(), vec![2, -3]);
+        assert_eq!(*b.ceil_to_i64().data(), vec![3, -2]);
+    }
+
+    #[test]
+    fn test_round_to_i64_saturates() {
+        use std::f64;
+
+        let a = Matrix::new(1, 3, vec![f64::NAN, 1e300, f64::NEG_INFINITY]);
+        assert_eq!(*a.round_to_i64().data(), vec![0, i64::max_value(), i64::min_value()]);
+    }
+
+    #[test]
+    fn test_into_vector() {
+        assert_eq!(Matrix::new(1, 1, vec![5]).into_vector().unwrap(), Vector::new(vec![5]));
+        assert_eq!(Matrix::<u8>::new(0, 1, vec![]).into_vector().unwrap().size(), 0);
+        assert!(Matrix::new(2, 2, vec![1, 2, 3, 4]).into_vector().is_err());
+        assert!(Matrix::<u8>::new(0, 0, vec![]).into_vector().is_err());
+    }
+
+    #[test]
+    fn test_reshape_zero_copy() {
+        let a = Matrix::new(2, 6, (0..12).collect::<Vec<_>>());
+        let ptr = a.data().as_ptr();
+
+        let b = a.reshape(3, 4).unwrap();
+        assert_eq!((b.rows(), b.cols()), (3, 4));
+        assert_eq!(b.data().as_ptr(), ptr);
+        assert_eq!(b[[1, 0]], 4);
+        assert_eq!(b[[2, 3]], 11);
+
+        assert!(b.reshape(5, 2).is_err());
+    }
+
+    #[test]
+    fn test_reshape_bad_dims() {
+        let a = Matrix::new(2, 3, vec![0.0; 6]);
+
+        assert!(a.view_reshape(4, 2).is_err());
+        assert!(a.view_reshape(usize::max_value(), 2).is_err());
+        assert!(a.reshape(5, 1).is_err());
+    }
+
+    #[test]
+    fn test_view_reshape() {
+        let a = Matrix::new(2, 3, (0..6).collect::<Vec<usize>>());
+        let view = a.view_reshape(3, 2).unwrap();
+
+        assert_eq!(view.rows(), 3);
+        assert_eq!(view.cols(), 2);
+        assert_eq!(view.into_matrix(), Matrix::new(3, 2, (0..6).collect::<Vec<usize>>()));
+    }
+
+    #[test]
+    fn test_tile() {
+        let a = Matrix::new(2, 3, (0..6).collect::<Vec<i32>>());
+        let t = a.tile(2, 3);
+
+        assert_eq!(t.rows(), 2 * a.rows());
+        assert_eq!(t.cols(), 3 * a.cols());
+
+        for bi in 0..2 {
+            for bj in 0..3 {
+                let block = t.sub_slice([bi * 2, bj * 3], 2, 3).into_matrix();
+                assert_eq!(block, a);
+            }
+        }
+
+        assert_eq!(a.repmat(2, 3), t);
+    }
+
+    #[test]
+    fn test_tile_empty() {
+        let a = Matrix::<f64>::new(0, 0, vec![]);
+        let t = a.tile(3, 4);
+        assert_eq!((t.rows(), t.cols()), (0, 0));
+
+        let b = Matrix::<f64>::new(2, 0, vec![]);
+        let t = b.tile(3, 4);
+        assert_eq!((t.rows(), t.cols()), (6, 0));
+        assert!(t.data().is_empty());
+
+        let c = Matrix::new(2, 2, vec![1.0; 4]);
+        let t = c.tile(0, 2);
+        assert_eq!((t.rows(), t.cols()), (0, 4));
+    }
+
+    #[test]
+    fn test_gaxpy() {
+        let a = Matrix::new(3, 4, (0..12).map(|x| x as f64 - 5.0).collect::<Vec<_>>());
+        let x = Vector::new(vec![1.0, -2.0, 0.5, 3.0]);
+        let y = Vector::new(vec![4.0, 0.0, -1.0]);
+        let alpha = 2.5;
+
+        let expected = &y + (&a * &x) * alpha;
+
+        let mut z = y.clone();
+        super::gaxpy(&a, &x, &mut z, alpha);
+        assert_eq!(z, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gaxpy_bad_dims() {
+        let a = Matrix::new(2, 3, vec![1.0; 6]);
+        let x = Vector::new(vec![1.0; 2]);
+        let mut y = Vector::new(vec![1.0; 2]);
+
+        super::gaxpy(&a, &x, &mut y, 1.0);
+    }
+
+    fn naive_cumulative<F: Fn(i64, i64) -> i64>(a: &Matrix<i64>, axis: Axes, f: F) -> Matrix<i64> {
+        let mut out = a.clone();
+        for i in 0..a.rows() {
+            for j in 0..a.cols() {
+                out[[i, j]] = match axis {
+                    Axes::Row => (0..i + 1).map(|k| a[[k, j]]).fold(None, |acc, x| {
+                        Some(acc.map_or(x, |acc| f(acc, x)))
+                    }).unwrap(),
+                    Axes::Col => (0..j + 1).map(|k| a[[i, k]]).fold(None, |acc, x| {
+                        Some(acc.map_or(x, |acc| f(acc, x)))
+                    }).unwrap(),
+                };
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_cumsum_cumprod() {
+        let a = Matrix::new(3, 4, (0..12).map(|x| (x * 5 % 7) as i64 - 3).collect::<Vec<_>>());
+
+        for &axis in &[Axes::Row, Axes::Col] {
+            assert_eq!(a.cumsum(axis), naive_cumulative(&a, axis, |x, y| x + y));
+            assert_eq!(a.cumprod(axis), naive_cumulative(&a, axis, |x, y| x * y));
+        }
+    }
+
+    #[test]
+    fn test_cumsum_single_row_col() {
+        let row = Matrix::new(1, 4, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(row.cumsum(Axes::Row), row);
+        assert_eq!(*row.cumsum(Axes::Col).data(), vec![1.0, 3.0, 6.0, 10.0]);
+        assert_eq!(*row.cumprod(Axes::Col).data(), vec![1.0, 2.0, 6.0, 24.0]);
+
+        let col = Matrix::new(4, 1, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(col.cumsum(Axes::Col), col);
+        assert_eq!(*col.cumsum(Axes::Row).data(), vec![1.0, 3.0, 6.0, 10.0]);
+        assert_eq!(*col.cumprod(Axes::Row).data(), vec![1.0, 2.0, 6.0, 24.0]);
+
+        let empty = Matrix::<f64>::new(3, 0, vec![]);
+        assert_eq!(empty.cumsum(Axes::Row), empty);
+    }
+
+    #[test]
+    fn test_cumsum_cols_rows() {
+        let ones = Matrix::new(4, 3, vec![1; 12]);
+        let c = ones.cumsum_cols();
+        for (i, row) in c.iter_rows().enumerate() {
+            assert_eq!(row, &[i + 1; 3][..]);
+        }
+        assert_eq!(ones.cumsum_rows().get_row(3), Some(&[1, 2, 3][..]));
+
+        let a = Matrix::new(3, 3, (0..9).map(|x| x as f64 - 4.0).collect::<Vec<_>>());
+        assert_eq!(a.cumsum_cols().get_row(2), Some(a.sum_rows().data().as_slice()));
+        assert_eq!(a.cumsum_rows().select_cols(&[2]).into_vec(), a.sum_cols().into_vec());
+
+        let a = Matrix::<f64>::new(0, 3, vec![]);
+        let b = Matrix::<f64>::new(2, 0, vec![]);
+        assert_eq!((a.cumsum_cols().rows(), a.cumsum_rows().cols()), (0, 3));
+        assert_eq!((b.cumsum_rows().rows(), b.cumsum_cols().cols()), (2, 0));
+    }
+
+    #[test]
+    fn test_toeplitz_structure() {
+        let col = Vector::new(vec![1.0, -2.0, 3.0, 0.5, 7.0]);
+        let row = Vector::new(vec![1.0, 4.0, -5.0]);
+        let t = Matrix::toeplitz(&col, &row).unwrap();
+
+        assert_eq!((t.rows(), t.cols()), (5, 3));
+        for i in 1..t.rows() {
+            for j in 1..t.cols() {
+                assert_eq!(t[[i, j]], t[[i - 1, j - 1]]);
+            }
+        }
+        assert_eq!(*t.transpose().data(),
+                   *Matrix::toeplitz(&row, &col).unwrap().data());
+
+        assert!(Matrix::toeplitz(&col, &Vector::new(vec![2.0, 4.0])).is_err());
+        assert_eq!(Matrix::toeplitz(&Vector::<f64>::new(vec![]), &row).unwrap().rows(), 0);
+    }
+
+    #[test]
+    fn test_toeplitz_matvec_matches_dense() {
+        let col = Vector::new(vec![2.0, -1.0, 0.5, 3.0]);
+        let x = Vector::new(vec![1.0, -2.0, 0.25, 4.0, -3.0, 1.5]);
+
+        for n in 1..x.size() + 1 {
+            let mut r = vec![2.0, 6.0, -4.0, 1.0, 0.0, 9.0];
+            r.truncate(n);
+            let row = Vector::new(r);
+            let x = Vector::new(x.data()[..row.size()].to_vec());
+
+            let dense = Matrix::toeplitz(&col, &row).unwrap() * &x;
+            assert_eq!(Matrix::toeplitz_matvec(&col, &row, &x).unwrap(), dense);
+        }
+
+        let bad_row = Vector::new(vec![1.0, 0.0]);
+        assert!(Matrix::toeplitz_matvec(&col, &bad_row, &Vector::new(vec![1.0, 1.0])).is_err());
+    }
+
+    #[test]
+    fn test_insert_row() {
+        let a = Matrix::new(3, 3, (0..9).collect::<Vec<i32>>());
+        let r = Vector::new(vec![-1, -2, -3]);
+
+        for k in 0..4 {
+            let b = a.insert_row(k, &r).unwrap();
+            assert_eq!(b.rows(), 4);
+            assert_eq!(b.get_row(k).unwrap(), &r.data()[..]);
+            assert_eq!(b.delete_row(k), a);
+        }
+
+        assert!(a.insert_row(4, &r).is_err());
+        let err = a.insert_row(0, &Vector::new(vec![1, 2])).unwrap_err();
+        assert_eq!(*err.kind(),
+                   ErrorKind::DimensionMismatch {
+                       expected: (1, 3),
+                       actual: (1, 2),
+                   });
+    }
+
+    #[test]
+    fn test_insert_col() {
+        let a = Matrix::new(2, 3, (0..6).collect::<Vec<i32>>());
+        let c = Vector::new(vec![7, 8]);
+
+        for k in 0..4 {
+            let b = a.insert_col(k, &c).unwrap();
+            assert_eq!(b.cols(), 4);
+            assert_eq!((b[[0, k]], b[[1, k]]), (7, 8));
+            assert_eq!(b.delete_col(k), a);
+        }
+
+        assert!(a.insert_col(5, &c).is_err());
+        assert!(a.insert_col(0, &Vector::new(vec![1, 2, 3])).is_err());
+    }
+
+    #[test]
+    fn test_delete_row_col() {
+        let a = Matrix::new(3, 3, (0..9).collect::<Vec<i32>>());
+
+        assert_eq!(*a.delete_row(1).delete_row(1).data(), vec![0, 1, 2]);
+        assert_eq!(*a.delete_col(0).delete_col(0).data(), vec![2, 5, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_delete_row_out_of_bounds() {
+        let a = Matrix::new(2, 2, vec![1.0; 4]);
+        let _ = a.delete_row(2);
+    }
+
+    #[test]
+    fn test_sort_rows_by_column() {
+        let a = Matrix::new(5, 2, vec![3.0, 0.0,
+                                       1.0, 1.0,
+                                       4.0, 2.0,
+                                       1.0, 3.0,
+                                       -2.0, 4.0]);
+        let mut b = a.clone();
+        let p = b.sort_rows_by_column(0);
+
+        // Stable on the duplicate key 1.0
+        assert_eq!(*b.data(), vec![-2.0, 4.0,
+                                   1.0, 1.0,
+                                   1.0, 3.0,
+                                   3.0, 0.0,
+                                   4.0, 2.0]);
+        assert_eq!(p.as_matrix() * &a, b);
+
+        let labels = Vector::new(vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(*(p.as_matrix() * labels).data(), vec![4.0, 1.0, 3.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_sort_rows_nan_last() {
+        let nan = ::std::f64::NAN;
+        let mut a = Matrix::new(4, 2, vec![nan, 0.0,
+                                           2.0, 1.0,
+                                           nan, 2.0,
+                                           1.0, 3.0]);
+        a.sort_rows_by_column(0);
+
+        assert_eq!(a[[0, 1]], 3.0);
+        assert_eq!(a[[1, 1]], 1.0);
+        assert_eq!(a[[2, 1]], 0.0);
+        assert_eq!(a[[3, 1]], 2.0);
+        assert!(a[[2, 0]].is_nan() && a[[3, 0]].is_nan());
+    }
+
+    #[test]
+    fn test_sort_rows_by_column_descending() {
+        let a = Matrix::new(4, 2, vec![1, 10, 3, 11, 2, 12, 3, 13]);
+        let mut b = a.clone();
+        let p = b.sort_rows_by_column_by(0, |x, y| y.cmp(x));
+
+        assert_eq!(*b.data(), vec![3, 11, 3, 13, 2, 12, 1, 10]);
+        assert_eq!(p.as_matrix() * &a, b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sort_rows_by_column_out_of_bounds() {
+        let mut a = Matrix::new(2, 2, vec![1.0; 4]);
+        a.sort_rows_by_column(2);
+    }
+
+    #[test]
+    fn test_equilibrate() {
+        let a = Matrix::new(3, 3, vec![1e4f64, -2e3, 0.5,
+                                       3e-2, 1e-3, -4e-4,
+                                       0.0, 7.0, 2e5]);
+        let (b, r, c) = a.equilibrate();
+
+        for row in b.iter_rows() {
+            let max = row.iter().fold(0.0f64, |m, x| m.max(x.abs()));
+            assert!(max <= 1.0 + 1e-12);
+        }
+        for col in b.iter_cols() {
+            let max = col.iter().fold(0.0f64, |m, x| m.max(x.abs()));
+            assert!((max - 1.0).abs() < 1e-12);
+        }
+
+        // Undo the scaling.
+        let mut back = b.clone();
+        back.scale_rows_in_place(&Vector::new(r.iter().map(|x| 1.0 / x).collect::<Vec<_>>()));
+        back.scale_cols_in_place(&Vector::new(c.iter().map(|x| 1.0 / x).collect::<Vec<_>>()));
+        for (x, y) in back.data().iter().zip(a.data()) {
+            assert!((x - y).abs() <= 1e-12 * y.abs());
+        }
+    }
+
+    #[test]
+    fn test_row_col_scale_zero_lines() {
+        let a = Matrix::new(2, 3, vec![0.0, 0.0, 0.0, 2.0, 0.0, -8.0]);
+
+        let (b, r) = a.row_scale();
+        assert_eq!(*r.data(), vec![1.0, 0.125]);
+        assert_eq!(*b.data(), vec![0.0, 0.0, 0.0, 0.25, 0.0, -1.0]);
+
+        let (b, c) = a.col_scale();
+        assert_eq!(*c.data(), vec![0.5, 1.0, 0.125]);
+        assert_eq!(*b.data(), vec![0.0, 0.0, 0.0, 1.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_rows_bad_size() {
+        let mut a = Matrix::new(2, 3, vec![1.0; 6]);
+        a.scale_rows_in_place(&Vector::new(vec![1.0; 3]));
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let a = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(a, Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+
+        let b = Matrix::from_rows((0..3).map(|i| (0..2).map(move |j| i * 2 + j))).unwrap();
+        assert_eq!(b, Matrix::new(3, 2, vec![0, 1, 2, 3, 4, 5]));
+
+        let empty = Matrix::<f64>::from_rows(Vec::<Vec<f64>>::new()).unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+    }
+
+    #[test]
+    fn test_from_rows_ragged() {
+        assert!(Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5]]).is_err());
+        assert!(Matrix::from_rows(vec![vec![1], vec![2], vec![3, 4]]).is_err());
+    }
+
+    #[test]
+    fn test_vec_of_vecs_round_trip() {
+        let a = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let vecs = a.to_vec_of_vecs();
+
+        assert_eq!(vecs, vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+        assert_eq!(Matrix::from_vec_of_vecs(vecs).unwrap(), a);
+
+        let b = Matrix::<u8>::new(3, 0, vec![]);
+        assert_eq!(Matrix::from_vec_of_vecs(b.to_vec_of_vecs()).unwrap(), b);
+
+        let empty = Matrix::<f64>::from_vec_of_vecs(vec![]).unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (0, 0));
+        assert!(empty.to_vec_of_vecs().is_empty());
+    }
+
+    #[test]
+    fn test_from_vec_of_vecs_ragged() {
+        assert!(Matrix::from_vec_of_vecs(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Matrix::from_vec_of_vecs(vec![vec![], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn test_diag_of_product() {
+        let a = Matrix::new(3, 4, (0..12).map(|x| x as f64 * 0.5 - 2.0).collect::<Vec<_>>());
+        let b = Matrix::new(4, 3, (0..12).map(|x| (x * x % 7) as f64).collect::<Vec<_>>());
+
+        assert_eq!(a.diag_of_product(&b), (&a * &b).diag());
+        assert_eq!(b.diag_of_product(&a), (&b * &a).diag());
+
+        let c = Matrix::new(4, 2, (0..8).map(|x| x as f64).collect::<Vec<_>>());
+        assert_eq!(a.diag_of_product(&c), (&a * &c).diag());
+    }
+
+    #[test]
+    fn test_diag_of_ata() {
+        let a = Matrix::new(3, 2, vec![1.0, -2.0, 3.0, 0.5, -4.0, 2.0]);
+        assert_eq!(a.diag_of_ata(), (a.transpose() * &a).diag());
+    }
+
+    #[test]
+    fn test_trace_of_product() {
+        let a = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 10]);
+        let id = Matrix::identity(3);
+        assert_eq!(a.trace_of_product(&id), a.diag().sum());
+
+        let b = Matrix::new(3, 2, vec![1, -1, 2, 0, 3, 5]);
+        let c = Matrix::new(2, 3, vec![2, 1, 0, -3, 4, 1]);
+        assert_eq!(b.trace_of_product(&c), (&b * &c).diag().sum());
+    }
+
+    #[test]
+    fn test_det_spd_matches_lu() {
+        use super::PartialPivLu;
+
+        let b = Matrix::new(5, 5, (0..25).map(|x| ((x * 7) % 11) as f64 - 5.0).collect::<Vec<_>>());
+        let a = &b * b.transpose() + Matrix::identity(5);
+        assert!(a.is_symmetric());
+
+        let (logdet, sign) = PartialPivLu::decompose(a.clone()).unwrap().logdet();
+        let lu_det = sign as f64 * logdet.exp();
+        assert!((a.det() - lu_det).abs() < 1e-8 * lu_det.abs());
+    }
+
+    #[test]
+    fn test_det_symmetric_indefinite_falls_back() {
+        // Symmetric but not positive definite, so Cholesky fails.
+        let a = Matrix::new(4, 4, vec![1.0f64, 2.0, 0.0, 0.0,
+                                       2.0, 1.0, 0.0, 0.0,
+                                       0.0, 0.0, 2.0, 1.0,
+                                       0.0, 0.0, 1.0, 3.0]);
+        assert!((a.det() + 15.0).abs() < 1e-10);
+
+        // Positive definite diagonal blocks but not symmetric.
+        let b = Matrix::new(4, 4, vec![4.0f64, 1.0, 0.0, 0.0,
+                                       0.0, 3.0, 0.0, 0.0,
+                                       0.0, 0.0, 2.0, 0.0,
+                                       0.0, 0.0, 1.0, 1.0]);
+        assert!((b.det() - 24.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_apply_on_left_right() {
+        use super::MatrixSliceMut;
+
+        let a = Matrix::new(3, 3, vec![1, 2, -1, 0, 3, 4, 2, -2, 1]);
+        let b = Matrix::new(3, 4, (0..12).map(|x| x * x % 5 - 2).collect::<Vec<i32>>());
+
+        let mut c = b.clone();
+        a.apply_on_left(&mut c);
+        assert_eq!(c, &a * &b);
+
+        let mut d = b.transpose();
+        a.apply_on_right(&mut d);
+        assert_eq!(d, b.transpose() * &a);
+
+        // Only the view inside the larger matrix changes.
+        let mut e = b.clone();
+        {
+            let mut block = MatrixSliceMut::from_matrix(&mut e, [0, 1], 3, 3);
+            a.apply_on_left(&mut block);
+        }
+        let expected = &a * b.select_cols(&[1, 2, 3]);
+        assert_eq!(e.select_cols(&[1, 2, 3]), expected);
+        assert_eq!(e.select_cols(&[0]), b.select_cols(&[0]));
+
+        // Wide enough to span several column blocks.
+        let wide = Matrix::new(3, 150, (0..450).map(|x| x % 7 - 3).collect::<Vec<i32>>());
+        let mut f = wide.clone();
+        a.apply_on_left(&mut f);
+        assert_eq!(f, &a * &wide);
+
+        let mut empty = Matrix::<i32>::zeros(3, 0);
+        a.apply_on_left(&mut empty);
+        assert_eq!(empty.cols(), 0);
+
+        let mut none = Matrix::<i32>::zeros(0, 2);
+        Matrix::<i32>::zeros(0, 0).apply_on_left(&mut none);
+        assert_eq!(none.cols(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_on_left_wrong_size() {
+        let a = Matrix::<f64>::identity(3);
+        a.apply_on_left(&mut Matrix::zeros(2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_on_right_not_square() {
+        let a = Matrix::<f64>::zeros(2, 3);
+        a.apply_on_right(&mut Matrix::zeros(3, 2));
+    }
+
+    #[test]
+    fn test_substitution_matrix_matches_vector() {
+        use super::{forward_substitution, back_substitution, forward_substitution_matrix,
+                    back_substitution_matrix, transpose_back_substitution_matrix};
+
+        let l = Matrix::new(4, 4, vec![2.0, 0.0, 0.0, 0.0,
+                                       1.0, 3.0, 0.0, 0.0,
+                                       -1.0, 0.5, 1.5, 0.0,
+                                       4.0, -2.0, 1.0, 2.5]);
+        let u = l.transpose();
+        let b = Matrix::new(4, 12, (0..48).map(|x| (x % 7) as f64 - 3.0).collect::<Vec<_>>());
+
+        let x_l = forward_substitution_matrix(&l, b.clone()).unwrap();
+        let x_u = back_substitution_matrix(&u, b.clone()).unwrap();
+        let x_t = transpose_back_substitution_matrix(&l, b.clone()).unwrap();
+
+        for j in 0..b.cols() {
+            let col = b.col_opt(j).unwrap().to_vector();
+            let v_l = forward_substitution(&l, col.clone()).unwrap();
+            let v_u = back_substitution(&u, col).unwrap();
+
+            for i in 0..4 {
+                assert!((x_l[[i, j]] - v_l[i]).abs() < 1e-12);
+                assert!((x_u[[i, j]] - v_u[i]).abs() < 1e-12);
+                assert!((x_t[[i, j]] - v_u[i]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unit_substitution() {
+        use super::{forward_substitution, back_substitution, unit_forward_substitution,
+                    unit_back_substitution};
+
+        let l = Matrix::new(4, 4, vec![1.0, 0.0, 0.0, 0.0,
+                                       0.5, 1.0, 0.0, 0.0,
+                                       -2.0, 3.0, 1.0, 0.0,
+                                       1.5, -1.0, 0.25, 1.0]);
+        let u = l.transpose();
+        let y = Vector::new(vec![1.0, -2.0, 3.5, 4.0]);
+
+        let x = unit_forward_substitution(&l, y.clone());
+        assert_eq!(x, forward_substitution(&l, y.clone()).unwrap());
+
+        let x = unit_back_substitution(&u, y.clone());
+        assert_eq!(x, back_substitution(&u, y.clone()).unwrap());
+
+        assert_eq!(l.solve_unit_l_triangular(y.clone()),
+                   l.solve_l_triangular(y.clone()).unwrap());
+        assert_eq!(u.solve_unit_u_triangular(y.clone()),
+                   u.solve_u_triangular(y.clone()).unwrap());
+
+        // The diagonal is never read.
+        let mut l_scaled = l.clone();
+        let mut u_scaled = u.clone();
+        for i in 0..4 {
+            l_scaled[[i, i]] = 7.0;
+            u_scaled[[i, i]] = 0.0;
+        }
+        assert_eq!(unit_forward_substitution(&l_scaled, y.clone()),
+                   unit_forward_substitution(&l, y.clone()));
+        assert_eq!(unit_back_substitution(&u_scaled, y.clone()),
+                   unit_back_substitution(&u, y));
+
+        let empty = Matrix::<f64>::new(0, 0, vec![]);
+        assert_eq!(unit_forward_substitution(&empty, Vector::new(vec![])).size(), 0);
+        assert_eq!(unit_back_substitution(&empty, Vector::new(vec![])).size(), 0);
+    }
+
+    #[test]
+    fn test_substitution_matrix_empty() {
+        use super::{forward_substitution_matrix, back_substitution_matrix};
+
+        let m = Matrix::<f64>::new(0, 0, vec![]);
+
+        let x = forward_substitution_matrix(&m, Matrix::new(0, 3, vec![])).unwrap();
+        assert_eq!((x.rows(), x.cols()), (0, 3));
+
+        let x = back_substitution_matrix(&m, Matrix::new(0, 0, vec![])).unwrap();
+        assert_eq!((x.rows(), x.cols()), (0, 0));
+
+        // Right-hand sides with no columns are also accepted.
+        let l = Matrix::new(2, 2, vec![1.0, 0.0, 1.0, 1.0]);
+        let x = forward_substitution_matrix(&l, Matrix::new(2, 0, vec![])).unwrap();
+        assert_eq!((x.rows(), x.cols()), (2, 0));
+    }
+
+    #[test]
+    fn test_substitution_matrix_singular() {
+        use super::back_substitution_matrix;
+
+        let u = Matrix::new(2, 2, vec![1.0, 2.0, 0.0, 0.0]);
+        assert!(back_substitution_matrix(&u, Matrix::new(2, 1, vec![1.0, 1.0])).is_err());
+    }
 }
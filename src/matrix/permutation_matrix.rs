@@ -0,0 +1,1324 @@
+//! Permutation matrices.
+//!
+//! A permutation matrix is stored as the permutation of indices it
+//! represents, requiring `n` entries for an `n x n` matrix.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Mul;
+use libnum::{Zero, One};
+
+use matrix::{Matrix, BaseMatrixMut};
+use vector::Vector;
+use error::{Error, ErrorKind};
+
+/// A permutation matrix.
+///
+/// The matrix `P` moves row `i` of `A` to row `perm[i]` of `PA`,
+/// where `perm` is the array given to `from_array`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, PermutationMatrix};
+///
+/// let p = PermutationMatrix::<f64>::from_array(vec![1, 2, 0]).unwrap();
+/// let a = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
+///
+/// assert_eq!(*(p.as_matrix() * a).data(), vec![3.0, 1.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermutationMatrix<T> {
+    perm: Vec<usize>,
+    marker: PhantomData<T>,
+}
+
+impl<T> PermutationMatrix<T> {
+    /// Constructs the `n x n` identity permutation.
+    pub fn identity(n: usize) -> PermutationMatrix<T> {
+        PermutationMatrix {
+            perm: (0..n).collect(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Constructs a permutation matrix from an array of indices.
+    ///
+    /// Row `i` is moved to row `array[i]` when applied from the left.
+    ///
+    /// # Failures
+    ///
+    /// - The array is not a permutation of `0..n`.
+    pub fn from_array(array: Vec<usize>) -> Result<PermutationMatrix<T>, Error> {
+        let n = array.len();
+        let mut seen = vec![false; n];
+
+        for &j in &array {
+            if j >= n {
+                return Err(Error::new(ErrorKind::InvalidArg,
+                                      format!("Index {} is out of range for a permutation of \
+                                               size {}.",
+                                              j,
+                                              n)));
+            }
+            if seen[j] {
+                return Err(Error::new(ErrorKind::InvalidArg,
+                                      format!("Index {} appears more than once in the \
+                                               permutation.",
+                                              j)));
+            }
+            seen[j] = true;
+        }
+
+        Ok(PermutationMatrix {
+            perm: array,
+            marker: PhantomData,
+        })
+    }
+
+    /// Constructs a permutation matrix from a vector of indices.
+    ///
+    /// This is the same as `from_array`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_vec(vec![2, 0, 1]).unwrap();
+    /// assert_eq!(p.as_slice(), &[2, 0, 1]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The vector is not a permutation of `0..n`.
+    pub fn from_vec(v: Vec<usize>) -> Result<PermutationMatrix<T>, Error> {
+        PermutationMatrix::from_array(v)
+    }
+
+    /// Constructs a permutation matrix from the inverse array of indices.
+    ///
+    /// Row `array[i]` is moved to row `i` when applied from the left.
+    /// This is the form of a pivot array, where `array[i]` is the row
+    /// which ends up in position `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_inverse(vec![1, 2, 0]).unwrap();
+    ///
+    /// assert_eq!(p, PermutationMatrix::from_array(vec![1, 2, 0]).unwrap().inverse());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The array is not a permutation of `0..n`.
+    pub fn from_inverse(array: Vec<usize>) -> Result<PermutationMatrix<T>, Error> {
+        PermutationMatrix::from_array(array).map(|p| p.inverse())
+    }
+
+    /// Returns the array of indices representing the permutation.
+    ///
+    /// Entry `i` is the row that row `i` is moved to, as in `from_array`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_array(vec![2, 0, 1]).unwrap();
+    ///
+    /// assert_eq!(p.as_index_vec(), vec![2, 0, 1]);
+    /// ```
+    pub fn as_index_vec(&self) -> Vec<usize> {
+        self.perm.clone()
+    }
+
+    /// Returns the array of indices as a slice, without copying.
+    ///
+    /// This is the same array as returned by `as_index_vec`.
+    pub fn as_slice(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Consumes the permutation and returns its array of indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_array(vec![2, 0, 1]).unwrap();
+    ///
+    /// assert_eq!(p.as_slice(), &[2, 0, 1]);
+    /// assert_eq!(p.into_vec(), vec![2, 0, 1]);
+    /// ```
+    pub fn into_vec(self) -> Vec<usize> {
+        self.perm
+    }
+
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// Returns the inverse permutation, which is also the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_array(vec![1, 2, 0]).unwrap();
+    /// let q = PermutationMatrix::<f64>::from_array(vec![2, 0, 1]).unwrap();
+    ///
+    /// assert_eq!(p.inverse(), q);
+    /// ```
+    pub fn inverse(&self) -> PermutationMatrix<T> {
+        let mut inv = vec![0; self.perm.len()];
+        for (i, &j) in self.perm.iter().enumerate() {
+            inv[j] = i;
+        }
+
+        PermutationMatrix {
+            perm: inv,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the decomposition of the permutation into disjoint cycles.
+    ///
+    /// Each cycle `[a, b, c]` means that `a` is moved to `b`, `b` to `c`
+    /// and `c` back to `a`. Cycles start at their smallest index and are
+    /// sorted by it. Fixed points are omitted, so the identity has no cycles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_array(vec![2, 3, 0, 1, 4]).unwrap();
+    ///
+    /// assert_eq!(p.cycles(), vec![vec![0, 2], vec![1, 3]]);
+    /// ```
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        self.cycle_decomposition(false)
+    }
+
+    /// Returns the decomposition of the permutation into disjoint cycles,
+    /// including each fixed point as a cycle of length one.
+    ///
+    /// The cycles are ordered as in `cycles`, and together they contain
+    /// every index exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_array(vec![2, 1, 0]).unwrap();
+    ///
+    /// assert_eq!(p.cycles_with_fixed_points(), vec![vec![0, 2], vec![1]]);
+    /// ```
+    pub fn cycles_with_fixed_points(&self) -> Vec<Vec<usize>> {
+        self.cycle_decomposition(true)
+    }
+
+    fn cycle_decomposition(&self, fixed_points: bool) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.perm.len()];
+        let mut cycles = Vec::new();
+
+        for start in 0..self.perm.len() {
+            if visited[start] || (!fixed_points && self.perm[start] == start) {
+                continue;
+            }
+
+            let mut cycle = Vec::new();
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                cycle.push(i);
+                i = self.perm[i];
+            }
+            cycles.push(cycle);
+        }
+
+        cycles
+    }
+
+    /// Returns the order of the permutation, the smallest `k > 0` for
+    /// which `p.pow(k)` is the identity.
+    ///
+    /// This is the least common multiple of the cycle lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// // A 2-cycle and a 3-cycle.
+    /// let p = PermutationMatrix::<f64>::from_array(vec![1, 0, 3, 4, 2]).unwrap();
+    ///
+    /// assert_eq!(p.order(), 6);
+    /// assert_eq!(p.pow(6), PermutationMatrix::identity(5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The order does not fit in a `usize`.
+    pub fn order(&self) -> usize {
+        fn gcd(mut a: usize, mut b: usize) -> usize {
+            while b != 0 {
+                let r = a % b;
+                a = b;
+                b = r;
+            }
+            a
+        }
+
+        self.cycles().iter().fold(1, |order, c| {
+            (order / gcd(order, c.len()))
+                .checked_mul(c.len())
+                .expect("Permutation order does not fit in a usize.")
+        })
+    }
+
+    /// Computes the `k`-th power of the permutation.
+    ///
+    /// Each cycle is rotated by `k` places, so this takes `O(n)` time
+    /// for any `k`. Negative powers are powers of the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_array(vec![1, 2, 3, 0]).unwrap();
+    ///
+    /// assert_eq!(p.pow(2), &p * &p);
+    /// assert_eq!(p.pow(-1), p.inverse());
+    /// assert_eq!(p.pow(0), PermutationMatrix::identity(4));
+    /// ```
+    pub fn pow(&self, k: i64) -> PermutationMatrix<T> {
+        let mut perm = self.perm.clone();
+
+        for cycle in self.cycles() {
+            let len = cycle.len() as i64;
+            let shift = (((k % len) + len) % len) as usize;
+
+            for (i, &j) in cycle.iter().enumerate() {
+                perm[j] = cycle[(i + shift) % cycle.len()];
+            }
+        }
+
+        PermutationMatrix {
+            perm: perm,
+            marker: PhantomData,
+        }
+    }
+
+    /// Constructs a permutation from its Lehmer code.
+    ///
+    /// Entry `i` of the code counts the entries after position `i` of the
+    /// index array which are smaller than entry `i`, so it must be less
+    /// than `n - i`. This is the inverse of `to_lehmer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_lehmer(&[2, 0, 1, 0]).unwrap();
+    ///
+    /// assert_eq!(p.as_slice(), &[2, 0, 3, 1]);
+    /// assert_eq!(p.to_lehmer(), vec![2, 0, 1, 0]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Entry `i` of the code is not less than `n - i`.
+    pub fn from_lehmer(lehmer: &[usize]) -> Result<PermutationMatrix<T>, Error> {
+        let n = lehmer.len();
+        let mut unused = (0..n).collect::<Vec<_>>();
+        let mut perm = Vec::with_capacity(n);
+
+        for (i, &l) in lehmer.iter().enumerate() {
+            if l >= n - i {
+                return Err(Error::new(ErrorKind::InvalidArg,
+                                      format!("Lehmer code entry {} must be less than {}.",
+                                              i,
+                                              n - i)));
+            }
+            perm.push(unused.remove(l));
+        }
+
+        Ok(PermutationMatrix {
+            perm: perm,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the Lehmer code of the permutation.
+    ///
+    /// Entry `i` is the number of entries after position `i` of the
+    /// index array which are smaller than entry `i`.
+    pub fn to_lehmer(&self) -> Vec<usize> {
+        let n = self.perm.len();
+
+        (0..n)
+            .map(|i| self.perm[i + 1..].iter().filter(|&&j| j < self.perm[i]).count())
+            .collect()
+    }
+
+    /// Returns the position of the index array among all permutations
+    /// of `0..n` in lexicographic order, starting from zero.
+    ///
+    /// The rank is the Lehmer code read as a factorial base number, and
+    /// lies in `0..n!`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::<f64>::from_array(vec![1, 0, 2]).unwrap();
+    ///
+    /// assert_eq!(p.rank(), 2);
+    /// assert_eq!(PermutationMatrix::<f64>::unrank(3, 2), p);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The rank does not fit in a `usize`, which can happen for `n > 20`
+    /// on 64-bit platforms.
+    pub fn rank(&self) -> usize {
+        let n = self.perm.len();
+
+        self.to_lehmer().iter().enumerate().fold(0usize, |rank, (i, &l)| {
+            rank.checked_mul(n - i)
+                .and_then(|r| r.checked_add(l))
+                .expect("Permutation rank does not fit in a usize.")
+        })
+    }
+
+    /// Constructs the permutation of `0..n` with the given lexicographic
+    /// rank. This is the inverse of `rank`.
+    ///
+    /// # Panics
+    ///
+    /// - `rank` is not less than `n!`.
+    pub fn unrank(n: usize, rank: usize) -> PermutationMatrix<T> {
+        let mut lehmer = vec![0; n];
+        let mut r = rank;
+
+        for i in (0..n).rev() {
+            lehmer[i] = r % (n - i);
+            r /= n - i;
+        }
+        assert!(r == 0, "Rank must be less than n!.");
+
+        PermutationMatrix::from_lehmer(&lehmer).expect("Factorial base digits are in range.")
+    }
+
+    /// Returns the parity of the permutation, which is also the
+    /// determinant of the matrix.
+    ///
+    /// This is `1` for an even and `-1` for an odd permutation. A cycle
+    /// of length `k` is a product of `k - 1` transpositions, so the parity
+    /// is read off from the cycle structure in `O(n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    ///
+    /// let swap = PermutationMatrix::<f64>::from_array(vec![1, 0, 2]).unwrap();
+    /// let rotate = PermutationMatrix::<f64>::from_array(vec![1, 2, 0]).unwrap();
+    ///
+    /// assert_eq!(swap.parity(), -1);
+    /// assert_eq!(rotate.parity(), 1);
+    /// assert_eq!(swap.as_matrix().det(), -1.0);
+    /// ```
+    pub fn parity(&self) -> i8 {
+        let transpositions = self.cycles().iter().map(|c| c.len() - 1).sum::<usize>();
+
+        if transpositions % 2 == 0 { 1 } else { -1 }
+    }
+}
+
+/// Formats the permutation in cycle notation.
+///
+/// For example, the permutation swapping `0` with `2` and `1` with `3`
+/// is displayed as `(0 2)(1 3)`. The identity is displayed as `()`.
+impl<T> fmt::Display for PermutationMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return f.write_str("()");
+        }
+
+        for cycle in cycles {
+            f.write_str("(")?;
+            for (index, i) in cycle.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "{}", i)?;
+            }
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone + Zero + One> PermutationMatrix<T> {
+    /// Converts the permutation into a dense `Matrix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, PermutationMatrix};
+    ///
+    /// let p = PermutationMatrix::<u32>::from_array(vec![1, 0]).unwrap();
+    /// assert_eq!(p.as_matrix(), Matrix::new(2, 2, vec![0, 1, 1, 0]));
+    /// ```
+    pub fn as_matrix(&self) -> Matrix<T> {
+        let n = self.perm.len();
+        let mut m = Matrix::zeros(n, n);
+
+        for (i, &j) in self.perm.iter().enumerate() {
+            m.data[j * n + i] = T::one();
+        }
+
+        m
+    }
+}
+
+impl<T: Copy> PermutationMatrix<T> {
+    /// Calls `swap(i, j)` for a sequence of transpositions which together
+    /// apply the permutation to `n` items in place.
+    ///
+    /// If `scatter` is true item `i` ends up at `perm[i]`, otherwise item
+    /// `perm[i]` ends up at `i`. Only a boolean per item is allocated.
+    fn cycle_swaps<F: FnMut(usize, usize)>(&self, scatter: bool, mut swap: F) {
+        let mut visited = vec![false; self.perm.len()];
+
+        for start in 0..self.perm.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+
+            let mut i = start;
+            while !visited[self.perm[i]] {
+                visited[self.perm[i]] = true;
+                if scatter {
+                    // `start` always holds the item which belongs at `perm[i]`.
+                    swap(start, self.perm[i]);
+                } else {
+                    // Pull the item for position `i` from `perm[i]`.
+                    swap(i, self.perm[i]);
+                }
+                i = self.perm[i];
+            }
+        }
+    }
+
+    /// Permutes the rows of `m` in place, computing `P * m`.
+    ///
+    /// Row `i` is moved to row `perm[i]`, matching the product with
+    /// `as_matrix()`. The rows are swapped along the cycles of the
+    /// permutation, so only `O(n)` booleans are allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, PermutationMatrix};
+    ///
+    /// let p = PermutationMatrix::<i32>::from_array(vec![1, 2, 0]).unwrap();
+    /// let mut a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+    /// p.permute_rows_in_place(&mut a);
+    ///
+    /// assert_eq!(a, Matrix::new(3, 2, vec![5, 6, 1, 2, 3, 4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix does not have `size()` rows.
+    pub fn permute_rows_in_place<M: BaseMatrixMut<T>>(&self, m: &mut M) {
+        assert!(m.rows() == self.perm.len(),
+                "Permutation and matrix dimensions do not agree.");
+
+        self.cycle_swaps(true, |i, j| m.swap_rows(i, j));
+    }
+
+    /// Permutes the rows of `m` in place, computing `P * m`.
+    ///
+    /// Use `permute_rows_in_place`, which also accepts matrix slices.
+    ///
+    /// # Panics
+    ///
+    /// - The matrix does not have `size()` rows.
+    #[deprecated(note = "Renamed to `permute_rows_in_place`.")]
+    pub fn permute_rows_inplace(&self, m: &mut Matrix<T>) {
+        self.permute_rows_in_place(m);
+    }
+
+    /// Permutes the columns of `m` in place, computing `m * P`.
+    ///
+    /// Column `j` of the result is column `perm[j]` of `m`, matching the
+    /// product with `as_matrix()`. Equivalently, column `perm[j]` is moved
+    /// to column `j`, the inverse of the movement in `permute_rows_in_place`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, PermutationMatrix};
+    ///
+    /// let p = PermutationMatrix::<i32>::from_array(vec![1, 2, 0]).unwrap();
+    /// let mut a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// p.permute_cols_in_place(&mut a);
+    ///
+    /// assert_eq!(a, Matrix::new(2, 3, vec![2, 3, 1, 5, 6, 4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix does not have `size()` columns.
+    pub fn permute_cols_in_place<M: BaseMatrixMut<T>>(&self, m: &mut M) {
+        assert!(m.cols() == self.perm.len(),
+                "Permutation and matrix dimensions do not agree.");
+
+        self.cycle_swaps(false, |i, j| m.swap_cols(i, j));
+    }
+
+    /// Permutes the entries of `v` in place, computing `P * v`.
+    ///
+    /// Entry `i` is moved to entry `perm[i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::PermutationMatrix;
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let p = PermutationMatrix::<i32>::from_array(vec![1, 2, 0]).unwrap();
+    /// let mut v = Vector::new(vec![1, 2, 3]);
+    /// p.permute_vector_in_place(&mut v);
+    ///
+    /// assert_eq!(v, Vector::new(vec![3, 1, 2]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector does not have `size()` entries.
+    pub fn permute_vector_in_place(&self, v: &mut Vector<T>) {
+        assert!(v.size() == self.perm.len(),
+                "Permutation and vector dimensions do not agree.");
+
+        let data = v.mut_data();
+        self.cycle_swaps(true, |i, j| data.swap(i, j));
+    }
+}
+
+/// Composes two permutation matrices.
+impl<T> Mul<PermutationMatrix<T>> for PermutationMatrix<T> {
+    type Output = PermutationMatrix<T>;
+
+    fn mul(self, rhs: PermutationMatrix<T>) -> PermutationMatrix<T> {
+        &self * &rhs
+    }
+}
+
+/// Composes two permutation matrices.
+impl<'a, T> Mul<&'a PermutationMatrix<T>> for PermutationMatrix<T> {
+    type Output = PermutationMatrix<T>;
+
+    fn mul(self, rhs: &PermutationMatrix<T>) -> PermutationMatrix<T> {
+        &self * rhs
+    }
+}
+
+/// Composes two permutation matrices.
+impl<'a, T> Mul<PermutationMatrix<T>> for &'a PermutationMatrix<T> {
+    type Output = PermutationMatrix<T>;
+
+    fn mul(self, rhs: PermutationMatrix<T>) -> PermutationMatrix<T> {
+        self * &rhs
+    }
+}
+
+/// Composes two permutation matrices.
+///
+/// The product `PQ` applies `Q` first and then `P`, as for the dense
+/// matrices, so row `i` is moved to row `p[q[i]]`. This takes `O(n)` time.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::PermutationMatrix;
+///
+/// let p = PermutationMatrix::<f64>::from_array(vec![1, 0, 2]).unwrap();
+/// let q = PermutationMatrix::<f64>::from_array(vec![0, 2, 1]).unwrap();
+///
+/// assert_eq!((&p * &q).as_matrix(), p.as_matrix() * q.as_matrix());
+/// ```
+///
+/// # Panics
+///
+/// - The permutations have different sizes.
+impl<'a, 'b, T> Mul<&'b PermutationMatrix<T>> for &'a PermutationMatrix<T> {
+    type Output = PermutationMatrix<T>;
+
+    fn mul(self, rhs: &PermutationMatrix<T>) -> PermutationMatrix<T> {
+        assert!(self.perm.len() == rhs.perm.len(),
+                "Permutation dimensions do not agree.");
+
+        PermutationMatrix {
+            perm: rhs.perm.iter().map(|&j| self.perm[j]).collect(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Permutes the rows of a matrix.
+impl<T: Copy> Mul<Matrix<T>> for PermutationMatrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        &self * rhs
+    }
+}
+
+/// Permutes the rows of a matrix.
+impl<'a, T: Copy> Mul<&'a Matrix<T>> for PermutationMatrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        &self * rhs
+    }
+}
+
+/// Permutes the rows of a matrix.
+///
+/// The rows are moved in place, so no new matrix is allocated.
+impl<'a, T: Copy> Mul<Matrix<T>> for &'a PermutationMatrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, mut rhs: Matrix<T>) -> Matrix<T> {
+        assert!(self.perm.len() == rhs.rows, "Matrix dimensions do not agree.");
+        self.permute_rows_in_place(&mut rhs);
+        rhs
+    }
+}
+
+/// Permutes the rows of a matrix.
+///
+/// Computes `PA` by copying each row of `A` to its new position,
+/// without forming the dense matrix `P`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, PermutationMatrix};
+///
+/// let p = PermutationMatrix::<i32>::from_array(vec![1, 2, 0]).unwrap();
+/// let a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+///
+/// assert_eq!(&p * &a, Matrix::new(3, 2, vec![5, 6, 1, 2, 3, 4]));
+/// assert_eq!(&p * &a, p.as_matrix() * &a);
+/// ```
+///
+/// # Panics
+///
+/// - The matrix does not have `size()` rows.
+impl<'a, 'b, T: Copy> Mul<&'b Matrix<T>> for &'a PermutationMatrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert!(self.perm.len() == rhs.rows, "Matrix dimensions do not agree.");
+
+        let cols = rhs.cols;
+        let mut data = Vec::with_capacity(rhs.data.len());
+        for &i in &self.inverse().perm {
+            data.extend_from_slice(&rhs.data[i * cols..(i + 1) * cols]);
+        }
+
+        Matrix::new(rhs.rows, cols, data)
+    }
+}
+
+/// Permutes the columns of a matrix.
+impl<T: Copy> Mul<PermutationMatrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: PermutationMatrix<T>) -> Matrix<T> {
+        &self * &rhs
+    }
+}
+
+/// Permutes the columns of a matrix.
+impl<'a, T: Copy> Mul<&'a PermutationMatrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &PermutationMatrix<T>) -> Matrix<T> {
+        &self * rhs
+    }
+}
+
+/// Permutes the columns of a matrix.
+impl<'a, T: Copy> Mul<PermutationMatrix<T>> for &'a Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: PermutationMatrix<T>) -> Matrix<T> {
+        self * &rhs
+    }
+}
+
+/// Permutes the columns of a matrix.
+///
+/// Computes `AP`, whose column `j` is column `perm[j]` of `A`,
+/// without forming the dense matrix `P`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, PermutationMatrix};
+///
+/// let p = PermutationMatrix::<i32>::from_array(vec![1, 2, 0]).unwrap();
+/// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+///
+/// assert_eq!(&a * &p, Matrix::new(2, 3, vec![2, 3, 1, 5, 6, 4]));
+/// assert_eq!(&a * &p, &a * p.as_matrix());
+/// ```
+///
+/// # Panics
+///
+/// - The matrix does not have `size()` columns.
+impl<'a, 'b, T: Copy> Mul<&'b PermutationMatrix<T>> for &'a Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &PermutationMatrix<T>) -> Matrix<T> {
+        assert!(self.cols == rhs.perm.len(), "Matrix dimensions do not agree.");
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for row in self.data.chunks(self.cols.max(1)).take(self.rows) {
+            data.extend(rhs.perm.iter().map(|&j| row[j]));
+        }
+
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+/// Permutes the entries of a vector.
+impl<T: Copy> Mul<Vector<T>> for PermutationMatrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        &self * &rhs
+    }
+}
+
+/// Permutes the entries of a vector.
+impl<'a, T: Copy> Mul<&'a Vector<T>> for PermutationMatrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Vector<T> {
+        &self * rhs
+    }
+}
+
+/// Permutes the entries of a vector.
+impl<'a, T: Copy> Mul<Vector<T>> for &'a PermutationMatrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        self * &rhs
+    }
+}
+
+/// Permutes the entries of a vector.
+///
+/// Entry `i` of `v` is moved to entry `perm[i]` of `Pv`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::PermutationMatrix;
+/// use rulinalg::vector::Vector;
+///
+/// let p = PermutationMatrix::<f64>::from_array(vec![1, 2, 0]).unwrap();
+/// let v = Vector::new(vec![1.0, 2.0, 3.0]);
+///
+/// assert_eq!(&p * &v, Vector::new(vec![3.0, 1.0, 2.0]));
+/// ```
+///
+/// # Panics
+///
+/// - The vector does not have `size()` entries.
+impl<'a, 'b, T: Copy> Mul<&'b Vector<T>> for &'a PermutationMatrix<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Vector<T> {
+        assert!(self.perm.len() == rhs.size(),
+                "Matrix and Vector dimensions do not agree.");
+
+        let data = rhs.data();
+        Vector::new(self.inverse().perm.iter().map(|&i| data[i]).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, MatrixSlice, MatrixSliceMut, BaseMatrix};
+    use vector::Vector;
+    use super::PermutationMatrix;
+
+    #[test]
+    fn test_from_array_invalid() {
+        assert!(PermutationMatrix::<f64>::from_array(vec![0, 0, 1]).is_err());
+        assert!(PermutationMatrix::<f64>::from_array(vec![0, 3, 1]).is_err());
+        assert!(PermutationMatrix::<f64>::from_array(vec![]).is_ok());
+    }
+
+    #[test]
+    fn test_from_array_error_messages() {
+        let err = PermutationMatrix::<f64>::from_array(vec![0, 3, 1]).unwrap_err();
+        assert_eq!(err.to_string(), "Index 3 is out of range for a permutation of size 3.");
+
+        let err = PermutationMatrix::<f64>::from_array(vec![1, 0, 1]).unwrap_err();
+        assert_eq!(err.to_string(), "Index 1 appears more than once in the permutation.");
+
+        assert!(PermutationMatrix::<f64>::from_array(vec![1]).is_err());
+        assert!(PermutationMatrix::<f64>::from_array(vec![0, 1, 2, usize::max_value()]).is_err());
+
+        let err = PermutationMatrix::<f64>::from_vec(vec![0, 3, 1]).unwrap_err();
+        assert_eq!(err.to_string(), "Index 3 is out of range for a permutation of size 3.");
+        assert_eq!(PermutationMatrix::<f64>::from_vec(vec![1, 0]).unwrap(),
+                   PermutationMatrix::from_array(vec![1, 0]).unwrap());
+    }
+
+    #[test]
+    fn test_slice_and_into_vec() {
+        let p = PermutationMatrix::<f64>::from_array(vec![3, 0, 2, 1]).unwrap();
+
+        assert_eq!(p.as_slice(), &p.as_index_vec()[..]);
+        assert_eq!(PermutationMatrix::<f64>::from_array(p.clone().into_vec()).unwrap(), p);
+    }
+
+    #[test]
+    fn test_parity() {
+        assert_eq!(PermutationMatrix::<f64>::identity(0).parity(), 1);
+        assert_eq!(PermutationMatrix::<f64>::identity(5).parity(), 1);
+
+        // Every transposition is odd.
+        for &(i, j) in &[(0, 1), (0, 4), (2, 3)] {
+            let mut array = (0..5).collect::<Vec<_>>();
+            array.swap(i, j);
+            let p = PermutationMatrix::<f64>::from_array(array).unwrap();
+            assert_eq!(p.parity(), -1);
+        }
+
+        for array in vec![vec![0, 1, 2], vec![0, 2, 1], vec![1, 0, 2],
+                          vec![1, 2, 0], vec![2, 0, 1], vec![2, 1, 0]] {
+            let p = PermutationMatrix::<f64>::from_array(array).unwrap();
+            assert_eq!(p.as_matrix().det(), p.parity() as f64);
+        }
+
+        // Parity is multiplicative.
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let q = PermutationMatrix::<f64>::from_array(vec![1, 0, 2, 3, 5, 4]).unwrap();
+        assert_eq!(p.parity(), -1);
+        assert_eq!((&p * &q).parity(), p.parity() * q.parity());
+        assert_eq!(p.inverse().parity(), p.parity());
+    }
+
+    #[test]
+    fn test_lehmer_round_trip() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let code = p.to_lehmer();
+
+        assert_eq!(code, vec![4, 0, 3, 2, 0, 0]);
+        assert_eq!(PermutationMatrix::from_lehmer(&code).unwrap(), p);
+        assert_eq!(PermutationMatrix::<f64>::identity(4).to_lehmer(), vec![0; 4]);
+        assert!(PermutationMatrix::<f64>::from_lehmer(&[]).unwrap().as_slice().is_empty());
+
+        let err = PermutationMatrix::<f64>::from_lehmer(&[0, 2, 0]).unwrap_err();
+        assert_eq!(err.to_string(), "Lehmer code entry 1 must be less than 2.");
+        assert!(PermutationMatrix::<f64>::from_lehmer(&[0, 0, 1]).is_err());
+    }
+
+    #[test]
+    fn test_rank_unrank_all_small() {
+        let mut factorial = 1;
+        for n in 0..6 {
+            if n > 0 {
+                factorial *= n;
+            }
+
+            let mut previous: Option<Vec<usize>> = None;
+            for r in 0..factorial {
+                let p = PermutationMatrix::<f64>::unrank(n, r);
+                assert_eq!(p.rank(), r);
+                assert_eq!(PermutationMatrix::unrank(n, p.rank()), p);
+                assert_eq!(PermutationMatrix::from_lehmer(&p.to_lehmer()).unwrap(), p);
+
+                // Ranks follow the lexicographic order of the index arrays.
+                if let Some(prev) = previous {
+                    assert!(prev.as_slice() < p.as_slice());
+                }
+                previous = Some(p.into_vec());
+            }
+
+            let reversed = PermutationMatrix::<f64>::from_array((0..n).rev().collect()).unwrap();
+            assert_eq!(reversed.rank(), factorial - 1);
+            assert_eq!(PermutationMatrix::<f64>::identity(n).rank(), 0);
+        }
+    }
+
+    // 20! - 1 only fits in a 64-bit usize.
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_rank_large() {
+        let reversed = PermutationMatrix::<f64>::from_array((0..20).rev().collect()).unwrap();
+        let factorial_20 = (1..21).fold(1u64, |f, k| f * k);
+
+        assert_eq!(reversed.rank() as u64, factorial_20 - 1);
+        assert_eq!(PermutationMatrix::<f64>::unrank(20, reversed.rank()), reversed);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unrank_out_of_range() {
+        let _ = PermutationMatrix::<f64>::unrank(3, 6);
+    }
+
+    #[test]
+    fn test_compose_then_apply() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let q = PermutationMatrix::<f64>::from_array(vec![2, 3, 0, 1, 5, 4]).unwrap();
+        let a = Matrix::new(6, 2, (0..12).map(|x| x as f64).collect::<Vec<_>>());
+
+        // (PQ)A applies Q first and then P.
+        assert_eq!(&(&p * &q) * &a, &p * &(&q * &a));
+        assert_eq!(&a.transpose() * &(&p * &q), &(&a.transpose() * &p) * &q);
+    }
+
+    #[test]
+    fn test_index_vec_round_trip() {
+        let p = PermutationMatrix::<f64>::from_array(vec![3, 0, 4, 1, 2]).unwrap();
+
+        assert_eq!(PermutationMatrix::from_array(p.as_index_vec()).unwrap(), p);
+        assert_eq!(PermutationMatrix::from_inverse(p.inverse().as_index_vec()).unwrap(), p);
+        assert!(PermutationMatrix::<f64>::from_inverse(vec![1, 1]).is_err());
+    }
+
+    #[test]
+    fn test_index_vec_applies_to_vector() {
+        let p = PermutationMatrix::<f64>::from_array(vec![3, 0, 4, 1, 2]).unwrap();
+        let v = Vector::new(vec![10.0, 20.0, 30.0, 40.0, 50.0]);
+
+        let mut permuted = vec![0.0; 5];
+        for (i, &j) in p.as_index_vec().iter().enumerate() {
+            permuted[j] = v[i];
+        }
+        assert_eq!(Vector::new(permuted), p.as_matrix() * &v);
+
+        // The inverse array gathers instead of scattering.
+        let pivots = p.inverse().as_index_vec();
+        let gathered = pivots.iter().map(|&i| v[i]).collect::<Vec<_>>();
+        assert_eq!(Vector::new(gathered), p.as_matrix() * v);
+    }
+
+    #[test]
+    fn test_cycles() {
+        // 0 -> 4 -> 1 -> 0, 2 -> 5 -> 2 and 3 fixed.
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+
+        assert_eq!(p.cycles(), vec![vec![0, 4, 1], vec![2, 5]]);
+        assert_eq!(p.inverse().cycles(), vec![vec![0, 1, 4], vec![2, 5]]);
+        assert!(PermutationMatrix::<f64>::identity(3).cycles().is_empty());
+        assert!(PermutationMatrix::<f64>::identity(0).cycles().is_empty());
+    }
+
+    #[test]
+    fn test_cycles_with_fixed_points() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        assert_eq!(p.cycles_with_fixed_points(), vec![vec![0, 4, 1], vec![2, 5], vec![3]]);
+
+        let cycles = PermutationMatrix::<f64>::identity(4).cycles_with_fixed_points();
+        assert_eq!(cycles.len(), 4);
+        assert!(cycles.iter().all(|c| c.len() == 1));
+        assert_eq!(cycles, vec![vec![0], vec![1], vec![2], vec![3]]);
+
+        assert!(PermutationMatrix::<f64>::identity(0).cycles_with_fixed_points().is_empty());
+    }
+
+    #[test]
+    fn test_order() {
+        assert_eq!(PermutationMatrix::<f64>::identity(0).order(), 1);
+        assert_eq!(PermutationMatrix::<f64>::identity(4).order(), 1);
+
+        let three_cycle = PermutationMatrix::<f64>::from_array(vec![1, 2, 0, 3]).unwrap();
+        assert_eq!(three_cycle.order(), 3);
+
+        // Cycles of length 4 and 6 have order lcm(4, 6) = 12.
+        let p = PermutationMatrix::<f64>::from_array(vec![1, 2, 3, 0, 5, 6, 7, 8, 9, 4])
+            .unwrap();
+        assert_eq!(p.order(), 12);
+        assert!((1..12).all(|k| p.pow(k) != PermutationMatrix::identity(10)));
+    }
+
+    #[test]
+    fn test_pow() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let id = PermutationMatrix::identity(6);
+
+        assert_eq!(p.pow(0), id);
+        assert_eq!(p.pow(1), p);
+        assert_eq!(p.pow(3), &(&p * &p) * &p);
+        assert_eq!(p.pow(-1), p.inverse());
+        assert_eq!(p.pow(-2), p.inverse().pow(2));
+        assert_eq!(p.pow(p.order() as i64), id);
+        assert_eq!(p.pow(7 * p.order() as i64 + 1), p);
+        assert_eq!(p.pow(i64::min_value()), p.pow(i64::min_value() % p.order() as i64));
+        assert_eq!(id.pow(5), id);
+    }
+
+    #[test]
+    fn test_display() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        assert_eq!(format!("{}", p), "(0 4 1)(2 5)");
+
+        let p = PermutationMatrix::<f64>::from_array(vec![2, 3, 0, 1]).unwrap();
+        assert_eq!(p.to_string(), "(0 2)(1 3)");
+
+        assert_eq!(PermutationMatrix::<f64>::identity(3).to_string(), "()");
+    }
+
+    #[test]
+    fn test_compose_swaps() {
+        let p = PermutationMatrix::<i32>::from_array(vec![1, 0, 2, 3]).unwrap();
+        let q = PermutationMatrix::<i32>::from_array(vec![0, 2, 1, 3]).unwrap();
+
+        assert_eq!((&p * &q).as_matrix(), p.as_matrix() * q.as_matrix());
+        assert_eq!((&q * &p).as_matrix(), q.as_matrix() * p.as_matrix());
+        assert!(&p * &q != &q * &p);
+        assert_eq!((&p * &q).cycles(), vec![vec![0, 1, 2]]);
+
+        let r = PermutationMatrix::<i32>::from_array(vec![3, 0, 1, 2]).unwrap();
+        assert_eq!((p.clone() * q.clone() * r.clone()).as_matrix(),
+                   p.as_matrix() * q.as_matrix() * r.as_matrix());
+    }
+
+    #[test]
+    fn test_compose_inverse() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let id = PermutationMatrix::identity(6);
+
+        assert_eq!(&p * &p.inverse(), id);
+        assert_eq!(&p.inverse() * &p, id);
+        assert_eq!(&p * &id, p);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_compose_size_mismatch() {
+        let _ = PermutationMatrix::<f64>::identity(2) * PermutationMatrix::identity(3);
+    }
+
+    #[test]
+    fn test_as_matrix_inverse() {
+        let p = PermutationMatrix::<i32>::from_array(vec![2, 0, 3, 1]).unwrap();
+        let a = Matrix::new(4, 2, (0..8).collect::<Vec<i32>>());
+
+        let pa = p.as_matrix() * &a;
+        assert_eq!(pa[[2, 0]], a[[0, 0]]);
+        assert_eq!(pa[[2, 1]], a[[0, 1]]);
+        assert_eq!(p.inverse().as_matrix() * pa, a);
+        assert_eq!(PermutationMatrix::<i32>::identity(4).as_matrix(), Matrix::identity(4));
+    }
+
+    /// Builds the permutation which sorts `keys`, giving a random
+    /// permutation for random keys.
+    fn perm_from_keys(keys: &[i32]) -> PermutationMatrix<i32> {
+        PermutationMatrix::from_array(Vector::new(keys.to_vec()).argsort()).unwrap()
+    }
+
+    #[test]
+    fn test_permute_rows_in_place_matches_product() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let a = Matrix::new(6, 3, (0..18).map(|x| x as f64).collect::<Vec<_>>());
+
+        let mut b = a.clone();
+        p.permute_rows_in_place(&mut b);
+        assert_eq!(b, p.as_matrix() * &a);
+
+        p.inverse().permute_rows_in_place(&mut b);
+        assert_eq!(b, a);
+
+        PermutationMatrix::identity(6).permute_rows_in_place(&mut b);
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_permute_rows_inplace_deprecated() {
+        let p = PermutationMatrix::<i32>::from_array(vec![1, 2, 0]).unwrap();
+        let a = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        let mut b = a.clone();
+        p.permute_rows_inplace(&mut b);
+        assert_eq!(b, p.as_matrix() * &a);
+    }
+
+    #[test]
+    fn test_permute_cols_in_place_matches_product() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let a = Matrix::new(3, 6, (0..18).map(|x| x as f64).collect::<Vec<_>>());
+
+        let mut b = a.clone();
+        p.permute_cols_in_place(&mut b);
+        assert_eq!(b, &a * p.as_matrix());
+
+        p.inverse().permute_cols_in_place(&mut b);
+        assert_eq!(b, a);
+
+        PermutationMatrix::identity(6).permute_cols_in_place(&mut b);
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    fn test_permute_vector_in_place_matches_product() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let v = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let mut w = v.clone();
+        p.permute_vector_in_place(&mut w);
+        assert_eq!(w, p.as_matrix() * &v);
+
+        PermutationMatrix::identity(6).permute_vector_in_place(&mut w);
+        assert_eq!(w, p.as_matrix() * &v);
+    }
+
+    #[test]
+    fn test_permute_in_place_on_slice() {
+        let p = PermutationMatrix::<i32>::from_array(vec![2, 0, 1]).unwrap();
+        let a = Matrix::new(4, 5, (0..20).collect::<Vec<i32>>());
+        let block = MatrixSlice::from_matrix(&a, [1, 1], 3, 3).into_matrix();
+
+        let mut b = a.clone();
+        p.permute_rows_in_place(&mut MatrixSliceMut::from_matrix(&mut b, [1, 1], 3, 3));
+        assert_eq!(MatrixSlice::from_matrix(&b, [1, 1], 3, 3).into_matrix(),
+                   p.as_matrix() * &block);
+
+        let mut c = a.clone();
+        p.permute_cols_in_place(&mut MatrixSliceMut::from_matrix(&mut c, [1, 1], 3, 3));
+        assert_eq!(MatrixSlice::from_matrix(&c, [1, 1], 3, 3).into_matrix(),
+                   &block * p.as_matrix());
+
+        // Entries outside the view are untouched.
+        for &(i, j) in &[(0, 0), (0, 2), (1, 0), (3, 4), (2, 4)] {
+            assert_eq!(b[[i, j]], a[[i, j]]);
+            assert_eq!(c[[i, j]], a[[i, j]]);
+        }
+    }
+
+    quickcheck! {
+        fn prop_permute_in_place_matches_product(keys: Vec<i32>, cols: u8) -> bool {
+            let p = perm_from_keys(&keys);
+            let (n, m) = (keys.len(), cols as usize % 5 + 1);
+            let a = Matrix::new(n, m, (0..n * m).map(|x| (x * 7 % 11) as i32).collect::<Vec<_>>());
+            let v = Vector::new(keys.clone());
+
+            let mut rows = a.clone();
+            p.permute_rows_in_place(&mut rows);
+            let mut cols = a.transpose();
+            p.permute_cols_in_place(&mut cols);
+            let mut w = v.clone();
+            p.permute_vector_in_place(&mut w);
+
+            rows == p.as_matrix() * &a &&
+            cols == a.transpose() * p.as_matrix() &&
+            w == p.as_matrix() * &v
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permute_rows_in_place_wrong_size() {
+        let p = PermutationMatrix::<f64>::identity(3);
+        let mut a = Matrix::<f64>::zeros(2, 2);
+        p.permute_rows_in_place(&mut a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permute_cols_in_place_wrong_size() {
+        let p = PermutationMatrix::<f64>::identity(3);
+        let mut a = Matrix::<f64>::zeros(3, 2);
+        p.permute_cols_in_place(&mut a);
+    }
+
+    #[test]
+    fn test_mul_matrix_matches_dense() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let a = Matrix::new(6, 3, (0..18).map(|x| x as f64).collect::<Vec<_>>());
+        let b = Matrix::new(3, 6, (0..18).map(|x| x as f64).collect::<Vec<_>>());
+
+        let pa = p.as_matrix() * &a;
+        assert_eq!(&p * &a, pa);
+        assert_eq!(&p * a.clone(), pa);
+        assert_eq!(p.clone() * &a, pa);
+        assert_eq!(p.clone() * a, pa);
+
+        let bp = &b * p.as_matrix();
+        assert_eq!(&b * &p, bp);
+        assert_eq!(&b * p.clone(), bp);
+        assert_eq!(b.clone() * &p, bp);
+        assert_eq!(b * p, bp);
+    }
+
+    #[test]
+    fn test_mul_vector_matches_dense() {
+        let p = PermutationMatrix::<f64>::from_array(vec![4, 0, 5, 3, 1, 2]).unwrap();
+        let v = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let pv = p.as_matrix() * &v;
+        assert_eq!(&p * &v, pv);
+        assert_eq!(&p * v.clone(), pv);
+        assert_eq!(p.clone() * &v, pv);
+        assert_eq!(p.inverse() * (p * v.clone()), v);
+    }
+
+    #[test]
+    fn test_mul_empty() {
+        let p = PermutationMatrix::<f64>::identity(0);
+        let a = Matrix::<f64>::zeros(0, 3);
+        let b = Matrix::<f64>::zeros(3, 0);
+
+        assert_eq!(&p * &a, a);
+        assert_eq!(&b * &p, b);
+        assert_eq!(&p * &Vector::<f64>::new(vec![]), Vector::new(vec![]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_matrix_wrong_rows() {
+        let _ = PermutationMatrix::<f64>::identity(3) * Matrix::<f64>::zeros(2, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_matrix_wrong_cols() {
+        let _ = Matrix::<f64>::zeros(3, 2) * PermutationMatrix::<f64>::identity(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_vector_wrong_size() {
+        let _ = PermutationMatrix::<f64>::identity(3) * Vector::new(vec![1.0, 2.0]);
+    }
+}
@@ -19,18 +19,20 @@
 //! let _new_mat = &mat_slice.transpose() * &a;
 //! ```
 
-use matrix::{Matrix, MatrixSlice, MatrixSliceMut, Rows, RowsMut, Axes};
+use matrix::{Matrix, MatrixSlice, MatrixSliceMut, Rows, RowsMut, Cols, ColsMut, Axes};
+use matrix::{Column, Diagonal, DiagonalMut};
 use matrix::{back_substitution, forward_substitution};
+use matrix::{unit_back_substitution, unit_forward_substitution};
 use vector::Vector;
 use utils;
-use libnum::{Zero, Float};
-use error::Error;
+use libnum::{Zero, One, Float};
+use error::{Error, ErrorKind};
 
 use std::any::Any;
-use std::cmp::min;
+use std::cmp::{min, Ordering};
 use std::marker::PhantomData;
 use std::mem;
-use std::ops::{Add, Mul, Div};
+use std::ops::{Add, Mul, Sub, Div, Range};
 use std::ptr;
 use std::slice;
 
@@ -65,10 +67,36 @@ pub trait BaseMatrix<T>: Sized {
     }
 
     /// Get a reference to a point in the matrix without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must satisfy `index[0] < rows` and `index[1] < cols`.
+    /// Use `get` for a bounds checked version.
     unsafe fn get_unchecked(&self, index: [usize; 2]) -> &T {
         &*(self.as_ptr().offset((index[0] * self.row_stride() + index[1]) as isize))
     }
 
+    /// Get a reference to a point in the matrix.
+    /// `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(a.get([1, 0]), Some(&3));
+    /// assert_eq!(a.get([2, 0]), None);
+    /// ```
+    fn get(&self, index: [usize; 2]) -> Option<&T> {
+        if index[0] < self.rows() && index[1] < self.cols() {
+            unsafe { Some(self.get_unchecked(index)) }
+        } else {
+            None
+        }
+    }
+
     /// Returns the row of a matrix at the given index.
     /// `None` if the index is out of bounds.
     ///
@@ -92,6 +120,46 @@ pub trait BaseMatrix<T>: Sized {
         }
     }
 
+    /// Returns the row of a matrix at the given index.
+    /// `None` if the index is out of bounds.
+    ///
+    /// This is the same as `get_row`, named to pair with `col_opt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(a.row_opt(1), Some(&[3, 4][..]));
+    /// assert!(a.row_opt(2).is_none());
+    /// ```
+    fn row_opt(&self, index: usize) -> Option<&[T]> {
+        self.get_row(index)
+    }
+
+    /// Returns a view of the column of a matrix at the given index.
+    /// `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(*a.col_opt(1).unwrap().to_vector().data(), vec![2, 4]);
+    /// assert!(a.col_opt(2).is_none());
+    /// ```
+    fn col_opt(&self, index: usize) -> Option<Column<'_, T>> {
+        if index < self.cols() {
+            self.iter_cols().nth(index)
+        } else {
+            None
+        }
+    }
+
     /// Returns the row of a matrix at the given index without doing unbounds checking
     ///
     /// # Examples
@@ -162,6 +230,34 @@ pub trait BaseMatrix<T>: Sized {
         }
     }
 
+    /// Iterate over the columns of the matrix.
+    ///
+    /// Each column is returned as a `Column` view into the
+    /// (strided) matrix data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3, 2, (0..6).collect::<Vec<usize>>());
+    ///
+    /// let col_sums = a.iter_cols()
+    ///                 .map(|col| col.iter().fold(0, |s, x| s + x))
+    ///                 .collect::<Vec<_>>();
+    /// assert_eq!(col_sums, vec![6, 9]);
+    /// ```
+    fn iter_cols(&self) -> Cols<'_, T> {
+        Cols {
+            slice_start: self.as_ptr(),
+            col_pos: 0,
+            slice_rows: self.rows(),
+            slice_cols: self.cols(),
+            row_stride: self.row_stride(),
+            _marker: PhantomData::<&T>,
+        }
+    }
+
     /// The sum of the rows of the matrix.
     ///
     /// Returns a Vector equal to the sums of elements over the matrices rows.
@@ -559,6 +655,94 @@ pub trait BaseMatrix<T>: Sized {
         Vector::new(diagonal)
     }
 
+    /// Iterate over the `k`-th diagonal of the matrix.
+    ///
+    /// A positive `k` selects a diagonal above the main diagonal,
+    /// a negative `k` one below it. If `k` lies outside of the
+    /// matrix the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3, 3, (0..9).collect::<Vec<usize>>());
+    ///
+    /// assert_eq!(a.diag_iter(0).cloned().collect::<Vec<_>>(), vec![0, 4, 8]);
+    /// assert_eq!(a.diag_iter(1).cloned().collect::<Vec<_>>(), vec![1, 5]);
+    /// assert_eq!(a.diag_iter(-2).cloned().collect::<Vec<_>>(), vec![6]);
+    /// assert_eq!(a.diag_iter(3).count(), 0);
+    /// ```
+    fn diag_iter(&self, k: isize) -> Diagonal<'_, T> {
+        let (offset, len) = diag_offset_len(self.rows(), self.cols(), self.row_stride(), k);
+
+        Diagonal {
+            ptr: unsafe { self.as_ptr().offset(offset as isize) },
+            diag_pos: 0,
+            diag_len: len,
+            stride: self.row_stride() + 1,
+            _marker: PhantomData::<&T>,
+        }
+    }
+
+    /// Iterate over the diagonal directly below the main diagonal.
+    ///
+    /// Equivalent to `diag_iter(-1)`.
+    fn sub_diag(&self) -> Diagonal<'_, T> {
+        self.diag_iter(-1)
+    }
+
+    /// Iterate over the diagonal directly above the main diagonal.
+    ///
+    /// Equivalent to `diag_iter(1)`.
+    fn super_diag(&self) -> Diagonal<'_, T> {
+        self.diag_iter(1)
+    }
+
+    /// Returns a copy of the matrix with all elements below the `k`-th diagonal zeroed.
+    ///
+    /// See `diag_iter` for the meaning of `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3, 3, (1..10).collect::<Vec<i32>>());
+    ///
+    /// assert_eq!(a.triu(0).into_vec(), vec![1, 2, 3, 0, 5, 6, 0, 0, 9]);
+    /// assert_eq!(a.triu(1).into_vec(), vec![0, 2, 3, 0, 0, 6, 0, 0, 0]);
+    /// ```
+    fn triu(&self, k: isize) -> Matrix<T>
+        where T: Copy + Zero
+    {
+        let mut m = Matrix::new(self.rows(), self.cols(), self.iter().cloned().collect::<Vec<_>>());
+        m.triu_in_place(k);
+        m
+    }
+
+    /// Returns a copy of the matrix with all elements above the `k`-th diagonal zeroed.
+    ///
+    /// See `diag_iter` for the meaning of `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3, 3, (1..10).collect::<Vec<i32>>());
+    ///
+    /// assert_eq!(a.tril(0).into_vec(), vec![1, 0, 0, 4, 5, 0, 7, 8, 9]);
+    /// assert_eq!(a.tril(-1).into_vec(), vec![0, 0, 0, 4, 0, 0, 7, 8, 0]);
+    /// ```
+    fn tril(&self, k: isize) -> Matrix<T>
+        where T: Copy + Zero
+    {
+        let mut m = Matrix::new(self.rows(), self.cols(), self.iter().cloned().collect::<Vec<_>>());
+        m.tril_in_place(k);
+        m
+    }
+
     /// Tranposes the given matrix
     ///
     /// # Examples
@@ -573,22 +757,52 @@ pub trait BaseMatrix<T>: Sized {
     fn transpose(&self) -> Matrix<T>
         where T: Copy
     {
-        let mut new_data = Vec::with_capacity(self.rows() * self.cols());
+        let mut out = Matrix {
+            rows: 0,
+            cols: 0,
+            data: Vec::with_capacity(self.rows() * self.cols()),
+        };
+        self.transpose_into(&mut out);
+        out
+    }
 
-        unsafe {
-            new_data.set_len(self.rows() * self.cols());
-            for i in 0..self.cols() {
-                for j in 0..self.rows() {
-                    *new_data.get_unchecked_mut(i * self.rows() + j) = *self.get_unchecked([j, i]);
-                }
+    /// Writes the transpose of the matrix into `out`.
+    ///
+    /// The buffer of `out` is reused, and only grows if it
+    /// is too small to hold the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let mut out = Matrix::new(0, 0, vec![]);
+    ///
+    /// a.transpose_into(&mut out);
+    /// assert_eq!(out, a.transpose());
+    /// ```
+    fn transpose_into(&self, out: &mut Matrix<T>)
+        where T: Copy
+    {
+        let (rows, cols) = (self.rows(), self.cols());
+        let len = rows * cols;
+
+        out.data.clear();
+        if len != 0 {
+            // Every entry is overwritten below, so any value will do.
+            let fill = unsafe { *self.get_unchecked([0, 0]) };
+            out.data.resize(len, fill);
+
+            unsafe {
+                transpose_recursive(self.as_ptr(), self.row_stride(),
+                                    out.data.as_mut_ptr(), rows,
+                                    rows, cols);
             }
         }
 
-        Matrix {
-            cols: self.rows(),
-            rows: self.cols(),
-            data: new_data,
-        }
+        out.rows = cols;
+        out.cols = rows;
     }
 
     /// Checks if matrix is diagonal.
@@ -620,6 +834,126 @@ pub trait BaseMatrix<T>: Sized {
         })
     }
 
+    /// Checks if the matrix is diagonal, up to the given tolerance.
+    ///
+    /// Every off-diagonal element must be within `tol` of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 1e-12, 0.0, 1.0]);
+    ///
+    /// assert!(a.is_diag_within(1e-10));
+    /// assert!(!a.is_diag_within(1e-14));
+    /// ```
+    fn is_diag_within(&self, tol: T) -> bool
+        where T: Float
+    {
+        self.is_lower_triangular_within(tol) && self.is_upper_triangular_within(tol)
+    }
+
+    /// Checks if the matrix is lower triangular.
+    ///
+    /// The matrix need not be square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 0, 0, 2, 3, 0]);
+    ///
+    /// assert!(a.is_lower_triangular());
+    /// assert!(!a.is_upper_triangular());
+    /// ```
+    fn is_lower_triangular(&self) -> bool
+        where T: Zero + PartialEq
+    {
+        (1..self.cols()).all(|k| self.diag_iter(k as isize).all(|x| *x == T::zero()))
+    }
+
+    /// Checks if the matrix is lower triangular, up to the given tolerance.
+    ///
+    /// Every element above the diagonal must be within `tol` of zero.
+    fn is_lower_triangular_within(&self, tol: T) -> bool
+        where T: Float
+    {
+        (1..self.cols()).all(|k| self.diag_iter(k as isize).all(|x| x.abs() <= tol))
+    }
+
+    /// Checks if the matrix is upper triangular.
+    ///
+    /// The matrix need not be square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3, 2, vec![1, 2, 0, 3, 0, 0]);
+    ///
+    /// assert!(a.is_upper_triangular());
+    /// assert!(!a.is_lower_triangular());
+    /// ```
+    fn is_upper_triangular(&self) -> bool
+        where T: Zero + PartialEq
+    {
+        (1..self.rows()).all(|k| self.diag_iter(-(k as isize)).all(|x| *x == T::zero()))
+    }
+
+    /// Checks if the matrix is upper triangular, up to the given tolerance.
+    ///
+    /// Every element below the diagonal must be within `tol` of zero.
+    fn is_upper_triangular_within(&self, tol: T) -> bool
+        where T: Float
+    {
+        (1..self.rows()).all(|k| self.diag_iter(-(k as isize)).all(|x| x.abs() <= tol))
+    }
+
+    /// Checks if the matrix is exactly symmetric.
+    ///
+    /// Returns `false` for non-square matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1, 2, 2, 1]);
+    /// let b = Matrix::new(2, 2, vec![1, 2, 3, 1]);
+    ///
+    /// assert!(a.is_symmetric());
+    /// assert!(!b.is_symmetric());
+    /// ```
+    fn is_symmetric(&self) -> bool
+        where T: PartialEq
+    {
+        is_symmetric_by(self, |a, b| a == b)
+    }
+
+    /// Checks if the matrix is symmetric, up to the given tolerance.
+    ///
+    /// Each pair of mirrored elements must differ by at most `tol`.
+    /// Returns `false` for non-square matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 2.0 + 1e-12, 1.0]);
+    ///
+    /// assert!(!a.is_symmetric());
+    /// assert!(a.is_symmetric_within(1e-10));
+    /// ```
+    fn is_symmetric_within(&self, tol: T) -> bool
+        where T: Float
+    {
+        is_symmetric_by(self, |a, b| (*a - *b).abs() <= tol)
+    }
+
     /// Solves an upper triangular linear system.
     ///
     /// Given a matrix `U`, which is upper triangular, and a vector `y`, this function returns `x`
@@ -713,1047 +1047,4037 @@ pub trait BaseMatrix<T>: Sized {
         forward_substitution(self, y)
     }
 
-    /// Split the matrix at the specified axis returning two `MatrixSlice`s.
+    /// Solves an upper triangular linear system with a unit diagonal.
+    ///
+    /// Given a matrix `U`, which is upper triangular with ones on the
+    /// diagonal, and a vector `y`, this function returns `x` such that
+    /// `Ux = y`. The diagonal is never read, so no division takes place
+    /// and the system always has a solution.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Axes, Matrix, BaseMatrix};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use rulinalg::vector::Vector;
     ///
-    /// let a = Matrix::new(3,3, vec![2.0; 9]);
-    /// let (b,c) = a.split_at(1, Axes::Row);
+    /// let u = Matrix::new(2, 2, vec![1.0, 2.0, 0.0, 1.0]);
+    /// let x = u.solve_unit_u_triangular(Vector::new(vec![3.0, 1.0]));
+    ///
+    /// assert_eq!(x, Vector::new(vec![1.0, 1.0]));
     /// ```
-    fn split_at(&self, mid: usize, axis: Axes) -> (MatrixSlice<T>, MatrixSlice<T>) {
-        let slice_1: MatrixSlice<T>;
-        let slice_2: MatrixSlice<T>;
+    ///
+    /// # Panics
+    ///
+    /// - Vector size and matrix column count are not equal.
+    /// - Matrix is not upper triangular.
+    fn solve_unit_u_triangular(&self, y: Vector<T>) -> Vector<T>
+        where T: Any + Float
+    {
+        assert!(self.cols() == y.size(),
+                "Vector size {0} != {1} Matrix column count.",
+                y.size(),
+                self.cols());
 
-        match axis {
-            Axes::Row => {
-                assert!(mid < self.rows());
-                unsafe {
-                    slice_1 = MatrixSlice::from_raw_parts(self.as_ptr(),
-                                                          mid,
-                                                          self.cols(),
-                                                          self.row_stride());
-                    slice_2 = MatrixSlice::from_raw_parts(
-                        self.as_ptr().offset((mid * self.row_stride()) as isize),
-                        self.rows() - mid, self.cols(), self.row_stride());
-                }
-            }
-            Axes::Col => {
-                assert!(mid < self.cols());
-                unsafe {
-                    slice_1 = MatrixSlice::from_raw_parts(self.as_ptr(),
-                                                          self.rows(),
-                                                          mid,
-                                                          self.row_stride());
-                    slice_2 = MatrixSlice::from_raw_parts(self.as_ptr().offset(mid as isize),
-                                                          self.rows(),
-                                                          self.cols() - mid,
-                                                          self.row_stride());
-                }
+        for (row_idx, row) in self.iter_rows().enumerate() {
+            if row.iter().take(row_idx).any(|data| data != &T::zero()) {
+                panic!("Matrix is not upper triangular");
             }
         }
 
-        (slice_1, slice_2)
+        unit_back_substitution(self, y)
     }
 
-    /// Produce a `MatrixSlice` from an existing matrix.
+    /// Solves a lower triangular linear system with a unit diagonal.
+    ///
+    /// Given a matrix `L`, which is lower triangular with ones on the
+    /// diagonal, and a vector `y`, this function returns `x` such that
+    /// `Lx = y`. The diagonal is never read, so no division takes place
+    /// and the system always has a solution.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrix, MatrixSlice};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use rulinalg::vector::Vector;
     ///
-    /// let a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let slice = MatrixSlice::from_matrix(&a, [1,1], 2, 2);
-    /// let new_slice = slice.sub_slice([0,0], 1, 1);
+    /// let l = Matrix::new(2, 2, vec![1.0, 0.0, 2.0, 1.0]);
+    /// let x = l.solve_unit_l_triangular(Vector::new(vec![1.0, 3.0]));
+    ///
+    /// assert_eq!(x, Vector::new(vec![1.0, 1.0]));
     /// ```
-    fn sub_slice<'a>(&self, start: [usize; 2], rows: usize, cols: usize) -> MatrixSlice<'a, T>
-        where T: 'a
-    {
-        assert!(start[0] + rows <= self.rows(),
-                "View dimensions exceed matrix dimensions.");
-        assert!(start[1] + cols <= self.cols(),
-                "View dimensions exceed matrix dimensions.");
+    ///
+    /// # Panics
+    ///
+    /// - Vector size and matrix column count are not equal.
+    /// - Matrix is not lower triangular.
+    fn solve_unit_l_triangular(&self, y: Vector<T>) -> Vector<T>
+        where T: Any + Float
+    {
+        assert!(self.cols() == y.size(),
+                "Vector size {0} != {1} Matrix column count.",
+                y.size(),
+                self.cols());
 
-        unsafe {
-            MatrixSlice::from_raw_parts(self.as_ptr().offset((start[0] * self.row_stride() + start[1]) as isize),
-                                        rows, cols, self.row_stride())
+        for (row_idx, row) in self.iter_rows().enumerate() {
+            if row.iter().skip(row_idx + 1).any(|data| data != &T::zero()) {
+                panic!("Matrix is not lower triangular.");
+            }
         }
-    }
-}
 
-/// Trait for mutable matrices.
-pub trait BaseMatrixMut<T>: BaseMatrix<T> {
-    /// Top left index of the slice.
-    fn as_mut_ptr(&mut self) -> *mut T;
+        unit_forward_substitution(self, y)
+    }
 
-    /// Returns a `MatrixSliceMut` over the whole matrix.
+    /// Applies a function to each element, producing a new matrix
+    /// of a possibly different type.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let mut a = Matrix::new(3, 3, vec![2.0; 9]);
-    /// let b = a.as_mut_slice();
+    /// let a = Matrix::new(2, 2, vec![1.0, -2.0, 3.0, -4.0]);
+    /// let positive = a.map(|x| x > 0.0);
+    ///
+    /// assert_eq!(*positive.data(), vec![true, false, true, false]);
     /// ```
-    fn as_mut_slice(&mut self) -> MatrixSliceMut<T> {
-        unsafe {
-            MatrixSliceMut::from_raw_parts(self.as_mut_ptr(),
-                                           self.rows(),
-                                           self.cols(),
-                                           self.row_stride())
-        }
-    }
-
-    /// Get a mutable reference to a point in the matrix without bounds checks.
-    unsafe fn get_unchecked_mut(&mut self, index: [usize; 2]) -> &mut T {
-        &mut *(self.as_mut_ptr().offset((index[0] * self.row_stride() + index[1]) as isize))
+    fn map<U, F>(&self, f: F) -> Matrix<U>
+        where T: Copy,
+              F: FnMut(T) -> U
+    {
+        Matrix::new(self.rows(), self.cols(), self.iter().cloned().map(f).collect::<Vec<_>>())
     }
 
-    /// Returns a mutable iterator over the matrix.
+    /// Applies a function to each element and its `[row, col]` index,
+    /// producing a new matrix.
+    ///
+    /// The function is called in row-major order.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
-    ///
-    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    ///
-    /// {
-    ///     let mut slice = a.sub_slice_mut([1,1], 2, 2);
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    ///     for d in slice.iter_mut() {
-    ///         *d = *d + 2;
-    ///     }
-    /// }
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let b = a.map_with_index(|i, j, x| if i == j { x } else { 0 });
     ///
-    /// // Only the matrix slice is updated.
-    /// assert_eq!(a.into_vec(), vec![0,1,2,3,6,7,6,9,10]);
+    /// assert_eq!(*b.data(), vec![1, 0, 0, 4]);
     /// ```
-    fn iter_mut<'a>(&mut self) -> SliceIterMut<'a, T>
-        where T: 'a
+    fn map_with_index<U, F>(&self, mut f: F) -> Matrix<U>
+        where T: Copy,
+              F: FnMut(usize, usize, T) -> U
     {
-        SliceIterMut {
-            slice_start: self.as_mut_ptr(),
-            row_pos: 0,
-            col_pos: 0,
-            slice_rows: self.rows(),
-            slice_cols: self.cols(),
-            row_stride: self.row_stride(),
-            _marker: PhantomData::<&mut T>,
+        let mut data = Vec::with_capacity(self.rows() * self.cols());
+
+        for (i, row) in self.iter_rows().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                data.push(f(i, j, x));
+            }
         }
+
+        Matrix::new(self.rows(), self.cols(), data)
     }
 
-    /// Returns a mutable reference to the row of a matrix at the given index.
-    /// `None` if the index is out of bounds.
+    /// Combines two matrices elementwise using the given function.
+    ///
+    /// The matrices may have different element types. Like the other
+    /// elementwise operations this panics if the dimensions differ.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let mut slice = a.sub_slice_mut([1,1], 2, 2);
-    /// {
-    ///     let row = slice.get_row_mut(1);
-    ///     let mut expected = vec![7usize, 8];
-    ///     assert_eq!(row, Some(&mut *expected));
-    /// }
-    /// assert!(slice.get_row_mut(5).is_none());
+    /// let a = Matrix::new(2, 2, vec![1.0, 5.0, 3.0, 2.0]);
+    /// let b = Matrix::new(2, 2, vec![4.0, 2.0, 3.0, 6.0]);
+    ///
+    /// let c = a.zip_map(&b, |x, y| if x > y { x } else { y });
+    /// assert_eq!(*c.data(), vec![4.0, 5.0, 3.0, 6.0]);
     /// ```
-    fn get_row_mut(&mut self, index: usize) -> Option<&mut [T]> {
-        if index < self.rows() {
-            unsafe { Some(self.get_row_unchecked_mut(index)) }
-        } else {
-            None
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn zip_map<U, V, M, F>(&self, m: &M, mut f: F) -> Matrix<V>
+        where T: Copy,
+              U: Copy,
+              M: BaseMatrix<U>,
+              F: FnMut(T, U) -> V
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        let mut data = Vec::with_capacity(self.rows() * self.cols());
+        for (self_r, m_r) in self.iter_rows().zip(m.iter_rows()) {
+            data.extend(self_r.iter().zip(m_r).map(|(&x, &y)| f(x, y)));
         }
+        Matrix::new(self.rows(), self.cols(), data)
     }
 
-    /// Returns a mutable reference to the row of a matrix at the given index
-    /// without doing unbounds checking
+    /// Compares two matrices elementwise, giving `true` where `self > m`.
+    ///
+    /// Comparisons involving NaN are always `false`, following IEEE 754.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let mut slice = a.sub_slice_mut([1,1], 2, 2);
-    /// let row = unsafe { slice.get_row_unchecked_mut(1) };
-    /// let mut expected = vec![7usize, 8];
-    /// assert_eq!(row, &mut *expected);
+    /// let a = Matrix::new(1, 3, vec![1.0, 5.0, 3.0]);
+    /// let b = Matrix::new(1, 3, vec![4.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(*a.gt(&b).data(), vec![false, true, false]);
     /// ```
-    unsafe fn get_row_unchecked_mut(&mut self, index: usize) -> &mut [T] {
-        let ptr = self.as_mut_ptr().offset((self.row_stride() * index) as isize);
-        ::std::slice::from_raw_parts_mut(ptr, self.cols())
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different dimensions.
+    fn gt<M: BaseMatrix<T>>(&self, m: &M) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.zip_map(m, |x, y| x > y)
     }
 
-    /// Swaps two rows in a matrix.
+    /// Compares two matrices elementwise, giving `true` where `self < m`.
     ///
-    /// # Examples
+    /// Comparisons involving NaN are always `false`.
     ///
-    /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// # Panics
     ///
-    /// let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<_>>());
-    /// a.swap_rows(1, 3);
-    /// assert_eq!(a.into_vec(), vec![0,1,6,7,4,5,2,3]);
-    /// ```
+    /// - The matrices have different dimensions.
+    fn lt<M: BaseMatrix<T>>(&self, m: &M) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.zip_map(m, |x, y| x < y)
+    }
+
+    /// Compares two matrices elementwise, giving `true` where `self >= m`.
+    ///
+    /// Comparisons involving NaN are always `false`.
     ///
     /// # Panics
     ///
-    /// Panics if `a` or `b` are out of bounds.
-    fn swap_rows(&mut self, a: usize, b: usize) {
-        assert!(a < self.rows(),
-                format!("Row index {0} larger than row count {1}", a, self.rows()));
-        assert!(b < self.rows(),
-                format!("Row index {0} larger than row count {1}", b, self.rows()));
+    /// - The matrices have different dimensions.
+    fn ge<M: BaseMatrix<T>>(&self, m: &M) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.zip_map(m, |x, y| x >= y)
+    }
 
-        unsafe {
-            let row_a = slice::from_raw_parts_mut(self.as_mut_ptr()
-                                                      .offset((self.row_stride() * a) as isize),
-                                                  self.cols());
-            let row_b = slice::from_raw_parts_mut(self.as_mut_ptr()
-                                                      .offset((self.row_stride() * b) as isize),
-                                                  self.cols());
+    /// Compares two matrices elementwise, giving `true` where `self <= m`.
+    ///
+    /// Comparisons involving NaN are always `false`.
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different dimensions.
+    fn le<M: BaseMatrix<T>>(&self, m: &M) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.zip_map(m, |x, y| x <= y)
+    }
 
-            for (x, y) in row_a.into_iter().zip(row_b.into_iter()) {
-                mem::swap(x, y);
-            }
-        }
+    /// Compares two matrices elementwise, giving `true` where the elements are equal.
+    ///
+    /// NaN is not equal to anything, including itself.
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different dimensions.
+    fn eq_elem<M: BaseMatrix<T>>(&self, m: &M) -> Matrix<bool>
+        where T: Copy + PartialEq
+    {
+        self.zip_map(m, |x, y| x == y)
     }
 
-    /// Swaps two columns in a matrix.
+    /// Compares each element with `t`, giving `true` where the element is greater.
+    ///
+    /// Comparisons involving NaN are always `false`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<_>>());
-    /// a.swap_cols(0, 1);
-    /// assert_eq!(a.into_vec(), vec![1,0,3,2,5,4,7,6]);
+    /// let a = Matrix::new(2, 2, vec![1.0, 5.0, -3.0, 2.0]);
+    ///
+    /// assert_eq!(*a.gt_scalar(1.5).data(), vec![false, true, false, true]);
     /// ```
+    fn gt_scalar(&self, t: T) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.map(|x| x > t)
+    }
+
+    /// Compares each element with `t`, giving `true` where the element is less.
     ///
-    /// # Panics
+    /// Comparisons involving NaN are always `false`.
+    fn lt_scalar(&self, t: T) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.map(|x| x < t)
+    }
+
+    /// Compares each element with `t`, giving `true` where the element is greater or equal.
     ///
-    /// Panics if `a` or `b` are out of bounds.
-    fn swap_cols(&mut self, a: usize, b: usize) {
-        assert!(a < self.cols(),
-                format!("Row index {0} larger than row count {1}", a, self.rows()));
-        assert!(b < self.cols(),
-                format!("Row index {0} larger than row count {1}", b, self.rows()));
+    /// Comparisons involving NaN are always `false`.
+    fn ge_scalar(&self, t: T) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.map(|x| x >= t)
+    }
 
-        unsafe {
-            for i in 0..self.rows() {
-                let a_ptr : *mut T = self.get_unchecked_mut([i, a]);
-                let b_ptr : *mut T = self.get_unchecked_mut([i, b]);
-                ptr::swap(a_ptr, b_ptr);
-            }
-        }
+    /// Compares each element with `t`, giving `true` where the element is less or equal.
+    ///
+    /// Comparisons involving NaN are always `false`.
+    fn le_scalar(&self, t: T) -> Matrix<bool>
+        where T: Copy + PartialOrd
+    {
+        self.map(|x| x <= t)
     }
 
-    /// Iterate over the mutable rows of the matrix.
+    /// Compares each element with `t`, giving `true` where the element equals `t`.
+    ///
+    /// NaN is not equal to anything, including itself.
+    fn eq_scalar(&self, t: T) -> Matrix<bool>
+        where T: Copy + PartialEq
+    {
+        self.map(|x| x == t)
+    }
+
+    /// Returns the elementwise maximum of two matrices.
+    ///
+    /// Follows `Float::max`, so where exactly one of the elements is NaN
+    /// the other element is taken.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
-    ///
-    /// let mut a = Matrix::new(3, 2, (0..6).collect::<Vec<usize>>());
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// for row in a.iter_rows_mut() {
-    ///     for r in row {
-    ///         *r = *r + 1;
-    ///     }
-    /// }
+    /// let a = Matrix::new(2, 2, vec![1.0, 5.0, 3.0, 2.0]);
+    /// let b = Matrix::new(2, 2, vec![4.0, 2.0, 3.0, 6.0]);
     ///
-    /// // Now contains the range 1..7
-    /// println!("{}", a);
+    /// assert_eq!(*a.elem_max(&b).data(), vec![4.0, 5.0, 3.0, 6.0]);
     /// ```
-    fn iter_rows_mut(&mut self) -> RowsMut<T> {
-        RowsMut {
-            slice_start: self.as_mut_ptr(),
-            row_pos: 0,
-            slice_rows: self.rows(),
-            slice_cols: self.cols(),
-            row_stride: self.row_stride() as isize,
-            _marker: PhantomData::<&mut T>,
-        }
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn elem_max<M: BaseMatrix<T>>(&self, m: &M) -> Matrix<T>
+        where T: Float
+    {
+        self.zip_map(m, |x, y| x.max(y))
     }
 
-    /// Sets the underlying matrix data to the target data.
+    /// Returns the elementwise minimum of two matrices.
+    ///
+    /// Follows `Float::min`, so where exactly one of the elements is NaN
+    /// the other element is taken.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
-    ///
-    /// let mut mat = Matrix::<f32>::zeros(4,4);
-    /// let one_block = Matrix::<f32>::ones(2,2);
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// // Get a mutable slice of the upper left 2x2 block.
-    /// let mat_block = mat.sub_slice_mut([0,0], 2, 2);
+    /// let a = Matrix::new(2, 2, vec![1.0, 5.0, 3.0, 2.0]);
+    /// let b = Matrix::new(2, 2, vec![4.0, 2.0, 3.0, 6.0]);
     ///
-    /// // Set the upper left 2x2 block to be ones.
-    /// mat_block.set_to(one_block);
+    /// assert_eq!(*a.elem_min(&b).data(), vec![1.0, 2.0, 3.0, 2.0]);
     /// ```
     ///
     /// # Panics
     ///
-    /// Panics if the dimensions of `self` and `target` are not the same.
-    fn set_to<M: BaseMatrix<T>>(mut self, target: M)
-        where T: Copy
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn elem_min<M: BaseMatrix<T>>(&self, m: &M) -> Matrix<T>
+        where T: Float
     {
-        assert!(self.rows() == target.rows(),
-                "Target has different row count to self.");
-        assert!(self.cols() == target.cols(),
-                "Target has different column count to self.");
-        for (s, t) in self.iter_rows_mut().zip(target.iter_rows()) {
-            // Vectorized assignment per row.
-            utils::in_place_vec_bin_op(s, t, |x, &y| *x = y);
-        }
+        self.zip_map(m, |x, y| x.min(y))
     }
 
-    /// Applies a function to each element in the matrix.
+    /// Returns the matrix with each element clamped to `[lo, hi]`.
+    ///
+    /// Each element is computed as `x.max(lo).min(hi)`, so following
+    /// `Float::max` a NaN element is replaced by `lo`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// fn add_two(a: f64) -> f64 {
-    ///     a + 2f64
-    /// }
+    /// let a = Matrix::new(2, 2, vec![-2.0, 0.5, 3.0, 1.0]);
     ///
-    /// let a = Matrix::new(2, 2, vec![0.;4]);
+    /// assert_eq!(*a.clamp(0.0, 1.0).data(), vec![0.0, 0.5, 1.0, 1.0]);
+    /// ```
     ///
-    /// let b = a.apply(&add_two);
+    /// # Panics
     ///
-    /// assert_eq!(*b.data(), vec![2.0; 4]);
-    /// ```
-    fn apply(mut self, f: &Fn(T) -> T) -> Self
-        where T: Copy
+    /// - `lo` is greater than `hi`, or either bound is NaN.
+    fn clamp(&self, lo: T, hi: T) -> Matrix<T>
+        where T: Float
     {
-        for val in self.iter_mut() {
-            *val = f(*val);
-        }
-        self
+        assert!(lo <= hi, "Clamp lower bound must not exceed the upper bound.");
+        self.map(|x| x.max(lo).min(hi))
     }
 
-    /// Split the matrix at the specified axis returning two `MatrixSliceMut`s.
+    /// Returns the matrix with the vector added to each row.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Axes, Matrix, BaseMatrixMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use rulinalg::vector::Vector;
     ///
-    /// let mut a = Matrix::new(3,3, vec![2.0; 9]);
-    /// let (b,c) = a.split_at_mut(1, Axes::Col);
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let b = a.add_row_vector(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*b.data(), vec![2.0, 4.0, 4.0, 6.0]);
     /// ```
-    fn split_at_mut(&mut self, mid: usize, axis: Axes) -> (MatrixSliceMut<T>, MatrixSliceMut<T>) {
-
-        let slice_1: MatrixSliceMut<T>;
-        let slice_2: MatrixSliceMut<T>;
-
-        match axis {
-            Axes::Row => {
-                assert!(mid < self.rows());
-                unsafe {
-                    slice_1 = MatrixSliceMut::from_raw_parts(self.as_mut_ptr(),
-                                                             mid,
-                                                             self.cols(),
-                                                             self.row_stride());
-                    slice_2 = MatrixSliceMut::from_raw_parts(
-                        self.as_mut_ptr().offset((mid * self.row_stride()) as isize),
-                        self.rows() - mid, self.cols(), self.row_stride());
-                }
-            }
-            Axes::Col => {
-                assert!(mid < self.cols());
-                unsafe {
-                    slice_1 = MatrixSliceMut::from_raw_parts(self.as_mut_ptr(),
-                                                             self.rows(),
-                                                             mid,
-                                                             self.row_stride());
-                    slice_2 = MatrixSliceMut::from_raw_parts(self.as_mut_ptr()
-                                                                 .offset(mid as isize),
-                                                             self.rows(),
-                                                             self.cols() - mid,
-                                                             self.row_stride());
-                }
-            }
-        }
-
-        (slice_1, slice_2)
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of columns.
+    fn add_row_vector(&self, v: &Vector<T>) -> Matrix<T>
+        where T: Copy + Add<T, Output = T>
+    {
+        let mut m = self.as_slice().into_matrix();
+        m.add_row_vector_mut(v);
+        m
     }
 
-    /// Produce a `MatrixSliceMut` from an existing matrix.
+    /// Returns the matrix with the vector subtracted from each row.
+    ///
+    /// Subtracting the column means centers the data.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, MatrixSliceMut, BaseMatrixMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use rulinalg::vector::Vector;
     ///
-    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let mut slice = MatrixSliceMut::from_matrix(&mut a, [1,1], 2, 2);
-    /// let new_slice = slice.sub_slice_mut([0,0], 1, 1);
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let b = a.sub_row_vector(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*b.data(), vec![0.0, 0.0, 2.0, 2.0]);
     /// ```
-    fn sub_slice_mut<'a>(&mut self,
-                         start: [usize; 2],
-                         rows: usize,
-                         cols: usize)
-                         -> MatrixSliceMut<'a, T>
-        where T: 'a
-    {
-        assert!(start[0] + rows <= self.rows(),
-                "View dimensions exceed matrix dimensions.");
-        assert!(start[1] + cols <= self.cols(),
-                "View dimensions exceed matrix dimensions.");
-
-        unsafe {
-            MatrixSliceMut::from_raw_parts(self.as_mut_ptr().offset((start[0] * self.row_stride() + start[1]) as isize),
-                                           rows, cols, self.row_stride())
-        }
-    }
-}
-
-impl<T> BaseMatrix<T> for Matrix<T> {
-    fn rows(&self) -> usize {
-        self.rows
-    }
-    fn cols(&self) -> usize {
-        self.cols
-    }
-    fn row_stride(&self) -> usize {
-        self.cols
-    }
-    fn as_ptr(&self) -> *const T {
-        self.data.as_ptr()
-    }
-
-    fn into_matrix(self) -> Matrix<T>
-        where T: Copy
-    {
-        self // for Matrix, this is a no-op
-    }
-
-    fn sum(&self) -> T
-        where T: Copy + Zero + Add<T, Output = T>
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of columns.
+    fn sub_row_vector(&self, v: &Vector<T>) -> Matrix<T>
+        where T: Copy + Sub<T, Output = T>
     {
-        utils::unrolled_sum(&self.data[..])
+        let mut m = self.as_slice().into_matrix();
+        m.sub_row_vector_mut(v);
+        m
     }
 
-    fn elemul(&self, m: &Self) -> Matrix<T>
+    /// Returns the matrix with each row multiplied elementwise by the vector.
+    ///
+    /// This scales column `j` by `v[j]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let b = a.mul_row_vector(&Vector::new(vec![2.0, 0.5]));
+    ///
+    /// assert_eq!(*b.data(), vec![2.0, 1.0, 6.0, 2.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of columns.
+    fn mul_row_vector(&self, v: &Vector<T>) -> Matrix<T>
         where T: Copy + Mul<T, Output = T>
     {
-        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
-        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
-
-        let data = utils::vec_bin_op(self.data(), m.data(), T::mul);
-        Matrix::new(self.rows(), self.cols(), data)
+        let mut m = self.as_slice().into_matrix();
+        m.mul_row_vector_mut(v);
+        m
     }
 
-    fn elediv(&self, m: &Self) -> Matrix<T>
-        where T: Copy + Div<T, Output = T>
+    /// Returns the matrix with the vector added to each column.
+    ///
+    /// Element `v[i]` is added to every entry of row `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let b = a.add_col_vector(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*b.data(), vec![2.0, 3.0, 5.0, 6.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of rows.
+    fn add_col_vector(&self, v: &Vector<T>) -> Matrix<T>
+        where T: Copy + Add<T, Output = T>
     {
-        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
-        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
-
-        let data = utils::vec_bin_op(self.data(), m.data(), T::div);
-        Matrix::new(self.rows(), self.cols(), data)
+        let mut m = self.as_slice().into_matrix();
+        m.add_col_vector_mut(v);
+        m
     }
 
-    fn vcat<S>(&self, m: &S) -> Matrix<T>
-        where T: Copy,
-              S: BaseMatrix<T>
+    /// Returns the matrix with the vector subtracted from each column.
+    ///
+    /// Element `v[i]` is subtracted from every entry of row `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let b = a.sub_col_vector(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*b.data(), vec![0.0, 1.0, 1.0, 2.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of rows.
+    fn sub_col_vector(&self, v: &Vector<T>) -> Matrix<T>
+        where T: Copy + Sub<T, Output = T>
     {
-        assert!(self.cols() == m.cols(),
-                "Matrix column counts are not equal.");
-
-        let mut new_data = self.data.clone();
-        new_data.reserve(m.rows() * m.cols());
-
-        for row in m.iter_rows() {
-            new_data.extend_from_slice(row);
-        }
-
-        Matrix {
-            cols: self.cols(),
-            rows: (self.rows() + m.rows()),
-            data: new_data,
-        }
-    }
-}
-
-impl<'a, T> BaseMatrix<T> for MatrixSlice<'a, T> {
-    fn rows(&self) -> usize {
-        self.rows
-    }
-    fn cols(&self) -> usize {
-        self.cols
+        let mut m = self.as_slice().into_matrix();
+        m.sub_col_vector_mut(v);
+        m
     }
-    fn row_stride(&self) -> usize {
-        self.row_stride
-    }
-    fn as_ptr(&self) -> *const T {
-        self.ptr
-    }
-}
-
-impl<'a, T> BaseMatrix<T> for MatrixSliceMut<'a, T> {
-    fn rows(&self) -> usize {
-        self.rows
-    }
-    fn cols(&self) -> usize {
-        self.cols
-    }
-    fn row_stride(&self) -> usize {
-        self.row_stride
-    }
-    fn as_ptr(&self) -> *const T {
-        self.ptr as *const T
-    }
-}
-
-impl<T> BaseMatrixMut<T> for Matrix<T> {
-    /// Top left index of the slice.
-    fn as_mut_ptr(&mut self) -> *mut T {
-        self.data.as_mut_ptr()
-    }
-}
-
-impl<'a, T> BaseMatrixMut<T> for MatrixSliceMut<'a, T> {
-    /// Top left index of the slice.
-    fn as_mut_ptr(&mut self) -> *mut T {
-        self.ptr
-    }
-}
 
-impl<'a, T> MatrixSlice<'a, T> {
-    /// Produce a `MatrixSlice` from a `Matrix`
+    /// Returns the `[row, col]` index of the largest element.
+    ///
+    /// Ties resolve to the first occurrence in row-major order.
+    /// NaN values are ignored unless every element is NaN.
+    /// Returns `None` for an empty matrix.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, MatrixSlice};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let slice = MatrixSlice::from_matrix(&a, [1,1], 2, 2);
+    /// let a = Matrix::new(2, 2, vec![1.0, 4.0, 4.0, 2.0]);
+    /// assert_eq!(a.argmax(), Some((0, 1)));
     /// ```
-    pub fn from_matrix(mat: &'a Matrix<T>,
-                       start: [usize; 2],
-                       rows: usize,
-                       cols: usize)
-                       -> MatrixSlice<T> {
-        assert!(start[0] + rows <= mat.rows(),
-                "View dimensions exceed matrix dimensions.");
-        assert!(start[1] + cols <= mat.cols(),
-                "View dimensions exceed matrix dimensions.");
-        unsafe {
-            MatrixSlice {
-                ptr: mat.data().get_unchecked(start[0] * mat.cols + start[1]) as *const T,
-                rows: rows,
-                cols: cols,
-                row_stride: mat.cols,
-                marker: PhantomData::<&'a T>,
-            }
-        }
+    fn argmax(&self) -> Option<(usize, usize)>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum(self, utils::argmax)
     }
 
-    /// Creates a `MatrixSlice` from raw parts.
+    /// Returns the `[row, col]` index of the smallest element.
+    ///
+    /// Ties resolve to the first occurrence in row-major order.
+    /// NaN values are ignored unless every element is NaN.
+    /// Returns `None` for an empty matrix.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::MatrixSlice;
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let mut a = vec![4.0; 16];
+    /// let a = Matrix::new(2, 2, vec![1.0, 4.0, -3.0, 2.0]);
+    /// assert_eq!(a.argmin(), Some((1, 0)));
+    /// ```
+    fn argmin(&self) -> Option<(usize, usize)>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum(self, utils::argmin)
+    }
+
+    /// The index of the largest element along the specified axis.
+    ///
+    /// - Axis Row - Row index of the maximum of each column.
+    /// - Axis Col - Column index of the maximum of each row.
+    ///
+    /// Ties and NaN values are handled as in `argmax`.
+    ///
+    /// # Examples
     ///
-    /// unsafe {
-    ///     // Create a matrix slice with 3 rows, and 3 cols
-    ///     // The row stride of 4 specifies the distance between the start of each row in the data.
-    ///     let b = MatrixSlice::from_raw_parts(a.as_ptr(), 3, 3, 4);
-    /// }
     /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix, Axes};
     ///
-    /// # Safety
+    /// let a = Matrix::new(2, 3, vec![1, 5, 3,
+    ///                                4, 2, 6]);
     ///
-    /// The pointer must be followed by a contiguous slice of data larger than `row_stride * rows`.
-    /// If not then other operations will produce undefined behaviour.
+    /// assert_eq!(a.argmax_axis(Axes::Row), vec![1, 0, 1]);
+    /// assert_eq!(a.argmax_axis(Axes::Col), vec![1, 2]);
+    /// ```
     ///
-    /// Additionally `cols` should be less than the `row_stride`. It is possible to use this
-    /// function safely whilst violating this condition. So long as
-    /// `max(cols, row_stride) * rows` is less than the data size.
-    pub unsafe fn from_raw_parts(ptr: *const T,
-                                 rows: usize,
-                                 cols: usize,
-                                 row_stride: usize)
-                                 -> MatrixSlice<'a, T> {
-        MatrixSlice {
-            ptr: ptr,
-            rows: rows,
-            cols: cols,
-            row_stride: row_stride,
-            marker: PhantomData::<&'a T>,
-        }
+    /// # Panics
+    ///
+    /// - The axis being reduced has length zero.
+    fn argmax_axis(&self, axis: Axes) -> Vec<usize>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum_axis_or_first(self, axis, |x, best| x > best)
     }
 
-    /// Produce a `MatrixSlice` from an existing `MatrixSlice`.
+    /// The index of the smallest element along the specified axis.
     ///
-    /// This function will be deprecated. Prefer using `BaseMatrix::sub_slice`.
+    /// - Axis Row - Row index of the minimum of each column.
+    /// - Axis Col - Column index of the minimum of each row.
+    ///
+    /// Ties and NaN values are handled as in `argmin`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, MatrixSlice};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix, Axes};
     ///
-    /// let a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let slice = MatrixSlice::from_matrix(&a, [1,1], 2, 2);
-    /// let new_slice = slice.reslice([0,0], 1, 1);
+    /// let a = Matrix::new(2, 3, vec![1, 5, 3,
+    ///                                4, 2, 6]);
+    ///
+    /// assert_eq!(a.argmin_axis(Axes::Row), vec![0, 1, 0]);
+    /// assert_eq!(a.argmin_axis(Axes::Col), vec![0, 1]);
     /// ```
-    pub fn reslice(self, start: [usize; 2], rows: usize, cols: usize) -> MatrixSlice<'a, T> {
-        self.sub_slice(start, rows, cols)
+    ///
+    /// # Panics
+    ///
+    /// - The axis being reduced has length zero.
+    fn argmin_axis(&self, axis: Axes) -> Vec<usize>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum_axis_or_first(self, axis, |x, best| x < best)
     }
-}
 
-impl<'a, T> MatrixSliceMut<'a, T> {
-    /// Produce a `MatrixSliceMut` from a mutable `Matrix`
+    /// Returns the row index of the largest element in each column.
+    ///
+    /// Ties resolve to the first occurrence and NaN values are skipped.
+    /// A column with no non-NaN values (including every column of a
+    /// matrix with no rows) gives `None`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, MatrixSliceMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use std::f64::NAN;
     ///
-    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let slice = MatrixSliceMut::from_matrix(&mut a, [1,1], 2, 2);
+    /// let a = Matrix::new(2, 3, vec![1.0, 5.0, NAN,
+    ///                                4.0, 2.0, NAN]);
+    ///
+    /// assert_eq!(a.col_argmax(), vec![Some(1), Some(0), None]);
     /// ```
-    pub fn from_matrix(mat: &'a mut Matrix<T>,
-                       start: [usize; 2],
-                       rows: usize,
-                       cols: usize)
-                       -> MatrixSliceMut<T> {
-        assert!(start[0] + rows <= mat.rows(),
-                "View dimensions exceed matrix dimensions.");
-        assert!(start[1] + cols <= mat.cols(),
-                "View dimensions exceed matrix dimensions.");
+    fn col_argmax(&self) -> Vec<Option<usize>>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum_axis(self, Axes::Row, |x, best| x > best)
+    }
 
-        let mat_cols = mat.cols();
+    /// Returns the row index of the smallest element in each column.
+    ///
+    /// Ties and NaN values are handled as in `col_argmax`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 3, vec![1, 5, 3,
+    ///                                4, 2, 3]);
+    ///
+    /// assert_eq!(a.col_argmin(), vec![Some(0), Some(1), Some(0)]);
+    /// ```
+    fn col_argmin(&self) -> Vec<Option<usize>>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum_axis(self, Axes::Row, |x, best| x < best)
+    }
 
-        unsafe {
-            MatrixSliceMut {
-                ptr: mat.mut_data().get_unchecked_mut(start[0] * mat_cols + start[1]) as *mut T,
-                rows: rows,
-                cols: cols,
-                row_stride: mat_cols,
-                marker: PhantomData::<&'a mut T>,
-            }
-        }
+    /// Returns the column index of the largest element in each row.
+    ///
+    /// Ties resolve to the first occurrence and NaN values are skipped.
+    /// A row with no non-NaN values (including every row of a matrix
+    /// with no columns) gives `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let logits = Matrix::new(2, 3, vec![0.1, 2.5, -1.0,
+    ///                                     3.0, 0.0, 3.0]);
+    ///
+    /// assert_eq!(logits.row_argmax(), vec![Some(1), Some(0)]);
+    /// ```
+    fn row_argmax(&self) -> Vec<Option<usize>>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum_axis(self, Axes::Col, |x, best| x > best)
     }
 
-    /// Creates a `MatrixSliceMut` from raw parts.
+    /// Returns the column index of the smallest element in each row.
+    ///
+    /// Ties and NaN values are handled as in `row_argmax`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::MatrixSliceMut;
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let mut a = vec![4.0; 16];
+    /// let a = Matrix::new(2, 3, vec![1, 5, 3,
+    ///                                4, 2, 2]);
     ///
-    /// unsafe {
-    ///     // Create a mutable matrix slice with 3 rows, and 3 cols
-    ///     // The row stride of 4 specifies the distance between the start of each row in the data.
-    ///     let b = MatrixSliceMut::from_raw_parts(a.as_mut_ptr(), 3, 3, 4);
-    /// }
+    /// assert_eq!(a.row_argmin(), vec![Some(0), Some(1)]);
     /// ```
+    fn row_argmin(&self) -> Vec<Option<usize>>
+        where T: Copy + PartialOrd
+    {
+        arg_extremum_axis(self, Axes::Col, |x, best| x < best)
+    }
+
+    /// Returns `true` if any element of the matrix is NaN.
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// The pointer must be followed by a contiguous slice of data larger than `row_stride * rows`.
-    /// If not then other operations will produce undefined behaviour.
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use std::f64;
     ///
-    /// Additionally `cols` should be less than the `row_stride`. It is possible to use this
-    /// function safely whilst violating this condition. So long as
-    /// `max(cols, row_stride) * rows` is less than the data size.
-    pub unsafe fn from_raw_parts(ptr: *mut T,
-                                 rows: usize,
-                                 cols: usize,
-                                 row_stride: usize)
-                                 -> MatrixSliceMut<'a, T> {
-        MatrixSliceMut {
-            ptr: ptr,
-            rows: rows,
-            cols: cols,
-            row_stride: row_stride,
-            marker: PhantomData::<&'a mut T>,
+    /// let a = Matrix::new(2, 2, vec![1.0, f64::NAN, 2.0, 3.0]);
+    ///
+    /// assert!(a.has_nan());
+    /// ```
+    fn has_nan(&self) -> bool
+        where T: Float
+    {
+        self.iter().any(|x| x.is_nan())
+    }
+
+    /// Returns `true` if no element of the matrix is NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use std::f64;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, f64::INFINITY, 2.0, 3.0]);
+    ///
+    /// assert!(!a.is_finite());
+    /// assert!(!a.has_nan());
+    /// ```
+    fn is_finite(&self) -> bool
+        where T: Float
+    {
+        self.iter().all(|x| x.is_finite())
+    }
+
+    /// Finds the first element which is NaN or infinite.
+    ///
+    /// Returns the `(row, col)` position of the element, searching
+    /// in row-major order, or `None` if all elements are finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    /// use std::f64;
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, f64::NEG_INFINITY, f64::NAN]);
+    ///
+    /// assert_eq!(a.find_non_finite(), Some((1, 0)));
+    /// ```
+    fn find_non_finite(&self) -> Option<(usize, usize)>
+        where T: Float
+    {
+        for (i, row) in self.iter_rows().enumerate() {
+            if let Some(j) = row.iter().position(|x| !x.is_finite()) {
+                return Some((i, j));
+            }
         }
+        None
     }
 
-    /// Produce a `MatrixSliceMut` from an existing `MatrixSliceMut`.
+    /// Computes the Frobenius norm of the matrix.
     ///
-    /// This function will be deprecated. Prefer using `BaseMatrixMut::sub_slice_mut` instead;
+    /// The sum of squares is accumulated relative to the largest
+    /// magnitude seen so far, so entries whose squares would overflow
+    /// still give a finite result.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rulinalg::matrix::{Matrix, MatrixSliceMut};
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
     ///
-    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
-    /// let slice = MatrixSliceMut::from_matrix(&mut a, [1,1], 2, 2);
-    /// let new_slice = slice.reslice([0,0], 1, 1);
+    /// let a = Matrix::new(2, 2, vec![1.0, -2.0, 2.0, 4.0]);
+    ///
+    /// assert_eq!(a.norm_frobenius(), 5.0);
     /// ```
-    pub fn reslice(mut self, start: [usize; 2], rows: usize, cols: usize) -> MatrixSliceMut<'a, T> {
-        self.sub_slice_mut(start, rows, cols)
+    fn norm_frobenius(&self) -> T
+        where T: Float
+    {
+        let mut scale = T::zero();
+        let mut ssq = T::one();
+
+        for row in self.iter_rows() {
+            for x in row.iter().map(|x| x.abs()).filter(|x| *x != T::zero()) {
+                if scale < x {
+                    let r = scale / x;
+                    ssq = T::one() + ssq * r * r;
+                    scale = x;
+                } else {
+                    let r = x / scale;
+                    ssq = ssq + r * r;
+                }
+            }
+        }
+
+        scale * ssq.sqrt()
     }
-}
 
-/// Iterator for matrix.
-///
-/// Iterates over the underlying slice data
-/// in row-major order.
-#[derive(Debug)]
-pub struct SliceIter<'a, T: 'a> {
-    slice_start: *const T,
-    row_pos: usize,
-    col_pos: usize,
-    slice_rows: usize,
-    slice_cols: usize,
-    row_stride: usize,
-    _marker: PhantomData<&'a T>,
-}
+    /// Computes the 1-norm of the matrix, the largest absolute column sum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, -2.0, 3.0, 4.0]);
+    ///
+    /// assert_eq!(a.norm_one(), 6.0);
+    /// ```
+    fn norm_one(&self) -> T
+        where T: Float
+    {
+        let mut sums = vec![T::zero(); self.cols()];
+        for row in self.iter_rows() {
+            utils::in_place_vec_bin_op(&mut sums, row, |s, &x| *s = *s + x.abs());
+        }
 
-/// Iterator for mutable matrix.
-///
-/// Iterates over the underlying slice data
-/// in row-major order.
-#[derive(Debug)]
-pub struct SliceIterMut<'a, T: 'a> {
-    slice_start: *mut T,
-    row_pos: usize,
-    col_pos: usize,
-    slice_rows: usize,
-    slice_cols: usize,
-    row_stride: usize,
-    _marker: PhantomData<&'a mut T>,
-}
+        sums.into_iter().fold(T::zero(), |m, s| m.max(s))
+    }
 
-macro_rules! impl_slice_iter (
-    ($slice_iter:ident, $data_type:ty) => (
-/// Iterates over the matrix slice data in row-major order.
-impl<'a, T> Iterator for $slice_iter<'a, T> {
-    type Item = $data_type;
+    /// Computes the infinity-norm of the matrix, the largest absolute row sum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![1.0, -2.0, 3.0, 4.0]);
+    ///
+    /// assert_eq!(a.norm_inf(), 7.0);
+    /// ```
+    fn norm_inf(&self) -> T
+        where T: Float
+    {
+        self.iter_rows()
+            .map(|row| row.iter().fold(T::zero(), |s, x| s + x.abs()))
+            .fold(T::zero(), |m, s| m.max(s))
+    }
 
-    fn next(&mut self) -> Option<$data_type> {
-// Set the position of the next element
-        if self.row_pos < self.slice_rows {
-            unsafe {
-                let iter_ptr = self.slice_start.offset((
-                                self.row_pos * self.row_stride + self.col_pos)
-                                as isize);
+    /// Estimates the 2-norm of the matrix, its largest singular value.
+    ///
+    /// Runs up to `max_iter` power iterations on `AᵀA`, starting from
+    /// the row of largest norm. The estimate never exceeds the true
+    /// 2-norm (up to rounding) and usually converges within a few
+    /// iterations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(2, 2, vec![3.0f64, 0.0, 4.0, 5.0]);
+    /// let est = a.norm_two_estimate(20);
+    ///
+    /// // The singular values of `a` are 3√5 and √5.
+    /// assert!((est - 3.0 * 5.0f64.sqrt()).abs() < 1e-8);
+    /// ```
+    fn norm_two_estimate(&self, max_iter: usize) -> T
+        where T: Float
+    {
+        let start = self.iter_rows()
+            .max_by(|a, b| {
+                let na = utils::dot(a, a);
+                let nb = utils::dot(b, b);
+                na.partial_cmp(&nb).unwrap_or(Ordering::Equal)
+            });
+
+        let mut x = match start {
+            Some(row) => row.to_vec(),
+            None => return T::zero(),
+        };
+
+        let mut estimate = T::zero();
+        for _ in 0..max_iter.max(1) {
+            let x_norm = utils::dot(&x, &x).sqrt();
+            if x_norm == T::zero() {
+                break;
+            }
 
-// If end of row, set to start of next row
-                if self.col_pos == self.slice_cols - 1 {
-                    self.row_pos += 1usize;
-                    self.col_pos = 0usize;
-                } else {
-                    self.col_pos += 1usize;
+            // y = Ax / ||x||, with ||y|| a lower bound for the 2-norm.
+            let y = self.iter_rows()
+                .map(|row| utils::dot(row, &x) / x_norm)
+                .collect::<Vec<_>>();
+            estimate = estimate.max(utils::dot(&y, &y).sqrt());
+
+            // x = Aᵀy
+            x = vec![T::zero(); self.cols()];
+            for (row, &y_i) in self.iter_rows().zip(&y) {
+                utils::in_place_vec_bin_op(&mut x, row, |s, &a| *s = *s + a * y_i);
+            }
+        }
+
+        estimate
+    }
+
+    /// Split the matrix at the specified axis returning two `MatrixSlice`s.
+    ///
+    /// The first slice contains the rows (or columns) `[0, mid)` and
+    /// the second the rest. Either may be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Axes, Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3,3, vec![2.0; 9]);
+    /// let (b,c) = a.split_at(1, Axes::Row);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `mid` is greater than the dimension along `axis`.
+    fn split_at(&self, mid: usize, axis: Axes) -> (MatrixSlice<T>, MatrixSlice<T>) {
+        let slice_1: MatrixSlice<T>;
+        let slice_2: MatrixSlice<T>;
+
+        match axis {
+            Axes::Row => {
+                assert!(mid <= self.rows(), "Split index is greater than row dimension.");
+                unsafe {
+                    slice_1 = MatrixSlice::from_raw_parts(self.as_ptr(),
+                                                          mid,
+                                                          self.cols(),
+                                                          self.row_stride());
+                    slice_2 = MatrixSlice::from_raw_parts(
+                        self.as_ptr().wrapping_offset((mid * self.row_stride()) as isize),
+                        self.rows() - mid, self.cols(), self.row_stride());
+                }
+            }
+            Axes::Col => {
+                assert!(mid <= self.cols(), "Split index is greater than column dimension.");
+                unsafe {
+                    slice_1 = MatrixSlice::from_raw_parts(self.as_ptr(),
+                                                          self.rows(),
+                                                          mid,
+                                                          self.row_stride());
+                    slice_2 = MatrixSlice::from_raw_parts(self.as_ptr().wrapping_offset(mid as isize),
+                                                          self.rows(),
+                                                          self.cols() - mid,
+                                                          self.row_stride());
                 }
+            }
+        }
+
+        (slice_1, slice_2)
+    }
+
+    /// Produce a `MatrixSlice` from an existing matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix, MatrixSlice};
+    ///
+    /// let a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let slice = MatrixSlice::from_matrix(&a, [1,1], 2, 2);
+    /// let new_slice = slice.sub_slice([0,0], 1, 1);
+    /// ```
+    fn sub_slice<'a>(&self, start: [usize; 2], rows: usize, cols: usize) -> MatrixSlice<'a, T>
+        where T: 'a
+    {
+        assert!(start[0] + rows <= self.rows(),
+                "View dimensions exceed matrix dimensions.");
+        assert!(start[1] + cols <= self.cols(),
+                "View dimensions exceed matrix dimensions.");
+
+        unsafe {
+            MatrixSlice::from_raw_parts(self.as_ptr().offset((start[0] * self.row_stride() + start[1]) as isize),
+                                        rows, cols, self.row_stride())
+        }
+    }
+
+    /// Copies the block given by `rows` and `cols` into a new `Matrix`.
+    ///
+    /// Unlike `sub_slice`, the result owns its data and can be
+    /// modified without affecting `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix};
+    ///
+    /// let a = Matrix::new(3, 3, (0..9).collect::<Vec<usize>>());
+    /// let b = a.submatrix(1..3, 0..2);
+    ///
+    /// assert_eq!(b, Matrix::new(2, 2, vec![3, 4, 6, 7]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - A range has its start greater than its end.
+    /// - A range extends beyond the matrix dimensions.
+    fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Matrix<T>
+        where T: Copy
+    {
+        assert!(rows.start <= rows.end && rows.end <= self.rows(),
+                "Row range {:?} is out of bounds for a matrix with {} rows.",
+                rows,
+                self.rows());
+        assert!(cols.start <= cols.end && cols.end <= self.cols(),
+                "Column range {:?} is out of bounds for a matrix with {} columns.",
+                cols,
+                self.cols());
+
+        let n_rows = rows.end - rows.start;
+        let n_cols = cols.end - cols.start;
+        let mut data = Vec::with_capacity(n_rows * n_cols);
+
+        for i in rows {
+            let row = unsafe { self.get_row_unchecked(i) };
+            data.extend_from_slice(&row[cols.start..cols.end]);
+        }
+
+        Matrix::new(n_rows, n_cols, data)
+    }
+}
+
+/// Trait for mutable matrices.
+pub trait BaseMatrixMut<T>: BaseMatrix<T> {
+    /// Top left index of the slice.
+    fn as_mut_ptr(&mut self) -> *mut T;
+
+    /// Returns a `MatrixSliceMut` over the whole matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3, 3, vec![2.0; 9]);
+    /// let b = a.as_mut_slice();
+    /// ```
+    fn as_mut_slice(&mut self) -> MatrixSliceMut<T> {
+        unsafe {
+            MatrixSliceMut::from_raw_parts(self.as_mut_ptr(),
+                                           self.rows(),
+                                           self.cols(),
+                                           self.row_stride())
+        }
+    }
+
+    /// Get a mutable reference to a point in the matrix without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The index must satisfy `index[0] < rows` and `index[1] < cols`.
+    /// Use `get_mut` for a bounds checked version.
+    unsafe fn get_unchecked_mut(&mut self, index: [usize; 2]) -> &mut T {
+        &mut *(self.as_mut_ptr().offset((index[0] * self.row_stride() + index[1]) as isize))
+    }
+
+    /// Get a mutable reference to a point in the matrix.
+    /// `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// if let Some(x) = a.get_mut([0, 1]) {
+    ///     *x = 5;
+    /// }
+    /// assert_eq!(*a.data(), vec![1, 5, 3, 4]);
+    /// assert!(a.get_mut([0, 2]).is_none());
+    /// ```
+    fn get_mut(&mut self, index: [usize; 2]) -> Option<&mut T> {
+        if index[0] < self.rows() && index[1] < self.cols() {
+            unsafe { Some(self.get_unchecked_mut(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable iterator over the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    ///
+    /// {
+    ///     let mut slice = a.sub_slice_mut([1,1], 2, 2);
+    ///
+    ///     for d in slice.iter_mut() {
+    ///         *d = *d + 2;
+    ///     }
+    /// }
+    ///
+    /// // Only the matrix slice is updated.
+    /// assert_eq!(a.into_vec(), vec![0,1,2,3,6,7,6,9,10]);
+    /// ```
+    fn iter_mut<'a>(&mut self) -> SliceIterMut<'a, T>
+        where T: 'a
+    {
+        SliceIterMut {
+            slice_start: self.as_mut_ptr(),
+            row_pos: 0,
+            col_pos: 0,
+            slice_rows: self.rows(),
+            slice_cols: self.cols(),
+            row_stride: self.row_stride(),
+            _marker: PhantomData::<&mut T>,
+        }
+    }
+
+    /// Returns a mutable reference to the row of a matrix at the given index.
+    /// `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let mut slice = a.sub_slice_mut([1,1], 2, 2);
+    /// {
+    ///     let row = slice.get_row_mut(1);
+    ///     let mut expected = vec![7usize, 8];
+    ///     assert_eq!(row, Some(&mut *expected));
+    /// }
+    /// assert!(slice.get_row_mut(5).is_none());
+    /// ```
+    fn get_row_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        if index < self.rows() {
+            unsafe { Some(self.get_row_unchecked_mut(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the row of a matrix at the given index
+    /// without doing unbounds checking
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let mut slice = a.sub_slice_mut([1,1], 2, 2);
+    /// let row = unsafe { slice.get_row_unchecked_mut(1) };
+    /// let mut expected = vec![7usize, 8];
+    /// assert_eq!(row, &mut *expected);
+    /// ```
+    unsafe fn get_row_unchecked_mut(&mut self, index: usize) -> &mut [T] {
+        let ptr = self.as_mut_ptr().offset((self.row_stride() * index) as isize);
+        ::std::slice::from_raw_parts_mut(ptr, self.cols())
+    }
+
+    /// Swaps two rows in a matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<_>>());
+    /// a.swap_rows(1, 3);
+    /// assert_eq!(a.into_vec(), vec![0,1,6,7,4,5,2,3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows(),
+                format!("Row index {0} larger than row count {1}", a, self.rows()));
+        assert!(b < self.rows(),
+                format!("Row index {0} larger than row count {1}", b, self.rows()));
+
+        unsafe {
+            let row_a = slice::from_raw_parts_mut(self.as_mut_ptr()
+                                                      .offset((self.row_stride() * a) as isize),
+                                                  self.cols());
+            let row_b = slice::from_raw_parts_mut(self.as_mut_ptr()
+                                                      .offset((self.row_stride() * b) as isize),
+                                                  self.cols());
+
+            for (x, y) in row_a.into_iter().zip(row_b.into_iter()) {
+                mem::swap(x, y);
+            }
+        }
+    }
+
+    /// Swaps two columns in a matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<_>>());
+    /// a.swap_cols(0, 1);
+    /// assert_eq!(a.into_vec(), vec![1,0,3,2,5,4,7,6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    fn swap_cols(&mut self, a: usize, b: usize) {
+        assert!(a < self.cols(),
+                format!("Row index {0} larger than row count {1}", a, self.rows()));
+        assert!(b < self.cols(),
+                format!("Row index {0} larger than row count {1}", b, self.rows()));
+
+        unsafe {
+            for i in 0..self.rows() {
+                let a_ptr : *mut T = self.get_unchecked_mut([i, a]);
+                let b_ptr : *mut T = self.get_unchecked_mut([i, b]);
+                ptr::swap(a_ptr, b_ptr);
+            }
+        }
+    }
+
+    /// Iterate over the mutable rows of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3, 2, (0..6).collect::<Vec<usize>>());
+    ///
+    /// for row in a.iter_rows_mut() {
+    ///     for r in row {
+    ///         *r = *r + 1;
+    ///     }
+    /// }
+    ///
+    /// // Now contains the range 1..7
+    /// println!("{}", a);
+    /// ```
+    fn iter_rows_mut(&mut self) -> RowsMut<T> {
+        RowsMut {
+            slice_start: self.as_mut_ptr(),
+            row_pos: 0,
+            slice_rows: self.rows(),
+            slice_cols: self.cols(),
+            row_stride: self.row_stride() as isize,
+            _marker: PhantomData::<&mut T>,
+        }
+    }
+
+    /// Iterate over the mutable columns of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 3, (0..6).collect::<Vec<usize>>());
+    ///
+    /// for (i, mut col) in a.iter_cols_mut().enumerate() {
+    ///     for x in col.iter_mut() {
+    ///         *x = i;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(a.into_vec(), vec![0, 1, 2, 0, 1, 2]);
+    /// ```
+    ///
+    /// The matrix cannot be used while a column is alive:
+    ///
+    /// ```compile_fail
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![0; 4]);
+    /// let mut col = a.iter_cols_mut().next().unwrap();
+    ///
+    /// a[[0, 0]] = 1;
+    /// col[0] = 2;
+    /// ```
+    ///
+    /// And the columns cannot outlive the matrix:
+    ///
+    /// ```compile_fail
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let col = {
+    ///     let mut a = Matrix::new(2, 2, vec![0; 4]);
+    ///     a.iter_cols_mut().next().unwrap()
+    /// };
+    /// ```
+    fn iter_cols_mut(&mut self) -> ColsMut<'_, T> {
+        ColsMut {
+            slice_start: self.as_mut_ptr(),
+            col_pos: 0,
+            slice_rows: self.rows(),
+            slice_cols: self.cols(),
+            row_stride: self.row_stride(),
+            _marker: PhantomData::<&mut T>,
+        }
+    }
+
+    /// Iterate mutably over the `k`-th diagonal of the matrix.
+    ///
+    /// See `diag_iter` for the meaning of `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 3, vec![0; 6]);
+    ///
+    /// for x in a.diag_iter_mut(1) {
+    ///     *x = 1;
+    /// }
+    ///
+    /// assert_eq!(a.into_vec(), vec![0, 1, 0, 0, 0, 1]);
+    /// ```
+    fn diag_iter_mut(&mut self, k: isize) -> DiagonalMut<'_, T> {
+        let (offset, len) = diag_offset_len(self.rows(), self.cols(), self.row_stride(), k);
+
+        DiagonalMut {
+            ptr: unsafe { self.as_mut_ptr().offset(offset as isize) },
+            diag_pos: 0,
+            diag_len: len,
+            stride: self.row_stride() + 1,
+            _marker: PhantomData::<&mut T>,
+        }
+    }
+
+    /// Sets the `k`-th diagonal of the matrix to the given values.
+    ///
+    /// See `diag_iter` for the meaning of `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3, 3, vec![0; 9]);
+    /// a.set_diag(-1, &[1, 2]);
+    ///
+    /// assert_eq!(a.into_vec(), vec![0, 0, 0, 1, 0, 0, 0, 2, 0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The number of values does not match the diagonal length.
+    fn set_diag(&mut self, k: isize, values: &[T])
+        where T: Copy
+    {
+        let diag = self.diag_iter_mut(k);
+        assert!(diag.len() == values.len(),
+                "Number of values does not match the diagonal length.");
+
+        for (x, v) in diag.zip(values.iter()) {
+            *x = *v;
+        }
+    }
+
+    /// Zeroes all elements below the `k`-th diagonal in place.
+    ///
+    /// See `diag_iter` for the meaning of `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// a.triu_in_place(-1);
+    /// assert_eq!(a.data(), &vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// a.triu_in_place(0);
+    /// assert_eq!(a.into_vec(), vec![1, 2, 3, 0, 5, 6]);
+    /// ```
+    fn triu_in_place(&mut self, k: isize)
+        where T: Zero
+    {
+        let cols = self.cols() as isize;
+        for (i, row) in self.iter_rows_mut().enumerate() {
+            let end = min(i as isize + k, cols);
+            if end > 0 {
+                for x in &mut row[..end as usize] {
+                    *x = T::zero();
+                }
+            }
+        }
+    }
+
+    /// Zeroes all elements above the `k`-th diagonal in place.
+    ///
+    /// See `diag_iter` for the meaning of `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// a.tril_in_place(0);
+    ///
+    /// assert_eq!(a.into_vec(), vec![1, 0, 0, 4, 5, 0]);
+    /// ```
+    fn tril_in_place(&mut self, k: isize)
+        where T: Zero
+    {
+        let cols = self.cols() as isize;
+        for (i, row) in self.iter_rows_mut().enumerate() {
+            let start = i as isize + k + 1;
+            if start < cols {
+                let start = if start < 0 { 0 } else { start as usize };
+                for x in &mut row[start..] {
+                    *x = T::zero();
+                }
+            }
+        }
+    }
+
+    /// Overwrites the matrix with its symmetric part, `(A + Aᵀ) / 2`.
+    ///
+    /// Works in place without allocating. Pairs which are already equal
+    /// are left unchanged, and the mean of the others is computed as
+    /// `lo + (hi - lo) / 2`. This only overflows if the two entries differ
+    /// by more than the largest value of `T`. For integers the mean is
+    /// rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 4.0, 1.0]);
+    /// a.symmetrize();
+    ///
+    /// assert_eq!(a.into_vec(), vec![1.0, 3.0, 3.0, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    fn symmetrize(&mut self)
+        where T: Copy + PartialOrd + One + Add<T, Output = T> + Sub<T, Output = T> +
+                 Div<T, Output = T>
+    {
+        assert!(self.rows() == self.cols(), "Matrix must be square to symmetrize.");
+
+        let two = T::one() + T::one();
+        for i in 0..self.rows() {
+            for j in 0..i {
+                unsafe {
+                    let a = *self.get_unchecked([i, j]);
+                    let b = *self.get_unchecked([j, i]);
+                    if a == b {
+                        continue;
+                    }
+
+                    let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+                    let v = lo + (hi - lo) / two;
+                    *self.get_unchecked_mut([i, j]) = v;
+                    *self.get_unchecked_mut([j, i]) = v;
+                }
+            }
+        }
+    }
+
+    /// Sets the underlying matrix data to the target data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut mat = Matrix::<f32>::zeros(4,4);
+    /// let one_block = Matrix::<f32>::ones(2,2);
+    ///
+    /// // Get a mutable slice of the upper left 2x2 block.
+    /// let mat_block = mat.sub_slice_mut([0,0], 2, 2);
+    ///
+    /// // Set the upper left 2x2 block to be ones.
+    /// mat_block.set_to(one_block);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimensions of `self` and `target` are not the same.
+    fn set_to<M: BaseMatrix<T>>(mut self, target: M)
+        where T: Copy
+    {
+        assert!(self.rows() == target.rows(),
+                "Target has different row count to self.");
+        assert!(self.cols() == target.cols(),
+                "Target has different column count to self.");
+        for (s, t) in self.iter_rows_mut().zip(target.iter_rows()) {
+            // Vectorized assignment per row.
+            utils::in_place_vec_bin_op(s, t, |x, &y| *x = y);
+        }
+    }
+
+    /// Overwrites the block starting at `[top, left]` with the contents of `block`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut mat = Matrix::zeros(3, 3);
+    /// mat.set_submatrix(1, 1, &Matrix::new(2, 2, vec![1, 2, 3, 4]));
+    ///
+    /// assert_eq!(mat, Matrix::new(3, 3, vec![0, 0, 0,
+    ///                                        0, 1, 2,
+    ///                                        0, 3, 4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The block does not fit inside the matrix at `[top, left]`.
+    fn set_submatrix<M: BaseMatrix<T>>(&mut self, top: usize, left: usize, block: &M)
+        where T: Copy
+    {
+        assert!(top <= self.rows() && block.rows() <= self.rows() - top,
+                "Block with {} rows does not fit at row {} of a matrix with {} rows.",
+                block.rows(),
+                top,
+                self.rows());
+        assert!(left <= self.cols() && block.cols() <= self.cols() - left,
+                "Block with {} columns does not fit at column {} of a matrix with {} columns.",
+                block.cols(),
+                left,
+                self.cols());
+
+        let right = left + block.cols();
+        for (i, row) in block.iter_rows().enumerate() {
+            let dest = unsafe { self.get_row_unchecked_mut(top + i) };
+            dest[left..right].copy_from_slice(row);
+        }
+    }
+
+    /// Overwrites the rows at the given indices with the rows of `rows`.
+    ///
+    /// Row `k` of `rows` is written to row `indices[k]` of `self`. If an
+    /// index is repeated the last matching row of `rows` is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut mat = Matrix::zeros(3, 2);
+    /// mat.set_rows(&[2, 0], &Matrix::new(2, 2, vec![1, 2, 3, 4])).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::new(3, 2, vec![3, 4,
+    ///                                        0, 0,
+    ///                                        1, 2]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `rows` does not have one row per index.
+    /// - `rows` has a different column count to `self`.
+    /// - An index is not less than the number of rows.
+    fn set_rows<M: BaseMatrix<T>>(&mut self, indices: &[usize], rows: &M) -> Result<(), Error>
+        where T: Copy
+    {
+        if rows.rows() != indices.len() || rows.cols() != self.cols() {
+            return Err(Error::new(ErrorKind::DimensionMismatch {
+                                      expected: (indices.len(), self.cols()),
+                                      actual: (rows.rows(), rows.cols()),
+                                  },
+                                  format!("Expected a {}x{} matrix of rows, got {}x{}.",
+                                          indices.len(),
+                                          self.cols(),
+                                          rows.rows(),
+                                          rows.cols())));
+        }
+        if let Some(&idx) = indices.iter().find(|&&idx| idx >= self.rows()) {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  format!("Row index {} is out of bounds for a matrix with {} \
+                                           rows.",
+                                          idx,
+                                          self.rows())));
+        }
+
+        for (&idx, row) in indices.iter().zip(rows.iter_rows()) {
+            let dest = unsafe { self.get_row_unchecked_mut(idx) };
+            dest.copy_from_slice(row);
+        }
+        Ok(())
+    }
+
+    /// Overwrites the columns at the given indices with the columns of `cols`.
+    ///
+    /// Column `k` of `cols` is written to column `indices[k]` of `self`. If an
+    /// index is repeated the last matching column of `cols` is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut mat = Matrix::zeros(2, 3);
+    /// mat.set_cols(&[1], &Matrix::new(2, 1, vec![5, 6])).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::new(2, 3, vec![0, 5, 0,
+    ///                                        0, 6, 0]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `cols` does not have one column per index.
+    /// - `cols` has a different row count to `self`.
+    /// - An index is not less than the number of columns.
+    fn set_cols<M: BaseMatrix<T>>(&mut self, indices: &[usize], cols: &M) -> Result<(), Error>
+        where T: Copy
+    {
+        if cols.cols() != indices.len() || cols.rows() != self.rows() {
+            return Err(Error::new(ErrorKind::DimensionMismatch {
+                                      expected: (self.rows(), indices.len()),
+                                      actual: (cols.rows(), cols.cols()),
+                                  },
+                                  format!("Expected a {}x{} matrix of columns, got {}x{}.",
+                                          self.rows(),
+                                          indices.len(),
+                                          cols.rows(),
+                                          cols.cols())));
+        }
+        if let Some(&idx) = indices.iter().find(|&&idx| idx >= self.cols()) {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  format!("Column index {} is out of bounds for a matrix with \
+                                           {} columns.",
+                                          idx,
+                                          self.cols())));
+        }
+
+        for (i, src) in cols.iter_rows().enumerate() {
+            let dest = unsafe { self.get_row_unchecked_mut(i) };
+            for (&idx, &x) in indices.iter().zip(src.iter()) {
+                dest[idx] = x;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a function to each element in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// fn add_two(a: f64) -> f64 {
+    ///     a + 2f64
+    /// }
+    ///
+    /// let a = Matrix::new(2, 2, vec![0.;4]);
+    ///
+    /// let b = a.apply(&add_two);
+    ///
+    /// assert_eq!(*b.data(), vec![2.0; 4]);
+    /// ```
+    fn apply(mut self, f: &Fn(T) -> T) -> Self
+        where T: Copy
+    {
+        for val in self.iter_mut() {
+            *val = f(*val);
+        }
+        self
+    }
+
+    /// Replaces each element with the elementwise maximum of `self` and `m`.
+    ///
+    /// NaN handling follows `Float::max`, as in `elem_max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(1, 3, vec![1.0, 5.0, 3.0]);
+    /// a.elem_max_mut(&Matrix::new(1, 3, vec![4.0, 2.0, 3.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![4.0, 5.0, 3.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn elem_max_mut<M: BaseMatrix<T>>(&mut self, m: &M)
+        where T: Float
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        for (s, r) in self.iter_rows_mut().zip(m.iter_rows()) {
+            utils::in_place_vec_bin_op(s, r, |x, &y| *x = x.max(y));
+        }
+    }
+
+    /// Replaces each element with the elementwise minimum of `self` and `m`.
+    ///
+    /// NaN handling follows `Float::min`, as in `elem_min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(1, 3, vec![1.0, 5.0, 3.0]);
+    /// a.elem_min_mut(&Matrix::new(1, 3, vec![4.0, 2.0, 3.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn elem_min_mut<M: BaseMatrix<T>>(&mut self, m: &M)
+        where T: Float
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        for (s, r) in self.iter_rows_mut().zip(m.iter_rows()) {
+            utils::in_place_vec_bin_op(s, r, |x, &y| *x = x.min(y));
+        }
+    }
+
+    /// Replaces each element with the elementwise product of `self` and `m`.
+    ///
+    /// This is the in-place form of `elemul`. The `*=` operator between
+    /// two matrices is not provided, since `*` is the matrix product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// a.elemul_assign(&Matrix::new(2, 2, vec![2.0, 0.5, -1.0, 4.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![2.0, 1.0, -3.0, 16.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn elemul_assign<M: BaseMatrix<T>>(&mut self, m: &M)
+        where T: Copy + Mul<T, Output = T>
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        for (s, r) in self.iter_rows_mut().zip(m.iter_rows()) {
+            utils::in_place_vec_bin_op(s, r, |x, &y| *x = *x * y);
+        }
+    }
+
+    /// Replaces each element with the elementwise division of `self` by `m`.
+    ///
+    /// This is the in-place form of `elediv`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![2.0, 1.0, -3.0, 16.0]);
+    /// a.elediv_assign(&Matrix::new(2, 2, vec![2.0, 0.5, -1.0, 4.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn elediv_assign<M: BaseMatrix<T>>(&mut self, m: &M)
+        where T: Copy + Div<T, Output = T>
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        for (s, r) in self.iter_rows_mut().zip(m.iter_rows()) {
+            utils::in_place_vec_bin_op(s, r, |x, &y| *x = *x / y);
+        }
+    }
+
+    /// Clamps each element to `[lo, hi]` in place.
+    ///
+    /// NaN elements are replaced by `lo`, as in `clamp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![-2.0, 0.5, 3.0, 1.0]);
+    /// a.clamp_mut(0.0, 1.0);
+    ///
+    /// assert_eq!(*a.data(), vec![0.0, 0.5, 1.0, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `lo` is greater than `hi`, or either bound is NaN.
+    fn clamp_mut(&mut self, lo: T, hi: T)
+        where T: Float
+    {
+        assert!(lo <= hi, "Clamp lower bound must not exceed the upper bound.");
+        for val in self.iter_mut() {
+            *val = val.max(lo).min(hi);
+        }
+    }
+
+    /// Applies a function to each element and its `[row, col]` index in place.
+    ///
+    /// The function is called in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 3, vec![0; 6]);
+    /// a.apply_with_index(|i, j, _| 10 * i + j);
+    ///
+    /// assert_eq!(*a.data(), vec![0, 1, 2, 10, 11, 12]);
+    /// ```
+    fn apply_with_index<F>(&mut self, mut f: F)
+        where T: Copy,
+              F: FnMut(usize, usize, T) -> T
+    {
+        for (i, row) in self.iter_rows_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = f(i, j, *x);
+            }
+        }
+    }
+
+    /// Combines another matrix into this one elementwise, in place.
+    ///
+    /// Each element is replaced by `f(self[[i, j]], m[[i, j]])`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let mask = Matrix::new(2, 2, vec![true, false, false, true]);
+    ///
+    /// a.zip_apply(&mask, |x, keep| if keep { x } else { 0.0 });
+    /// assert_eq!(*a.data(), vec![1.0, 0.0, 0.0, 4.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrices have different row counts.
+    /// - The matrices have different column counts.
+    fn zip_apply<U, M, F>(&mut self, m: &M, mut f: F)
+        where T: Copy,
+              U: Copy,
+              M: BaseMatrix<U>,
+              F: FnMut(T, U) -> T
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        for (self_r, m_r) in self.iter_rows_mut().zip(m.iter_rows()) {
+            for (x, &y) in self_r.iter_mut().zip(m_r) {
+                *x = f(*x, y);
+            }
+        }
+    }
+
+    /// Adds the vector to each row of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// a.add_row_vector_mut(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![2.0, 4.0, 4.0, 6.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of columns.
+    fn add_row_vector_mut(&mut self, v: &Vector<T>)
+        where T: Copy + Add<T, Output = T>
+    {
+        broadcast_row(self, v, |x, y| x + y);
+    }
+
+    /// Subtracts the vector from each row of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// a.sub_row_vector_mut(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![0.0, 0.0, 2.0, 2.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of columns.
+    fn sub_row_vector_mut(&mut self, v: &Vector<T>)
+        where T: Copy + Sub<T, Output = T>
+    {
+        broadcast_row(self, v, |x, y| x - y);
+    }
+
+    /// Multiplies each row of the matrix elementwise by the vector.
+    ///
+    /// This scales column `j` by `v[j]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// a.mul_row_vector_mut(&Vector::new(vec![2.0, 0.5]));
+    ///
+    /// assert_eq!(*a.data(), vec![2.0, 1.0, 6.0, 2.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of columns.
+    fn mul_row_vector_mut(&mut self, v: &Vector<T>)
+        where T: Copy + Mul<T, Output = T>
+    {
+        broadcast_row(self, v, |x, y| x * y);
+    }
+
+    /// Adds the vector to each column of the matrix.
+    ///
+    /// Element `v[i]` is added to every entry of row `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// a.add_col_vector_mut(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![2.0, 3.0, 5.0, 6.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of rows.
+    fn add_col_vector_mut(&mut self, v: &Vector<T>)
+        where T: Copy + Add<T, Output = T>
+    {
+        broadcast_col(self, v, |x, y| x + y);
+    }
+
+    /// Subtracts the vector from each column of the matrix.
+    ///
+    /// Element `v[i]` is subtracted from every entry of row `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, BaseMatrixMut};
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// a.sub_col_vector_mut(&Vector::new(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(*a.data(), vec![0.0, 1.0, 1.0, 2.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector size does not match the number of rows.
+    fn sub_col_vector_mut(&mut self, v: &Vector<T>)
+        where T: Copy + Sub<T, Output = T>
+    {
+        broadcast_col(self, v, |x, y| x - y);
+    }
+
+    /// Split the matrix at the specified axis returning two `MatrixSliceMut`s.
+    ///
+    /// The slices are disjoint, so both can be written to at once.
+    /// The first slice contains the rows (or columns) `[0, mid)` and
+    /// the second the rest. Either may be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Axes, Matrix, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3,3, vec![2.0; 9]);
+    /// let (b,c) = a.split_at_mut(1, Axes::Col);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `mid` is greater than the dimension along `axis`.
+    fn split_at_mut(&mut self, mid: usize, axis: Axes) -> (MatrixSliceMut<T>, MatrixSliceMut<T>) {
+
+        let slice_1: MatrixSliceMut<T>;
+        let slice_2: MatrixSliceMut<T>;
+
+        match axis {
+            Axes::Row => {
+                assert!(mid <= self.rows(), "Split index is greater than row dimension.");
+                unsafe {
+                    slice_1 = MatrixSliceMut::from_raw_parts(self.as_mut_ptr(),
+                                                             mid,
+                                                             self.cols(),
+                                                             self.row_stride());
+                    slice_2 = MatrixSliceMut::from_raw_parts(
+                        self.as_mut_ptr().wrapping_offset((mid * self.row_stride()) as isize),
+                        self.rows() - mid, self.cols(), self.row_stride());
+                }
+            }
+            Axes::Col => {
+                assert!(mid <= self.cols(), "Split index is greater than column dimension.");
+                unsafe {
+                    slice_1 = MatrixSliceMut::from_raw_parts(self.as_mut_ptr(),
+                                                             self.rows(),
+                                                             mid,
+                                                             self.row_stride());
+                    slice_2 = MatrixSliceMut::from_raw_parts(self.as_mut_ptr()
+                                                                 .wrapping_offset(mid as isize),
+                                                             self.rows(),
+                                                             self.cols() - mid,
+                                                             self.row_stride());
+                }
+            }
+        }
+
+        (slice_1, slice_2)
+    }
+
+    /// Produce a `MatrixSliceMut` from an existing matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, MatrixSliceMut, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let mut slice = MatrixSliceMut::from_matrix(&mut a, [1,1], 2, 2);
+    /// let new_slice = slice.sub_slice_mut([0,0], 1, 1);
+    /// ```
+    fn sub_slice_mut<'a>(&mut self,
+                         start: [usize; 2],
+                         rows: usize,
+                         cols: usize)
+                         -> MatrixSliceMut<'a, T>
+        where T: 'a
+    {
+        assert!(start[0] + rows <= self.rows(),
+                "View dimensions exceed matrix dimensions.");
+        assert!(start[1] + cols <= self.cols(),
+                "View dimensions exceed matrix dimensions.");
+
+        unsafe {
+            MatrixSliceMut::from_raw_parts(self.as_mut_ptr().offset((start[0] * self.row_stride() + start[1]) as isize),
+                                           rows, cols, self.row_stride())
+        }
+    }
+}
+
+impl<T> BaseMatrix<T> for Matrix<T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+    fn cols(&self) -> usize {
+        self.cols
+    }
+    fn row_stride(&self) -> usize {
+        self.cols
+    }
+    fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    fn into_matrix(self) -> Matrix<T>
+        where T: Copy
+    {
+        self // for Matrix, this is a no-op
+    }
+
+    fn sum(&self) -> T
+        where T: Copy + Zero + Add<T, Output = T>
+    {
+        utils::unrolled_sum(&self.data[..])
+    }
+
+    fn elemul(&self, m: &Self) -> Matrix<T>
+        where T: Copy + Mul<T, Output = T>
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        let data = utils::vec_bin_op(self.data(), m.data(), T::mul);
+        Matrix::new(self.rows(), self.cols(), data)
+    }
+
+    fn elediv(&self, m: &Self) -> Matrix<T>
+        where T: Copy + Div<T, Output = T>
+    {
+        assert!(self.rows() == m.rows(), "Matrix row counts not equal.");
+        assert!(self.cols() == m.cols(), "Matrix column counts not equal.");
+
+        let data = utils::vec_bin_op(self.data(), m.data(), T::div);
+        Matrix::new(self.rows(), self.cols(), data)
+    }
+
+    fn vcat<S>(&self, m: &S) -> Matrix<T>
+        where T: Copy,
+              S: BaseMatrix<T>
+    {
+        assert!(self.cols() == m.cols(),
+                "Matrix column counts are not equal.");
+
+        let mut new_data = self.data.clone();
+        new_data.reserve(m.rows() * m.cols());
+
+        for row in m.iter_rows() {
+            new_data.extend_from_slice(row);
+        }
+
+        Matrix {
+            cols: self.cols(),
+            rows: (self.rows() + m.rows()),
+            data: new_data,
+        }
+    }
+}
+
+impl<'a, T> BaseMatrix<T> for MatrixSlice<'a, T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+    fn cols(&self) -> usize {
+        self.cols
+    }
+    fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+}
+
+impl<'a, T> BaseMatrix<T> for MatrixSliceMut<'a, T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+    fn cols(&self) -> usize {
+        self.cols
+    }
+    fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    fn as_ptr(&self) -> *const T {
+        self.ptr as *const T
+    }
+}
+
+impl<T> BaseMatrixMut<T> for Matrix<T> {
+    /// Top left index of the slice.
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+}
+
+impl<'a, T> BaseMatrixMut<T> for MatrixSliceMut<'a, T> {
+    /// Top left index of the slice.
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+}
+
+/// Checks that strided data of length `len` can hold a `rows x cols` view.
+fn check_strided_len(len: usize, rows: usize, cols: usize, row_stride: usize) -> Result<(), Error> {
+    if row_stride < cols {
+        return Err(Error::new(ErrorKind::InvalidArg,
+                              "Row stride must not be less than the number of columns."));
+    }
+
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+
+    let required = (rows - 1).checked_mul(row_stride).and_then(|n| n.checked_add(cols));
+    match required {
+        Some(n) if n <= len => Ok(()),
+        _ => {
+            Err(Error::new(ErrorKind::InvalidArg,
+                           "Data is too short for the given dimensions and row stride."))
+        }
+    }
+}
+
+impl<'a, T> MatrixSlice<'a, T> {
+    /// Produce a `MatrixSlice` from a `Matrix`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, MatrixSlice};
+    ///
+    /// let a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let slice = MatrixSlice::from_matrix(&a, [1,1], 2, 2);
+    /// ```
+    pub fn from_matrix(mat: &'a Matrix<T>,
+                       start: [usize; 2],
+                       rows: usize,
+                       cols: usize)
+                       -> MatrixSlice<T> {
+        assert!(start[0] + rows <= mat.rows(),
+                "View dimensions exceed matrix dimensions.");
+        assert!(start[1] + cols <= mat.cols(),
+                "View dimensions exceed matrix dimensions.");
+        unsafe {
+            MatrixSlice {
+                ptr: mat.data().get_unchecked(start[0] * mat.cols + start[1]) as *const T,
+                rows: rows,
+                cols: cols,
+                row_stride: mat.cols,
+                marker: PhantomData::<&'a T>,
+            }
+        }
+    }
+
+    /// Creates a `MatrixSlice` from raw parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::MatrixSlice;
+    ///
+    /// let mut a = vec![4.0; 16];
+    ///
+    /// unsafe {
+    ///     // Create a matrix slice with 3 rows, and 3 cols
+    ///     // The row stride of 4 specifies the distance between the start of each row in the data.
+    ///     let b = MatrixSlice::from_raw_parts(a.as_ptr(), 3, 3, 4);
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be followed by a contiguous slice of data larger than `row_stride * rows`.
+    /// If not then other operations will produce undefined behaviour.
+    ///
+    /// Additionally `cols` should be less than the `row_stride`. It is possible to use this
+    /// function safely whilst violating this condition. So long as
+    /// `max(cols, row_stride) * rows` is less than the data size.
+    pub unsafe fn from_raw_parts(ptr: *const T,
+                                 rows: usize,
+                                 cols: usize,
+                                 row_stride: usize)
+                                 -> MatrixSlice<'a, T> {
+        MatrixSlice {
+            ptr: ptr,
+            rows: rows,
+            cols: cols,
+            row_stride: row_stride,
+            marker: PhantomData::<&'a T>,
+        }
+    }
+
+    /// Creates a `MatrixSlice` viewing strided row-major data.
+    ///
+    /// Row `i` of the slice starts at `data[i * row_stride]`. This allows
+    /// padded or interleaved buffers to be used without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{MatrixSlice, BaseMatrix};
+    ///
+    /// // Two rows of three elements, each padded to a stride of four.
+    /// let data = vec![1, 2, 3, 0,
+    ///                 4, 5, 6];
+    /// let a = MatrixSlice::from_slice(&data, 2, 3, 4).unwrap();
+    ///
+    /// assert_eq!(a.row_stride(), 4);
+    /// assert_eq!(a[[1, 2]], 6);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `row_stride` is less than `cols`.
+    /// - `data` is shorter than `(rows - 1) * row_stride + cols`.
+    pub fn from_slice(data: &'a [T],
+                      rows: usize,
+                      cols: usize,
+                      row_stride: usize)
+                      -> Result<MatrixSlice<'a, T>, Error> {
+        check_strided_len(data.len(), rows, cols, row_stride)?;
+        unsafe { Ok(MatrixSlice::from_raw_parts(data.as_ptr(), rows, cols, row_stride)) }
+    }
+
+    /// Produce a `MatrixSlice` from an existing `MatrixSlice`.
+    ///
+    /// This function will be deprecated. Prefer using `BaseMatrix::sub_slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, MatrixSlice};
+    ///
+    /// let a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let slice = MatrixSlice::from_matrix(&a, [1,1], 2, 2);
+    /// let new_slice = slice.reslice([0,0], 1, 1);
+    /// ```
+    pub fn reslice(self, start: [usize; 2], rows: usize, cols: usize) -> MatrixSlice<'a, T> {
+        self.sub_slice(start, rows, cols)
+    }
+}
+
+impl<'a, T> MatrixSliceMut<'a, T> {
+    /// Produce a `MatrixSliceMut` from a mutable `Matrix`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, MatrixSliceMut};
+    ///
+    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let slice = MatrixSliceMut::from_matrix(&mut a, [1,1], 2, 2);
+    /// ```
+    pub fn from_matrix(mat: &'a mut Matrix<T>,
+                       start: [usize; 2],
+                       rows: usize,
+                       cols: usize)
+                       -> MatrixSliceMut<T> {
+        assert!(start[0] + rows <= mat.rows(),
+                "View dimensions exceed matrix dimensions.");
+        assert!(start[1] + cols <= mat.cols(),
+                "View dimensions exceed matrix dimensions.");
+
+        let mat_cols = mat.cols();
+
+        unsafe {
+            MatrixSliceMut {
+                ptr: mat.mut_data().get_unchecked_mut(start[0] * mat_cols + start[1]) as *mut T,
+                rows: rows,
+                cols: cols,
+                row_stride: mat_cols,
+                marker: PhantomData::<&'a mut T>,
+            }
+        }
+    }
+
+    /// Creates a `MatrixSliceMut` from raw parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::MatrixSliceMut;
+    ///
+    /// let mut a = vec![4.0; 16];
+    ///
+    /// unsafe {
+    ///     // Create a mutable matrix slice with 3 rows, and 3 cols
+    ///     // The row stride of 4 specifies the distance between the start of each row in the data.
+    ///     let b = MatrixSliceMut::from_raw_parts(a.as_mut_ptr(), 3, 3, 4);
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be followed by a contiguous slice of data larger than `row_stride * rows`.
+    /// If not then other operations will produce undefined behaviour.
+    ///
+    /// Additionally `cols` should be less than the `row_stride`. It is possible to use this
+    /// function safely whilst violating this condition. So long as
+    /// `max(cols, row_stride) * rows` is less than the data size.
+    pub unsafe fn from_raw_parts(ptr: *mut T,
+                                 rows: usize,
+                                 cols: usize,
+                                 row_stride: usize)
+                                 -> MatrixSliceMut<'a, T> {
+        MatrixSliceMut {
+            ptr: ptr,
+            rows: rows,
+            cols: cols,
+            row_stride: row_stride,
+            marker: PhantomData::<&'a mut T>,
+        }
+    }
+
+    /// Creates a `MatrixSliceMut` viewing strided row-major data.
+    ///
+    /// Row `i` of the slice starts at `data[i * row_stride]`. Elements
+    /// in the padding between rows are never accessed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{MatrixSliceMut, BaseMatrixMut};
+    ///
+    /// let mut data = vec![1, 2, -1,
+    ///                     3, 4, -1];
+    /// {
+    ///     let a = MatrixSliceMut::from_slice(&mut data, 2, 2, 3).unwrap();
+    ///     a.apply(&|x| x * 10);
+    /// }
+    ///
+    /// assert_eq!(data, vec![10, 20, -1, 30, 40, -1]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `row_stride` is less than `cols`.
+    /// - `data` is shorter than `(rows - 1) * row_stride + cols`.
+    pub fn from_slice(data: &'a mut [T],
+                      rows: usize,
+                      cols: usize,
+                      row_stride: usize)
+                      -> Result<MatrixSliceMut<'a, T>, Error> {
+        check_strided_len(data.len(), rows, cols, row_stride)?;
+        unsafe { Ok(MatrixSliceMut::from_raw_parts(data.as_mut_ptr(), rows, cols, row_stride)) }
+    }
+
+    /// Produce a `MatrixSliceMut` from an existing `MatrixSliceMut`.
+    ///
+    /// This function will be deprecated. Prefer using `BaseMatrixMut::sub_slice_mut` instead;
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, MatrixSliceMut};
+    ///
+    /// let mut a = Matrix::new(3,3, (0..9).collect::<Vec<usize>>());
+    /// let slice = MatrixSliceMut::from_matrix(&mut a, [1,1], 2, 2);
+    /// let new_slice = slice.reslice([0,0], 1, 1);
+    /// ```
+    pub fn reslice(mut self, start: [usize; 2], rows: usize, cols: usize) -> MatrixSliceMut<'a, T> {
+        self.sub_slice_mut(start, rows, cols)
+    }
+
+    /// Splits the slice at the specified axis into two disjoint slices.
+    ///
+    /// Unlike `BaseMatrixMut::split_at_mut` this consumes the slice,
+    /// so the halves borrow from the original matrix rather than
+    /// from the slice. This allows recursive splitting, as in
+    /// blocked algorithms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Axes, Matrix, MatrixSliceMut, BaseMatrixMut};
+    ///
+    /// let mut a = Matrix::new(3, 3, vec![0; 9]);
+    /// {
+    ///     let slice = MatrixSliceMut::from_matrix(&mut a, [0, 0], 3, 3);
+    ///     let (panel, rest) = slice.into_split_at_mut(1, Axes::Col);
+    ///     let (_, mut trailing) = rest.into_split_at_mut(1, Axes::Row);
+    ///
+    ///     panel.apply(&|_| 1);
+    ///     trailing.apply(&|_| 2);
+    /// }
+    ///
+    /// assert_eq!(*a.data(), vec![1, 0, 0,
+    ///                            1, 2, 2,
+    ///                            1, 2, 2]);
+    /// ```
+    ///
+    /// The parent cannot be used while the halves are alive:
+    ///
+    /// ```compile_fail
+    /// use rulinalg::matrix::{Axes, Matrix, MatrixSliceMut};
+    ///
+    /// let mut a = Matrix::new(2, 2, vec![0; 4]);
+    /// let (mut b, _) = MatrixSliceMut::from_matrix(&mut a, [0, 0], 2, 2)
+    ///     .into_split_at_mut(1, Axes::Row);
+    ///
+    /// a[[0, 0]] = 1;
+    /// b[[0, 0]] = 2;
+    /// ```
+    ///
+    /// And the halves cannot outlive the parent:
+    ///
+    /// ```compile_fail
+    /// use rulinalg::matrix::{Axes, Matrix, MatrixSliceMut};
+    ///
+    /// let b = {
+    ///     let mut a = Matrix::new(2, 2, vec![0; 4]);
+    ///     MatrixSliceMut::from_matrix(&mut a, [0, 0], 2, 2).into_split_at_mut(1, Axes::Row).0
+    /// };
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `mid` is greater than the dimension along `axis`.
+    pub fn into_split_at_mut(mut self, mid: usize, axis: Axes) -> (MatrixSliceMut<'a, T>, MatrixSliceMut<'a, T>) {
+        let (a, b) = BaseMatrixMut::split_at_mut(&mut self, mid, axis);
+
+        // The halves are disjoint and `self` is consumed, so they may
+        // borrow for the full lifetime of the original slice.
+        unsafe {
+            (MatrixSliceMut::from_raw_parts(a.ptr, a.rows, a.cols, a.row_stride),
+             MatrixSliceMut::from_raw_parts(b.ptr, b.rows, b.cols, b.row_stride))
+        }
+    }
+}
+
+/// Applies `f(x, v[j])` to each element `x` in column `j`.
+fn broadcast_row<T, M, F>(m: &mut M, v: &Vector<T>, f: F)
+    where T: Copy,
+          M: BaseMatrixMut<T>,
+          F: Fn(T, T) -> T
+{
+    assert!(v.size() == m.cols(), "Vector size must match the number of columns.");
+
+    for row in m.iter_rows_mut() {
+        utils::in_place_vec_bin_op(row, v.data(), |x, &y| *x = f(*x, y));
+    }
+}
+
+/// Applies `f(x, v[i])` to each element `x` in row `i`.
+fn broadcast_col<T, M, F>(m: &mut M, v: &Vector<T>, f: F)
+    where T: Copy,
+          M: BaseMatrixMut<T>,
+          F: Fn(T, T) -> T
+{
+    assert!(v.size() == m.rows(), "Vector size must match the number of rows.");
+
+    for (row, &y) in m.iter_rows_mut().zip(v.data()) {
+        for x in row {
+            *x = f(*x, y);
+        }
+    }
+}
+
+/// Checks that `f(m[[i, j]], m[[j, i]])` holds for all `i > j` of a square matrix.
+fn is_symmetric_by<T, M, F>(m: &M, f: F) -> bool
+    where M: BaseMatrix<T>,
+          F: Fn(&T, &T) -> bool
+{
+    if m.rows() != m.cols() {
+        return false;
+    }
+
+    unsafe {
+        (0..m.rows()).all(|i| (0..i).all(|j| f(m.get_unchecked([i, j]), m.get_unchecked([j, i]))))
+    }
+}
+
+/// Finds the index of an extremal element using `f` on each row.
+///
+/// `f` must return the index and value of the first extremum of a
+/// non-empty slice, as `utils::argmax` and `utils::argmin` do.
+fn arg_extremum<T, M, F>(m: &M, f: F) -> Option<(usize, usize)>
+    where T: Copy + PartialOrd,
+          M: BaseMatrix<T>,
+          F: Fn(&[T]) -> (usize, T)
+{
+    if m.rows() == 0 || m.cols() == 0 {
+        return None;
+    }
+
+    let row_best = m.iter_rows().map(|row| f(row)).collect::<Vec<_>>();
+    let values = row_best.iter().map(|&(_, x)| x).collect::<Vec<_>>();
+    let (i, _) = f(&values);
+
+    Some((i, row_best[i].0))
+}
+
+/// Finds the index of the extremal element of each lane along an axis.
+///
+/// `better(x, best)` decides whether `x` replaces the running extremum,
+/// so ties resolve to the first occurrence. NaN values are skipped, and
+/// lanes which are empty or contain only NaN values give `None`.
+fn arg_extremum_axis<T, M, F>(m: &M, axis: Axes, better: F) -> Vec<Option<usize>>
+    where T: Copy + PartialOrd,
+          M: BaseMatrix<T>,
+          F: Fn(&T, &T) -> bool
+{
+    let update = |best: &mut Option<(usize, T)>, k: usize, x: &T| {
+        if x.partial_cmp(x).is_none() {
+            return;
+        }
+
+        let replace = match *best {
+            Some((_, ref b)) => better(x, b),
+            None => true,
+        };
+        if replace {
+            *best = Some((k, *x));
+        }
+    };
+
+    let best = match axis {
+        Axes::Row => {
+            // Walk down the rows, keeping a running extremum per column.
+            let mut best = vec![None; m.cols()];
+            for (i, row) in m.iter_rows().enumerate() {
+                for (b, x) in best.iter_mut().zip(row) {
+                    update(b, i, x);
+                }
+            }
+            best
+        }
+        Axes::Col => {
+            m.iter_rows()
+                .map(|row| {
+                    let mut best = None;
+                    for (j, x) in row.iter().enumerate() {
+                        update(&mut best, j, x);
+                    }
+                    best
+                })
+                .collect()
+        }
+    };
+
+    best.into_iter().map(|b| b.map(|(k, _)| k)).collect()
+}
+
+/// Finds the index of the extremal element of each lane along an axis,
+/// giving the first index for lanes which contain only NaN values.
+///
+/// # Panics
+///
+/// - The axis being reduced has length zero.
+fn arg_extremum_axis_or_first<T, M, F>(m: &M, axis: Axes, better: F) -> Vec<usize>
+    where T: Copy + PartialOrd,
+          M: BaseMatrix<T>,
+          F: Fn(&T, &T) -> bool
+{
+    let len = match axis {
+        Axes::Row => m.rows(),
+        Axes::Col => m.cols(),
+    };
+    assert!(len != 0, "Cannot reduce along an axis of length zero.");
+
+    arg_extremum_axis(m, axis, better).into_iter().map(|k| k.unwrap_or(0)).collect()
+}
+
+/// Blocks with both dimensions at most this size are transposed directly.
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Writes the transpose of the `rows x cols` matrix at `src` to `dst`.
+///
+/// The larger dimension is halved recursively until the block is small
+/// enough to stay in cache, so no tuning for the cache size is needed.
+unsafe fn transpose_recursive<T: Copy>(src: *const T, src_stride: usize,
+                                       dst: *mut T, dst_stride: usize,
+                                       rows: usize, cols: usize) {
+    if rows <= TRANSPOSE_BLOCK && cols <= TRANSPOSE_BLOCK {
+        for i in 0..rows {
+            for j in 0..cols {
+                *dst.offset((j * dst_stride + i) as isize) = *src.offset((i * src_stride + j) as isize);
+            }
+        }
+    } else if rows >= cols {
+        let mid = rows / 2;
+        transpose_recursive(src, src_stride, dst, dst_stride, mid, cols);
+        transpose_recursive(src.offset((mid * src_stride) as isize), src_stride,
+                            dst.offset(mid as isize), dst_stride,
+                            rows - mid, cols);
+    } else {
+        let mid = cols / 2;
+        transpose_recursive(src, src_stride, dst, dst_stride, rows, mid);
+        transpose_recursive(src.offset(mid as isize), src_stride,
+                            dst.offset((mid * dst_stride) as isize), dst_stride,
+                            rows, cols - mid);
+    }
+}
+
+/// Returns the offset of the first element and the length of the `k`-th diagonal.
+///
+/// The offset is zero whenever the diagonal is empty, so that it never
+/// points past the data of an empty matrix.
+fn diag_offset_len(rows: usize, cols: usize, row_stride: usize, k: isize) -> (usize, usize) {
+    let (offset, len) = if k >= 0 {
+        let k = k as usize;
+        if k >= cols {
+            (0, 0)
+        } else {
+            (k, min(rows, cols - k))
+        }
+    } else {
+        let k = (-k) as usize;
+        if k >= rows {
+            (0, 0)
+        } else {
+            (k * row_stride, min(rows - k, cols))
+        }
+    };
+
+    if len == 0 { (0, 0) } else { (offset, len) }
+}
+
+/// Iterator for matrix.
+///
+/// Iterates over the underlying slice data
+/// in row-major order.
+#[derive(Debug)]
+pub struct SliceIter<'a, T: 'a> {
+    slice_start: *const T,
+    row_pos: usize,
+    col_pos: usize,
+    slice_rows: usize,
+    slice_cols: usize,
+    row_stride: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+/// Iterator for mutable matrix.
+///
+/// Iterates over the underlying slice data
+/// in row-major order.
+#[derive(Debug)]
+pub struct SliceIterMut<'a, T: 'a> {
+    slice_start: *mut T,
+    row_pos: usize,
+    col_pos: usize,
+    slice_rows: usize,
+    slice_cols: usize,
+    row_stride: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+macro_rules! impl_slice_iter (
+    ($slice_iter:ident, $data_type:ty) => (
+/// Iterates over the matrix slice data in row-major order.
+impl<'a, T> Iterator for $slice_iter<'a, T> {
+    type Item = $data_type;
+
+    fn next(&mut self) -> Option<$data_type> {
+// Set the position of the next element
+        if self.row_pos < self.slice_rows {
+            unsafe {
+                let iter_ptr = self.slice_start.offset((
+                                self.row_pos * self.row_stride + self.col_pos)
+                                as isize);
+
+// If end of row, set to start of next row
+                if self.col_pos == self.slice_cols - 1 {
+                    self.row_pos += 1usize;
+                    self.col_pos = 0usize;
+                } else {
+                    self.col_pos += 1usize;
+                }
+
+                Some(mem::transmute(iter_ptr))
+            }
+        } else {
+            None
+        }
+    }
+}
+    );
+);
+
+impl_slice_iter!(SliceIter, &'a T);
+impl_slice_iter!(SliceIterMut, &'a mut T);
+
+#[cfg(test)]
+mod tests {
+    use super::{BaseMatrix, BaseMatrixMut};
+    use matrix::{Matrix, MatrixSlice, MatrixSliceMut, Axes};
+    use vector::Vector;
+
+    #[test]
+    #[should_panic]
+    fn make_slice_bad_dim() {
+        let a = Matrix::new(3, 3, vec![2.0; 9]);
+        let _ = MatrixSlice::from_matrix(&a, [1, 1], 3, 2);
+    }
+
+    #[test]
+    fn make_slice() {
+        let a = Matrix::new(3, 3, vec![2.0; 9]);
+        let b = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+
+        assert_eq!(b.rows(), 2);
+        assert_eq!(b.cols(), 2);
+    }
+
+    #[test]
+    fn reslice() {
+        let mut a = Matrix::new(4, 4, (0..16).collect::<Vec<_>>());
+
+        {
+            let b = MatrixSlice::from_matrix(&a, [1, 1], 3, 3);
+            let c = b.reslice([0, 1], 2, 2);
+
+            assert_eq!(c.rows(), 2);
+            assert_eq!(c.cols(), 2);
+
+            assert_eq!(c[[0, 0]], 6);
+            assert_eq!(c[[0, 1]], 7);
+            assert_eq!(c[[1, 0]], 10);
+            assert_eq!(c[[1, 1]], 11);
+        }
+
+        let b = MatrixSliceMut::from_matrix(&mut a, [1, 1], 3, 3);
+
+        let c = b.reslice([0, 1], 2, 2);
+
+        assert_eq!(c.rows(), 2);
+        assert_eq!(c.cols(), 2);
+
+        assert_eq!(c[[0, 0]], 6);
+        assert_eq!(c[[0, 1]], 7);
+        assert_eq!(c[[1, 0]], 10);
+        assert_eq!(c[[1, 1]], 11);
+    }
+
+    #[test]
+    fn test_submatrix() {
+        let a = Matrix::new(4, 4, (0..16).collect::<Vec<_>>());
+        let mut b = a.submatrix(1..3, 1..3);
+
+        assert_eq!(b, Matrix::new(2, 2, vec![5, 6, 9, 10]));
+
+        b[[0, 0]] = 100;
+        assert_eq!(a[[1, 1]], 5);
+
+        let empty = a.submatrix(2..2, 0..4);
+        assert_eq!(empty.rows(), 0);
+        assert_eq!(empty.cols(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_submatrix_out_of_bounds() {
+        let a = Matrix::new(4, 4, (0..16).collect::<Vec<_>>());
+        let _ = a.submatrix(2..5, 0..2);
+    }
+
+    #[test]
+    fn test_non_finite_detection() {
+        use std::f64;
+
+        let clean = Matrix::new(3, 3, (0..9).map(|x| x as f64).collect::<Vec<_>>());
+        assert!(!clean.has_nan());
+        assert!(clean.is_finite());
+        assert_eq!(clean.find_non_finite(), None);
+
+        for &bad in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            for &(i, j) in &[(0, 0), (1, 2), (2, 1), (2, 2)] {
+                let mut a = clean.clone();
+                a[[i, j]] = bad;
+
+                assert_eq!(a.has_nan(), bad.is_nan());
+                assert!(!a.is_finite());
+                assert_eq!(a.find_non_finite(), Some((i, j)));
+            }
+        }
+
+        // The first position in row-major order is reported.
+        let mut a = clean.clone();
+        a[[2, 0]] = f64::NAN;
+        a[[1, 2]] = f64::INFINITY;
+        assert_eq!(a.find_non_finite(), Some((1, 2)));
+
+        // Elements outside of a slice are ignored.
+        let s = MatrixSlice::from_matrix(&a, [0, 0], 1, 3);
+        assert!(s.is_finite());
+        assert_eq!(s.find_non_finite(), None);
+    }
+
+    #[test]
+    fn test_from_slice_padded() {
+        // A 3x2 matrix stored with a row stride of 3, the final row unpadded.
+        let data = vec![1.0, 2.0, 99.0,
+                        3.0, 4.0, 99.0,
+                        5.0, 6.0];
+        let a = MatrixSlice::from_slice(&data, 3, 2, 3).unwrap();
+
+        assert_eq!(a.row_stride(), 3);
+        assert_eq!(a.into_matrix(), Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+
+        let b = Matrix::new(2, 2, vec![1.0, 1.0, 0.0, 1.0]);
+        assert_eq!(a * &b, Matrix::new(3, 2, vec![1.0, 3.0, 3.0, 7.0, 5.0, 11.0]));
+        assert_eq!(a + a, Matrix::new(3, 2, vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]));
+        assert_eq!(a.sum_rows(), Vector::new(vec![9.0, 12.0]));
+    }
+
+    #[test]
+    fn test_from_slice_mut_padded() {
+        let mut data = vec![1, 2, 0, 0,
+                            3, 4, 0, 0];
+        {
+            let mut a = MatrixSliceMut::from_slice(&mut data, 2, 2, 4).unwrap();
+            a[[1, 0]] = 7;
+            a += 1;
+        }
+
+        assert_eq!(data, vec![2, 3, 0, 0, 8, 5, 0, 0]);
+    }
+
+    #[test]
+    fn test_from_slice_invalid() {
+        let mut data = vec![0; 7];
+
+        assert!(MatrixSlice::from_slice(&data, 2, 4, 4).is_err());
+        assert!(MatrixSlice::from_slice(&data, 2, 3, 2).is_err());
+        assert!(MatrixSliceMut::from_slice(&mut data, 3, 2, 3).is_err());
+
+        assert!(MatrixSlice::from_slice(&data, 2, 3, 4).is_ok());
+        assert!(MatrixSlice::from_slice(&data, 0, 3, 100).is_ok());
+        assert!(MatrixSlice::from_slice(&data, usize::max_value(), 1, 2).is_err());
+    }
+
+    #[test]
+    fn test_set_submatrix() {
+        let block = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+
+        for top in 0..3 {
+            for left in 0..3 {
+                let mut a = Matrix::zeros(4, 4);
+                a.set_submatrix(top, left, &block);
+
+                for i in 0..4 {
+                    for j in 0..4 {
+                        let expected = if i >= top && i < top + 2 && j >= left && j < left + 2 {
+                            block[[i - top, j - left]]
+                        } else {
+                            0
+                        };
+                        assert_eq!(a[[i, j]], expected);
+                    }
+                }
+            }
+        }
+
+        // Blocks may also come from slices and be written into slices.
+        let src = Matrix::new(3, 3, (0..9).collect::<Vec<_>>());
+        let mut a = Matrix::zeros(4, 4);
+        a.sub_slice_mut([1, 1], 3, 3).set_submatrix(1, 0, &src.sub_slice([0, 1], 2, 2));
+        assert_eq!(a.sub_slice([2, 1], 2, 2).into_matrix(),
+                   Matrix::new(2, 2, vec![1, 2, 4, 5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_submatrix_out_of_bounds() {
+        let mut a = Matrix::zeros(4, 4);
+        a.set_submatrix(3, 0, &Matrix::new(2, 2, vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_matrix_norms() {
+        let a = Matrix::new(3, 2, vec![1.0, -2.0,
+                                       -3.0, 4.0,
+                                       0.0, 6.0]);
+
+        assert!((a.norm_frobenius() - 66f64.sqrt()).abs() < 1e-12);
+        assert_eq!(a.norm_one(), 12.0);
+        assert_eq!(a.norm_inf(), 7.0);
+
+        // Largest singular value, from the eigenvalues of AᵀA = [[10, -14], [-14, 56]].
+        let sigma = (33.0 + 725f64.sqrt()).sqrt();
+        assert!((a.norm_two_estimate(50) - sigma).abs() < 1e-8);
+        assert!(a.norm_two_estimate(1) <= sigma + 1e-12);
+
+        let s = a.sub_slice([1, 0], 2, 2);
+        assert_eq!(s.norm_one(), 10.0);
+        assert_eq!(s.norm_inf(), 7.0);
+        assert!((s.norm_frobenius() - 61f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_matrix_norms_scaling() {
+        let a = Matrix::new(2, 3, vec![1.0f64, 2.0, -1.5, 0.5, -4.0, 3.0]);
+        let c = -2.5f64;
+        let ca = &a * c;
+
+        assert!((ca.norm_frobenius() - c.abs() * a.norm_frobenius()).abs() < 1e-12);
+        assert!((ca.norm_one() - c.abs() * a.norm_one()).abs() < 1e-12);
+        assert!((ca.norm_inf() - c.abs() * a.norm_inf()).abs() < 1e-12);
+        assert!((ca.norm_two_estimate(30) - c.abs() * a.norm_two_estimate(30)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_matrix_norms_overflow_and_empty() {
+        let a = Matrix::new(2, 2, vec![3e154f64, 4e154, 0.0, 0.0]);
+        let norm = a.norm_frobenius();
+        assert!(norm.is_finite());
+        assert!((norm / 5e154 - 1.0).abs() < 1e-12);
+
+        let empty = Matrix::<f64>::new(0, 0, vec![]);
+        assert_eq!(empty.norm_frobenius(), 0.0);
+        assert_eq!(empty.norm_one(), 0.0);
+        assert_eq!(empty.norm_inf(), 0.0);
+        assert_eq!(empty.norm_two_estimate(10), 0.0);
+
+        let zero = Matrix::<f64>::zeros(2, 3);
+        assert_eq!(zero.norm_two_estimate(10), 0.0);
+    }
+
+    #[test]
+    fn test_sub_slice() {
+        let mut a = Matrix::new(4, 4, (0..16).collect::<Vec<_>>());
+        {
+            let slice = a.sub_slice([1, 1], 3, 2);
+            assert_eq!(&slice.iter().cloned().collect::<Vec<_>>(), &vec![5, 6, 9, 10, 13, 14]);
+
+            let slice = slice.sub_slice([1, 1], 2, 1);
+            assert_eq!(&slice.iter().cloned().collect::<Vec<_>>(), &vec![10, 14]);
+        }
+        {
+            let mut slice_mut = a.sub_slice_mut([3, 1], 1, 1);
+            unsafe {
+                *slice_mut.get_unchecked_mut([0, 0]) = 25;
+                assert_eq!(*a.get_unchecked([3, 1]), 25);
+            }
+        }
+    }
+
+    #[test]
+    fn slice_into_matrix() {
+        let mut a = Matrix::new(3, 3, vec![2.0; 9]);
+
+        {
+            let b = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+            let c = b.into_matrix();
+            assert_eq!(c.rows(), 2);
+            assert_eq!(c.cols(), 2);
+        }
+
+        let d = MatrixSliceMut::from_matrix(&mut a, [1, 1], 2, 2);
+        let e = d.into_matrix();
+        assert_eq!(e.rows(), 2);
+        assert_eq!(e.cols(), 2);
+    }
+
+    #[test]
+    fn test_split_matrix() {
+        let a = Matrix::new(3, 3, (0..9).collect::<Vec<_>>());
+
+        let (b, c) = a.split_at(1, Axes::Row);
+
+        assert_eq!(b.rows(), 1);
+        assert_eq!(b.cols(), 3);
+        assert_eq!(c.rows(), 2);
+        assert_eq!(c.cols(), 3);
+
+        assert_eq!(b[[0, 0]], 0);
+        assert_eq!(b[[0, 1]], 1);
+        assert_eq!(b[[0, 2]], 2);
+        assert_eq!(c[[0, 0]], 3);
+        assert_eq!(c[[0, 1]], 4);
+        assert_eq!(c[[0, 2]], 5);
+        assert_eq!(c[[1, 0]], 6);
+        assert_eq!(c[[1, 1]], 7);
+        assert_eq!(c[[1, 2]], 8);
+    }
+
+    #[test]
+    fn test_split_slice_mut_disjoint() {
+        let mut a = Matrix::new(4, 3, vec![0; 12]);
+
+        {
+            let slice = MatrixSliceMut::from_matrix(&mut a, [1, 0], 3, 3);
+            let (mut top, bottom) = slice.into_split_at_mut(1, Axes::Row);
+            let (mut left, mut right) = bottom.into_split_at_mut(2, Axes::Col);
+
+            top[[0, 1]] = 1;
+            left[[1, 0]] = 2;
+            right[[0, 0]] = 3;
+            right[[1, 0]] = 4;
+        }
+
+        assert_eq!(*a.data(), vec![0, 0, 0,
+                                   0, 1, 0,
+                                   0, 0, 3,
+                                   2, 0, 4]);
+    }
+
+    #[test]
+    fn test_split_at_mut_bounds() {
+        let mut a = Matrix::new(2, 3, (0..6).collect::<Vec<_>>());
+
+        {
+            let (b, c) = a.split_at_mut(0, Axes::Row);
+            assert_eq!((b.rows(), b.cols()), (0, 3));
+            assert_eq!((c.rows(), c.cols()), (2, 3));
+            assert_eq!(c[[1, 2]], 5);
+        }
+
+        {
+            let (b, c) = a.split_at_mut(2, Axes::Row);
+            assert_eq!((b.rows(), b.cols()), (2, 3));
+            assert_eq!((c.rows(), c.cols()), (0, 3));
+        }
+
+        {
+            let slice = MatrixSliceMut::from_matrix(&mut a, [1, 0], 1, 3);
+            let (b, c) = slice.into_split_at_mut(3, Axes::Col);
+            assert_eq!((b.rows(), b.cols()), (1, 3));
+            assert_eq!((c.rows(), c.cols()), (1, 0));
+            assert_eq!(b[[0, 0]], 3);
+        }
+
+        let (b, c) = a.split_at(0, Axes::Col);
+        assert_eq!((b.cols(), c.cols()), (0, 3));
+
+        let mut empty = Matrix::<i32>::new(0, 3, vec![]);
+        {
+            let (b, c) = empty.split_at(3, Axes::Col);
+            assert_eq!((b.rows(), b.cols()), (0, 3));
+            assert_eq!((c.rows(), c.cols()), (0, 0));
+        }
+        let (b, c) = empty.split_at_mut(3, Axes::Col);
+        assert_eq!((b.rows(), b.cols()), (0, 3));
+        assert_eq!((c.rows(), c.cols()), (0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_mut_out_of_bounds() {
+        let mut a = Matrix::new(2, 3, vec![0; 6]);
+        let _ = a.split_at_mut(3, Axes::Row);
+    }
+
+    #[test]
+    fn test_split_matrix_mut() {
+        let mut a = Matrix::new(3, 3, (0..9).collect::<Vec<_>>());
+
+        {
+            let (mut b, mut c) = a.split_at_mut(1, Axes::Row);
+
+            assert_eq!(b.rows(), 1);
+            assert_eq!(b.cols(), 3);
+            assert_eq!(c.rows(), 2);
+            assert_eq!(c.cols(), 3);
+
+            assert_eq!(b[[0, 0]], 0);
+            assert_eq!(b[[0, 1]], 1);
+            assert_eq!(b[[0, 2]], 2);
+            assert_eq!(c[[0, 0]], 3);
+            assert_eq!(c[[0, 1]], 4);
+            assert_eq!(c[[0, 2]], 5);
+            assert_eq!(c[[1, 0]], 6);
+            assert_eq!(c[[1, 1]], 7);
+            assert_eq!(c[[1, 2]], 8);
+
+            b[[0, 0]] = 4;
+            c[[0, 0]] = 5;
+        }
+
+        assert_eq!(a[[0, 0]], 4);
+        assert_eq!(a[[0, 1]], 1);
+        assert_eq!(a[[0, 2]], 2);
+        assert_eq!(a[[1, 0]], 5);
+        assert_eq!(a[[1, 1]], 4);
+        assert_eq!(a[[1, 2]], 5);
+        assert_eq!(a[[2, 0]], 6);
+        assert_eq!(a[[2, 1]], 7);
+        assert_eq!(a[[2, 2]], 8);
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut a = Matrix::new(4, 3, (0..12).collect::<Vec<usize>>());
+        a.swap_rows(0, 1);
+
+        assert_eq!(a.data(), &[3, 4, 5, 0, 1, 2, 6, 7, 8, 9, 10, 11]);
+
+        {
+            let mut b = MatrixSliceMut::from_matrix(&mut a, [0, 0], 4, 2);
+            b.swap_rows(0, 1);
+        }
+
+        assert_eq!(a.into_vec(), vec![0, 1, 5, 3, 4, 2, 6, 7, 8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn test_matrix_swap_cols() {
+        let mut a = Matrix::new(4, 3, (0..12).collect::<Vec<usize>>());
+        a.swap_cols(0, 1);
+
+        assert_eq!(a.data(), &[1, 0, 2, 4, 3, 5, 7, 6, 8, 10, 9, 11]);
+
+        {
+            let mut b = MatrixSliceMut::from_matrix(&mut a, [0, 0], 3, 3);
+            b.swap_cols(0, 2);
+        }
+
+        assert_eq!(a.into_vec(), vec![2, 0, 1, 5, 3, 4, 8, 6, 7, 10, 9, 11]);
+    }
+
+    #[test]
+    fn test_matrix_swap_same_rows() {
+        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+        a.swap_rows(0, 0);
+
+        assert_eq!(a.into_vec(), (0..8).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_matrix_swap_same_cols() {
+        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+        a.swap_cols(0, 0);
+
+        assert_eq!(a.into_vec(), (0..8).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_swap_row_high_first() {
+        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+        a.swap_rows(5, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_swap_row_high_second() {
+        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+        a.swap_rows(0, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_swap_col_high_first() {
+        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+        a.swap_cols(2, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_swap_col_high_second() {
+        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+        a.swap_cols(1, 2);
+    }
+
+    #[test]
+    fn test_matrix_select_rows() {
+        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+
+        let b = a.select_rows(&[0, 2, 3]);
+
+        assert_eq!(b.into_vec(), vec![0, 1, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_matrix_select_cols() {
+        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+
+        let b = a.select_cols(&[1]);
+
+        assert_eq!(b.into_vec(), vec![1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_matrix_select() {
+        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+
+        let b = a.select(&[0, 2], &[1]);
+
+        assert_eq!(b.into_vec(), vec![1, 5]);
+    }
+
+    #[test]
+    fn matrix_diag() {
+        let a = Matrix::new(3, 3, vec![1., 3., 5., 2., 4., 7., 1., 1., 0.]);
+
+        let b = a.is_diag();
+
+        assert!(!b);
+
+        let c = Matrix::new(3, 3, vec![1., 0., 0., 0., 2., 0., 0., 0., 3.]);
+        let d = c.is_diag();
+
+        assert!(d);
+    }
+
+    #[test]
+    fn transpose_mat() {
+        let a = Matrix::new(5, 2, vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10.]);
+
+        let c = a.transpose();
+
+        assert_eq!(c.cols(), a.rows());
+        assert_eq!(c.rows(), a.cols());
+
+        assert_eq!(a[[0, 0]], c[[0, 0]]);
+        assert_eq!(a[[1, 0]], c[[0, 1]]);
+        assert_eq!(a[[2, 0]], c[[0, 2]]);
+        assert_eq!(a[[3, 0]], c[[0, 3]]);
+        assert_eq!(a[[4, 0]], c[[0, 4]]);
+        assert_eq!(a[[0, 1]], c[[1, 0]]);
+        assert_eq!(a[[1, 1]], c[[1, 1]]);
+        assert_eq!(a[[2, 1]], c[[1, 2]]);
+        assert_eq!(a[[3, 1]], c[[1, 3]]);
+        assert_eq!(a[[4, 1]], c[[1, 4]]);
+    }
+
+    #[test]
+    fn test_diag_iter_reconstruct() {
+        let a = Matrix::new(3, 4, (0..12).collect::<Vec<usize>>());
+        let mut b = Matrix::new(3, 4, vec![0; 12]);
+
+        for k in -2..4 {
+            let diag = a.diag_iter(k).cloned().collect::<Vec<_>>();
+            b.set_diag(k, &diag);
+        }
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_diag_iter_rectangular_lengths() {
+        let a = Matrix::new(2, 4, vec![0; 8]);
+
+        let lens = (-3..6).map(|k| a.diag_iter(k).len()).collect::<Vec<_>>();
+        assert_eq!(lens, vec![0, 0, 1, 2, 2, 2, 1, 0, 0]);
+
+        assert_eq!(a.sub_diag().len(), 1);
+        assert_eq!(a.super_diag().len(), 2);
+    }
+
+    #[test]
+    fn test_diag_iter_empty() {
+        let mut a = Matrix::<f64>::new(0, 4, vec![]);
+
+        for k in -2..6 {
+            assert_eq!(a.diag_iter(k).len(), 0);
+            assert_eq!(a.diag_iter_mut(k).count(), 0);
+        }
+
+        let b = Matrix::<f64>::new(3, 0, vec![]);
+        assert_eq!(b.diag_iter(-1).len(), 0);
+
+        // Empty diagonals start at the beginning of the data.
+        assert_eq!(super::diag_offset_len(0, 4, 4, 2), (0, 0));
+        assert_eq!(super::diag_offset_len(3, 0, 0, -1), (0, 0));
+        assert_eq!(super::diag_offset_len(3, 4, 4, 2), (2, 2));
+    }
+
+    #[test]
+    fn test_diag_iter_mut_slice() {
+        let mut a = Matrix::new(4, 4, vec![0; 16]);
+
+        {
+            let mut slice = MatrixSliceMut::from_matrix(&mut a, [1, 1], 3, 3);
+            for x in slice.diag_iter_mut(0) {
+                *x = 1;
+            }
+            for x in slice.diag_iter_mut(-1) {
+                *x = 2;
+            }
+        }
+
+        assert_eq!(a.into_vec(), vec![0, 0, 0, 0,
+                                      0, 1, 0, 0,
+                                      0, 2, 1, 0,
+                                      0, 0, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_diag_bad_len() {
+        let mut a = Matrix::new(3, 3, vec![0; 9]);
+        a.set_diag(1, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_triu_tril_rectangular() {
+        let a = Matrix::new(3, 4, (1..13).collect::<Vec<i32>>());
+
+        assert_eq!(a.triu(-1).into_vec(), vec![1, 2, 3, 4,
+                                               5, 6, 7, 8,
+                                               0, 10, 11, 12]);
+        assert_eq!(a.triu(0).into_vec(), vec![1, 2, 3, 4,
+                                              0, 6, 7, 8,
+                                              0, 0, 11, 12]);
+        assert_eq!(a.triu(1).into_vec(), vec![0, 2, 3, 4,
+                                              0, 0, 7, 8,
+                                              0, 0, 0, 12]);
+
+        assert_eq!(a.tril(-1).into_vec(), vec![0, 0, 0, 0,
+                                               5, 0, 0, 0,
+                                               9, 10, 0, 0]);
+        assert_eq!(a.tril(0).into_vec(), vec![1, 0, 0, 0,
+                                              5, 6, 0, 0,
+                                              9, 10, 11, 0]);
+        assert_eq!(a.tril(1).into_vec(), vec![1, 2, 0, 0,
+                                              5, 6, 7, 0,
+                                              9, 10, 11, 12]);
+    }
+
+    #[test]
+    fn test_triu_tril_large_offset() {
+        let a = Matrix::new(3, 4, (1..13).collect::<Vec<i32>>());
+
+        assert_eq!(a.triu(-10), a);
+        assert_eq!(a.triu(10).into_vec(), vec![0; 12]);
+        assert_eq!(a.tril(10), a);
+        assert_eq!(a.tril(-10).into_vec(), vec![0; 12]);
+    }
+
+    #[test]
+    fn test_triu_tril_reconstruct() {
+        let a = Matrix::new(3, 4, (1..13).collect::<Vec<i32>>());
+
+        assert_eq!(a.tril(0) + a.triu(1), a);
+    }
+
+    #[test]
+    fn test_triu_tril_identities() {
+        let a = Matrix::new(4, 4, (1..17).map(|x| x as f64).collect::<Vec<_>>());
+
+        assert_eq!(a.triu(0) + a.tril(-1), a);
+        assert_eq!(a.tril(0).triu(0), Matrix::from_diag(a.diag().data()));
+
+        for &(rows, cols) in &[(2, 5), (5, 2)] {
+            let b = Matrix::new(rows, cols, (0..rows * cols).map(|x| x as f64).collect::<Vec<_>>());
+
+            assert_eq!(b.triu(0) + b.tril(-1), b);
+            for k in -3..4 {
+                let mut upper = b.clone();
+                upper.triu_in_place(k);
+                assert_eq!(upper, b.triu(k));
+
+                let mut lower = b.clone();
+                lower.tril_in_place(k);
+                assert_eq!(lower, b.tril(k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_triu_in_place_slice() {
+        let mut a = Matrix::new(3, 3, vec![1; 9]);
+        MatrixSliceMut::from_matrix(&mut a, [1, 0], 2, 3).triu_in_place(0);
+
+        assert_eq!(a.into_vec(), vec![1, 1, 1, 1, 1, 1, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_structure_predicates() {
+        let lower = Matrix::new(3, 3, vec![1., 0., 0., 2., 3., 0., 4., 5., 6.]);
+        let upper = lower.transpose();
+        let diag = Matrix::new(3, 3, vec![1., 0., 0., 0., 2., 0., 0., 0., 3.]);
+
+        assert!(lower.is_lower_triangular() && !lower.is_upper_triangular());
+        assert!(upper.is_upper_triangular() && !upper.is_lower_triangular());
+        assert!(diag.is_lower_triangular() && diag.is_upper_triangular());
+        assert!(diag.is_diag_within(0.0));
+        assert!(!lower.is_diag_within(0.5));
+    }
+
+    #[test]
+    fn test_structure_predicates_perturbed() {
+        let mut lower = Matrix::new(3, 3, vec![1., 0., 0., 2., 3., 0., 4., 5., 6.]);
+        lower[[0, 2]] = 1e-12;
+
+        assert!(!lower.is_lower_triangular());
+        assert!(lower.is_lower_triangular_within(1e-10));
+        assert!(!lower.is_lower_triangular_within(1e-14));
+
+        let mut upper = lower.transpose();
+        upper[[1, 0]] = -1e-12;
+        assert!(!upper.is_upper_triangular());
+        assert!(upper.is_upper_triangular_within(1e-10));
+
+        let mut diag = Matrix::<f64>::identity(3);
+        diag[[2, 1]] = 1e-12;
+        assert!(!diag.is_diag());
+        assert!(diag.is_diag_within(1e-10));
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let mut a = Matrix::new(3, 3, vec![1., 2., 3., 2., 4., 5., 3., 5., 6.]);
+        assert!(a.is_symmetric());
+        assert!(a.is_symmetric_within(0.0));
+
+        a[[2, 0]] += 1e-12;
+        assert!(!a.is_symmetric());
+        assert!(a.is_symmetric_within(1e-10));
+        assert!(!a.is_symmetric_within(1e-14));
+    }
+
+    #[test]
+    fn test_is_symmetric_non_square() {
+        let a = Matrix::new(2, 3, vec![1., 2., 3., 2., 4., 5.]);
+
+        assert!(!a.is_symmetric());
+        assert!(!a.is_symmetric_within(1.0));
+    }
+
+    #[test]
+    fn test_symmetrize() {
+        let mut a = Matrix::new(3, 3, vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        a.symmetrize();
+
+        assert!(a.is_symmetric());
+        assert_eq!(a.into_vec(), vec![1., 3., 5., 3., 5., 7., 5., 7., 9.]);
+    }
+
+    #[test]
+    fn test_symmetrize_symmetric_is_noop() {
+        let data = vec![0.1, 0.7, 1e-300, 0.7, 3.3, -2.9, 1e-300, -2.9, 1e300];
+        let mut a = Matrix::new(3, 3, data.clone());
+        a.symmetrize();
+
+        let bits = |v: &[f64]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(a.data()), bits(&data));
+    }
+
+    #[test]
+    fn test_symmetrize_no_overflow() {
+        let max = ::std::f64::MAX;
+        let mut a = Matrix::new(2, 2, vec![1.0, max, max / 2.0, 1.0]);
+        a.symmetrize();
+
+        assert!(a.is_symmetric());
+        assert_eq!(a[[0, 1]], 0.75 * max);
+    }
+
+    #[test]
+    fn test_symmetrize_integer_rounds_down() {
+        let mut a = Matrix::new(3, 3, vec![0, 1, -4, 3, 0, 7, 1, 0, 0]);
+        a.symmetrize();
+
+        // The means of 1 and 3, -4 and 1, and 7 and 0.
+        assert_eq!(a.into_vec(), vec![0, 2, -2, 2, 0, 3, -2, 3, 0]);
+
+        let mut b = Matrix::new(2, 2, vec![0u8, 255, 0, 0]);
+        b.symmetrize();
+        assert_eq!(b.into_vec(), vec![0, 127, 127, 0]);
+    }
+
+    #[test]
+    fn test_transpose_into() {
+        let mut out = Matrix::new(0, 0, vec![]);
+
+        for &(rows, cols) in &[(1, 1), (1, 37), (37, 1), (5, 3), (33, 65), (64, 64), (100, 7)] {
+            let a = Matrix::new(rows, cols, (0..rows * cols).collect::<Vec<usize>>());
+
+            a.transpose_into(&mut out);
+            assert_eq!(out, a.transpose());
+
+            let mut back = Matrix::new(1, 1, vec![0]);
+            out.transpose_into(&mut back);
+            assert_eq!(back, a);
+        }
+    }
+
+    #[test]
+    fn test_transpose_into_slice() {
+        let a = Matrix::new(4, 5, (0..20).collect::<Vec<usize>>());
+        let slice = MatrixSlice::from_matrix(&a, [1, 1], 3, 2);
+        let mut out = Matrix::new(3, 3, vec![0; 9]);
+
+        slice.transpose_into(&mut out);
+        assert_eq!(out, slice.transpose());
+
+        let empty = Matrix::<usize>::new(0, 3, vec![]);
+        empty.transpose_into(&mut out);
+        assert_eq!((out.rows(), out.cols(), out.data().len()), (3, 0, 0));
+    }
+
+    #[test]
+    fn test_transpose_in_place() {
+        for &(rows, cols) in &[(4, 4), (1, 6), (6, 1), (3, 5)] {
+            let a = Matrix::new(rows, cols, (0..rows * cols).collect::<Vec<usize>>());
+            let mut b = a.clone();
 
-                Some(mem::transmute(iter_ptr))
-            }
-        } else {
-            None
+            b.transpose_in_place();
+            assert_eq!(b, a.transpose());
+
+            b.transpose_in_place();
+            assert_eq!(b, a);
         }
     }
-}
-    );
-);
 
-impl_slice_iter!(SliceIter, &'a T);
-impl_slice_iter!(SliceIterMut, &'a mut T);
+    #[test]
+    fn test_transpose_in_place_empty() {
+        let mut a = Matrix::<f64>::new(0, 0, vec![]);
+        a.transpose_in_place();
 
-#[cfg(test)]
-mod tests {
-    use super::{BaseMatrix, BaseMatrixMut};
-    use matrix::{Matrix, MatrixSlice, MatrixSliceMut, Axes};
+        assert_eq!(a.rows(), 0);
+        assert_eq!(a.cols(), 0);
+    }
 
     #[test]
-    #[should_panic]
-    fn make_slice_bad_dim() {
-        let a = Matrix::new(3, 3, vec![2.0; 9]);
-        let _ = MatrixSlice::from_matrix(&a, [1, 1], 3, 2);
+    fn test_map_with_index_identity() {
+        let a = Matrix::<f64>::zeros(4, 4);
+        let id = a.map_with_index(|i, j, _| if i == j { 1.0 } else { 0.0 });
+
+        assert_eq!(id, Matrix::identity(4));
     }
 
     #[test]
-    fn make_slice() {
-        let a = Matrix::new(3, 3, vec![2.0; 9]);
-        let b = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+    fn test_apply_with_index_zero_boundary() {
+        let mut a = Matrix::new(4, 5, vec![1; 20]);
+        let (rows, cols) = (a.rows(), a.cols());
+        a.apply_with_index(|i, j, x| {
+            if i == 0 || j == 0 || i == rows - 1 || j == cols - 1 { 0 } else { x }
+        });
 
-        assert_eq!(b.rows(), 2);
-        assert_eq!(b.cols(), 2);
+        assert_eq!(*a.data(), vec![0, 0, 0, 0, 0,
+                                   0, 1, 1, 1, 0,
+                                   0, 1, 1, 1, 0,
+                                   0, 0, 0, 0, 0]);
     }
 
     #[test]
-    fn reslice() {
-        let mut a = Matrix::new(4, 4, (0..16).collect::<Vec<_>>());
+    fn test_with_index_call_order_on_slice() {
+        let mut a = Matrix::new(4, 4, (0..16).collect::<Vec<usize>>());
+
+        let mut log = Vec::new();
+        let b = MatrixSlice::from_matrix(&a, [1, 1], 2, 3).map_with_index(|i, j, x| {
+            log.push((i, j, x));
+            x * 2
+        });
+        assert_eq!(log, vec![(0, 0, 5), (0, 1, 6), (0, 2, 7),
+                             (1, 0, 9), (1, 1, 10), (1, 2, 11)]);
+        assert_eq!(*b.data(), vec![10, 12, 14, 18, 20, 22]);
 
+        let mut log = Vec::new();
         {
-            let b = MatrixSlice::from_matrix(&a, [1, 1], 3, 3);
-            let c = b.reslice([0, 1], 2, 2);
+            let mut slice = MatrixSliceMut::from_matrix(&mut a, [2, 0], 2, 2);
+            slice.apply_with_index(|i, j, x| {
+                log.push((i, j));
+                x + 100
+            });
+        }
+        assert_eq!(log, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(*a.data(), vec![0, 1, 2, 3,
+                                   4, 5, 6, 7,
+                                   108, 109, 10, 11,
+                                   112, 113, 14, 15]);
+    }
 
-            assert_eq!(c.rows(), 2);
-            assert_eq!(c.cols(), 2);
+    #[test]
+    fn test_zip_map_max() {
+        let a = Matrix::new(2, 3, vec![1.0, 5.0, -2.0, 0.0, 7.0, 3.0]);
+        let b = Matrix::new(2, 3, vec![4.0, 2.0, -3.0, 0.5, 7.0, 8.0]);
 
-            assert_eq!(c[[0, 0]], 6);
-            assert_eq!(c[[0, 1]], 7);
-            assert_eq!(c[[1, 0]], 10);
-            assert_eq!(c[[1, 1]], 11);
-        }
+        let c = a.zip_map(&b, |x: f64, y| x.max(y));
+        assert_eq!(*c.data(), vec![4.0, 5.0, -2.0, 0.5, 7.0, 8.0]);
+    }
 
-        let b = MatrixSliceMut::from_matrix(&mut a, [1, 1], 3, 3);
+    #[test]
+    fn test_zip_map_mixed_types() {
+        let a = Matrix::new(2, 2, vec![1.5, 2.5, 3.5, 4.5]);
+        let b = Matrix::new(2, 2, vec![2i32, -1, 0, 3]);
 
-        let c = b.reslice([0, 1], 2, 2);
+        let c = a.zip_map(&b, |x, y| x * y as f64);
+        assert_eq!(*c.data(), vec![3.0, -2.5, 0.0, 13.5]);
+    }
 
-        assert_eq!(c.rows(), 2);
-        assert_eq!(c.cols(), 2);
+    #[test]
+    #[should_panic]
+    fn test_zip_map_dim_mismatch() {
+        let a = Matrix::new(2, 2, vec![1.0; 4]);
+        let b = Matrix::new(2, 3, vec![1.0; 6]);
 
-        assert_eq!(c[[0, 0]], 6);
-        assert_eq!(c[[0, 1]], 7);
-        assert_eq!(c[[1, 0]], 10);
-        assert_eq!(c[[1, 1]], 11);
+        let _ = a.zip_map(&b, |x, y| x + y);
     }
 
     #[test]
-    fn test_sub_slice() {
-        let mut a = Matrix::new(4, 4, (0..16).collect::<Vec<_>>());
-        {
-            let slice = a.sub_slice([1, 1], 3, 2);
-            assert_eq!(&slice.iter().cloned().collect::<Vec<_>>(), &vec![5, 6, 9, 10, 13, 14]);
+    #[should_panic]
+    fn test_zip_apply_dim_mismatch() {
+        let mut a = Matrix::new(2, 2, vec![1.0; 4]);
+        let b = Matrix::new(3, 2, vec![1.0; 6]);
 
-            let slice = slice.sub_slice([1, 1], 2, 1);
-            assert_eq!(&slice.iter().cloned().collect::<Vec<_>>(), &vec![10, 14]);
-        }
-        {
-            let mut slice_mut = a.sub_slice_mut([3, 1], 1, 1);
-            unsafe {
-                *slice_mut.get_unchecked_mut([0, 0]) = 25;
-                assert_eq!(*a.get_unchecked([3, 1]), 25);
-            }
-        }
+        a.zip_apply(&b, |x, y| x + y);
     }
 
     #[test]
-    fn slice_into_matrix() {
-        let mut a = Matrix::new(3, 3, vec![2.0; 9]);
+    fn test_zip_on_slices() {
+        let mut a = Matrix::new(3, 3, (0..9).collect::<Vec<i32>>());
+        let b = Matrix::new(3, 3, vec![10; 9]);
+
+        let c = MatrixSlice::from_matrix(&a, [1, 1], 2, 2)
+            .zip_map(&MatrixSlice::from_matrix(&b, [0, 0], 2, 2), |x, y| x + y);
+        assert_eq!(*c.data(), vec![14, 15, 17, 18]);
 
         {
-            let b = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
-            let c = b.into_matrix();
-            assert_eq!(c.rows(), 2);
-            assert_eq!(c.cols(), 2);
+            let mut slice = MatrixSliceMut::from_matrix(&mut a, [0, 1], 3, 2);
+            let mask = Matrix::new(3, 2, vec![true, false, false, true, true, true]);
+            slice.zip_apply(&mask, |x, m| if m { -x } else { x });
         }
-
-        let d = MatrixSliceMut::from_matrix(&mut a, [1, 1], 2, 2);
-        let e = d.into_matrix();
-        assert_eq!(e.rows(), 2);
-        assert_eq!(e.cols(), 2);
+        assert_eq!(*a.data(), vec![0, -1, 2, 3, 4, -5, 6, -7, -8]);
     }
 
     #[test]
-    fn test_split_matrix() {
-        let a = Matrix::new(3, 3, (0..9).collect::<Vec<_>>());
+    fn test_transpose_matches_naive() {
+        for &(rows, cols) in &[(0, 5), (1, 1), (33, 7), (7, 100), (130, 65), (64, 64)] {
+            let a = Matrix::new(rows, cols, (0..rows * cols).collect::<Vec<usize>>());
+            let at = a.transpose();
+
+            assert_eq!(at.rows(), cols);
+            assert_eq!(at.cols(), rows);
+            for i in 0..rows {
+                for j in 0..cols {
+                    assert_eq!(at[[j, i]], a[[i, j]]);
+                }
+            }
+        }
 
-        let (b, c) = a.split_at(1, Axes::Row);
+        let a = Matrix::new(100, 90, (0..9000).collect::<Vec<usize>>());
+        let slice = MatrixSlice::from_matrix(&a, [3, 5], 70, 41);
+        let st = slice.transpose();
+        for i in 0..70 {
+            for j in 0..41 {
+                assert_eq!(st[[j, i]], a[[i + 3, j + 5]]);
+            }
+        }
+    }
 
-        assert_eq!(b.rows(), 1);
+    #[test]
+    fn test_map_f64_to_f32() {
+        let a = Matrix::new(2, 3, vec![1.5f64, -2.25, 3.0, 0.0, 1e10, -0.5]);
+        let b = a.map(|x| x as f32);
+
+        assert_eq!(b.rows(), 2);
         assert_eq!(b.cols(), 3);
-        assert_eq!(c.rows(), 2);
-        assert_eq!(c.cols(), 3);
+        assert_eq!(*b.data(), vec![1.5f32, -2.25, 3.0, 0.0, 1e10, -0.5]);
 
-        assert_eq!(b[[0, 0]], 0);
-        assert_eq!(b[[0, 1]], 1);
-        assert_eq!(b[[0, 2]], 2);
-        assert_eq!(c[[0, 0]], 3);
-        assert_eq!(c[[0, 1]], 4);
-        assert_eq!(c[[0, 2]], 5);
-        assert_eq!(c[[1, 0]], 6);
-        assert_eq!(c[[1, 1]], 7);
-        assert_eq!(c[[1, 2]], 8);
+        let c = a.map_into(|x| x as f32);
+        assert_eq!(c, b);
     }
 
     #[test]
-    fn test_split_matrix_mut() {
-        let mut a = Matrix::new(3, 3, (0..9).collect::<Vec<_>>());
+    fn test_map_i32_to_f64() {
+        let a = Matrix::new(3, 3, (0..9).collect::<Vec<i32>>());
+        let slice = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
 
-        {
-            let (mut b, mut c) = a.split_at_mut(1, Axes::Row);
+        let b = slice.map(|x| x as f64 * 0.5);
+        assert_eq!(*b.data(), vec![2.0, 2.5, 3.5, 4.0]);
+    }
 
-            assert_eq!(b.rows(), 1);
-            assert_eq!(b.cols(), 3);
-            assert_eq!(c.rows(), 2);
-            assert_eq!(c.cols(), 3);
+    #[test]
+    fn test_map_bool_mask() {
+        let a = Matrix::new(2, 2, vec![1.0, 5.0, -3.0, 2.0]);
+        let mask = a.map(|x| x >= 2.0);
 
-            assert_eq!(b[[0, 0]], 0);
-            assert_eq!(b[[0, 1]], 1);
-            assert_eq!(b[[0, 2]], 2);
-            assert_eq!(c[[0, 0]], 3);
-            assert_eq!(c[[0, 1]], 4);
-            assert_eq!(c[[0, 2]], 5);
-            assert_eq!(c[[1, 0]], 6);
-            assert_eq!(c[[1, 1]], 7);
-            assert_eq!(c[[1, 2]], 8);
+        assert_eq!(*mask.data(), vec![false, true, false, true]);
+    }
 
-            b[[0, 0]] = 4;
-            c[[0, 0]] = 5;
-        }
+    #[test]
+    fn test_map_empty() {
+        let a = Matrix::<f64>::new(0, 3, vec![]);
 
-        assert_eq!(a[[0, 0]], 4);
-        assert_eq!(a[[0, 1]], 1);
-        assert_eq!(a[[0, 2]], 2);
-        assert_eq!(a[[1, 0]], 5);
-        assert_eq!(a[[1, 1]], 4);
-        assert_eq!(a[[1, 2]], 5);
-        assert_eq!(a[[2, 0]], 6);
-        assert_eq!(a[[2, 1]], 7);
-        assert_eq!(a[[2, 2]], 8);
+        let b = a.map(|x| x as i64);
+        assert_eq!(b.rows(), 0);
+        assert_eq!(b.cols(), 3);
+
+        let c = a.map_into(|x| x > 0.0);
+        assert_eq!(c.rows(), 0);
+        assert_eq!(c.cols(), 3);
     }
 
     #[test]
-    fn test_swap_rows() {
-        let mut a = Matrix::new(4, 3, (0..12).collect::<Vec<usize>>());
-        a.swap_rows(0, 1);
+    fn test_argmax_argmin_ties() {
+        let a = Matrix::new(3, 3, vec![1, 7, 2,
+                                       7, 0, 0,
+                                       3, 7, 0]);
 
-        assert_eq!(a.data(), &[3, 4, 5, 0, 1, 2, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(a.argmax(), Some((0, 1)));
+        assert_eq!(a.argmin(), Some((1, 1)));
+    }
 
-        {
-            let mut b = MatrixSliceMut::from_matrix(&mut a, [0, 0], 4, 2);
-            b.swap_rows(0, 1);
-        }
+    #[test]
+    fn test_argmax_argmin_nan() {
+        let nan = ::std::f64::NAN;
+        let a = Matrix::new(2, 3, vec![nan, 1.0, nan,
+                                       -2.0, nan, 5.0]);
 
-        assert_eq!(a.into_vec(), vec![0, 1, 5, 3, 4, 2, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(a.argmax(), Some((1, 2)));
+        assert_eq!(a.argmin(), Some((1, 0)));
+
+        let all_nan = Matrix::new(2, 2, vec![nan; 4]);
+        assert_eq!(all_nan.argmax(), Some((0, 0)));
+        assert_eq!(all_nan.argmin(), Some((0, 0)));
     }
 
     #[test]
-    fn test_matrix_swap_cols() {
-        let mut a = Matrix::new(4, 3, (0..12).collect::<Vec<usize>>());
-        a.swap_cols(0, 1);
+    fn test_argmax_argmin_small() {
+        let a = Matrix::new(1, 1, vec![3.0]);
+        assert_eq!(a.argmax(), Some((0, 0)));
+        assert_eq!(a.argmin(), Some((0, 0)));
 
-        assert_eq!(a.data(), &[1, 0, 2, 4, 3, 5, 7, 6, 8, 10, 9, 11]);
+        let empty = Matrix::<f64>::new(0, 0, vec![]);
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.argmin(), None);
 
-        {
-            let mut b = MatrixSliceMut::from_matrix(&mut a, [0, 0], 3, 3);
-            b.swap_cols(0, 2);
-        }
+        let no_cols = Matrix::<f64>::new(3, 0, vec![]);
+        assert_eq!(no_cols.argmax(), None);
+    }
 
-        assert_eq!(a.into_vec(), vec![2, 0, 1, 5, 3, 4, 8, 6, 7, 10, 9, 11]);
+    #[test]
+    fn test_col_row_argmax_argmin() {
+        let a = Matrix::new(3, 3, vec![-1.0, 4.0, -7.0,
+                                       -5.0, 4.0, 2.0,
+                                       -1.0, -9.0, 2.0]);
+
+        assert_eq!(a.col_argmax(), vec![Some(0), Some(0), Some(1)]);
+        assert_eq!(a.col_argmin(), vec![Some(1), Some(2), Some(0)]);
+        assert_eq!(a.row_argmax(), vec![Some(1), Some(1), Some(2)]);
+        assert_eq!(a.row_argmin(), vec![Some(2), Some(0), Some(1)]);
+
+        let single = Matrix::new(1, 3, vec![3, -2, 3]);
+        assert_eq!(single.col_argmax(), vec![Some(0); 3]);
+        assert_eq!(single.row_argmax(), vec![Some(0)]);
+        assert_eq!(single.row_argmin(), vec![Some(1)]);
     }
 
     #[test]
-    fn test_matrix_swap_same_rows() {
-        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-        a.swap_rows(0, 0);
+    fn test_col_row_argmax_nan() {
+        let nan = ::std::f64::NAN;
+        let a = Matrix::new(3, 2, vec![nan, nan,
+                                       1.0, nan,
+                                       -2.0, nan]);
+
+        assert_eq!(a.col_argmax(), vec![Some(1), None]);
+        assert_eq!(a.col_argmin(), vec![Some(2), None]);
+        assert_eq!(a.row_argmax(), vec![None, Some(0), Some(0)]);
+
+        let no_rows = Matrix::<f64>::new(0, 2, vec![]);
+        assert_eq!(no_rows.col_argmin(), vec![None, None]);
+        assert!(no_rows.row_argmin().is_empty());
+    }
 
-        assert_eq!(a.into_vec(), (0..8).collect::<Vec<usize>>());
+    #[test]
+    fn test_argmax_argmin_axis() {
+        let a = Matrix::new(3, 4, vec![1.0, 9.0, 3.0, 3.0,
+                                       8.0, 2.0, 3.0, -1.0,
+                                       8.0, 4.0, 0.0, 6.0]);
+
+        assert_eq!(a.argmax_axis(Axes::Row), vec![1, 0, 0, 2]);
+        assert_eq!(a.argmin_axis(Axes::Row), vec![0, 1, 2, 1]);
+        assert_eq!(a.argmax_axis(Axes::Col), vec![1, 0, 0]);
+        assert_eq!(a.argmin_axis(Axes::Col), vec![0, 3, 2]);
+
+        let slice = MatrixSlice::from_matrix(&a, [1, 1], 2, 3);
+        assert_eq!(slice.argmax(), Some((1, 2)));
+        assert_eq!(slice.argmax_axis(Axes::Col), vec![1, 2]);
+        assert_eq!(slice.argmin_axis(Axes::Row), vec![0, 1, 0]);
+
+        let nan = ::std::f64::NAN;
+        let b = Matrix::new(2, 2, vec![nan, nan, 1.0, nan]);
+        assert_eq!(b.argmax_axis(Axes::Row), vec![1, 0]);
+        assert_eq!(b.argmin_axis(Axes::Col), vec![0, 0]);
     }
 
     #[test]
-    fn test_matrix_swap_same_cols() {
-        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-        a.swap_cols(0, 0);
+    #[should_panic]
+    fn test_argmax_axis_empty_lane() {
+        Matrix::<f64>::new(0, 2, vec![]).argmax_axis(Axes::Row);
+    }
 
-        assert_eq!(a.into_vec(), (0..8).collect::<Vec<usize>>());
+    #[test]
+    fn test_broadcast_mean_centering() {
+        let a = Matrix::new(4, 3, vec![1.0f64, 10.0, -2.0,
+                                       3.0, 14.0, 0.0,
+                                       5.0, 12.0, 4.0,
+                                       7.0, 8.0, 2.0]);
+
+        let centered = a.sub_row_vector(&a.mean(Axes::Row));
+        for m in centered.mean(Axes::Row).data() {
+            assert!(m.abs() < 1e-12);
+        }
+
+        let row_centered = a.sub_col_vector(&a.mean(Axes::Col));
+        for m in row_centered.mean(Axes::Col).data() {
+            assert!(m.abs() < 1e-12);
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn test_matrix_swap_row_high_first() {
-        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-        a.swap_rows(5, 0);
+    fn test_broadcast_in_place_matches() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let r = Vector::new(vec![10, 20, 30]);
+        let c = Vector::new(vec![1, 2]);
+
+        let mut b = a.clone();
+        b.add_row_vector_mut(&r);
+        assert_eq!(b, a.add_row_vector(&r));
+        assert_eq!(*b.data(), vec![11, 22, 33, 14, 25, 36]);
+
+        let mut b = a.clone();
+        b.mul_row_vector_mut(&r);
+        assert_eq!(b, a.mul_row_vector(&r));
+        assert_eq!(*b.data(), vec![10, 40, 90, 40, 100, 180]);
+
+        let mut b = a.clone();
+        b.add_col_vector_mut(&c);
+        assert_eq!(b, a.add_col_vector(&c));
+        assert_eq!(*b.data(), vec![2, 3, 4, 6, 7, 8]);
+
+        let mut b = a.clone();
+        b.sub_col_vector_mut(&c);
+        assert_eq!(b, a.sub_col_vector(&c));
+        assert_eq!(*b.data(), vec![0, 1, 2, 2, 3, 4]);
     }
 
     #[test]
-    #[should_panic]
-    fn test_matrix_swap_row_high_second() {
-        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-        a.swap_rows(0, 5);
+    fn test_broadcast_slices() {
+        let mut a = Matrix::new(3, 3, (0..9).collect::<Vec<i32>>());
+        let v = Vector::new(vec![100, 200]);
+
+        {
+            let slice = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+            assert_eq!(*slice.add_row_vector(&v).data(), vec![104, 205, 107, 208]);
+            assert_eq!(*slice.sub_col_vector(&v).data(), vec![-96, -95, -193, -192]);
+        }
+
+        {
+            let mut slice = MatrixSliceMut::from_matrix(&mut a, [0, 1], 2, 2);
+            slice.add_col_vector_mut(&v);
+        }
+        assert_eq!(*a.data(), vec![0, 101, 102, 3, 204, 205, 6, 7, 8]);
     }
 
     #[test]
     #[should_panic]
-    fn test_matrix_swap_col_high_first() {
-        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-        a.swap_cols(2, 1);
+    fn test_broadcast_row_vector_bad_size() {
+        let a = Matrix::new(2, 3, vec![0.0; 6]);
+        let _ = a.add_row_vector(&Vector::new(vec![1.0, 2.0]));
     }
 
     #[test]
     #[should_panic]
-    fn test_matrix_swap_col_high_second() {
-        let mut a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-        a.swap_cols(1, 2);
+    fn test_broadcast_col_vector_bad_size() {
+        let mut a = Matrix::new(2, 3, vec![0.0; 6]);
+        a.sub_col_vector_mut(&Vector::new(vec![1.0, 2.0, 3.0]));
     }
 
     #[test]
-    fn test_matrix_select_rows() {
-        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+    fn test_get_checked() {
+        let mut a = Matrix::new(4, 3, (0..12).collect::<Vec<i32>>());
 
-        let b = a.select_rows(&[0, 2, 3]);
+        {
+            let slice = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+            for i in 0..2 {
+                for j in 0..2 {
+                    assert_eq!(slice.get([i, j]), Some(unsafe { slice.get_unchecked([i, j]) }));
+                }
+            }
+            assert_eq!(slice.get([0, 0]), Some(&4));
+            assert_eq!(slice.get([2, 0]), None);
+            assert_eq!(slice.get([0, 2]), None);
+        }
 
-        assert_eq!(b.into_vec(), vec![0, 1, 4, 5, 6, 7]);
+        {
+            let mut slice = MatrixSliceMut::from_matrix(&mut a, [2, 0], 2, 3);
+            *slice.get_mut([1, 2]).unwrap() = -1;
+            assert!(slice.get_mut([2, 2]).is_none());
+        }
+
+        assert_eq!(a.get([3, 2]), Some(&-1));
+        assert_eq!(a.get([4, 0]), None);
+        assert_eq!(a.get([0, usize::max_value()]), None);
     }
 
     #[test]
-    fn test_matrix_select_cols() {
-        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
+    fn test_row_col_opt() {
+        let a = Matrix::new(3, 3, (0..9).collect::<Vec<i32>>());
+        let slice = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
 
-        let b = a.select_cols(&[1]);
+        assert_eq!(slice.row_opt(1), Some(&[7, 8][..]));
+        assert!(slice.row_opt(2).is_none());
 
-        assert_eq!(b.into_vec(), vec![1, 3, 5, 7]);
+        let col = slice.col_opt(0).unwrap();
+        assert_eq!((col[0], col[1]), (4, 7));
+        assert!(slice.col_opt(2).is_none());
     }
 
     #[test]
-    fn test_matrix_select() {
-        let a = Matrix::new(4, 2, (0..8).collect::<Vec<usize>>());
-
-        let b = a.select(&[0, 2], &[1]);
-
-        assert_eq!(b.into_vec(), vec![1, 5]);
+    #[should_panic(expected = "Index [1, 3] is out of bounds for a 2x3 matrix.")]
+    fn test_index_panic_message() {
+        let a = Matrix::new(2, 3, vec![0; 6]);
+        let _ = a[[1, 3]];
     }
 
     #[test]
-    fn matrix_diag() {
-        let a = Matrix::new(3, 3, vec![1., 3., 5., 2., 4., 7., 1., 1., 0.]);
+    #[should_panic(expected = "Index [2, 0] is out of bounds for a 2x2 matrix.")]
+    fn test_slice_index_panic_message() {
+        let a = Matrix::new(3, 3, vec![0; 9]);
+        let slice = MatrixSlice::from_matrix(&a, [1, 1], 2, 2);
+        let _ = slice[[2, 0]];
+    }
 
-        let b = a.is_diag();
+    #[test]
+    fn test_elemul_elediv_assign() {
+        let mut a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::new(2, 2, vec![2.0, 0.5, -1.0, 4.0]);
 
-        assert!(!b);
+        a.elemul_assign(&b);
+        assert_eq!(a, Matrix::new(2, 2, vec![2.0, 1.0, -3.0, 16.0]));
+        assert_eq!(a, Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]).elemul(&b));
 
-        let c = Matrix::new(3, 3, vec![1., 0., 0., 0., 2., 0., 0., 0., 3.]);
-        let d = c.is_diag();
+        a.elediv_assign(&b);
+        assert_eq!(a, Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
 
-        assert!(d);
+        let c = Matrix::new(3, 3, (1..10).map(|x| x as f64).collect::<Vec<_>>());
+        a.elemul_assign(&MatrixSlice::from_matrix(&c, [1, 1], 2, 2));
+        assert_eq!(a.into_vec(), vec![5.0, 12.0, 24.0, 36.0]);
     }
 
     #[test]
-    fn transpose_mat() {
-        let a = Matrix::new(5, 2, vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10.]);
-
-        let c = a.transpose();
+    fn test_elemul_elediv_assign_slice() {
+        let mut a = Matrix::new(3, 3, (1..10).map(|x| x as f64).collect::<Vec<_>>());
+        let b = Matrix::new(2, 2, vec![2.0, 2.0, 0.5, 0.5]);
+        {
+            let mut a_slice = MatrixSliceMut::from_matrix(&mut a, [1, 1], 2, 2);
+            a_slice.elemul_assign(&b);
+        }
+        assert_eq!(a.data(), &vec![1.0, 2.0, 3.0, 4.0, 10.0, 12.0, 7.0, 4.0, 4.5]);
 
-        assert_eq!(c.cols(), a.rows());
-        assert_eq!(c.rows(), a.cols());
+        let mut c = Matrix::new(2, 3, vec![1.0, 1.0, 2.0, 2.0, 2.0, 2.0]);
+        {
+            let c_slice = MatrixSliceMut::from_matrix(&mut c, [0, 1], 2, 2);
+            let mut a_slice = MatrixSliceMut::from_matrix(&mut a, [1, 1], 2, 2);
+            a_slice.elediv_assign(&c_slice);
+        }
+        assert_eq!(a.into_vec(), vec![1.0, 2.0, 3.0, 4.0, 10.0, 6.0, 7.0, 2.0, 2.25]);
+    }
 
-        assert_eq!(a[[0, 0]], c[[0, 0]]);
-        assert_eq!(a[[1, 0]], c[[0, 1]]);
-        assert_eq!(a[[2, 0]], c[[0, 2]]);
-        assert_eq!(a[[3, 0]], c[[0, 3]]);
-        assert_eq!(a[[4, 0]], c[[0, 4]]);
-        assert_eq!(a[[0, 1]], c[[1, 0]]);
-        assert_eq!(a[[1, 1]], c[[1, 1]]);
-        assert_eq!(a[[2, 1]], c[[1, 2]]);
-        assert_eq!(a[[3, 1]], c[[1, 3]]);
-        assert_eq!(a[[4, 1]], c[[1, 4]]);
+    #[test]
+    #[should_panic]
+    fn test_elemul_assign_dimension_mismatch() {
+        let mut a = Matrix::new(2, 2, vec![1.0; 4]);
+        a.elemul_assign(&Matrix::new(1, 4, vec![1.0; 4]));
     }
 }
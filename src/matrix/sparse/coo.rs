@@ -0,0 +1,198 @@
+use std::ops::AddAssign;
+use libnum::Zero;
+
+use matrix::{Matrix, BaseMatrix};
+use super::{CscMatrix, CsrMatrix};
+
+/// A sparse matrix in coordinate (COO) format.
+///
+/// Entries are stored as unordered `(row, col, value)` triplets, which
+/// makes this format convenient for assembling a matrix before
+/// converting it to `CsrMatrix` or `CscMatrix`. Duplicate entries are
+/// allowed and are summed on conversion.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::Matrix;
+/// use rulinalg::matrix::sparse::CooMatrix;
+///
+/// let mut a = CooMatrix::new(2, 2);
+/// a.push(0, 0, 1.0);
+/// a.push(1, 1, 2.0);
+/// a += (0, 0, 3.0);
+///
+/// assert_eq!(a.to_csr().to_dense(), Matrix::new(2, 2, vec![4.0, 0.0, 0.0, 2.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T> CooMatrix<T> {
+    /// Constructs an empty COO matrix with the given dimensions.
+    pub fn new(rows: usize, cols: usize) -> CooMatrix<T> {
+        CooMatrix {
+            rows: rows,
+            cols: cols,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries.
+    ///
+    /// Duplicate entries are counted separately.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the stored `(row, col, value)` triplets.
+    pub fn entries(&self) -> &Vec<(usize, usize, T)> {
+        &self.entries
+    }
+
+    /// Adds the entry `val` at position `[i, j]`.
+    ///
+    /// If an entry already exists at this position the two
+    /// are summed on conversion.
+    ///
+    /// # Panics
+    ///
+    /// - The index is out of bounds.
+    pub fn push(&mut self, i: usize, j: usize, val: T) {
+        assert!(i < self.rows, "Row index is greater than row dimension.");
+        assert!(j < self.cols, "Column index is greater than column dimension.");
+
+        self.entries.push((i, j, val));
+    }
+}
+
+impl<T: Copy + Zero> CooMatrix<T> {
+    /// Constructs a COO matrix from the non-zero entries of a dense matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::matrix::sparse::CooMatrix;
+    ///
+    /// let a = CooMatrix::from_dense(&Matrix::new(2, 2, vec![0, 1, 2, 0]));
+    ///
+    /// assert_eq!(a.nnz(), 2);
+    /// ```
+    pub fn from_dense(m: &Matrix<T>) -> CooMatrix<T> {
+        let mut coo = CooMatrix::new(m.rows(), m.cols());
+
+        for (i, row) in m.iter_rows().enumerate() {
+            for (j, &val) in row.iter().enumerate() {
+                if !val.is_zero() {
+                    coo.entries.push((i, j, val));
+                }
+            }
+        }
+
+        coo
+    }
+
+    /// Converts the matrix into compressed sparse row format.
+    ///
+    /// Duplicate entries are summed.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix::from_triplets(self.rows, self.cols, &self.entries)
+    }
+
+    /// Converts the matrix into compressed sparse column format.
+    ///
+    /// Duplicate entries are summed.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix::from_triplets(self.rows, self.cols, &self.entries)
+    }
+}
+
+/// Adds a `(row, col, value)` entry to the matrix.
+///
+/// Equivalent to calling `push`.
+impl<T> AddAssign<(usize, usize, T)> for CooMatrix<T> {
+    fn add_assign(&mut self, entry: (usize, usize, T)) {
+        self.push(entry.0, entry.1, entry.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::Matrix;
+    use super::CooMatrix;
+
+    #[test]
+    fn test_coo_duplicates_summed() {
+        let mut a = CooMatrix::new(3, 3);
+        a.push(0, 1, 1.0);
+        a.push(2, 2, 5.0);
+        a.push(0, 1, 2.0);
+        a += (2, 2, -1.0);
+        a += (1, 0, 4.0);
+
+        assert_eq!(a.nnz(), 5);
+
+        let expected = Matrix::new(3, 3, vec![0.0, 3.0, 0.0,
+                                              4.0, 0.0, 0.0,
+                                              0.0, 0.0, 4.0]);
+        let csr = a.to_csr();
+        let csc = a.to_csc();
+
+        assert_eq!(csr.nnz(), 3);
+        assert_eq!(csc.nnz(), 3);
+        assert_eq!(csr.to_dense(), expected);
+        assert_eq!(csc.to_dense(), expected);
+    }
+
+    #[test]
+    fn test_coo_from_dense_roundtrip() {
+        let m = Matrix::new(3, 4, vec![1.0, 0.0, 0.0, -2.0,
+                                       0.0, 0.0, 0.0, 0.0,
+                                       0.0, 3.5, 7.0, 0.0]);
+        let a = CooMatrix::from_dense(&m);
+
+        assert_eq!(a.nnz(), 4);
+        assert_eq!(a.to_csr().to_dense(), m);
+        assert_eq!(a.to_csc().to_dense(), m);
+    }
+
+    #[test]
+    fn test_coo_assembly_order() {
+        let entries = vec![(0, 0, 1.0), (3, 1, 2.0), (1, 2, -1.0),
+                           (0, 0, 0.5), (2, 3, 4.0), (3, 1, 1.0)];
+
+        let mut forward = CooMatrix::new(4, 4);
+        for &e in &entries {
+            forward += e;
+        }
+
+        let mut backward = CooMatrix::new(4, 4);
+        for &e in entries.iter().rev() {
+            backward += e;
+        }
+
+        assert_eq!(forward.to_csr(), backward.to_csr());
+        assert_eq!(forward.to_csc(), backward.to_csc());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_coo_push_out_of_bounds() {
+        let mut a = CooMatrix::new(2, 2);
+        a.push(0, 2, 1.0);
+    }
+}
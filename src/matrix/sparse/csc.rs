@@ -0,0 +1,233 @@
+use std::ops::{Mul, Add};
+use libnum::Zero;
+
+use matrix::Matrix;
+use vector::Vector;
+use super::compress;
+
+/// A sparse matrix in compressed sparse column (CSC) format.
+///
+/// The row indices and values of the non-zero entries are stored
+/// column by column. The entries of column `j` are found in the range
+/// `col_ptr[j]..col_ptr[j + 1]`, sorted by row.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::sparse::CscMatrix;
+/// use rulinalg::vector::Vector;
+///
+/// let a = CscMatrix::from_triplets(2, 3, &[(0, 0, 1.0), (1, 2, 2.0)]);
+/// let y = a * Vector::new(vec![1.0, 1.0, 1.0]);
+///
+/// assert_eq!(y, Vector::new(vec![1.0, 2.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+    row_indices: Vec<usize>,
+    col_ptr: Vec<usize>,
+}
+
+impl<T> CscMatrix<T> {
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the stored values.
+    pub fn data(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// Returns the row index of each stored value.
+    pub fn row_indices(&self) -> &Vec<usize> {
+        &self.row_indices
+    }
+
+    /// Returns the offsets of the start of each column in the stored values.
+    ///
+    /// Has length `cols + 1`, with the final entry equal to `nnz()`.
+    pub fn col_ptr(&self) -> &Vec<usize> {
+        &self.col_ptr
+    }
+}
+
+impl<T: Copy + Zero + Add<T, Output = T>> CscMatrix<T> {
+    /// Constructs a CSC matrix from `(row, col, value)` triplets.
+    ///
+    /// The triplets may be given in any order. Duplicate entries
+    /// are summed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::sparse::CscMatrix;
+    ///
+    /// let a = CscMatrix::from_triplets(2, 2, &[(1, 1, 2), (1, 0, 1), (1, 1, 3)]);
+    ///
+    /// assert_eq!(a.nnz(), 2);
+    /// assert_eq!(a.get_elem(1, 1), 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - A triplet lies outside of the matrix dimensions.
+    pub fn from_triplets(rows: usize, cols: usize, tri: &[(usize, usize, T)]) -> CscMatrix<T> {
+        for &(i, j, _) in tri {
+            assert!(i < rows && j < cols, "Triplet index is outside of the matrix.");
+        }
+
+        let transposed = tri.iter().map(|&(i, j, v)| (j, i, v)).collect();
+        let (col_ptr, row_indices, data) = compress(cols, transposed);
+
+        CscMatrix {
+            rows: rows,
+            cols: cols,
+            data: data,
+            row_indices: row_indices,
+            col_ptr: col_ptr,
+        }
+    }
+
+    /// Returns the `[i, j]` element of the matrix.
+    ///
+    /// Uses a binary search over the stored entries of column `j`.
+    ///
+    /// # Panics
+    ///
+    /// - The index is out of bounds.
+    pub fn get_elem(&self, i: usize, j: usize) -> T {
+        assert!(i < self.rows, "Row index is greater than row dimension.");
+        assert!(j < self.cols, "Column index is greater than column dimension.");
+
+        let (start, end) = (self.col_ptr[j], self.col_ptr[j + 1]);
+        match self.row_indices[start..end].binary_search(&i) {
+            Ok(idx) => self.data[start + idx],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Converts the matrix into a dense `Matrix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::matrix::sparse::CscMatrix;
+    ///
+    /// let a = CscMatrix::from_triplets(2, 2, &[(0, 1, 1), (1, 0, 2)]);
+    ///
+    /// assert_eq!(a.to_dense(), Matrix::new(2, 2, vec![0, 1, 2, 0]));
+    /// ```
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data = vec![T::zero(); self.rows * self.cols];
+
+        for j in 0..self.cols {
+            for idx in self.col_ptr[j]..self.col_ptr[j + 1] {
+                data[self.row_indices[idx] * self.cols + j] = self.data[idx];
+            }
+        }
+
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+impl<T> Mul<Vector<T>> for CscMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        (&self) * (&v)
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+impl<'a, T> Mul<Vector<T>> for &'a CscMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        self * (&v)
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+impl<'a, T> Mul<&'a Vector<T>> for CscMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        (&self) * v
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+///
+/// Runs in `O(nnz + rows + cols)` time.
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a CscMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        assert!(v.size() == self.cols, "Matrix and Vector dimensions do not agree.");
+
+        let x = v.data();
+        let mut new_data = vec![T::zero(); self.rows];
+
+        for j in 0..self.cols {
+            for idx in self.col_ptr[j]..self.col_ptr[j + 1] {
+                let i = self.row_indices[idx];
+                new_data[i] = new_data[i] + self.data[idx] * x[j];
+            }
+        }
+
+        Vector::new(new_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::Matrix;
+    use vector::Vector;
+    use super::CscMatrix;
+
+    #[test]
+    fn test_csc_to_dense() {
+        let tri = vec![(0, 1, 1.0), (0, 3, 2.0), (2, 0, 3.0), (1, 3, 4.0), (0, 1, 1.0)];
+        let a = CscMatrix::from_triplets(3, 4, &tri);
+
+        assert_eq!(a.nnz(), 4);
+        assert_eq!(*a.col_ptr(), vec![0, 1, 2, 2, 4]);
+        assert_eq!(*a.row_indices(), vec![2, 0, 0, 1]);
+        assert_eq!(a.to_dense(), Matrix::new(3, 4, vec![0.0, 2.0, 0.0, 2.0,
+                                                        0.0, 0.0, 0.0, 4.0,
+                                                        3.0, 0.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_csc_mul_vector() {
+        let tri = vec![(0, 1, 1.0), (0, 3, 2.0), (2, 0, 3.0), (1, 3, 4.0)];
+        let a = CscMatrix::from_triplets(3, 4, &tri);
+        let x = Vector::new(vec![1.0, -1.0, 2.0, 0.5]);
+
+        assert_eq!(&a * &x, a.to_dense() * x);
+    }
+}
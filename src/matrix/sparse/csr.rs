@@ -0,0 +1,338 @@
+use std::ops::{Mul, Add};
+use libnum::Zero;
+
+use matrix::Matrix;
+use vector::Vector;
+use super::compress;
+
+/// A sparse matrix in compressed sparse row (CSR) format.
+///
+/// The column indices and values of the non-zero entries are stored
+/// row by row. The entries of row `i` are found in the range
+/// `row_ptr[i]..row_ptr[i + 1]`, sorted by column.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::sparse::CsrMatrix;
+/// use rulinalg::vector::Vector;
+///
+/// let a = CsrMatrix::from_triplets(2, 3, &[(0, 0, 1.0), (1, 2, 2.0)]);
+/// let y = a * Vector::new(vec![1.0, 1.0, 1.0]);
+///
+/// assert_eq!(y, Vector::new(vec![1.0, 2.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+    col_indices: Vec<usize>,
+    row_ptr: Vec<usize>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the stored values.
+    pub fn data(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// Returns the column index of each stored value.
+    pub fn col_indices(&self) -> &Vec<usize> {
+        &self.col_indices
+    }
+
+    /// Returns the offsets of the start of each row in the stored values.
+    ///
+    /// Has length `rows + 1`, with the final entry equal to `nnz()`.
+    pub fn row_ptr(&self) -> &Vec<usize> {
+        &self.row_ptr
+    }
+}
+
+impl<T: Copy + Zero + Add<T, Output = T>> CsrMatrix<T> {
+    /// Constructs a CSR matrix from `(row, col, value)` triplets.
+    ///
+    /// The triplets may be given in any order. Duplicate entries
+    /// are summed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::sparse::CsrMatrix;
+    ///
+    /// let a = CsrMatrix::from_triplets(2, 2, &[(1, 1, 2), (0, 1, 1), (1, 1, 3)]);
+    ///
+    /// assert_eq!(a.nnz(), 2);
+    /// assert_eq!(a.get_elem(1, 1), 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - A triplet lies outside of the matrix dimensions.
+    pub fn from_triplets(rows: usize, cols: usize, tri: &[(usize, usize, T)]) -> CsrMatrix<T> {
+        for &(i, j, _) in tri {
+            assert!(i < rows && j < cols, "Triplet index is outside of the matrix.");
+        }
+
+        let (row_ptr, col_indices, data) = compress(rows, tri.to_vec());
+
+        CsrMatrix {
+            rows: rows,
+            cols: cols,
+            data: data,
+            col_indices: col_indices,
+            row_ptr: row_ptr,
+        }
+    }
+
+    /// Returns the `[i, j]` element of the matrix.
+    ///
+    /// Uses a binary search over the stored entries of row `i`.
+    ///
+    /// # Panics
+    ///
+    /// - The index is out of bounds.
+    pub fn get_elem(&self, i: usize, j: usize) -> T {
+        assert!(i < self.rows, "Row index is greater than row dimension.");
+        assert!(j < self.cols, "Column index is greater than column dimension.");
+
+        let (start, end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+        match self.col_indices[start..end].binary_search(&j) {
+            Ok(idx) => self.data[start + idx],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Converts the matrix into a dense `Matrix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::Matrix;
+    /// use rulinalg::matrix::sparse::CsrMatrix;
+    ///
+    /// let a = CsrMatrix::from_triplets(2, 2, &[(0, 1, 1), (1, 0, 2)]);
+    ///
+    /// assert_eq!(a.to_dense(), Matrix::new(2, 2, vec![0, 1, 2, 0]));
+    /// ```
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data = vec![T::zero(); self.rows * self.cols];
+
+        for i in 0..self.rows {
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                data[i * self.cols + self.col_indices[idx]] = self.data[idx];
+            }
+        }
+
+        Matrix::new(self.rows, self.cols, data)
+    }
+
+    /// Returns the transpose of the matrix, also in CSR format.
+    ///
+    /// Runs in `O(nnz + rows + cols)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::sparse::CsrMatrix;
+    ///
+    /// let a = CsrMatrix::from_triplets(2, 3, &[(0, 2, 1), (1, 0, 2)]);
+    /// let at = a.transpose();
+    ///
+    /// assert_eq!(at.rows(), 3);
+    /// assert_eq!(at.get_elem(2, 0), 1);
+    /// ```
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        let nnz = self.nnz();
+
+        // Count the entries in each column to find the new row offsets.
+        let mut row_ptr = vec![0; self.cols + 1];
+        for &j in &self.col_indices {
+            row_ptr[j + 1] += 1;
+        }
+        for j in 0..self.cols {
+            row_ptr[j + 1] += row_ptr[j];
+        }
+
+        let mut next = row_ptr.clone();
+        let mut data = vec![T::zero(); nnz];
+        let mut col_indices = vec![0; nnz];
+
+        for i in 0..self.rows {
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let j = self.col_indices[idx];
+                let dest = next[j];
+                data[dest] = self.data[idx];
+                col_indices[dest] = i;
+                next[j] += 1;
+            }
+        }
+
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            data: data,
+            col_indices: col_indices,
+            row_ptr: row_ptr,
+        }
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+impl<T> Mul<Vector<T>> for CsrMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        (&self) * (&v)
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+impl<'a, T> Mul<Vector<T>> for &'a CsrMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        self * (&v)
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+impl<'a, T> Mul<&'a Vector<T>> for CsrMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        (&self) * v
+    }
+}
+
+/// Multiplies sparse matrix by vector.
+///
+/// Runs in `O(nnz + rows)` time.
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a CsrMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        assert!(v.size() == self.cols, "Matrix and Vector dimensions do not agree.");
+
+        let x = v.data();
+        let mut new_data = Vec::with_capacity(self.rows);
+
+        for i in 0..self.rows {
+            let mut sum = T::zero();
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                sum = sum + self.data[idx] * x[self.col_indices[idx]];
+            }
+            new_data.push(sum);
+        }
+
+        Vector::new(new_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, BaseMatrix};
+    use vector::Vector;
+    use super::CsrMatrix;
+
+    fn sparse_10x10() -> Vec<(usize, usize, f64)> {
+        vec![(0, 0, 4.0), (0, 9, -1.0), (1, 1, 3.0), (2, 5, 2.5),
+             (3, 3, 1.0), (3, 0, -2.0), (5, 7, 6.0), (6, 6, 1.5),
+             (7, 2, -3.0), (8, 8, 2.0), (9, 0, 1.0), (9, 9, 5.0)]
+    }
+
+    fn dense_from_triplets(rows: usize, cols: usize, tri: &[(usize, usize, f64)]) -> Matrix<f64> {
+        let mut m = Matrix::zeros(rows, cols);
+        for &(i, j, v) in tri {
+            m[[i, j]] += v;
+        }
+        m
+    }
+
+    #[test]
+    fn test_csr_to_dense() {
+        let tri = sparse_10x10();
+        let a = CsrMatrix::from_triplets(10, 10, &tri);
+
+        assert_eq!(a.nnz(), tri.len());
+        assert_eq!(a.row_ptr().len(), 11);
+        assert_eq!(a.to_dense(), dense_from_triplets(10, 10, &tri));
+    }
+
+    #[test]
+    fn test_csr_get_elem() {
+        let tri = sparse_10x10();
+        let a = CsrMatrix::from_triplets(10, 10, &tri);
+        let dense = dense_from_triplets(10, 10, &tri);
+
+        for i in 0..10 {
+            for j in 0..10 {
+                assert_eq!(a.get_elem(i, j), dense[[i, j]]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_csr_mul_vector() {
+        let tri = sparse_10x10();
+        let a = CsrMatrix::from_triplets(10, 10, &tri);
+        let x = Vector::new((0..10).map(|i| i as f64 - 4.5).collect::<Vec<_>>());
+
+        let expected = dense_from_triplets(10, 10, &tri) * &x;
+        assert_eq!(&a * &x, expected);
+    }
+
+    #[test]
+    fn test_csr_duplicate_triplets() {
+        let tri = vec![(0, 1, 1.0), (2, 0, 2.0), (0, 1, 3.0), (1, 1, -1.0), (2, 0, 0.5)];
+        let a = CsrMatrix::from_triplets(3, 2, &tri);
+
+        assert_eq!(a.nnz(), 3);
+        assert_eq!(a.get_elem(0, 1), 4.0);
+
+        let x = Vector::new(vec![2.0, 1.0]);
+        assert_eq!(a * &x, dense_from_triplets(3, 2, &tri) * x);
+    }
+
+    #[test]
+    fn test_csr_transpose() {
+        let tri = vec![(0, 1, 1.0), (0, 3, 2.0), (2, 0, 3.0), (1, 3, 4.0)];
+        let a = CsrMatrix::from_triplets(3, 4, &tri);
+        let at = a.transpose();
+
+        assert_eq!(at.rows(), 4);
+        assert_eq!(at.cols(), 3);
+        assert_eq!(at.to_dense(), a.to_dense().transpose());
+        assert_eq!(at.transpose(), a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_csr_triplet_out_of_bounds() {
+        let _ = CsrMatrix::from_triplets(2, 2, &[(2, 0, 1.0)]);
+    }
+}
@@ -0,0 +1,47 @@
+//! Sparse matrix formats.
+//!
+//! These store only the non-zero entries of a matrix and
+//! are intended for large problems where most entries are zero.
+
+use std::ops::Add;
+
+mod coo;
+mod csc;
+mod csr;
+
+pub use self::coo::CooMatrix;
+pub use self::csc::CscMatrix;
+pub use self::csr::CsrMatrix;
+
+/// Compresses `(major, minor, value)` triplets.
+///
+/// Returns the offsets of each major index, the minor indices and the
+/// values, sorted by major then minor index. Duplicate entries are summed.
+fn compress<T>(major_dim: usize, mut tri: Vec<(usize, usize, T)>) -> (Vec<usize>, Vec<usize>, Vec<T>)
+    where T: Copy + Add<T, Output = T>
+{
+    tri.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+    let mut data = Vec::with_capacity(tri.len());
+    let mut minor_indices = Vec::with_capacity(tri.len());
+    let mut major_ptr = vec![0; major_dim + 1];
+    let mut last = None;
+
+    for (i, j, val) in tri {
+        if last == Some((i, j)) {
+            let idx = data.len() - 1;
+            data[idx] = data[idx] + val;
+        } else {
+            data.push(val);
+            minor_indices.push(j);
+            major_ptr[i + 1] += 1;
+            last = Some((i, j));
+        }
+    }
+
+    for i in 0..major_dim {
+        major_ptr[i + 1] += major_ptr[i];
+    }
+
+    (major_ptr, minor_indices, data)
+}
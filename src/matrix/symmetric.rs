@@ -0,0 +1,515 @@
+//! Symmetric matrices with packed storage.
+//!
+//! Only the lower triangle of the matrix is stored, requiring
+//! `n * (n + 1) / 2` entries for an `n x n` matrix.
+
+use std::ops::{Mul, Add, Sub, Index, IndexMut};
+use libnum::{Zero, One};
+
+use matrix::{Matrix, BaseMatrix};
+use vector::Vector;
+use utils;
+
+/// A symmetric matrix.
+///
+/// The lower triangle is stored row by row in a packed buffer.
+/// Indexing with `[i, j]` or `[j, i]` refers to the same element.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, SymmetricMatrix};
+///
+/// let lower = Matrix::new(2, 2, vec![1.0, 0.0, 2.0, 3.0]);
+/// let sym = SymmetricMatrix::from_lower(lower);
+///
+/// assert_eq!(sym[[0, 1]], 2.0);
+/// assert_eq!(sym[[1, 0]], 2.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricMatrix<T> {
+    size: usize,
+    data: Vec<T>,
+}
+
+impl<T> SymmetricMatrix<T> {
+    /// Constructor for SymmetricMatrix from packed lower triangular data.
+    ///
+    /// The data holds the lower triangle row by row, i.e.
+    /// `[a00, a10, a11, a20, a21, a22, ...]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::SymmetricMatrix;
+    ///
+    /// let sym = SymmetricMatrix::new(2, vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(sym[[0, 1]], 2.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The data length is not `size * (size + 1) / 2`.
+    pub fn new<U: Into<Vec<T>>>(size: usize, data: U) -> SymmetricMatrix<T> {
+        let our_data = data.into();
+
+        assert!(size * (size + 1) / 2 == our_data.len(),
+                "Data length must be size * (size + 1) / 2.");
+
+        SymmetricMatrix {
+            size: size,
+            data: our_data,
+        }
+    }
+
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.size
+    }
+
+    /// Returns a non-mutable reference to the packed lower triangular data.
+    pub fn data(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// Consumes the SymmetricMatrix and returns the packed data.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Returns the offset of the `[i, j]` element in the packed data.
+    fn packed_index(i: usize, j: usize) -> usize {
+        if i >= j {
+            i * (i + 1) / 2 + j
+        } else {
+            j * (j + 1) / 2 + i
+        }
+    }
+}
+
+impl<T: Copy> SymmetricMatrix<T> {
+    /// Constructs a SymmetricMatrix from the lower triangle of a square matrix.
+    ///
+    /// Elements above the diagonal are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, SymmetricMatrix};
+    ///
+    /// let lower = Matrix::new(2, 2, vec![1.0, 0.0, 2.0, 3.0]);
+    /// let sym = SymmetricMatrix::from_lower(lower);
+    ///
+    /// assert_eq!(*sym.data(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    pub fn from_lower(lower: Matrix<T>) -> SymmetricMatrix<T> {
+        assert!(lower.rows() == lower.cols(),
+                "Matrix must be square to construct a symmetric matrix.");
+
+        let n = lower.rows();
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+
+        for (i, row) in lower.iter_rows().enumerate() {
+            data.extend_from_slice(&row[..i + 1]);
+        }
+
+        SymmetricMatrix {
+            size: n,
+            data: data,
+        }
+    }
+
+    /// Converts the SymmetricMatrix into a dense `Matrix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, SymmetricMatrix};
+    ///
+    /// let sym = SymmetricMatrix::new(2, vec![1.0, 2.0, 3.0]);
+    /// let dense = sym.into_dense();
+    ///
+    /// assert_eq!(dense, Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 3.0]));
+    /// ```
+    pub fn into_dense(self) -> Matrix<T> {
+        let n = self.size;
+        let mut data = Vec::with_capacity(n * n);
+
+        for i in 0..n {
+            for j in 0..n {
+                data.push(self.data[Self::packed_index(i, j)]);
+            }
+        }
+
+        Matrix::new(n, n, data)
+    }
+}
+
+impl<T: Copy + Zero> SymmetricMatrix<T> {
+    /// Constructs a SymmetricMatrix of zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::SymmetricMatrix;
+    ///
+    /// let sym = SymmetricMatrix::<f64>::zeros(3);
+    /// assert_eq!(sym.data().len(), 6);
+    /// ```
+    pub fn zeros(size: usize) -> SymmetricMatrix<T> {
+        SymmetricMatrix {
+            size: size,
+            data: vec![T::zero(); size * (size + 1) / 2],
+        }
+    }
+
+    /// Returns the diagonal of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::SymmetricMatrix;
+    ///
+    /// let sym = SymmetricMatrix::new(2, vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(*sym.diag().data(), vec![1.0, 3.0]);
+    /// ```
+    pub fn diag(&self) -> Vector<T> {
+        Vector::new((0..self.size)
+            .map(|i| self.data[Self::packed_index(i, i)])
+            .collect::<Vec<_>>())
+    }
+
+    /// Returns the sum of the diagonal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::SymmetricMatrix;
+    ///
+    /// let sym = SymmetricMatrix::new(2, vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(sym.trace(), 4.0);
+    /// ```
+    pub fn trace(&self) -> T {
+        (0..self.size).fold(T::zero(), |sum, i| sum + self.data[Self::packed_index(i, i)])
+    }
+}
+
+impl<T: Copy + Zero + One> SymmetricMatrix<T> {
+    /// Constructs the identity SymmetricMatrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, SymmetricMatrix};
+    ///
+    /// let sym = SymmetricMatrix::<f64>::identity(3);
+    /// assert_eq!(sym.into_dense(), Matrix::identity(3));
+    /// ```
+    pub fn identity(size: usize) -> SymmetricMatrix<T> {
+        let mut sym = SymmetricMatrix::zeros(size);
+        for i in 0..size {
+            sym.data[Self::packed_index(i, i)] = T::one();
+        }
+        sym
+    }
+}
+
+/// Indexes symmetric matrix.
+///
+/// Takes row index first then column.
+impl<T> Index<[usize; 2]> for SymmetricMatrix<T> {
+    type Output = T;
+
+    fn index(&self, idx: [usize; 2]) -> &T {
+        assert!(idx[0] < self.size,
+                "Row index is greater than row dimension.");
+        assert!(idx[1] < self.size,
+                "Column index is greater than column dimension.");
+
+        &self.data[Self::packed_index(idx[0], idx[1])]
+    }
+}
+
+/// Indexes mutable symmetric matrix.
+///
+/// Modifying `[i, j]` also modifies `[j, i]`.
+impl<T> IndexMut<[usize; 2]> for SymmetricMatrix<T> {
+    fn index_mut(&mut self, idx: [usize; 2]) -> &mut T {
+        assert!(idx[0] < self.size,
+                "Row index is greater than row dimension.");
+        assert!(idx[1] < self.size,
+                "Column index is greater than column dimension.");
+
+        &mut self.data[Self::packed_index(idx[0], idx[1])]
+    }
+}
+
+/// Multiplies symmetric matrix by vector.
+impl<T> Mul<Vector<T>> for SymmetricMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        (&self) * (&v)
+    }
+}
+
+/// Multiplies symmetric matrix by vector.
+impl<'a, T> Mul<Vector<T>> for &'a SymmetricMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        self * (&v)
+    }
+}
+
+/// Multiplies symmetric matrix by vector.
+impl<'a, T> Mul<&'a Vector<T>> for SymmetricMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        (&self) * v
+    }
+}
+
+/// Multiplies symmetric matrix by vector.
+///
+/// Only the packed lower triangle is read. Each stored element below
+/// the diagonal is read once and contributes to both `y[i]` and `y[j]`,
+/// so the matrix is traversed once in its packed order.
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a SymmetricMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        assert!(v.size() == self.size, "Matrix and Vector dimensions do not agree.");
+
+        let mut new_data = vec![T::zero(); self.size];
+        let x = v.data();
+
+        let mut offset = 0;
+        for i in 0..self.size {
+            let row = &self.data[offset..offset + i + 1];
+            let mut sum = T::zero();
+
+            for (j, &a) in row[..i].iter().enumerate() {
+                sum = sum + a * x[j];
+                new_data[j] = new_data[j] + a * x[i];
+            }
+
+            new_data[i] = new_data[i] + sum + row[i] * x[i];
+            offset += i + 1;
+        }
+
+        Vector::new(new_data)
+    }
+}
+
+/// Implements an elementwise operation between symmetric matrices on the packed data.
+macro_rules! impl_sym_bin_op (
+    ($trt:ident, $op:ident, $f:path, $doc:expr) => (
+
+#[doc=$doc]
+impl<'a, 'b, T> $trt<&'b SymmetricMatrix<T>> for &'a SymmetricMatrix<T>
+    where T: Copy + $trt<T, Output = T>
+{
+    type Output = SymmetricMatrix<T>;
+
+    fn $op(self, m: &SymmetricMatrix<T>) -> SymmetricMatrix<T> {
+        assert!(self.size == m.size, "Matrix dimensions do not agree.");
+
+        SymmetricMatrix {
+            size: self.size,
+            data: $f(&self.data, &m.data),
+        }
+    }
+}
+
+#[doc=$doc]
+impl<'a, T> $trt<&'a SymmetricMatrix<T>> for SymmetricMatrix<T>
+    where T: Copy + $trt<T, Output = T>
+{
+    type Output = SymmetricMatrix<T>;
+
+    fn $op(self, m: &SymmetricMatrix<T>) -> SymmetricMatrix<T> {
+        (&self).$op(m)
+    }
+}
+
+#[doc=$doc]
+impl<'a, T> $trt<SymmetricMatrix<T>> for &'a SymmetricMatrix<T>
+    where T: Copy + $trt<T, Output = T>
+{
+    type Output = SymmetricMatrix<T>;
+
+    fn $op(self, m: SymmetricMatrix<T>) -> SymmetricMatrix<T> {
+        self.$op(&m)
+    }
+}
+
+#[doc=$doc]
+impl<T> $trt<SymmetricMatrix<T>> for SymmetricMatrix<T>
+    where T: Copy + $trt<T, Output = T>
+{
+    type Output = SymmetricMatrix<T>;
+
+    fn $op(self, m: SymmetricMatrix<T>) -> SymmetricMatrix<T> {
+        (&self).$op(&m)
+    }
+}
+    );
+);
+
+impl_sym_bin_op!(Add, add, utils::vec_sum, "Adds symmetric matrices elementwise.");
+impl_sym_bin_op!(Sub, sub, utils::vec_sub, "Subtracts symmetric matrices elementwise.");
+
+/// Multiplies symmetric matrix by scalar.
+impl<T> Mul<T> for SymmetricMatrix<T>
+    where T: Copy + Mul<T, Output = T>
+{
+    type Output = SymmetricMatrix<T>;
+
+    fn mul(self, f: T) -> SymmetricMatrix<T> {
+        (&self) * f
+    }
+}
+
+/// Multiplies symmetric matrix by scalar.
+impl<'a, T> Mul<T> for &'a SymmetricMatrix<T>
+    where T: Copy + Mul<T, Output = T>
+{
+    type Output = SymmetricMatrix<T>;
+
+    fn mul(self, f: T) -> SymmetricMatrix<T> {
+        SymmetricMatrix {
+            size: self.size,
+            data: self.data.iter().map(|&x| x * f).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, Cholesky};
+    use vector::Vector;
+    use super::SymmetricMatrix;
+
+    #[test]
+    fn test_symmetric_index() {
+        let sym = SymmetricMatrix::new(3, vec![1, 2, 3, 4, 5, 6]);
+
+        let expected = [[1, 2, 4], [2, 3, 5], [4, 5, 6]];
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(sym[[i, j]], expected[i][j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetric_index_mut() {
+        let mut sym = SymmetricMatrix::new(2, vec![1, 2, 3]);
+        sym[[0, 1]] = 7;
+
+        assert_eq!(sym[[1, 0]], 7);
+    }
+
+    #[test]
+    fn test_from_lower_into_dense() {
+        let lower = Matrix::new(3, 3, vec![1, 9, 9,
+                                           2, 3, 9,
+                                           4, 5, 6]);
+        let dense = SymmetricMatrix::from_lower(lower).into_dense();
+
+        assert_eq!(dense, Matrix::new(3, 3, vec![1, 2, 4,
+                                                 2, 3, 5,
+                                                 4, 5, 6]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_bad_len() {
+        let _ = SymmetricMatrix::new(3, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_symmetric_mul_vector() {
+        let sym = SymmetricMatrix::new(4, vec![1.0,
+                                               2.0, 3.0,
+                                               4.0, 5.0, 6.0,
+                                               7.0, 8.0, 9.0, 10.0]);
+        let v = Vector::new(vec![1.0, -2.0, 3.0, 0.5]);
+
+        let expected = sym.clone().into_dense() * &v;
+        assert_eq!(&sym * &v, expected);
+    }
+
+    #[test]
+    fn test_symmetric_cholesky() {
+        let sym = SymmetricMatrix::new(3, vec![4.0,
+                                               12.0, 37.0,
+                                               -16.0, -43.0, 98.0]);
+        let l = sym.cholesky().unwrap().unpack();
+
+        assert_eq!(*l.data(), vec![2.0, 0.0, 0.0,
+                                   6.0, 1.0, 0.0,
+                                   -8.0, 5.0, 3.0]);
+    }
+
+    #[test]
+    fn test_symmetric_cholesky_matches_dense() {
+        let sym = SymmetricMatrix::new(4, vec![10.0,
+                                               2.0, 8.0,
+                                               -1.0, 3.0, 9.0,
+                                               0.5, -2.0, 1.0, 7.0]);
+        let packed = sym.cholesky().unwrap();
+        let dense = Cholesky::decompose(sym.clone().into_dense()).unwrap();
+
+        assert_eq!(packed.l(), dense.l());
+
+        let indefinite = SymmetricMatrix::new(2, vec![1.0, 2.0, 1.0]);
+        assert!(indefinite.cholesky().is_err());
+        assert!(SymmetricMatrix::<f64>::zeros(0).cholesky().is_ok());
+    }
+
+    #[test]
+    fn test_symmetric_packed_ops() {
+        let a = SymmetricMatrix::new(3, vec![1, 2, 3, 4, 5, 6]);
+        let b = SymmetricMatrix::new(3, vec![6, 5, 4, 3, 2, 1]);
+
+        assert_eq!((&a + &b).into_dense(), a.clone().into_dense() + b.clone().into_dense());
+        assert_eq!((&a - &b).into_dense(), a.clone().into_dense() - b.clone().into_dense());
+        assert_eq!((&a * 2).into_dense(), a.clone().into_dense() * 2);
+        assert_eq!(a.clone() + SymmetricMatrix::zeros(3), a);
+
+        assert_eq!(*a.diag().data(), vec![1, 3, 6]);
+        assert_eq!(a.trace(), 10);
+        assert_eq!(SymmetricMatrix::<i32>::identity(3).into_dense(), Matrix::identity(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_symmetric_add_bad_dims() {
+        let _ = SymmetricMatrix::<f64>::zeros(2) + SymmetricMatrix::zeros(3);
+    }
+}
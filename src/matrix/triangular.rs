@@ -0,0 +1,627 @@
+//! Triangular matrices with packed storage.
+//!
+//! Only the non-zero triangle of the matrix is stored, requiring
+//! `n * (n + 1) / 2` entries for an `n x n` matrix.
+
+use std::any::Any;
+use std::ops::{Mul, Add};
+use libnum::{Zero, Float};
+
+use matrix::{Matrix, BaseMatrix, BaseMatrixMut};
+use vector::Vector;
+use error::{Error, ErrorKind};
+use utils;
+
+/// The triangle of a `TriangularMatrix` holding the non-zero entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Triangle {
+    Lower,
+    Upper,
+}
+
+/// A lower or upper triangular matrix.
+///
+/// The non-zero triangle is stored row by row in a packed buffer.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, TriangularMatrix};
+/// use rulinalg::vector::Vector;
+///
+/// let l = TriangularMatrix::lower(Matrix::new(2, 2, vec![2.0, 0.0, 1.0, 1.0]));
+/// let x = l.solve(Vector::new(vec![2.0, 3.0])).unwrap();
+///
+/// assert_eq!(x, Vector::new(vec![1.0, 2.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriangularMatrix<T> {
+    size: usize,
+    triangle: Triangle,
+    data: Vec<T>,
+}
+
+impl<T> TriangularMatrix<T> {
+    /// Returns the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.size
+    }
+
+    /// Returns true if the matrix is lower triangular.
+    pub fn is_lower(&self) -> bool {
+        self.triangle == Triangle::Lower
+    }
+
+    /// Returns true if the matrix is upper triangular.
+    pub fn is_upper(&self) -> bool {
+        self.triangle == Triangle::Upper
+    }
+
+    /// Returns a non-mutable reference to the packed data.
+    ///
+    /// The stored part of each row is laid out one after the other.
+    pub fn data(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// Returns the stored part of row `i` and the column of its first entry.
+    fn packed_row(&self, i: usize) -> (&[T], usize) {
+        match self.triangle {
+            Triangle::Lower => {
+                let start = i * (i + 1) / 2;
+                (&self.data[start..start + i + 1], 0)
+            }
+            Triangle::Upper => {
+                let start = i * self.size - i * (i.saturating_sub(1)) / 2;
+                (&self.data[start..start + self.size - i], i)
+            }
+        }
+    }
+}
+
+impl<T: Copy + Zero> TriangularMatrix<T> {
+    /// Constructs a lower triangular matrix from a square matrix.
+    ///
+    /// Elements above the diagonal are ignored.
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    pub fn lower(data: Matrix<T>) -> TriangularMatrix<T> {
+        TriangularMatrix::from_matrix(data, Triangle::Lower)
+    }
+
+    /// Constructs an upper triangular matrix from a square matrix.
+    ///
+    /// Elements below the diagonal are ignored.
+    ///
+    /// # Panics
+    ///
+    /// - The matrix is not square.
+    pub fn upper(data: Matrix<T>) -> TriangularMatrix<T> {
+        TriangularMatrix::from_matrix(data, Triangle::Upper)
+    }
+
+    fn from_matrix(m: Matrix<T>, triangle: Triangle) -> TriangularMatrix<T> {
+        assert!(m.rows() == m.cols(),
+                "Matrix must be square to construct a triangular matrix.");
+
+        let n = m.rows();
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+
+        for (i, row) in m.iter_rows().enumerate() {
+            match triangle {
+                Triangle::Lower => data.extend_from_slice(&row[..i + 1]),
+                Triangle::Upper => data.extend_from_slice(&row[i..]),
+            }
+        }
+
+        TriangularMatrix {
+            size: n,
+            triangle: triangle,
+            data: data,
+        }
+    }
+
+    /// Returns the `[i, j]` element of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// - The index is out of bounds.
+    pub fn get_elem(&self, i: usize, j: usize) -> T {
+        assert!(i < self.size, "Row index is greater than row dimension.");
+        assert!(j < self.size, "Column index is greater than column dimension.");
+
+        let (row, first) = self.packed_row(i);
+        if j < first || j >= first + row.len() {
+            T::zero()
+        } else {
+            row[j - first]
+        }
+    }
+
+    /// Converts the TriangularMatrix into a dense `Matrix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, TriangularMatrix};
+    ///
+    /// let m = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// let u = TriangularMatrix::upper(m.clone()).into_dense();
+    /// assert_eq!(u, Matrix::new(2, 2, vec![1, 2, 0, 4]));
+    ///
+    /// let l = TriangularMatrix::lower(m).into_dense();
+    /// assert_eq!(l, Matrix::new(2, 2, vec![1, 0, 3, 4]));
+    /// ```
+    pub fn into_dense(self) -> Matrix<T> {
+        let n = self.size;
+        let mut data = vec![T::zero(); n * n];
+
+        for i in 0..n {
+            let (row, first) = self.packed_row(i);
+            data[i * n + first..i * n + first + row.len()].copy_from_slice(row);
+        }
+
+        Matrix::new(n, n, data)
+    }
+}
+
+impl<T: Any + Float> TriangularMatrix<T> {
+    /// Solves the linear system `Tx = b`.
+    ///
+    /// Uses forward substitution for lower and back substitution for
+    /// upper triangular matrices.
+    ///
+    /// # Panics
+    ///
+    /// - The size of `b` does not match the dimension of the matrix.
+    ///
+    /// # Failures
+    ///
+    /// - The matrix is singular.
+    pub fn solve(&self, b: Vector<T>) -> Result<Vector<T>, Error> {
+        assert!(b.size() == self.size,
+                "Vector size must match the dimension of the matrix.");
+
+        let n = self.size;
+        let mut x = b.into_vec();
+
+        match self.triangle {
+            Triangle::Lower => {
+                for i in 0..n {
+                    let (row, _) = self.packed_row(i);
+                    let sum = utils::dot(&row[..i], &x[..i]);
+                    x[i] = (x[i] - sum) / checked_diag(row[i])?;
+                }
+            }
+            Triangle::Upper => {
+                for i in (0..n).rev() {
+                    let (row, _) = self.packed_row(i);
+                    let sum = utils::dot(&row[1..], &x[i + 1..]);
+                    x[i] = (x[i] - sum) / checked_diag(row[0])?;
+                }
+            }
+        }
+
+        Ok(Vector::new(x))
+    }
+
+    /// Computes the inverse of the matrix.
+    ///
+    /// The inverse of a triangular matrix has the same triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, TriangularMatrix};
+    ///
+    /// let u = TriangularMatrix::upper(Matrix::new(2, 2, vec![2.0, 2.0, 0.0, 4.0]));
+    /// let inv = u.inv().unwrap().into_dense();
+    ///
+    /// assert_eq!(inv, Matrix::new(2, 2, vec![0.5, -0.25, 0.0, 0.25]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The matrix is singular.
+    pub fn inv(&self) -> Result<TriangularMatrix<T>, Error> {
+        let n = self.size;
+        let mut inv = vec![T::zero(); n * n];
+
+        // Solve for each column of the inverse in turn.
+        match self.triangle {
+            Triangle::Lower => {
+                for c in 0..n {
+                    for i in c..n {
+                        let (row, _) = self.packed_row(i);
+                        let mut sum = if i == c { T::one() } else { T::zero() };
+                        for j in c..i {
+                            sum = sum - row[j] * inv[j * n + c];
+                        }
+                        inv[i * n + c] = sum / checked_diag(row[i])?;
+                    }
+                }
+            }
+            Triangle::Upper => {
+                for c in 0..n {
+                    for i in (0..c + 1).rev() {
+                        let (row, _) = self.packed_row(i);
+                        let mut sum = if i == c { T::one() } else { T::zero() };
+                        for j in i + 1..c + 1 {
+                            sum = sum - row[j - i] * inv[j * n + c];
+                        }
+                        inv[i * n + c] = sum / checked_diag(row[0])?;
+                    }
+                }
+            }
+        }
+
+        Ok(TriangularMatrix::from_matrix(Matrix::new(n, n, inv), self.triangle))
+    }
+}
+
+impl<T> TriangularMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    /// Overwrites `other` with `self * other`.
+    ///
+    /// Only the stored triangle takes part in the product. The rows of
+    /// `other` are updated in an order where each new row depends only
+    /// on rows not yet overwritten, so no scratch space is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, TriangularMatrix};
+    ///
+    /// let l = TriangularMatrix::lower(Matrix::new(2, 2, vec![1, 0, 2, 3]));
+    /// let mut b = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// l.apply_on_left(&mut b);
+    /// assert_eq!(b, Matrix::new(2, 2, vec![1, 2, 11, 16]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The size of `self` does not match the rows of `other`.
+    pub fn apply_on_left<M: BaseMatrixMut<T>>(&self, other: &mut M) {
+        assert!(self.size == other.rows(), "Matrix dimensions do not agree.");
+
+        let n = self.size;
+        let update_row = |other: &mut M, i: usize| {
+            let (row, first) = self.packed_row(i);
+            for c in 0..other.cols() {
+                let mut sum = T::zero();
+                for (k, &a) in row.iter().enumerate() {
+                    sum = sum + a * unsafe { *other.get_unchecked([first + k, c]) };
+                }
+                unsafe {
+                    *other.get_unchecked_mut([i, c]) = sum;
+                }
+            }
+        };
+
+        // Row i of a lower triangular product depends on rows 0..i + 1,
+        // and of an upper triangular product on rows i..n.
+        match self.triangle {
+            Triangle::Lower => for i in (0..n).rev() { update_row(other, i) },
+            Triangle::Upper => for i in 0..n { update_row(other, i) },
+        }
+    }
+
+    /// Overwrites `other` with `other * self`.
+    ///
+    /// Only the stored triangle takes part in the product, and no
+    /// scratch space is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::matrix::{Matrix, TriangularMatrix};
+    ///
+    /// let u = TriangularMatrix::upper(Matrix::new(2, 2, vec![1, 2, 0, 3]));
+    /// let mut b = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    ///
+    /// u.apply_on_right(&mut b);
+    /// assert_eq!(b, Matrix::new(2, 2, vec![1, 8, 3, 18]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The size of `self` does not match the columns of `other`.
+    pub fn apply_on_right<M: BaseMatrixMut<T>>(&self, other: &mut M) {
+        assert!(other.cols() == self.size, "Matrix dimensions do not agree.");
+
+        let n = self.size;
+        for row in other.iter_rows_mut() {
+            // Entry j of a row times an upper triangular matrix depends on
+            // entries 0..j + 1, and times a lower triangular one on j..n.
+            let update = |row: &mut [T], j: usize| {
+                let mut sum = T::zero();
+                let (lo, hi) = match self.triangle {
+                    Triangle::Lower => (j, n),
+                    Triangle::Upper => (0, j + 1),
+                };
+                for k in lo..hi {
+                    let (self_row, first) = self.packed_row(k);
+                    sum = sum + row[k] * self_row[j - first];
+                }
+                row[j] = sum;
+            };
+
+            match self.triangle {
+                Triangle::Lower => for j in 0..n { update(row, j) },
+                Triangle::Upper => for j in (0..n).rev() { update(row, j) },
+            }
+        }
+    }
+}
+
+/// Returns the diagonal value if it is safe to divide by it.
+fn checked_diag<T: Float>(diag: T) -> Result<T, Error> {
+    if diag.abs() < T::min_positive_value() + T::min_positive_value() {
+        Err(Error::new(ErrorKind::AlgebraFailure,
+                       "Linear system cannot be solved (matrix is singular)."))
+    } else {
+        Ok(diag)
+    }
+}
+
+/// Multiplies triangular matrix by vector.
+impl<T> Mul<Vector<T>> for TriangularMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        (&self) * (&v)
+    }
+}
+
+/// Multiplies triangular matrix by vector.
+impl<'a, T> Mul<Vector<T>> for &'a TriangularMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        self * (&v)
+    }
+}
+
+/// Multiplies triangular matrix by vector.
+impl<'a, T> Mul<&'a Vector<T>> for TriangularMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        (&self) * v
+    }
+}
+
+/// Multiplies triangular matrix by vector.
+///
+/// Only the stored triangle takes part in the product.
+impl<'a, 'b, T> Mul<&'b Vector<T>> for &'a TriangularMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        assert!(v.size() == self.size, "Matrix and Vector dimensions do not agree.");
+
+        let mut new_data = Vec::with_capacity(self.size);
+
+        for i in 0..self.size {
+            let (row, first) = self.packed_row(i);
+            new_data.push(utils::dot(row, &v.data()[first..first + row.len()]));
+        }
+
+        Vector::new(new_data)
+    }
+}
+
+/// Multiplies two triangular matrices.
+///
+/// # Panics
+///
+/// - The matrices have different dimensions.
+/// - One matrix is lower and the other upper triangular.
+impl<T> Mul<TriangularMatrix<T>> for TriangularMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = TriangularMatrix<T>;
+
+    fn mul(self, m: TriangularMatrix<T>) -> TriangularMatrix<T> {
+        (&self) * (&m)
+    }
+}
+
+/// Multiplies two triangular matrices.
+///
+/// # Panics
+///
+/// - The matrices have different dimensions.
+/// - One matrix is lower and the other upper triangular.
+impl<'a, 'b, T> Mul<&'b TriangularMatrix<T>> for &'a TriangularMatrix<T>
+    where T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>
+{
+    type Output = TriangularMatrix<T>;
+
+    fn mul(self, m: &TriangularMatrix<T>) -> TriangularMatrix<T> {
+        assert!(self.size == m.size, "Matrix dimensions do not agree.");
+        assert!(self.triangle == m.triangle,
+                "Triangular matrices must both be lower or both be upper triangular.");
+
+        let n = self.size;
+        let mut data = Vec::with_capacity(self.data.len());
+
+        for i in 0..n {
+            let (row, first) = self.packed_row(i);
+            let (lo, hi) = match self.triangle {
+                Triangle::Lower => (0, i + 1),
+                Triangle::Upper => (i, n),
+            };
+
+            // Entry [i, j] only depends on the overlapping range of
+            // row i of self and column j of m.
+            for j in lo..hi {
+                let mut sum = T::zero();
+                let (k_lo, k_hi) = match self.triangle {
+                    Triangle::Lower => (j, i + 1),
+                    Triangle::Upper => (i, j + 1),
+                };
+                for k in k_lo..k_hi {
+                    sum = sum + row[k - first] * m.get_elem(k, j);
+                }
+                data.push(sum);
+            }
+        }
+
+        TriangularMatrix {
+            size: n,
+            triangle: self.triangle,
+            data: data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::{Matrix, BaseMatrix};
+    use vector::Vector;
+    use super::TriangularMatrix;
+
+    fn dense() -> Matrix<f64> {
+        Matrix::new(4, 4, vec![2.0, 1.0, -1.0, 3.0,
+                               4.0, 5.0, 2.0, -2.0,
+                               1.0, -3.0, 4.0, 1.0,
+                               0.5, 2.0, -1.0, 3.0])
+    }
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-10, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn test_triangular_into_dense() {
+        let m = Matrix::new(3, 3, (1..10).collect::<Vec<i32>>());
+
+        let l = TriangularMatrix::lower(m.clone());
+        assert_eq!(*l.data(), vec![1, 4, 5, 7, 8, 9]);
+        assert_eq!(l.into_dense(), Matrix::new(3, 3, vec![1, 0, 0, 4, 5, 0, 7, 8, 9]));
+
+        let u = TriangularMatrix::upper(m);
+        assert_eq!(*u.data(), vec![1, 2, 3, 5, 6, 9]);
+        assert_eq!(u.get_elem(2, 0), 0);
+        assert_eq!(u.get_elem(1, 2), 6);
+        assert_eq!(u.into_dense(), Matrix::new(3, 3, vec![1, 2, 3, 0, 5, 6, 0, 0, 9]));
+    }
+
+    #[test]
+    fn test_triangular_mul_vector() {
+        let v = Vector::new(vec![1.0, -2.0, 0.5, 3.0]);
+
+        for t in vec![TriangularMatrix::lower(dense()), TriangularMatrix::upper(dense())] {
+            let expected = t.clone().into_dense() * &v;
+            assert_eq!(&t * &v, expected);
+        }
+    }
+
+    #[test]
+    fn test_triangular_mul_triangular() {
+        let a = dense();
+        let b = dense().transpose();
+
+        let l = TriangularMatrix::lower(a.clone()) * TriangularMatrix::lower(b.clone());
+        let expected = TriangularMatrix::lower(a.clone()).into_dense() *
+                       TriangularMatrix::lower(b.clone()).into_dense();
+        assert!(l.is_lower());
+        assert_eq!(l.into_dense(), expected);
+
+        let u = TriangularMatrix::upper(a.clone()) * TriangularMatrix::upper(b.clone());
+        let expected = TriangularMatrix::upper(a).into_dense() *
+                       TriangularMatrix::upper(b).into_dense();
+        assert!(u.is_upper());
+        assert_eq!(u.into_dense(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_triangular_mul_mixed() {
+        let _ = TriangularMatrix::lower(dense()) * TriangularMatrix::upper(dense());
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        let b = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+
+        let l = TriangularMatrix::lower(dense());
+        let x = l.solve(b.clone()).unwrap();
+        let expected = l.clone().into_dense().solve_l_triangular(b.clone()).unwrap();
+        assert_close(x.data(), expected.data());
+
+        let u = TriangularMatrix::upper(dense());
+        let x = u.solve(b.clone()).unwrap();
+        let expected = u.clone().into_dense().solve_u_triangular(b).unwrap();
+        assert_close(x.data(), expected.data());
+    }
+
+    #[test]
+    fn test_triangular_solve_singular() {
+        let l = TriangularMatrix::lower(Matrix::new(2, 2, vec![1.0, 0.0, 1.0, 0.0]));
+        assert!(l.solve(Vector::new(vec![1.0, 1.0])).is_err());
+        assert!(l.inv().is_err());
+    }
+
+    #[test]
+    fn test_triangular_inv() {
+        for t in vec![TriangularMatrix::lower(dense()), TriangularMatrix::upper(dense())] {
+            let inv = t.inv().unwrap();
+            assert_eq!(inv.is_lower(), t.is_lower());
+
+            let expected = t.clone().into_dense().inverse().unwrap();
+            assert_close(inv.into_dense().data(), expected.data());
+        }
+    }
+
+    #[test]
+    fn test_triangular_apply_on_left_right() {
+        let b = Matrix::new(4, 3, (0..12).map(|x| x as f64 * 0.5 - 2.0).collect::<Vec<_>>());
+
+        for t in vec![TriangularMatrix::lower(dense()), TriangularMatrix::upper(dense())] {
+            let dense = t.clone().into_dense();
+
+            let mut c = b.clone();
+            t.apply_on_left(&mut c);
+            assert_close(c.data(), (&dense * &b).data());
+
+            let mut d = b.transpose();
+            t.apply_on_right(&mut d);
+            assert_close(d.data(), (b.transpose() * &dense).data());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_triangular_apply_on_left_wrong_size() {
+        let l = TriangularMatrix::lower(dense());
+        l.apply_on_left(&mut Matrix::<f64>::zeros(3, 4));
+    }
+}
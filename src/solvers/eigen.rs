@@ -0,0 +1,336 @@
+//! Iterative eigenvalue solvers.
+//!
+//! # Examples
+//!
+//! ```
+//! use rulinalg::matrix::Matrix;
+//! use rulinalg::solvers::eigen::power_iteration;
+//!
+//! let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 2.0]);
+//! let (lambda, x) = power_iteration(|v| &a * v, 2, 1e-10, 1000).unwrap();
+//!
+//! assert!((lambda - 3.0f64).abs() < 1e-8);
+//! assert!((x[0] - x[1]).abs() < 1e-8);
+//! ```
+
+use std::any::Any;
+
+use libnum::{Float, FromPrimitive};
+
+use matrix::{Matrix, BaseMatrix, PartialPivLu};
+use vector::Vector;
+use error::{Error, ErrorKind};
+use Metric;
+
+/// Returns a deterministic unit starting vector.
+///
+/// The entries are distinct so that the vector is unlikely to be
+/// orthogonal to the eigenvector we are looking for.
+fn start_vector<T: Float + FromPrimitive>(n: usize) -> Vector<T> {
+    let x = Vector::new((0..n)
+        .map(|i| T::one() + T::from_usize(i).unwrap() / T::from_usize(n).unwrap())
+        .collect::<Vec<_>>());
+    let norm = x.norm();
+    x / norm
+}
+
+/// Repeatedly applies `op` to a unit vector until `op(x) ≈ mu * x`.
+///
+/// Returns the Rayleigh quotient `mu` and the normalized `x`.
+fn iterate<T, F>(mut op: F, n: usize, tol: T, max_iter: usize) -> Result<(T, Vector<T>), Error>
+    where T: Float + FromPrimitive,
+          F: FnMut(&Vector<T>) -> Result<Vector<T>, Error>
+{
+    if n == 0 {
+        return Err(Error::new(ErrorKind::InvalidArg, "Dimension must be greater than zero."));
+    }
+
+    let mut x = start_vector(n);
+    let mut residual = T::infinity();
+
+    for _ in 0..max_iter {
+        let y = op(&x)?;
+        assert!(y.size() == n, "Operator must return a vector of size n.");
+
+        let mu = x.dot(&y);
+        residual = (&y - &x * mu).norm();
+
+        let norm = y.norm();
+        if norm == T::zero() || !norm.is_finite() {
+            return Err(Error::new(ErrorKind::AlgebraFailure,
+                                  "Iteration broke down (operator returned a zero or non-finite vector)."));
+        }
+
+        if residual <= tol * mu.abs().max(T::one()) {
+            return Ok((mu, x));
+        }
+
+        x = y / norm;
+    }
+
+    Err(Error::new(ErrorKind::ConvergenceFailed {
+                       iterations: max_iter,
+                       residual: residual.to_f64().unwrap_or(::std::f64::NAN),
+                   },
+                   "Iteration did not converge within the maximum number of iterations."))
+}
+
+/// Computes the dominant eigenpair with power iteration.
+///
+/// `matvec` computes the product `Ax` of the `n x n` matrix `A`
+/// with a vector, so `A` need not be formed explicitly. Returns the
+/// eigenvalue of largest magnitude and a unit eigenvector.
+///
+/// Iteration stops once `‖Ax - λx‖ <= tol * max(1, |λ|)`. Convergence
+/// is slow when the two largest eigenvalues are close in magnitude.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::Matrix;
+/// use rulinalg::solvers::eigen::power_iteration;
+///
+/// let a = Matrix::new(3, 3, vec![4.0, 1.0, 0.0,
+///                                1.0, 3.0, 0.0,
+///                                0.0, 0.0, 1.0]);
+/// let (lambda, x) = power_iteration(|v| &a * v, 3, 1e-12, 1000).unwrap();
+///
+/// let expected = (7.0 + 5.0f64.sqrt()) / 2.0;
+/// assert!((lambda - expected).abs() < 1e-8);
+/// assert!(x[2].abs() < 1e-8);
+/// ```
+///
+/// # Panics
+///
+/// - `matvec` returns a vector whose size is not `n`.
+///
+/// # Failures
+///
+/// - `n` is zero.
+/// - `matvec` returns a zero or non-finite vector.
+/// - The iteration does not converge within `max_iter` iterations.
+pub fn power_iteration<T, F>(matvec: F,
+                             n: usize,
+                             tol: T,
+                             max_iter: usize)
+                             -> Result<(T, Vector<T>), Error>
+    where T: Float + FromPrimitive,
+          F: Fn(&Vector<T>) -> Vector<T>
+{
+    iterate(|x| Ok(matvec(x)), n, tol, max_iter)
+}
+
+/// Computes the eigenpair closest to `shift` with shifted inverse iteration.
+///
+/// `matvec` computes the product `Ax` of the `n x n` matrix `A` with a
+/// vector. The matrix `A - shift * I` is formed from the products with
+/// the `n` unit vectors and factored once with `PartialPivLu`. Power
+/// iteration on its inverse converges to the eigenvalue `λ` of `A`
+/// nearest to `shift`, which is returned along with a unit eigenvector.
+///
+/// For operators too large to form, `inverse_iteration_with` takes a
+/// solver for `A - shift * I` instead.
+///
+/// Iteration stops once the residual of the shifted inverse operator
+/// is at most `tol * max(1, |μ|)`, where `μ = 1 / (λ - shift)` is its
+/// dominant eigenvalue.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::Matrix;
+/// use rulinalg::solvers::eigen::inverse_iteration;
+///
+/// let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 2.0]);
+/// let (lambda, _) = inverse_iteration(|v| &a * v, 0.8, 2, 1e-12, 100).unwrap();
+///
+/// assert!((lambda - 1.0f64).abs() < 1e-8);
+/// ```
+///
+/// # Panics
+///
+/// - `matvec` returns a vector whose size is not `n`.
+///
+/// # Failures
+///
+/// - `n` is zero.
+/// - `A - shift * I` is singular.
+/// - The iteration does not converge within `max_iter` iterations.
+pub fn inverse_iteration<T, F>(matvec: F,
+                               shift: T,
+                               n: usize,
+                               tol: T,
+                               max_iter: usize)
+                               -> Result<(T, Vector<T>), Error>
+    where T: Any + Float + FromPrimitive,
+          F: Fn(&Vector<T>) -> Vector<T>
+{
+    if n == 0 {
+        return Err(Error::new(ErrorKind::InvalidArg, "Dimension must be greater than zero."));
+    }
+
+    // Row j of the transpose is the column A e_j.
+    let mut transpose = Vec::with_capacity(n * n);
+    let mut e = Vector::zeros(n);
+    for j in 0..n {
+        e[j] = T::one();
+        let col = matvec(&e);
+        assert!(col.size() == n, "Operator must return a vector of size n.");
+        transpose.extend_from_slice(col.data());
+        e[j] = T::zero();
+    }
+
+    let mut shifted = Matrix::new(n, n, transpose).transpose();
+    for i in 0..n {
+        shifted[[i, i]] = shifted[[i, i]] - shift;
+    }
+
+    let lu = PartialPivLu::decompose(shifted)?;
+    inverse_iteration_with(|x| lu.solve(x.clone()), shift, n, tol, max_iter)
+}
+
+/// Computes the eigenpair closest to `shift` with shifted inverse iteration,
+/// using the given solver.
+///
+/// `solve` must apply `(A - shift * I)⁻¹` to a vector, for example
+/// using a factorization of `A - shift * I` computed once up front, or
+/// an iterative solver. Otherwise this is the same as `inverse_iteration`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::matrix::{Matrix, PartialPivLu};
+/// use rulinalg::solvers::eigen::inverse_iteration_with;
+///
+/// let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 2.0]);
+/// let shift = 0.8;
+///
+/// let lu = PartialPivLu::decompose(&a - Matrix::identity(2) * shift).unwrap();
+/// let (lambda, _) = inverse_iteration_with(|v| lu.solve(v.clone()), shift, 2, 1e-12, 100)
+///     .unwrap();
+///
+/// assert!((lambda - 1.0f64).abs() < 1e-8);
+/// ```
+///
+/// # Panics
+///
+/// - `solve` returns a vector whose size is not `n`.
+///
+/// # Failures
+///
+/// - `n` is zero.
+/// - `solve` fails or returns a zero or non-finite vector.
+/// - The iteration does not converge within `max_iter` iterations.
+pub fn inverse_iteration_with<T, F>(solve: F,
+                                    shift: T,
+                                    n: usize,
+                                    tol: T,
+                                    max_iter: usize)
+                                    -> Result<(T, Vector<T>), Error>
+    where T: Float + FromPrimitive,
+          F: Fn(&Vector<T>) -> Result<Vector<T>, Error>
+{
+    let (mu, x) = iterate(solve, n, tol, max_iter)?;
+    Ok((shift + mu.recip(), x))
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix::Matrix;
+    use vector::Vector;
+    use Metric;
+    use error::ErrorKind;
+    use super::{power_iteration, inverse_iteration, inverse_iteration_with};
+
+    fn tridiagonal(n: usize) -> Matrix<f64> {
+        // Eigenvalues are 2 - 2 cos(k pi / (n + 1)) for k = 1..n.
+        let mut a = Matrix::zeros(n, n);
+        for i in 0..n {
+            a[[i, i]] = 2.0;
+            if i + 1 < n {
+                a[[i, i + 1]] = -1.0;
+                a[[i + 1, i]] = -1.0;
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn test_power_iteration_dominant() {
+        let a = Matrix::new(3, 3, vec![2.0f64, 0.0, 0.0,
+                                       0.0, -5.0, 0.0,
+                                       0.0, 0.0, 1.0]);
+        let (lambda, x) = power_iteration(|v| &a * v, 3, 1e-12, 1000).unwrap();
+
+        assert!((lambda + 5.0).abs() < 1e-8);
+        assert!((x.norm() - 1.0).abs() < 1e-12);
+        assert!((x[1].abs() - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_power_iteration_residual() {
+        let n = 10;
+        let a = tridiagonal(n);
+        let (lambda, x) = power_iteration(|v| &a * v, n, 1e-10, 10000).unwrap();
+
+        let expected = 2.0 - 2.0 * (n as f64 * ::std::f64::consts::PI / (n as f64 + 1.0)).cos();
+        assert!((lambda - expected).abs() < 1e-8);
+        assert!((x.norm() - 1.0).abs() < 1e-12);
+        assert!((&a * &x - &x * lambda).norm() < 1e-8);
+    }
+
+    #[test]
+    fn test_inverse_iteration_smallest() {
+        let n = 10;
+        let a = tridiagonal(n);
+
+        let expected = 2.0 - 2.0 * (::std::f64::consts::PI / (n as f64 + 1.0)).cos();
+
+        let (lambda, x) = inverse_iteration(|v| &a * v, 0.0, n, 1e-12, 10000).unwrap();
+        assert!((lambda - expected).abs() < 1e-8);
+        assert!((x.norm() - 1.0).abs() < 1e-12);
+        assert!((&a * &x - &x * lambda).norm() < 1e-6);
+
+        let inv = a.inverse().unwrap();
+        let (lambda, x) = inverse_iteration_with(|v| Ok(&inv * v), 0.0, n, 1e-12, 10000).unwrap();
+        assert!((lambda - expected).abs() < 1e-8);
+        assert!((x.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_iteration_small_mu() {
+        // Here |1 / (λ - shift)| is less than one, so the tolerance is absolute.
+        let a = Matrix::new(3, 3, vec![7.0f64, 0.0, 0.0,
+                                       0.0, 8.0, 0.0,
+                                       0.0, 0.0, 10.0]);
+        let (lambda, x) = inverse_iteration(|v| &a * v, 4.0, 3, 1e-10, 1000).unwrap();
+
+        assert!((lambda - 7.0).abs() < 1e-6);
+        assert!((x[0].abs() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_inverse_iteration_singular_shift() {
+        let a = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 3.0]);
+        assert!(inverse_iteration(|v| &a * v, 3.0, 2, 1e-10, 100).is_err());
+        assert!(inverse_iteration(|v: &Vector<f64>| v.clone(), 0.5, 0, 1e-10, 100).is_err());
+    }
+
+    #[test]
+    fn test_power_iteration_errors() {
+        let zero = Matrix::<f64>::zeros(2, 2);
+        assert!(power_iteration(|v| &zero * v, 2, 1e-10, 100).is_err());
+        assert!(power_iteration(|v: &Vector<f64>| v.clone(), 0, 1e-10, 100).is_err());
+
+        // Rotation has no dominant real eigenvalue.
+        let rot = Matrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+        let err = power_iteration(|v| &rot * v, 2, 1e-10, 100).unwrap_err();
+        match *err.kind() {
+            ErrorKind::ConvergenceFailed { iterations, residual } => {
+                assert_eq!(iterations, 100);
+                assert!(residual > 0.1);
+            }
+            _ => panic!("Expected ConvergenceFailed error."),
+        }
+    }
+}
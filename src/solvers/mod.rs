@@ -0,0 +1,8 @@
+//! Iterative solvers.
+//!
+//! The solvers in this module only access the matrix through a
+//! closure computing matrix-vector products, so they can be used
+//! with sparse or structured matrices which are never formed
+//! explicitly.
+
+pub mod eigen;
@@ -2,9 +2,11 @@
 //!
 //! Contains support methods for linear algebra structs.
 
+use std::any::{Any, TypeId};
 use std::cmp;
-use libnum::Zero;
+use libnum::{Zero, Float};
 use std::ops::{Add, Mul, Sub, Div};
+use std::slice;
 
 /// Compute dot product of two slices.
 ///
@@ -50,6 +52,211 @@ pub fn dot<T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>>(u: &[T], v:
     s
 }
 
+macro_rules! impl_dot_lanes (
+    ($(#[$attr:meta])* fn $name:ident, $t:ty) => (
+
+$(#[$attr])*
+pub fn $name(u: &[$t], v: &[$t]) -> $t {
+    const LANES: usize = 8;
+
+    let len = cmp::min(u.len(), v.len());
+    let (xs, ys) = (&u[..len], &v[..len]);
+
+    // Independent accumulators, which the compiler maps onto SIMD
+    // registers so that consecutive additions do not wait on each other.
+    let mut acc = [0.0 as $t; LANES];
+    let mut x_chunks = xs.chunks_exact(LANES);
+    let mut y_chunks = ys.chunks_exact(LANES);
+
+    for (x, y) in (&mut x_chunks).zip(&mut y_chunks) {
+        for k in 0..LANES {
+            acc[k] += x[k] * y[k];
+        }
+    }
+
+    let mut s = ((acc[0] + acc[4]) + (acc[1] + acc[5])) + ((acc[2] + acc[6]) + (acc[3] + acc[7]));
+    for (x, y) in x_chunks.remainder().iter().zip(y_chunks.remainder()) {
+        s += x * y;
+    }
+    s
+}
+    );
+);
+
+impl_dot_lanes!(
+    /// Compute dot product of two `f32` slices.
+    ///
+    /// The products are accumulated in `8` lanes which are vectorized
+    /// by the compiler. The result may differ from a sequential sum in
+    /// the last few bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::utils;
+    /// let a = vec![1.0,2.0,3.0,4.0];
+    /// let b = vec![1.0,2.0,3.0,4.0];
+    ///
+    /// let c = utils::dot_f32(&a,&b);
+    /// assert_eq!(c, 30.0);
+    /// ```
+    fn dot_f32, f32);
+
+impl_dot_lanes!(
+    /// Compute dot product of two `f64` slices.
+    ///
+    /// The products are accumulated in `8` lanes which are vectorized
+    /// by the compiler. The result may differ from a sequential sum in
+    /// the last few bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::utils;
+    /// let a = vec![1.0,2.0,3.0,4.0];
+    /// let b = vec![1.0,2.0,3.0,4.0];
+    ///
+    /// let c = utils::dot_f64(&a,&b);
+    /// assert_eq!(c, 30.0);
+    /// ```
+    fn dot_f64, f64);
+
+/// Compute dot product of two slices using explicit SIMD instructions.
+///
+/// For `f64` and `f32` on x86 targets compiled with AVX2 enabled
+/// (e.g. `-C target-feature=+avx2`), the products are computed `4`
+/// or `8` elements at a time with a scalar tail. Otherwise this falls
+/// back to `dot_f64`, `dot_f32` or `dot` as appropriate.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::utils;
+/// let a = vec![1.0,2.0,3.0,4.0,5.0];
+/// let b = vec![1.0,2.0,3.0,4.0,5.0];
+///
+/// let c = utils::dot_simd(&a,&b);
+/// assert_eq!(c, 55.0);
+/// ```
+pub fn dot_simd<T>(u: &[T], v: &[T]) -> T
+    where T: Any + Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+{
+    let len = cmp::min(u.len(), v.len());
+
+    unsafe {
+        if TypeId::of::<T>() == TypeId::of::<f64>() {
+            let u = slice::from_raw_parts(u.as_ptr() as *const f64, len);
+            let v = slice::from_raw_parts(v.as_ptr() as *const f64, len);
+            let s = simd::dot_f64(u, v);
+            *(&s as *const f64 as *const T)
+        } else if TypeId::of::<T>() == TypeId::of::<f32>() {
+            let u = slice::from_raw_parts(u.as_ptr() as *const f32, len);
+            let v = slice::from_raw_parts(v.as_ptr() as *const f32, len);
+            let s = simd::dot_f32(u, v);
+            *(&s as *const f32 as *const T)
+        } else {
+            dot(u, v)
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx2"))]
+mod simd {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    /// Sums the four lanes of the register.
+    unsafe fn hsum_pd(x: __m256d) -> f64 {
+        let mut lanes = [0.0; 4];
+        _mm256_storeu_pd(lanes.as_mut_ptr(), x);
+        (lanes[0] + lanes[2]) + (lanes[1] + lanes[3])
+    }
+
+    /// Sums the eight lanes of the register.
+    unsafe fn hsum_ps(x: __m256) -> f32 {
+        let mut lanes = [0.0; 8];
+        _mm256_storeu_ps(lanes.as_mut_ptr(), x);
+        ((lanes[0] + lanes[4]) + (lanes[1] + lanes[5])) +
+        ((lanes[2] + lanes[6]) + (lanes[3] + lanes[7]))
+    }
+
+    pub fn dot_f64(u: &[f64], v: &[f64]) -> f64 {
+        debug_assert_eq!(u.len(), v.len());
+        let n = u.len();
+        // Two accumulators so that consecutive additions are independent.
+        let blocks = n / 8;
+
+        unsafe {
+            let (x, y) = (u.as_ptr(), v.as_ptr());
+            let mut acc0 = _mm256_setzero_pd();
+            let mut acc1 = _mm256_setzero_pd();
+
+            for b in 0..blocks {
+                let i = b * 8;
+                acc0 = _mm256_add_pd(acc0, _mm256_mul_pd(_mm256_loadu_pd(x.offset(i as isize)),
+                                                         _mm256_loadu_pd(y.offset(i as isize))));
+                acc1 = _mm256_add_pd(acc1,
+                                     _mm256_mul_pd(_mm256_loadu_pd(x.offset(i as isize + 4)),
+                                                   _mm256_loadu_pd(y.offset(i as isize + 4))));
+            }
+
+            let mut i = blocks * 8;
+            if n - i >= 4 {
+                acc0 = _mm256_add_pd(acc0, _mm256_mul_pd(_mm256_loadu_pd(x.offset(i as isize)),
+                                                         _mm256_loadu_pd(y.offset(i as isize))));
+                i += 4;
+            }
+
+            let mut s = hsum_pd(_mm256_add_pd(acc0, acc1));
+            for k in i..n {
+                s += u[k] * v[k];
+            }
+            s
+        }
+    }
+
+    pub fn dot_f32(u: &[f32], v: &[f32]) -> f32 {
+        debug_assert_eq!(u.len(), v.len());
+        let n = u.len();
+        let blocks = n / 16;
+
+        unsafe {
+            let (x, y) = (u.as_ptr(), v.as_ptr());
+            let mut acc0 = _mm256_setzero_ps();
+            let mut acc1 = _mm256_setzero_ps();
+
+            for b in 0..blocks {
+                let i = b * 16;
+                acc0 = _mm256_add_ps(acc0, _mm256_mul_ps(_mm256_loadu_ps(x.offset(i as isize)),
+                                                         _mm256_loadu_ps(y.offset(i as isize))));
+                acc1 = _mm256_add_ps(acc1,
+                                     _mm256_mul_ps(_mm256_loadu_ps(x.offset(i as isize + 8)),
+                                                   _mm256_loadu_ps(y.offset(i as isize + 8))));
+            }
+
+            let mut i = blocks * 16;
+            if n - i >= 8 {
+                acc0 = _mm256_add_ps(acc0, _mm256_mul_ps(_mm256_loadu_ps(x.offset(i as isize)),
+                                                         _mm256_loadu_ps(y.offset(i as isize))));
+                i += 8;
+            }
+
+            let mut s = hsum_ps(_mm256_add_ps(acc0, acc1));
+            for k in i..n {
+                s += u[k] * v[k];
+            }
+            s
+        }
+    }
+}
+
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx2")))]
+mod simd {
+    pub use super::{dot_f32, dot_f64};
+}
+
 /// Unrolled sum
 ///
 /// Computes the sum over the slice consuming it in the process.
@@ -225,10 +432,156 @@ pub fn ele_div<T: Copy + Div<T, Output = T>>(u: &[T], v: &[T]) -> Vec<T> {
     vec_bin_op(u, v, |x, y| x / y)
 }
 
+/// Soft-thresholding of a slice.
+///
+/// Computes `sign(x) * max(|x| - lambda, 0)` for each element,
+/// shrinking every value towards zero by `lambda`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::utils;
+/// let a = vec![-3.0, -0.5, 0.0, 2.0];
+///
+/// let c = utils::soft_threshold(&a, 1.0);
+///
+/// assert_eq!(c, vec![-2.0, 0.0, 0.0, 1.0]);
+/// ```
+pub fn soft_threshold<T: Float>(u: &[T], lambda: T) -> Vec<T> {
+    u.iter()
+        .map(|&x| {
+            let shrunk = x.abs() - lambda;
+            if shrunk > T::zero() {
+                x.signum() * shrunk
+            } else {
+                T::zero()
+            }
+        })
+        .collect()
+}
+
+
+/// Adds `x` to the running sum `s`, returning the new sum and the
+/// rounding error of the addition exactly (Knuth's TwoSum).
+fn two_sum<T: Float>(s: T, x: T) -> (T, T) {
+    let t = s + x;
+    let z = t - s;
+    (t, (s - (t - z)) + (x - z))
+}
+
+/// Compensated summation of a slice.
+///
+/// Uses Kahan–Babuška (Neumaier) summation, which tracks the rounding
+/// error of each addition. The result is as accurate as if the sum were
+/// computed in twice the working precision and then rounded, at roughly
+/// four times the cost of `unrolled_sum`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::utils;
+///
+/// let a = vec![1.0, 1e100, 1.0, -1e100];
+///
+/// assert_eq!(utils::sum_compensated(&a), 2.0);
+/// ```
+pub fn sum_compensated<T: Float>(xs: &[T]) -> T {
+    let mut s = T::zero();
+    let mut c = T::zero();
+
+    for &x in xs {
+        let (t, e) = two_sum(s, x);
+        s = t;
+        c = c + e;
+    }
+
+    s + c
+}
+
+/// Compensated dot product of two slices.
+///
+/// The rounding error of every product is recovered with a fused
+/// multiply-add and the sum is accumulated as in `sum_compensated`
+/// (Ogita, Rump and Oishi's `Dot2`). The slices are truncated to the
+/// shorter length, as in `dot`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::utils;
+///
+/// let a = vec![1e16, 1.0, -1e16];
+/// let b = vec![1.0, 1.0, 1.0];
+///
+/// assert_eq!(utils::dot(&a, &b), 0.0);
+/// assert_eq!(utils::dot_compensated(&a, &b), 1.0);
+/// ```
+pub fn dot_compensated<T: Float>(u: &[T], v: &[T]) -> T {
+    let mut s = T::zero();
+    let mut c = T::zero();
+
+    for (&x, &y) in u.iter().zip(v) {
+        let p = x * y;
+        let p_err = x.mul_add(y, -p);
+        let (t, e) = two_sum(s, p);
+        s = t;
+        c = c + (e + p_err);
+    }
+
+    s + c
+}
+
+/// Compares two values, ordering values which cannot be compared with
+/// themselves (NaN) last.
+///
+/// All NaN values compare equal to each other, so a stable sort using
+/// this comparison keeps them in their original order.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::utils;
+/// use std::f64::NAN;
+///
+/// let mut a = vec![2.0, NAN, 1.0];
+/// a.sort_by(utils::nan_last_cmp);
+///
+/// assert_eq!(&a[..2], &[1.0, 2.0]);
+/// assert!(a[2].is_nan());
+/// ```
+pub fn nan_last_cmp<T: PartialOrd>(x: &T, y: &T) -> cmp::Ordering {
+    match (is_unordered(x), is_unordered(y)) {
+        (false, false) => x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal),
+        (false, true) => cmp::Ordering::Less,
+        (true, false) => cmp::Ordering::Greater,
+        (true, true) => cmp::Ordering::Equal,
+    }
+}
+
+/// Returns `true` if `x` cannot be compared with itself, as for NaN.
+fn is_unordered<T: PartialOrd>(x: &T) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+/// Returns `true` if `x` should replace `best` as the running extremum.
+///
+/// Values which cannot be compared with themselves (NaN) never replace
+/// a comparable value, but are replaced by any comparable value.
+fn replaces<T: PartialOrd>(x: &T, best: &T, greater: bool) -> bool {
+    if is_unordered(best) {
+        !is_unordered(x)
+    } else if greater {
+        x > best
+    } else {
+        x < best
+    }
+}
 
 /// Find argmax of slice.
 ///
-/// Returns index of first occuring maximum.
+/// Returns index of first occuring maximum. NaN values are
+/// ignored unless every value is NaN, in which case the first
+/// index is returned.
 ///
 /// # Examples
 ///
@@ -240,6 +593,10 @@ pub fn ele_div<T: Copy + Div<T, Output = T>>(u: &[T], v: &[T]) -> Vec<T> {
 /// assert_eq!(c.0, 3);
 /// assert_eq!(c.1, 4.0);
 /// ```
+///
+/// # Panics
+///
+/// - The slice is empty.
 pub fn argmax<T: Copy + PartialOrd>(u: &[T]) -> (usize, T) {
     assert!(u.len() != 0);
 
@@ -247,7 +604,7 @@ pub fn argmax<T: Copy + PartialOrd>(u: &[T]) -> (usize, T) {
     let mut max = u[max_index];
 
     for (i, v) in (u.iter()).enumerate() {
-        if max < *v {
+        if replaces(v, &max, true) {
             max_index = i;
             max = *v;
         }
@@ -258,7 +615,9 @@ pub fn argmax<T: Copy + PartialOrd>(u: &[T]) -> (usize, T) {
 
 /// Find argmin of slice.
 ///
-/// Returns index of first occuring minimum.
+/// Returns index of first occuring minimum. NaN values are
+/// ignored unless every value is NaN, in which case the first
+/// index is returned.
 ///
 /// # Examples
 ///
@@ -270,6 +629,10 @@ pub fn argmax<T: Copy + PartialOrd>(u: &[T]) -> (usize, T) {
 /// assert_eq!(c.0, 1);
 /// assert_eq!(c.1, 2.0);
 /// ```
+///
+/// # Panics
+///
+/// - The slice is empty.
 pub fn argmin<T: Copy + PartialOrd>(u: &[T]) -> (usize, T) {
     assert!(u.len() != 0);
 
@@ -277,7 +640,7 @@ pub fn argmin<T: Copy + PartialOrd>(u: &[T]) -> (usize, T) {
     let mut min = u[min_index];
 
     for (i, v) in (u.iter()).enumerate() {
-        if min > *v {
+        if replaces(v, &min, false) {
             min_index = i;
             min = *v;
         }
@@ -308,3 +671,120 @@ pub fn find<T: PartialEq>(p: &[T], u: T) -> usize {
 
     panic!("Value not found.")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{dot, dot_f32, dot_f64, dot_simd, argmax, argmin, sum_compensated, dot_compensated};
+    use super::unrolled_sum;
+
+    fn naive_dot<T: Copy + ::std::ops::Add<T, Output = T> + ::std::ops::Mul<T, Output = T>>
+        (u: &[T], v: &[T], zero: T) -> T {
+        u.iter().zip(v).fold(zero, |s, (&x, &y)| s + x * y)
+    }
+
+    #[test]
+    fn test_dot_integers_exact() {
+        for n in 0..40 {
+            let u = (0..n).map(|x| (x * 7 % 11) as i64 - 5).collect::<Vec<_>>();
+            let v = (0..n).map(|x| (x * 3 % 13) as i64 - 6).collect::<Vec<_>>();
+
+            assert_eq!(dot(&u, &v), naive_dot(&u, &v, 0));
+        }
+    }
+
+    #[test]
+    fn test_dot_lanes_match_scalar() {
+        for n in 0..100 {
+            let u = (0..n).map(|x| (x as f64 * 0.37).sin()).collect::<Vec<_>>();
+            let v = (0..n).map(|x| (x as f64 * 1.3).cos()).collect::<Vec<_>>();
+
+            let expected = naive_dot(&u, &v, 0.0);
+            assert!((dot_f64(&u, &v) - expected).abs() < 1e-12);
+            assert!((dot(&u, &v) - expected).abs() < 1e-12);
+
+            let u32 = u.iter().map(|&x| x as f32).collect::<Vec<_>>();
+            let v32 = v.iter().map(|&x| x as f32).collect::<Vec<_>>();
+            assert!((dot_f32(&u32, &v32) - expected as f32).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_dot_simd_matches_scalar() {
+        for n in 0..34 {
+            let u = (0..n).map(|x| (x as f64 * 0.37).sin()).collect::<Vec<_>>();
+            let v = (0..n).map(|x| (x as f64 * 1.3).cos()).collect::<Vec<_>>();
+
+            let expected = naive_dot(&u, &v, 0.0);
+            assert!((dot_simd(&u, &v) - expected).abs() < 1e-12);
+
+            let u32 = u.iter().map(|&x| x as f32).collect::<Vec<_>>();
+            let v32 = v.iter().map(|&x| x as f32).collect::<Vec<_>>();
+            let expected32 = naive_dot(&u32, &v32, 0.0);
+            assert!((dot_simd(&u32, &v32) - expected32).abs() < 1e-4);
+
+            // Integral values are summed exactly in any order.
+            let a = (0..n).map(|x| (x % 7) as f64).collect::<Vec<_>>();
+            let b = (0..n).map(|x| (x % 5) as f64 - 2.0).collect::<Vec<_>>();
+            assert_eq!(dot_simd(&a, &b), naive_dot(&a, &b, 0.0));
+
+            let a = (0..n).map(|x| (x % 7) as f32).collect::<Vec<_>>();
+            let b = (0..n).map(|x| (x % 5) as f32 - 2.0).collect::<Vec<_>>();
+            assert_eq!(dot_simd(&a, &b), naive_dot(&a, &b, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_dot_simd_other_types() {
+        let u = (0..20).map(|x| x as i32 - 7).collect::<Vec<_>>();
+        let v = (0..20).map(|x| (x % 3) as i32).collect::<Vec<_>>();
+
+        assert_eq!(dot_simd(&u, &v), naive_dot(&u, &v, 0));
+    }
+
+    #[test]
+    fn test_dot_lanes_integral_values_exact() {
+        let u = (0..37).map(|x| x as f64).collect::<Vec<_>>();
+        let v = (0..37).map(|x| (x % 5) as f64).collect::<Vec<_>>();
+
+        assert_eq!(dot_f64(&u, &v), naive_dot(&u, &v, 0.0));
+        assert_eq!(dot_f64(&u, &v), dot(&u, &v));
+    }
+
+    #[test]
+    fn test_argmax_argmin_nan() {
+        let nan = ::std::f64::NAN;
+
+        assert_eq!(argmax(&[nan, 1.0, 3.0, nan, 3.0]).0, 2);
+        assert_eq!(argmin(&[nan, 1.0, -3.0, nan, -3.0]).0, 2);
+        assert_eq!(argmax(&[nan, nan]).0, 0);
+        assert_eq!(argmin(&[nan, nan]).0, 0);
+    }
+
+    #[test]
+    fn test_sum_compensated_cancellation() {
+        // The small terms are lost entirely by naive summation.
+        let xs = vec![1.0, 1e16, 1.0, -1e16, 3.0, 1e-16];
+        assert_eq!(xs.iter().fold(0.0, |s, x| s + x), 3.0);
+        assert_eq!(sum_compensated(&xs), 5.0 + 1e-16);
+
+        let benign = (1..100).map(|x| x as f64).collect::<Vec<_>>();
+        assert_eq!(sum_compensated(&benign), unrolled_sum(&benign));
+        assert_eq!(sum_compensated::<f64>(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_dot_compensated_cancellation() {
+        // x * y has an exact rounding error that naive summation loses:
+        // (1 + 2^-30)^2 = 1 + 2^-29 + 2^-60.
+        let a = 1.0 + 2f64.powi(-30);
+        let u = vec![a, -1.0, -2f64.powi(-29)];
+        let v = vec![a, 1.0, 1.0];
+
+        assert_eq!(dot(&u, &v), 0.0);
+        assert_eq!(dot_compensated(&u, &v), 2f64.powi(-60));
+
+        let x = (0..50).map(|i| i as f64 * 0.5).collect::<Vec<_>>();
+        let y = (0..50).map(|i| 4.0 - i as f64).collect::<Vec<_>>();
+        assert_eq!(dot_compensated(&x, &y), dot(&x, &y));
+    }
+}
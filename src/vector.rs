@@ -5,13 +5,19 @@
 
 use std::ops::{Mul, Add, Div, Sub, Index, IndexMut, Neg, MulAssign, DivAssign, SubAssign, AddAssign};
 use libnum::{One, Zero, Float, FromPrimitive};
-use std::cmp::PartialEq;
+use std::cmp::{Ordering, PartialEq};
 use std::fmt;
 use std::slice::{Iter, IterMut};
 use std::vec::IntoIter;
 use Metric;
+use error::{Error, ErrorKind};
+use matrix::{Matrix, PermutationMatrix};
 use utils;
 
+mod slice;
+
+pub use self::slice::{BaseVector, BaseVectorMut, VectorSlice, VectorSliceMut};
+
 /// The Vector struct.
 ///
 /// Can be instantiated with any type.
@@ -63,6 +69,42 @@ impl<T> Vector<T> {
         self.data
     }
 
+    /// Consumes the Vector and returns it as an `n x 1` column matrix.
+    ///
+    /// The data is moved, not copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    /// use rulinalg::matrix::BaseMatrix;
+    ///
+    /// let m = Vector::new(vec![1, 2, 3]).into_matrix_col();
+    ///
+    /// assert_eq!((m.rows(), m.cols()), (3, 1));
+    /// ```
+    pub fn into_matrix_col(self) -> Matrix<T> {
+        Matrix::new(self.size, 1, self.data)
+    }
+
+    /// Consumes the Vector and returns it as a `1 x n` row matrix.
+    ///
+    /// The data is moved, not copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    /// use rulinalg::matrix::BaseMatrix;
+    ///
+    /// let m = Vector::new(vec![1, 2, 3]).into_matrix_row();
+    ///
+    /// assert_eq!((m.rows(), m.cols()), (1, 3));
+    /// ```
+    pub fn into_matrix_row(self) -> Matrix<T> {
+        Matrix::new(1, self.size, self.data)
+    }
+
     /// Returns an iterator over the Vector's data.
     pub fn iter(&self) -> Iter<T> {
         self.data.iter()
@@ -73,6 +115,107 @@ impl<T> Vector<T> {
         self.mut_data().iter_mut()
     }
 
+    /// Returns a reference to the element at the given index.
+    /// `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(a.get(1), Some(&2));
+    /// assert_eq!(a.get(3), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.data.get(idx)
+    }
+
+    /// Returns a mutable reference to the element at the given index.
+    /// `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Vector::new(vec![1, 2, 3]);
+    ///
+    /// if let Some(x) = a.get_mut(0) {
+    ///     *x = 7;
+    /// }
+    /// assert_eq!(*a.data(), vec![7, 2, 3]);
+    /// ```
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.data.get_mut(idx)
+    }
+
+    /// Moves all elements of `other` onto the end of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Vector::new(vec![1, 2]);
+    /// a.append(Vector::new(vec![3, 4]));
+    ///
+    /// assert_eq!(a, Vector::new(vec![1, 2, 3, 4]));
+    /// ```
+    pub fn append(&mut self, other: Vector<T>) {
+        self.data.extend(other.data);
+        self.size = self.data.len();
+    }
+
+    /// Copies all elements of `other` onto the end of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Vector::new(vec![1, 2]);
+    /// let b = Vector::new(vec![3, 4]);
+    /// a.extend(&b);
+    ///
+    /// assert_eq!(a, Vector::new(vec![1, 2, 3, 4]));
+    /// ```
+    pub fn extend(&mut self, other: &Vector<T>)
+        where T: Clone
+    {
+        self.data.extend_from_slice(&other.data);
+        self.size = self.data.len();
+    }
+
+    /// Splits the vector into two at the given index.
+    ///
+    /// The first vector holds the elements `[0, index)` and the
+    /// second the elements `[index, size)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let (a, b) = Vector::new(vec![1, 2, 3, 4, 5]).split_at(2);
+    ///
+    /// assert_eq!(a, Vector::new(vec![1, 2]));
+    /// assert_eq!(b, Vector::new(vec![3, 4, 5]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `index` is greater than the size of the vector.
+    pub fn split_at(mut self, index: usize) -> (Vector<T>, Vector<T>) {
+        assert!(index <= self.size,
+                "Split index {} is out of bounds for vector of size {}.",
+                index,
+                self.size);
+
+        let tail = self.data.split_off(index);
+        (Vector::new(self.data), Vector::new(tail))
+    }
 }
 
 impl<T> IntoIterator for Vector<T> {
@@ -148,6 +291,25 @@ impl<T: Copy> Vector<T> {
         }
         self
     }
+
+    /// Applies a function to each element, producing a new vector
+    /// of a possibly different type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1, 2, 3]);
+    /// let b = a.map(|x| x as f64 / 2.0);
+    ///
+    /// assert_eq!(b.into_vec(), vec![0.5, 1.0, 1.5]);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Vector<U>
+        where F: FnMut(T) -> U
+    {
+        Vector::new(self.data.iter().cloned().map(f).collect::<Vec<_>>())
+    }
 }
 
 impl<T: Copy + PartialOrd> Vector<T> {
@@ -155,6 +317,9 @@ impl<T: Copy + PartialOrd> Vector<T> {
     ///
     /// Returns the index of the largest value in the vector.
     ///
+    /// Ties resolve to the first occurrence. NaN values are
+    /// ignored unless every value is NaN.
+    ///
     /// # Examples
     ///
     /// ```
@@ -165,6 +330,10 @@ impl<T: Copy + PartialOrd> Vector<T> {
     /// assert_eq!(b.0, 3);
     /// assert_eq!(b.1, 5.0);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector is empty.
     pub fn argmax(&self) -> (usize, T) {
         utils::argmax(&self.data)
     }
@@ -173,6 +342,9 @@ impl<T: Copy + PartialOrd> Vector<T> {
     ///
     /// Returns the index of the smallest value in the vector.
     ///
+    /// Ties resolve to the first occurrence. NaN values are
+    /// ignored unless every value is NaN.
+    ///
     /// # Examples
     ///
     /// ```
@@ -183,6 +355,10 @@ impl<T: Copy + PartialOrd> Vector<T> {
     /// assert_eq!(b.0, 2);
     /// assert_eq!(b.1, 0.0);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vector is empty.
     pub fn argmin(&self) -> (usize, T) {
         utils::argmin(&self.data)
     }
@@ -210,6 +386,124 @@ impl<T: Copy + PartialOrd> Vector<T> {
 
         Vector::new(new_data)
     }
+
+    /// Returns the indices which would sort the vector in ascending order.
+    ///
+    /// The sort is stable, and NaN values are placed last in their
+    /// original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![3.0, 1.0, 2.0]);
+    ///
+    /// assert_eq!(a.argsort(), vec![1, 2, 0]);
+    /// ```
+    pub fn argsort(&self) -> Vec<usize> {
+        self.argsort_by(utils::nan_last_cmp)
+    }
+
+    /// Returns the indices which would sort the vector in descending order.
+    ///
+    /// The sort is stable, so equal values keep their original order,
+    /// and NaN values are placed last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![3.0, 1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(a.argsort_desc(), vec![0, 3, 2, 1]);
+    /// ```
+    pub fn argsort_desc(&self) -> Vec<usize> {
+        self.argsort_by(|x, y| match x.partial_cmp(y) {
+            Some(ord) => ord.reverse(),
+            // At least one of the pair is NaN, which still goes last.
+            None => utils::nan_last_cmp(x, y),
+        })
+    }
+
+    fn argsort_by<F>(&self, mut compare: F) -> Vec<usize>
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        let mut order = (0..self.size).collect::<Vec<_>>();
+        {
+            let data = &self.data;
+            order.sort_by(|&i, &j| compare(&data[i], &data[j]));
+        }
+        order
+    }
+
+    /// Sorts the vector in ascending order, in place.
+    ///
+    /// The sort is stable, and NaN values are placed last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    /// use std::f64;
+    ///
+    /// let mut a = Vector::new(vec![2.0, f64::NAN, 1.0]);
+    /// a.sort_in_place();
+    ///
+    /// assert_eq!(&a.data()[..2], &[1.0, 2.0]);
+    /// assert!(a[2].is_nan());
+    /// ```
+    pub fn sort_in_place(&mut self) {
+        self.data.sort_by(utils::nan_last_cmp);
+    }
+
+    /// Returns a sorted copy of the vector.
+    ///
+    /// The sort is stable, and NaN values are placed last. This is the
+    /// non-mutating counterpart of `sort_in_place`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![3, 1, 2]);
+    ///
+    /// assert_eq!(a.sorted(), Vector::new(vec![1, 2, 3]));
+    /// ```
+    pub fn sorted(&self) -> Vector<T> {
+        let mut sorted = self.clone();
+        sorted.sort_in_place();
+        sorted
+    }
+
+    /// Sorts the vector in ascending order and returns the sorting permutation.
+    ///
+    /// The sort is stable, and NaN values are placed last. The returned
+    /// permutation `P` satisfies `P * v = sorted`, so it can be used to
+    /// reorder data stored alongside the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Vector::new(vec![3.0, 1.0, 2.0]);
+    /// let labels = Vector::new(vec![0.0, 1.0, 2.0]);
+    /// let p = a.sort_with_permutation();
+    ///
+    /// assert_eq!(a, Vector::new(vec![1.0, 2.0, 3.0]));
+    /// assert_eq!(p.as_matrix() * labels, Vector::new(vec![1.0, 2.0, 0.0]));
+    /// ```
+    pub fn sort_with_permutation(&mut self) -> PermutationMatrix<T> {
+        let order = self.argsort();
+        self.data = order.iter().map(|&i| self.data[i]).collect();
+
+        // Entry `order[k]` moves to entry `k`.
+        PermutationMatrix::from_inverse(order)
+            .expect("Sorted indices always form a permutation.")
+    }
 }
 
 impl<T: Clone + Zero> Vector<T> {
@@ -271,6 +565,97 @@ impl<T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>> Vector<T> {
     }
 }
 
+impl<T: Copy + Add<T, Output = T> + Mul<T, Output = T>> Vector<T> {
+    /// Computes `self += alpha * x` in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut y = Vector::new(vec![1.0, 2.0, 3.0]);
+    /// let x = Vector::new(vec![1.0, 1.0, 2.0]);
+    ///
+    /// y.axpy(2.0, &x);
+    /// assert_eq!(y.into_vec(), vec![3.0, 4.0, 7.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    pub fn axpy(&mut self, alpha: T, x: &Vector<T>) {
+        assert!(self.size == x.size, "Vector dimensions do not agree.");
+
+        for (y, &x) in self.data.iter_mut().zip(&x.data) {
+            *y = *y + alpha * x;
+        }
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Vector<T> {
+    /// Computes `self *= alpha` in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut x = Vector::new(vec![1.0, -2.0, 3.0]);
+    ///
+    /// x.scal(0.5);
+    /// assert_eq!(x.into_vec(), vec![0.5, -1.0, 1.5]);
+    /// ```
+    pub fn scal(&mut self, alpha: T) {
+        for x in &mut self.data {
+            *x = *x * alpha;
+        }
+    }
+}
+
+impl<T: Copy + Add<T, Output = T>> Vector<T> {
+    /// The cumulative sum of the vector.
+    ///
+    /// Overflow follows the semantics of the `+` operator for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1, 2, 3, 4]);
+    /// assert_eq!(a.cumsum().into_vec(), vec![1, 3, 6, 10]);
+    /// ```
+    pub fn cumsum(&self) -> Vector<T> {
+        let mut data = self.data.clone();
+        for i in 1..data.len() {
+            data[i] = data[i - 1] + data[i];
+        }
+        Vector::new(data)
+    }
+}
+
+impl<T: Copy + Mul<T, Output = T>> Vector<T> {
+    /// The cumulative product of the vector.
+    ///
+    /// Overflow follows the semantics of the `*` operator for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1, 2, 3, 4]);
+    /// assert_eq!(a.cumprod().into_vec(), vec![1, 2, 6, 24]);
+    /// ```
+    pub fn cumprod(&self) -> Vector<T> {
+        let mut data = self.data.clone();
+        for i in 1..data.len() {
+            data[i] = data[i - 1] * data[i];
+        }
+        Vector::new(data)
+    }
+}
+
 impl<T: Copy + Zero + Add<T, Output = T>> Vector<T> {
     /// The sum of the vector.
     ///
@@ -309,6 +694,44 @@ impl<T: Copy + Mul<T, Output = T>> Vector<T> {
         assert_eq!(self.size, v.size);
         Vector::new(utils::ele_mul(&self.data, &v.data))
     }
+
+    /// The outer product of two vectors.
+    ///
+    /// Returns the `m x n` matrix `self * vᵀ`, where `m` and `n`
+    /// are the sizes of `self` and `v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let u = Vector::new(vec![1.0, 2.0]);
+    /// let v = Vector::new(vec![3.0, 4.0, 5.0]);
+    ///
+    /// let m = u.outer(&v);
+    /// assert_eq!(*m.data(), vec![3.0, 4.0, 5.0, 6.0, 8.0, 10.0]);
+    /// ```
+    pub fn outer(&self, v: &Vector<T>) -> Matrix<T> {
+        outer(self, v)
+    }
+
+    /// The outer product of the vector with itself, `self * selfᵀ`.
+    ///
+    /// The result is symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let u = Vector::new(vec![1.0, 2.0]);
+    ///
+    /// let m = u.outer_self();
+    /// assert_eq!(*m.data(), vec![1.0, 2.0, 2.0, 4.0]);
+    /// ```
+    pub fn outer_self(&self) -> Matrix<T> {
+        outer(self, self)
+    }
 }
 
 impl<T: Copy + Div<T, Output = T>> Vector<T> {
@@ -329,52 +752,687 @@ impl<T: Copy + Div<T, Output = T>> Vector<T> {
         assert_eq!(self.size, v.size);
         Vector::new(utils::ele_div(&self.data, &v.data))
     }
-}
+}
+
+impl<T: Float + FromPrimitive> Vector<T> {
+    /// The mean of the vector.
+    ///
+    /// Returns the arithmetic mean of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::<f32>::new(vec![1.0,2.0,3.0,4.0]);
+    ///
+    /// let c = a.mean();
+    /// assert_eq!(c, 2.5);
+    /// ```
+    pub fn mean(&self) -> T {
+        let sum = self.sum();
+        sum / FromPrimitive::from_usize(self.size()).unwrap()
+    }
+
+    /// The variance of the vector.
+    ///
+    /// Returns the unbiased sample variance of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::<f32>::new(vec![1.0,2.0,3.0,4.0]);
+    ///
+    /// let c = a.variance();
+    /// assert_eq!(c, 5.0/3.0);
+    /// ```
+    pub fn variance(&self) -> T {
+        let m = self.mean();
+        let mut var = T::zero();
+
+        for u in &self.data {
+            var = var + (*u - m) * (*u - m);
+        }
+
+        var / FromPrimitive::from_usize(self.size() - 1).unwrap()
+    }
+
+    /// Constructs a vector of `n` evenly spaced points from `start` to `end`.
+    ///
+    /// Both endpoints are included exactly. Each point is interpolated
+    /// directly from the endpoints, so no error accumulates along the
+    /// vector. If `n` is `1` the vector holds only `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::linspace(0.0, 1.0, 5);
+    ///
+    /// assert_eq!(*a.data(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn linspace(start: T, end: T, n: usize) -> Vector<T> {
+        if n == 1 {
+            return Vector::new(vec![start]);
+        }
+
+        let last = T::from_usize(n.saturating_sub(1)).unwrap();
+        Vector::new((0..n)
+            .map(|i| {
+                let t = T::from_usize(i).unwrap() / last;
+                start * (T::one() - t) + end * t
+            })
+            .collect::<Vec<_>>())
+    }
+
+    /// Constructs a vector of `n` points evenly spaced on a log scale.
+    ///
+    /// The points run from `10^start_exp` to `10^end_exp`, with the
+    /// exponents given by `linspace`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::logspace(0.0, 2.0, 3);
+    ///
+    /// assert_eq!(*a.data(), vec![1.0, 10.0, 100.0]);
+    /// ```
+    pub fn logspace(start_exp: T, end_exp: T, n: usize) -> Vector<T> {
+        let ten = T::from_f64(10.0).unwrap();
+        Vector::linspace(start_exp, end_exp, n).apply(&|x| ten.powf(x))
+    }
+
+    /// Constructs a vector from `start` up to, but excluding, `end`.
+    ///
+    /// Consecutive points are `step` apart. Each point is computed as
+    /// `start + i * step`, so no error accumulates along the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::range(1.0, -1.0, -0.5).unwrap();
+    ///
+    /// assert_eq!(*a.data(), vec![1.0, 0.5, 0.0, -0.5]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - `start` or `end` is not finite.
+    /// - `step` is zero or not finite.
+    /// - `step` points away from `end`.
+    /// - The number of points does not fit in a `usize`.
+    pub fn range(start: T, end: T, step: T) -> Result<Vector<T>, Error> {
+        if !start.is_finite() || !end.is_finite() {
+            return Err(Error::new(ErrorKind::InvalidArg, "Range bounds must be finite."));
+        }
+        if step == T::zero() || !step.is_finite() {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Range step must be non-zero and finite."));
+        }
+        if (end - start) * step < T::zero() {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Range step points away from the end of the range."));
+        }
+
+        let n = ((end - start) / step).ceil().to_usize().ok_or_else(|| {
+            Error::new(ErrorKind::InvalidArg, "Range has too many points.")
+        })?;
+        let mut data = (0..n)
+            .map(|i| start + T::from_usize(i).unwrap() * step)
+            .collect::<Vec<_>>();
+
+        // Rounding in the division can admit points at or beyond `end`.
+        while data.last().map_or(false, |&x| (x - end) * step >= T::zero()) {
+            data.pop();
+        }
+
+        Ok(Vector::new(data))
+    }
+}
+
+impl<T: Float> Vector<T> {
+    /// Returns the elementwise maximum of two vectors.
+    ///
+    /// Follows `Float::max`, so where exactly one of the elements is NaN
+    /// the other element is taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1.0, 5.0, 3.0]);
+    /// let b = Vector::new(vec![4.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(*a.elem_max(&b).data(), vec![4.0, 5.0, 3.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    pub fn elem_max(&self, v: &Vector<T>) -> Vector<T> {
+        let mut max = self.clone();
+        max.elem_max_mut(v);
+        max
+    }
+
+    /// Returns the elementwise minimum of two vectors.
+    ///
+    /// Follows `Float::min`, so where exactly one of the elements is NaN
+    /// the other element is taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1.0, 5.0, 3.0]);
+    /// let b = Vector::new(vec![4.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(*a.elem_min(&b).data(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    pub fn elem_min(&self, v: &Vector<T>) -> Vector<T> {
+        let mut min = self.clone();
+        min.elem_min_mut(v);
+        min
+    }
+
+    /// Replaces each element with the elementwise maximum of `self` and `v`.
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    pub fn elem_max_mut(&mut self, v: &Vector<T>) {
+        assert!(self.size == v.size, "Vector dimensions do not agree.");
+        utils::in_place_vec_bin_op(&mut self.data, &v.data, |x, &y| *x = x.max(y));
+    }
+
+    /// Replaces each element with the elementwise minimum of `self` and `v`.
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    pub fn elem_min_mut(&mut self, v: &Vector<T>) {
+        assert!(self.size == v.size, "Vector dimensions do not agree.");
+        utils::in_place_vec_bin_op(&mut self.data, &v.data, |x, &y| *x = x.min(y));
+    }
+
+    /// Returns the vector with each element clamped to `[lo, hi]`.
+    ///
+    /// Each element is computed as `x.max(lo).min(hi)`, so following
+    /// `Float::max` a NaN element is replaced by `lo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![-2.0, 0.5, 3.0]);
+    ///
+    /// assert_eq!(*a.clamp(0.0, 1.0).data(), vec![0.0, 0.5, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `lo` is greater than `hi`, or either bound is NaN.
+    pub fn clamp(&self, lo: T, hi: T) -> Vector<T> {
+        let mut clamped = self.clone();
+        clamped.clamp_mut(lo, hi);
+        clamped
+    }
+
+    /// Clamps each element to `[lo, hi]` in place.
+    ///
+    /// # Panics
+    ///
+    /// - `lo` is greater than `hi`, or either bound is NaN.
+    pub fn clamp_mut(&mut self, lo: T, hi: T) {
+        assert!(lo <= hi, "Clamp lower bound must not exceed the upper bound.");
+        for x in &mut self.data {
+            *x = x.max(lo).min(hi);
+        }
+    }
+
+    /// Returns `true` if any element of the vector is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    /// use std::f64;
+    ///
+    /// assert!(Vector::new(vec![1.0, f64::NAN]).has_nan());
+    /// ```
+    pub fn has_nan(&self) -> bool {
+        self.data.iter().any(|x| x.is_nan())
+    }
+
+    /// Returns `true` if no element of the vector is NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    /// use std::f64;
+    ///
+    /// assert!(Vector::new(vec![1.0, 2.0]).is_finite());
+    /// assert!(!Vector::new(vec![1.0, f64::INFINITY]).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.data.iter().all(|x| x.is_finite())
+    }
+
+    /// Finds the index of the first element which is NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    /// use std::f64;
+    ///
+    /// let a = Vector::new(vec![1.0, 2.0, f64::NEG_INFINITY, f64::NAN]);
+    ///
+    /// assert_eq!(a.find_non_finite(), Some(2));
+    /// ```
+    pub fn find_non_finite(&self) -> Option<usize> {
+        self.data.iter().position(|x| !x.is_finite())
+    }
+
+    /// Soft-thresholds each element of the vector.
+    ///
+    /// Computes `sign(x) * max(|x| - lambda, 0)` element-wise. This
+    /// is the proximal operator of `lambda * ||x||_1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![-3.0, 0.5, 2.0]);
+    ///
+    /// assert_eq!(a.soft_threshold(1.0), Vector::new(vec![-2.0, 0.0, 1.0]));
+    /// ```
+    pub fn soft_threshold(&self, lambda: T) -> Vector<T> {
+        Vector::new(utils::soft_threshold(&self.data, lambda))
+    }
+
+    /// Computes the dot product using compensated summation.
+    ///
+    /// Slower than `dot` but accurate to nearly twice the working
+    /// precision, which matters for long vectors with cancellation.
+    /// See `utils::dot_compensated`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1e16, 1.0, -1e16]);
+    /// let b = Vector::new(vec![1.0, 1.0, 1.0]);
+    ///
+    /// assert_eq!(a.dot(&b), 0.0);
+    /// assert_eq!(a.dot_accurate(&b), 1.0);
+    /// ```
+    pub fn dot_accurate(&self, v: &Vector<T>) -> T {
+        utils::dot_compensated(&self.data, &v.data)
+    }
+
+    /// Computes the sum of the vector using compensated summation.
+    ///
+    /// See `utils::sum_compensated`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1.0, 1e100, 1.0, -1e100]);
+    ///
+    /// assert_eq!(a.sum(), 0.0);
+    /// assert_eq!(a.sum_accurate(), 2.0);
+    /// ```
+    pub fn sum_accurate(&self) -> T {
+        utils::sum_compensated(&self.data)
+    }
+
+    /// Computes the 1-norm of the vector, the sum of absolute values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![3.0, -4.0]);
+    /// assert_eq!(a.norm_1(), 7.0);
+    /// ```
+    pub fn norm_1(&self) -> T {
+        self.data.iter().fold(T::zero(), |s, x| s + x.abs())
+    }
+
+    /// Computes the euclidean norm of the vector.
+    ///
+    /// Squares are accumulated relative to the largest magnitude seen
+    /// so far, so entries near the limits of the float range do not
+    /// overflow or underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1e200, -1e200, 1e200, -1e200]);
+    /// assert_eq!(a.norm_2(), 2e200);
+    /// ```
+    pub fn norm_2(&self) -> T {
+        scaled_norm_2(self.data.iter().cloned())
+    }
+
+    /// Computes the infinity-norm of the vector, the largest absolute value.
+    ///
+    /// Returns zero for an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![3.0, -4.0]);
+    /// assert_eq!(a.norm_inf(), 4.0);
+    /// ```
+    pub fn norm_inf(&self) -> T {
+        self.data.iter().fold(T::zero(), |m, x| m.max(x.abs()))
+    }
+
+    /// Computes the p-norm of the vector, `(Σ|x|ᵖ)^(1/p)`.
+    ///
+    /// The elements are scaled by the largest absolute value before
+    /// being raised to the power `p`. An infinite `p` gives the
+    /// infinity-norm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1.0f64, 2.0, 2.0]);
+    ///
+    /// assert!((a.norm_p(3.0) - 17f64.cbrt()).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - `p` is less than 1 or NaN.
+    pub fn norm_p(&self, p: T) -> T {
+        assert!(p >= T::one(), "The p-norm requires p >= 1.");
+
+        if p == T::infinity() {
+            return self.norm_inf();
+        }
+
+        let max = self.norm_inf();
+        if max == T::zero() || !max.is_finite() {
+            return max;
+        }
+
+        let sum = self.data.iter().fold(T::zero(), |s, x| s + (x.abs() / max).powf(p));
+        max * sum.powf(p.recip())
+    }
+
+    /// Returns the euclidean distance `||self - other||` between two vectors.
+    ///
+    /// The difference is not allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![1.0, 1.0]);
+    /// let b = Vector::new(vec![4.0, 5.0]);
+    ///
+    /// assert_eq!(a.metric(&b), 5.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    pub fn metric(&self, other: &Vector<T>) -> T {
+        assert!(self.size == other.size, "Vector sizes do not match.");
+        scaled_norm_2(self.data.iter().zip(&other.data).map(|(&x, &y)| x - y))
+    }
+
+    /// Returns the vector scaled to unit euclidean norm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let a = Vector::new(vec![3.0, 4.0]);
+    /// assert_eq!(a.normalize().unwrap(), Vector::new(vec![0.6, 0.8]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The vector has zero norm (this includes the empty vector).
+    pub fn normalize(&self) -> Result<Vector<T>, Error> {
+        let mut v = self.clone();
+        v.normalize_mut()?;
+        Ok(v)
+    }
+
+    /// Scales the vector in place to unit euclidean norm.
+    ///
+    /// The vector is left unchanged on failure.
+    ///
+    /// # Failures
+    ///
+    /// - The vector has zero norm (this includes the empty vector).
+    pub fn normalize_mut(&mut self) -> Result<(), Error> {
+        let norm = self.norm_2();
+        if norm == T::zero() {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Cannot normalize a vector with zero norm."));
+        }
+
+        for x in &mut self.data {
+            *x = *x / norm;
+        }
+        Ok(())
+    }
+
+    /// Computes the cross product of two 3-dimensional vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let x = Vector::new(vec![1.0, 0.0, 0.0]);
+    /// let y = Vector::new(vec![0.0, 1.0, 0.0]);
+    ///
+    /// assert_eq!(x.cross(&y).unwrap(), Vector::new(vec![0.0, 0.0, 1.0]));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Either vector does not have size 3.
+    pub fn cross(&self, other: &Vector<T>) -> Result<Vector<T>, Error> {
+        if self.size != 3 || other.size != 3 {
+            let size = if self.size != 3 { self.size } else { other.size };
+            return Err(Error::new(ErrorKind::DimensionMismatch {
+                                      expected: (3, 1),
+                                      actual: (size, 1),
+                                  },
+                                  "Cross product requires vectors of size 3."));
+        }
+
+        let (u, v) = (&self.data, &other.data);
+        Ok(Vector::new(vec![u[1] * v[2] - u[2] * v[1],
+                            u[2] * v[0] - u[0] * v[2],
+                            u[0] * v[1] - u[1] * v[0]]))
+    }
 
-impl<T: Float + FromPrimitive> Vector<T> {
-    /// The mean of the vector.
+    /// Computes the angle between two vectors in radians.
     ///
-    /// Returns the arithmetic mean of the vector.
+    /// The angle is computed as `atan2(|u × v|, u · v)`, which stays
+    /// accurate for nearly parallel vectors where `acos` of the
+    /// normalized dot product does not. For sizes other than 3 the
+    /// norm of the cross product is replaced by that of the exterior
+    /// product, which takes `O(n²)` time.
+    ///
+    /// The result lies in `[0, π]`.
     ///
     /// # Examples
     ///
     /// ```
     /// use rulinalg::vector::Vector;
+    /// use std::f64::consts::PI;
     ///
-    /// let a = Vector::<f32>::new(vec![1.0,2.0,3.0,4.0]);
+    /// let x = Vector::new(vec![1.0, 0.0, 0.0]);
+    /// let y = Vector::new(vec![1.0, 1.0, 0.0]);
     ///
-    /// let c = a.mean();
-    /// assert_eq!(c, 2.5);
+    /// assert!((x.angle(&y).unwrap() - PI / 4.0).abs() < 1e-15);
     /// ```
-    pub fn mean(&self) -> T {
-        let sum = self.sum();
-        sum / FromPrimitive::from_usize(self.size()).unwrap()
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    ///
+    /// # Failures
+    ///
+    /// - Either vector is zero, in which case the angle is undefined.
+    pub fn angle(&self, other: &Vector<T>) -> Result<T, Error> {
+        assert!(self.size == other.size, "Vector sizes do not match.");
+
+        if self.norm_inf() == T::zero() || other.norm_inf() == T::zero() {
+            return Err(Error::new(ErrorKind::InvalidArg,
+                                  "Cannot compute the angle with a zero vector."));
+        }
+
+        let (u, v) = (&self.data, &other.data);
+        let wedge = (0..self.size).flat_map(|i| {
+            (i + 1..self.size).map(move |j| u[i] * v[j] - u[j] * v[i])
+        });
+
+        Ok(scaled_norm_2(wedge).atan2(self.dot(other)))
     }
 
-    /// The variance of the vector.
+    /// Computes the projection of the vector onto `other`.
     ///
-    /// Returns the unbiased sample variance of the vector.
+    /// This is the component of `self` in the direction of `other`,
+    /// `(self · other / other · other) other`.
     ///
     /// # Examples
     ///
     /// ```
     /// use rulinalg::vector::Vector;
     ///
-    /// let a = Vector::<f32>::new(vec![1.0,2.0,3.0,4.0]);
+    /// let a = Vector::new(vec![2.0, 3.0]);
+    /// let b = Vector::new(vec![4.0, 0.0]);
     ///
-    /// let c = a.variance();
-    /// assert_eq!(c, 5.0/3.0);
+    /// assert_eq!(a.proj_onto(&b).unwrap(), Vector::new(vec![2.0, 0.0]));
     /// ```
-    pub fn variance(&self) -> T {
-        let m = self.mean();
-        let mut var = T::zero();
+    ///
+    /// # Panics
+    ///
+    /// - The vectors have different sizes.
+    ///
+    /// # Failures
+    ///
+    /// - `other` is the zero vector.
+    pub fn proj_onto(&self, other: &Vector<T>) -> Result<Vector<T>, Error> {
+        assert!(self.size == other.size, "Vector sizes do not match.");
 
-        for u in &self.data {
-            var = var + (*u - m) * (*u - m);
+        // Project onto the unit vector so that `other · other` cannot
+        // overflow or underflow.
+        let unit = other.normalize().map_err(|_| {
+            Error::new(ErrorKind::InvalidArg, "Cannot project onto a zero vector.")
+        })?;
+
+        let scale = self.dot(&unit);
+        Ok(Vector::new(unit.data.iter().map(|&x| scale * x).collect::<Vec<_>>()))
+    }
+}
+
+/// Computes the euclidean norm of the values with scaled accumulation.
+fn scaled_norm_2<T: Float, I: Iterator<Item = T>>(xs: I) -> T {
+    let mut scale = T::zero();
+    let mut ssq = T::one();
+
+    for x in xs.map(|x| x.abs()).filter(|x| *x != T::zero()) {
+        if scale < x {
+            let r = scale / x;
+            ssq = T::one() + ssq * r * r;
+            scale = x;
+        } else {
+            let r = x / scale;
+            ssq = ssq + r * r;
         }
+    }
 
-        var / FromPrimitive::from_usize(self.size() - 1).unwrap()
+    scale * ssq.sqrt()
+}
+
+/// Concatenates vectors end to end.
+///
+/// An empty list gives an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::vector::{self, Vector};
+///
+/// let a = Vector::new(vec![1, 2]);
+/// let b = Vector::new(vec![3]);
+///
+/// assert_eq!(vector::concat(&[&a, &b, &a]), Vector::new(vec![1, 2, 3, 1, 2]));
+/// ```
+pub fn concat<T: Clone>(vectors: &[&Vector<T>]) -> Vector<T> {
+    let size = vectors.iter().map(|v| v.size).sum();
+    let mut data = Vec::with_capacity(size);
+
+    for v in vectors {
+        data.extend_from_slice(&v.data);
+    }
+    Vector::new(data)
+}
+
+/// The outer product of two vectors.
+///
+/// Returns the `m x n` matrix `u * vᵀ`, where `m` and `n`
+/// are the sizes of `u` and `v`.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::vector::{self, Vector};
+///
+/// let u = Vector::new(vec![1, 2]);
+/// let v = Vector::new(vec![3, 4]);
+///
+/// let m = vector::outer(&u, &v);
+/// assert_eq!(*m.data(), vec![3, 4, 6, 8]);
+/// ```
+pub fn outer<T: Copy + Mul<T, Output = T>>(u: &Vector<T>, v: &Vector<T>) -> Matrix<T> {
+    let mut data = Vec::with_capacity(u.size * v.size);
+
+    for &x in &u.data {
+        data.extend(v.data.iter().map(|&y| x * y));
     }
+
+    Matrix::new(u.size, v.size, data)
 }
 
 /// Multiplies vector by scalar.
@@ -681,7 +1739,10 @@ impl<T> Index<usize> for Vector<T> {
     type Output = T;
 
     fn index(&self, idx: usize) -> &T {
-        assert!(idx < self.size);
+        assert!(idx < self.size,
+                "Index {} is out of bounds for a vector of size {}.",
+                idx,
+                self.size);
         unsafe { self.data.get_unchecked(idx) }
     }
 }
@@ -689,7 +1750,10 @@ impl<T> Index<usize> for Vector<T> {
 /// Indexes mutable vector.
 impl<T> IndexMut<usize> for Vector<T> {
     fn index_mut(&mut self, idx: usize) -> &mut T {
-        assert!(idx < self.size);
+        assert!(idx < self.size,
+                "Index {} is out of bounds for a vector of size {}.",
+                idx,
+                self.size);
         unsafe { self.data.get_unchecked_mut(idx) } 
     }
 }
@@ -779,8 +1843,298 @@ impl_op_assign_vec!(SubAssign, Sub, sub, sub_assign, "subtraction");
 
 #[cfg(test)]
 mod tests {
-    use super::Vector;
+    use super::{Vector, outer, concat};
     use super::super::Metric;
+    use matrix::{Matrix, BaseMatrix};
+
+    #[test]
+    fn test_split_concat_round_trip() {
+        let a = Vector::new((0..7).collect::<Vec<_>>());
+
+        for i in 0..8 {
+            let (l, r) = a.clone().split_at(i);
+            assert_eq!((l.size(), r.size()), (i, 7 - i));
+            assert_eq!(concat(&[&l, &r]), a);
+        }
+
+        assert_eq!(concat::<f64>(&[]), Vector::new(vec![]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_out_of_bounds() {
+        let _ = Vector::new(vec![1, 2, 3]).split_at(4);
+    }
+
+    #[test]
+    fn test_extend_append() {
+        let mut a = Vector::<f64>::new(vec![]);
+        a.extend(&Vector::new(vec![1.0, 2.0]));
+        assert_eq!(a.size(), 2);
+
+        a.append(Vector::new(vec![3.0]));
+        assert_eq!(a, Vector::new(vec![1.0, 2.0, 3.0]));
+
+        // The new size is used by subsequent arithmetic.
+        let b = &a + &Vector::new(vec![1.0, 1.0, 1.0]);
+        assert_eq!(b, Vector::new(vec![2.0, 3.0, 4.0]));
+        assert_eq!(a.dot(&b), 20.0);
+
+        let (x, y) = b.split_at(1);
+        assert_eq!(x * 2.0, Vector::new(vec![4.0]));
+        assert_eq!(y.sum(), 7.0);
+    }
+
+    #[test]
+    fn test_cross_basis() {
+        let x = Vector::new(vec![1.0, 0.0, 0.0]);
+        let y = Vector::new(vec![0.0, 1.0, 0.0]);
+        let z = Vector::new(vec![0.0, 0.0, 1.0]);
+
+        assert_eq!(x.cross(&y).unwrap(), z);
+        assert_eq!(y.cross(&z).unwrap(), x);
+        assert_eq!(z.cross(&x).unwrap(), y);
+        assert_eq!(y.cross(&x).unwrap(), -z);
+        assert_eq!(x.cross(&x).unwrap(), Vector::zeros(3));
+
+        assert!(Vector::new(vec![1.0, 2.0]).cross(&Vector::new(vec![3.0, 4.0])).is_err());
+        assert!(x.cross(&Vector::new(vec![1.0, 2.0, 3.0, 4.0])).is_err());
+    }
+
+    quickcheck! {
+        fn prop_cross_anticommutative(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
+            let u = Vector::new(vec![a.0, a.1, a.2]);
+            let v = Vector::new(vec![b.0, b.1, b.2]);
+
+            u.cross(&v).unwrap() == -v.cross(&u).unwrap()
+        }
+    }
+
+    quickcheck! {
+        fn prop_sort_matches_std(a: Vec<i32>) -> bool {
+            let mut v = Vector::new(a.clone());
+            let mut expected = a;
+            expected.sort();
+            let p = v.clone().sort_with_permutation();
+            let orig = Matrix::new(v.size(), 1, v.data().clone());
+
+            v.sort_in_place();
+            *v.data() == expected && *(p.as_matrix() * orig).data() == expected
+        }
+    }
+
+    #[test]
+    fn test_sort_stable_with_duplicates() {
+        let keys = Vector::new(vec![2.0, 1.0, 2.0, 1.0, 0.0]);
+        let mut sorted = keys.clone();
+        let p = sorted.sort_with_permutation();
+
+        assert_eq!(keys.argsort(), vec![4, 1, 3, 0, 2]);
+        assert_eq!(sorted, Vector::new(vec![0.0, 1.0, 1.0, 2.0, 2.0]));
+        assert_eq!(p.as_matrix() * &keys, sorted);
+        assert_eq!(keys.sorted(), sorted);
+    }
+
+    #[test]
+    fn test_argsort_is_permutation() {
+        let a = Vector::new(vec![5, 3, 9, 3, 1, 7, 5]);
+
+        for order in vec![a.argsort(), a.argsort_desc()] {
+            let mut seen = order.clone();
+            seen.sort();
+            assert_eq!(seen, (0..7).collect::<Vec<_>>());
+        }
+
+        assert_eq!(a.select(&a.argsort()), a.sorted());
+        assert_eq!(a.argsort_desc(), vec![2, 5, 0, 6, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_argsort_empty() {
+        let mut a = Vector::<f64>::new(vec![]);
+
+        assert!(a.argsort().is_empty());
+        assert!(a.argsort_desc().is_empty());
+        assert_eq!(a.sorted().size(), 0);
+        a.sort_in_place();
+        assert_eq!(a.size(), 0);
+    }
+
+    #[test]
+    fn test_sort_nan_last() {
+        let nan = ::std::f64::NAN;
+        let a = Vector::new(vec![nan, 3.0, nan, -1.0]);
+
+        assert_eq!(a.argsort(), vec![3, 1, 0, 2]);
+        assert_eq!(a.argsort_desc(), vec![1, 3, 0, 2]);
+
+        let b = a.sorted();
+        assert_eq!(&b.data()[..2], &[-1.0, 3.0]);
+        assert!(b[2].is_nan() && b[3].is_nan());
+    }
+
+    #[test]
+    fn test_linspace_endpoints() {
+        let a = Vector::linspace(0.0, 0.3, 7);
+
+        assert_eq!(a.size(), 7);
+        assert_eq!(a[0], 0.0);
+        assert_eq!(a[6], 0.3);
+        assert!((a[3] - 0.15f64).abs() < 1e-15);
+
+        assert_eq!(*Vector::linspace(2.0, 5.0, 1).data(), vec![2.0]);
+        assert_eq!(Vector::<f64>::linspace(2.0, 5.0, 0).size(), 0);
+        assert_eq!(*Vector::linspace(1.0, -1.0, 3).data(), vec![1.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn test_logspace_powers_of_ten() {
+        let a = Vector::logspace(-2.0, 3.0, 6);
+
+        for (i, x) in a.iter().enumerate() {
+            let expected = 10f64.powi(i as i32 - 2);
+            assert!(((x - expected) / expected).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(*Vector::range(0.0, 1.0, 0.25).unwrap().data(),
+                   vec![0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(*Vector::range(3.0, 0.0, -1.5).unwrap().data(), vec![3.0, 1.5]);
+        assert_eq!(Vector::range(1.0, 1.0, 1.0).unwrap().size(), 0);
+
+        let a = Vector::range(0.0, 1.0, 0.1).unwrap();
+        assert_eq!(a.size(), 10);
+        assert_eq!(a[9], 0.9);
+    }
+
+    #[test]
+    fn test_range_invalid_step() {
+        assert!(Vector::range(0.0, 1.0, 0.0).is_err());
+        assert!(Vector::range(0.0, 1.0, -0.5).is_err());
+        assert!(Vector::range(1.0, 0.0, 0.5).is_err());
+        assert!(Vector::range(0.0, 1.0, ::std::f64::NAN).is_err());
+        assert!(Vector::range(0.0, ::std::f64::INFINITY, 1.0).is_err());
+        assert!(Vector::range(::std::f64::NEG_INFINITY, 0.0, 1.0).is_err());
+        assert!(Vector::range(0.0, 1.0, ::std::f64::INFINITY).is_err());
+        assert!(Vector::range(0.0, 1e300, 1e-300).is_err());
+    }
+
+    #[test]
+    fn test_range_excludes_end() {
+        let a = Vector::range(0.0, 2.1, 0.3).unwrap();
+        assert_eq!(a.size(), 7);
+        assert!(a.iter().all(|&x| x < 2.1));
+
+        let b = Vector::range(0.0, 0.07, 0.01).unwrap();
+        assert_eq!(b.size(), 7);
+        assert!(b.iter().all(|&x| x < 0.07));
+
+        let c = Vector::range(2.1, 0.0, -0.3).unwrap();
+        assert!(c.iter().all(|&x| x > 0.0));
+    }
+
+    #[test]
+    fn test_elem_max_min_clamp() {
+        let nan = ::std::f64::NAN;
+        let a = Vector::new(vec![1.0, 5.0, nan, -4.0]);
+        let b = Vector::new(vec![2.0, 3.0, 7.0, -4.0]);
+
+        assert_eq!(*a.elem_max(&b).data(), vec![2.0, 5.0, 7.0, -4.0]);
+        assert_eq!(*a.elem_min(&b).data(), vec![1.0, 3.0, 7.0, -4.0]);
+
+        let mut c = a.clone();
+        c.clamp_mut(0.0, 2.0);
+        assert_eq!(*c.data(), vec![1.0, 2.0, 0.0, 0.0]);
+        assert_eq!(b.clamp(-1.0, 3.0), Vector::new(vec![2.0, 3.0, 3.0, -1.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_inverted_bounds() {
+        let _ = Vector::new(vec![1.0, 2.0]).clamp(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_elem_max_size_mismatch() {
+        let _ = Vector::new(vec![1.0, 2.0]).elem_max(&Vector::new(vec![1.0]));
+    }
+
+    #[test]
+    fn test_angle() {
+        use std::f64::consts::PI;
+
+        let x = Vector::new(vec![1.0, 0.0, 0.0]);
+        let y = Vector::new(vec![0.0, 2.0, 0.0]);
+
+        assert_eq!(x.angle(&y).unwrap(), PI / 2.0);
+        assert_eq!(x.angle(&(&x * 3.0)).unwrap(), 0.0);
+        assert_eq!(x.angle(&-&x).unwrap(), PI);
+
+        // Nearly parallel vectors, where acos loses all accuracy.
+        let v = Vector::new(vec![1.0, 1e-10, 0.0]);
+        assert!((x.angle(&v).unwrap() - 1e-10).abs() < 1e-24);
+
+        // Sizes other than 3.
+        let a = Vector::new(vec![1.0, 0.0, 0.0, 0.0]);
+        let b = Vector::new(vec![1.0, 0.0, 0.0, 1.0]);
+        assert!((a.angle(&b).unwrap() - PI / 4.0).abs() < 1e-15);
+        assert_eq!(Vector::new(vec![2.0, 0.0]).angle(&Vector::new(vec![-1.0, 0.0])).unwrap(), PI);
+    }
+
+    #[test]
+    fn test_angle_zero_vector() {
+        let x = Vector::new(vec![1.0, 0.0, 0.0]);
+        let zero = Vector::zeros(3);
+
+        assert!(x.angle(&zero).is_err());
+        assert!(zero.angle(&x).is_err());
+        assert!(zero.angle(&zero).is_err());
+    }
+
+    #[test]
+    fn test_proj_onto() {
+        let a = Vector::new(vec![3.0f64, 4.0, 5.0]);
+        let x = Vector::new(vec![2.0, 0.0, 0.0]);
+
+        assert_eq!(a.proj_onto(&x).unwrap(), Vector::new(vec![3.0, 0.0, 0.0]));
+
+        // The residual is orthogonal to the direction.
+        let b = Vector::new(vec![1.0, -2.0, 2.0]);
+        let p = a.proj_onto(&b).unwrap();
+        assert!((&a - &p).dot(&b).abs() < 1e-12);
+        assert!(p.angle(&b).unwrap() < 1e-12);
+
+        // Tiny directions are handled without underflow.
+        let tiny = Vector::new(vec![1e-200, 0.0, 0.0]);
+        assert_eq!(a.proj_onto(&tiny).unwrap(), Vector::new(vec![3.0, 0.0, 0.0]));
+
+        assert!(a.proj_onto(&Vector::zeros(3)).is_err());
+        assert_eq!(Vector::zeros(3).proj_onto(&a).unwrap(), Vector::zeros(3));
+    }
+
+    #[test]
+    fn test_non_finite_detection() {
+        use std::f64;
+
+        let clean = Vector::new(vec![1.0, -2.0, 0.0, 1e300]);
+        assert!(!clean.has_nan());
+        assert!(clean.is_finite());
+        assert_eq!(clean.find_non_finite(), None);
+
+        for &bad in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            for i in 0..4 {
+                let mut v = clean.clone();
+                v[i] = bad;
+
+                assert_eq!(v.has_nan(), bad.is_nan());
+                assert!(!v.is_finite());
+                assert_eq!(v.find_non_finite(), Some(i));
+            }
+        }
+    }
 
     #[test]
     fn test_display() {
@@ -1160,4 +2514,252 @@ mod tests {
 
         assert_eq!(our_vector.into_vec(), vec![2., 3., 4., 5.]);
     }
+
+    #[test]
+    fn test_outer_2x2() {
+        let u = Vector::new(vec![1.0, 2.0]);
+        let v = Vector::new(vec![3.0, -1.0]);
+
+        let m = u.outer(&v);
+        assert_eq!(m.rows(), 2);
+        assert_eq!(m.cols(), 2);
+        assert_eq!(*m.data(), vec![3.0, -1.0, 6.0, -2.0]);
+
+        assert_eq!(u.outer_self(), Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]));
+    }
+
+    #[test]
+    fn test_outer_elementary() {
+        let unit = |i: usize, n: usize| {
+            let mut e = Vector::<i32>::zeros(n);
+            e[i] = 1;
+            e
+        };
+
+        for i in 0..3 {
+            for j in 0..4 {
+                let e_ij = outer(&unit(i, 3), &unit(j, 4));
+
+                let mut expected = Matrix::<i32>::zeros(3, 4);
+                expected[[i, j]] = 1;
+                assert_eq!(e_ij, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_outer_rank_one_update() {
+        let a = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0]);
+        let u = Vector::new(vec![1.0, -2.0, 0.5]);
+        let v = Vector::new(vec![2.0, 0.0, 4.0]);
+        let alpha = 3.0;
+
+        let updated = &a + outer(&u, &v) * alpha;
+        let diff = updated - &a;
+
+        // Every row of the update is a multiple of vᵀ.
+        for (i, row) in diff.iter_rows().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                assert_eq!(x, alpha * u[i] * v[j]);
+            }
+        }
+
+        // So any 2x2 minor of the update vanishes.
+        assert_eq!(diff[[0, 0]] * diff[[1, 2]] - diff[[0, 2]] * diff[[1, 0]], 0.0);
+    }
+
+    #[test]
+    fn test_vector_map() {
+        let v = Vector::new(vec![1.0f64, -2.0, 3.5]);
+
+        let w = v.map(|x| x as f32);
+        assert_eq!(w, Vector::new(vec![1.0f32, -2.0, 3.5]));
+
+        let mask = v.map(|x| x > 0.0);
+        assert_eq!(mask.into_vec(), vec![true, false, true]);
+
+        let empty = Vector::<i32>::new(vec![]).map(|x| x as f64);
+        assert_eq!(empty.size(), 0);
+    }
+
+    #[test]
+    fn test_axpy() {
+        let mut y = Vector::new(vec![1.0, 0.0, -1.0, 2.5]);
+        let x = Vector::new(vec![2.0, 4.0, 1.0, -1.0]);
+
+        y.axpy(-0.5, &x);
+        assert_eq!(y, Vector::new(vec![0.0, -2.0, -1.5, 3.0]));
+
+        let mut z = Vector::new(vec![1, 2, 3]);
+        z.axpy(3, &Vector::new(vec![1, 1, 1]));
+        assert_eq!(z.into_vec(), vec![4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector dimensions do not agree.")]
+    fn test_axpy_size_mismatch() {
+        let mut y = Vector::new(vec![1.0, 2.0]);
+        y.axpy(1.0, &Vector::new(vec![1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_scal() {
+        let mut x = Vector::new(vec![1.0, -2.0, 4.0]);
+        x.scal(-2.0);
+        assert_eq!(x.into_vec(), vec![-2.0, 4.0, -8.0]);
+
+        let mut empty = Vector::<f64>::new(vec![]);
+        empty.scal(3.0);
+        assert_eq!(empty.size(), 0);
+    }
+
+    #[test]
+    fn test_argmax_argmin_ties_and_nan() {
+        let v = Vector::new(vec![::std::f64::NAN, 2.0, -1.0, 2.0, -1.0]);
+
+        assert_eq!(v.argmax(), (1, 2.0));
+        assert_eq!(v.argmin(), (2, -1.0));
+
+        let single = Vector::new(vec![4]);
+        assert_eq!(single.argmax(), (0, 4));
+        assert_eq!(single.argmin(), (0, 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_argmax_empty() {
+        let _ = Vector::<f64>::new(vec![]).argmax();
+    }
+
+    #[test]
+    fn test_vector_cumsum_cumprod() {
+        let v = Vector::new(vec![2.0, -1.0, 0.5, 4.0]);
+
+        assert_eq!(v.cumsum().into_vec(), vec![2.0, 1.0, 1.5, 5.5]);
+        assert_eq!(v.cumprod().into_vec(), vec![2.0, -2.0, -1.0, -4.0]);
+        assert_eq!(Vector::<i32>::new(vec![]).cumsum().size(), 0);
+        assert_eq!(Vector::<i32>::new(vec![]).cumprod().size(), 0);
+
+        let w = Vector::new((1..20).collect::<Vec<i64>>());
+        assert_eq!(w.cumsum()[18], w.sum());
+    }
+
+    #[test]
+    fn test_vector_soft_threshold() {
+        let zero = Vector::new(vec![0.0; 4]);
+        for &lambda in &[1e-8, 0.5, 1.0, 100.0] {
+            assert_eq!(zero.soft_threshold(lambda), zero);
+        }
+
+        let a = Vector::new(vec![-2.5, -1.0, 0.25, 1.0, 4.0]);
+        assert_eq!(a.soft_threshold(1.0), Vector::new(vec![-1.5, 0.0, 0.0, 0.0, 3.0]));
+    }
+
+    #[test]
+    fn test_vector_get_checked() {
+        let mut a = Vector::new(vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(a.get(2), Some(&3.0));
+        assert_eq!(a.get(3), None);
+
+        *a.get_mut(0).unwrap() = 5.0;
+        assert!(a.get_mut(3).is_none());
+        assert_eq!(a[0], 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 3 is out of bounds for a vector of size 3.")]
+    fn test_vector_index_panic_message() {
+        let a = Vector::new(vec![1.0, 2.0, 3.0]);
+        let _ = a[3];
+    }
+
+    #[test]
+    fn test_vector_p_norms() {
+        let a = Vector::new(vec![1.0f64, -2.0, 3.0, -0.5]);
+
+        assert_eq!(a.norm_1(), 6.5);
+        assert_eq!(a.norm_inf(), 3.0);
+        assert!((a.norm_2() - a.norm()).abs() < 1e-12);
+        assert!((a.norm_p(2.0) - a.norm_2()).abs() < 1e-12);
+        assert!((a.norm_p(1.0) - a.norm_1()).abs() < 1e-12);
+        assert_eq!(a.norm_p(f64::INFINITY), 3.0);
+
+        // The p-norm decreases towards the infinity-norm as p grows.
+        let mut prev = a.norm_p(1.0);
+        for &p in &[2.0, 4.0, 16.0, 64.0] {
+            let n = a.norm_p(p);
+            assert!(n <= prev);
+            prev = n;
+        }
+        assert!((a.norm_p(400.0) - 3.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_vector_norm_extremes() {
+        let big = Vector::new(vec![1e200f64, 1e200, 1e200, 1e200]);
+        assert_eq!(big.norm_2(), 2e200);
+        assert!((big.norm_p(3.0) / (4f64.cbrt() * 1e200) - 1.0).abs() < 1e-12);
+
+        let tiny = Vector::new(vec![3e-200f64, 4e-200]);
+        assert!((tiny.norm_2() / 5e-200 - 1.0).abs() < 1e-12);
+
+        let empty = Vector::<f64>::new(vec![]);
+        assert_eq!(empty.norm_2(), 0.0);
+        assert_eq!(empty.norm_p(3.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vector_norm_p_invalid() {
+        let _ = Vector::new(vec![1.0, 2.0]).norm_p(0.5);
+    }
+
+    #[test]
+    fn test_vector_metric() {
+        let a = Vector::new(vec![1e200f64, 2.0, -1.0]);
+        let b = Vector::new(vec![-1e200f64, 2.0, -1.0]);
+
+        assert_eq!(a.metric(&b), 2e200);
+        assert_eq!(a.metric(&a), 0.0);
+
+        let c = Vector::new(vec![1.0, 2.0, 3.0]);
+        let d = Vector::new(vec![4.0, -2.0, 3.0]);
+        assert_eq!(c.metric(&d), (&c - &d).norm());
+    }
+
+    #[test]
+    fn test_vector_normalize() {
+        let a = Vector::new(vec![0.0f64, 3e200, 4e200]);
+        let n = a.normalize().unwrap();
+        assert!((n.norm_2() - 1.0).abs() < 1e-12);
+        for (x, y) in n.iter().zip(&[0.0, 0.6, 0.8]) {
+            assert!((x - y).abs() < 1e-15);
+        }
+
+        let mut zero = Vector::new(vec![0.0, 0.0]);
+        assert!(zero.normalize().is_err());
+        assert!(zero.normalize_mut().is_err());
+        assert_eq!(*zero.data(), vec![0.0, 0.0]);
+
+        assert!(Vector::<f64>::new(vec![]).normalize().is_err());
+    }
+
+    #[test]
+    fn test_vector_into_matrix() {
+        let v = Vector::new(vec![1.0, 2.0, 3.0]);
+        let ptr = v.data().as_ptr();
+
+        let col = v.into_matrix_col();
+        assert_eq!((col.rows(), col.cols()), (3, 1));
+        assert_eq!(col.data().as_ptr(), ptr);
+        assert_eq!(col[[2, 0]], 3.0);
+
+        let v = col.into_vector().unwrap();
+        assert_eq!(v.data().as_ptr(), ptr);
+
+        let row = v.into_matrix_row();
+        assert_eq!((row.rows(), row.cols()), (1, 3));
+        assert_eq!(row.into_vector().unwrap(), Vector::new(vec![1.0, 2.0, 3.0]));
+    }
 }
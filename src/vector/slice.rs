@@ -0,0 +1,542 @@
+//! Slices of vectors.
+//!
+//! `VectorSlice` and `VectorSliceMut` are views into a contiguous range
+//! of a `Vector`, created without copying by `Vector::slice` and
+//! `Vector::slice_mut`.
+//!
+//! Most of their functionality is found in the `BaseVector` and
+//! `BaseVectorMut` traits. These are also implemented by `Vector`, so
+//! functions can be generic over vectors and slices.
+
+use std::ops::{Add, Mul, Sub, Index, IndexMut, Range, AddAssign, SubAssign};
+use std::slice::{Iter, IterMut};
+use libnum::{Zero, Float};
+
+use super::{Vector, scaled_norm_2};
+use utils;
+
+/// A `VectorSlice`
+///
+/// This struct provides an immutable view into a contiguous range of
+/// a vector.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::vector::{Vector, BaseVector};
+///
+/// let a = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+/// let b = Vector::new(vec![2.0; 4]);
+///
+/// let d = a.slice(1..3).dot(&b.slice(0..2));
+/// assert_eq!(d, 10.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct VectorSlice<'a, T: 'a> {
+    data: &'a [T],
+}
+
+/// A `VectorSliceMut`
+///
+/// This struct provides a mutable view into a contiguous range of
+/// a vector.
+///
+/// # Examples
+///
+/// ```
+/// use rulinalg::vector::{Vector, BaseVectorMut};
+///
+/// let mut a = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+/// let x = Vector::new(vec![1.0, 1.0]);
+///
+/// a.slice_mut(2..4).axpy(2.0, &x);
+/// assert_eq!(a, Vector::new(vec![1.0, 2.0, 5.0, 6.0]));
+/// ```
+///
+/// The vector is borrowed for as long as the slice lives, so it
+/// cannot be read or sliced again in that time.
+///
+/// ```compile_fail
+/// use rulinalg::vector::Vector;
+///
+/// let mut a = Vector::new(vec![1.0, 2.0, 3.0]);
+/// let mut s = a.slice_mut(0..2);
+/// let t = a.slice(1..3);
+///
+/// s[0] = t[0];
+/// ```
+///
+/// ```compile_fail
+/// use rulinalg::vector::Vector;
+///
+/// let mut a = Vector::new(vec![1.0, 2.0, 3.0]);
+/// let mut s = a.slice_mut(0..2);
+/// let mut t = a.slice_mut(2..3);
+///
+/// s[0] = 1.0;
+/// t[0] = 1.0;
+/// ```
+#[derive(Debug)]
+pub struct VectorSliceMut<'a, T: 'a> {
+    data: &'a mut [T],
+}
+
+/// Checks that `range` lies within a vector of the given size.
+fn check_range(range: &Range<usize>, size: usize) {
+    assert!(range.start <= range.end, "Slice range start is greater than its end.");
+    assert!(range.end <= size, "Slice range is out of bounds.");
+}
+
+impl<'a, T> VectorSlice<'a, T> {
+    /// Produces a `VectorSlice` from a range of a vector.
+    ///
+    /// # Panics
+    ///
+    /// - The range is out of bounds.
+    pub fn from_vector(vector: &'a Vector<T>, range: Range<usize>) -> VectorSlice<'a, T> {
+        check_range(&range, vector.size());
+        VectorSlice { data: &vector.data()[range] }
+    }
+}
+
+impl<'a, T> VectorSliceMut<'a, T> {
+    /// Produces a `VectorSliceMut` from a range of a vector.
+    ///
+    /// # Panics
+    ///
+    /// - The range is out of bounds.
+    pub fn from_vector(vector: &'a mut Vector<T>, range: Range<usize>) -> VectorSliceMut<'a, T> {
+        check_range(&range, vector.size());
+        VectorSliceMut { data: &mut vector.mut_data()[range] }
+    }
+}
+
+impl<T> Vector<T> {
+    /// Returns an immutable view of the elements in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::{Vector, BaseVector};
+    ///
+    /// let a = Vector::new(vec![1, 2, 3, 4]);
+    /// let s = a.slice(1..3);
+    ///
+    /// assert_eq!(s.to_vector(), Vector::new(vec![2, 3]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The range is out of bounds.
+    pub fn slice(&self, range: Range<usize>) -> VectorSlice<'_, T> {
+        VectorSlice::from_vector(self, range)
+    }
+
+    /// Returns a mutable view of the elements in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::Vector;
+    ///
+    /// let mut a = Vector::new(vec![1, 2, 3, 4]);
+    /// a.slice_mut(1..3)[0] = 7;
+    ///
+    /// assert_eq!(a, Vector::new(vec![1, 7, 3, 4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The range is out of bounds.
+    pub fn slice_mut(&mut self, range: Range<usize>) -> VectorSliceMut<'_, T> {
+        VectorSliceMut::from_vector(self, range)
+    }
+}
+
+/// Trait for immutable vector structs.
+pub trait BaseVector<T>: Sized {
+    /// Returns the elements as a contiguous slice.
+    fn as_slice(&self) -> &[T];
+
+    /// Returns the number of elements.
+    fn size(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns an iterator over the elements.
+    fn iter(&self) -> Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an immutable view of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// - The range is out of bounds.
+    fn sub_slice(&self, range: Range<usize>) -> VectorSlice<'_, T> {
+        check_range(&range, self.size());
+        VectorSlice { data: &self.as_slice()[range] }
+    }
+
+    /// Copies the elements into a new `Vector`.
+    fn to_vector(&self) -> Vector<T>
+        where T: Clone
+    {
+        Vector::new(self.as_slice().to_vec())
+    }
+
+    /// Computes the dot product with another vector or slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::{Vector, BaseVector};
+    ///
+    /// let a = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+    ///
+    /// assert_eq!(a.slice(0..2).dot(&a.slice(2..4)), 11.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The sizes do not match.
+    fn dot<V: BaseVector<T>>(&self, v: &V) -> T
+        where T: Copy + Zero + Add<T, Output = T> + Mul<T, Output = T>
+    {
+        assert!(self.size() == v.size(), "Vector sizes do not match.");
+        utils::dot(self.as_slice(), v.as_slice())
+    }
+
+    /// Computes the sum of absolute values of the elements.
+    fn norm_1(&self) -> T
+        where T: Float
+    {
+        self.iter().fold(T::zero(), |s, x| s + x.abs())
+    }
+
+    /// Computes the euclidean norm.
+    ///
+    /// Uses scaled accumulation, as in `Vector::norm_2`, so that the
+    /// squares of the elements cannot overflow.
+    fn norm_2(&self) -> T
+        where T: Float
+    {
+        scaled_norm_2(self.iter().cloned())
+    }
+
+    /// Computes the largest absolute value of the elements.
+    fn norm_inf(&self) -> T
+        where T: Float
+    {
+        self.iter().fold(T::zero(), |m, x| m.max(x.abs()))
+    }
+}
+
+/// Trait for mutable vector structs.
+pub trait BaseVectorMut<T>: BaseVector<T> {
+    /// Returns the elements as a contiguous mutable slice.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Returns a mutable iterator over the elements.
+    fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns a mutable view of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// - The range is out of bounds.
+    fn sub_slice_mut(&mut self, range: Range<usize>) -> VectorSliceMut<'_, T> {
+        check_range(&range, self.size());
+        VectorSliceMut { data: &mut self.as_mut_slice()[range] }
+    }
+
+    /// Copies the elements of `v` into `self`.
+    ///
+    /// # Panics
+    ///
+    /// - The sizes do not match.
+    fn set_from<V: BaseVector<T>>(&mut self, v: &V)
+        where T: Copy
+    {
+        assert!(self.size() == v.size(), "Vector sizes do not match.");
+        self.as_mut_slice().copy_from_slice(v.as_slice());
+    }
+
+    /// Computes `self += alpha * x` in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rulinalg::vector::{Vector, BaseVectorMut};
+    ///
+    /// let mut y = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+    /// let x = Vector::new(vec![1.0, 1.0, 1.0, 1.0]);
+    ///
+    /// y.slice_mut(0..2).axpy(3.0, &x.slice(2..4));
+    /// assert_eq!(y.into_vec(), vec![4.0, 5.0, 3.0, 4.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - The sizes do not match.
+    fn axpy<V: BaseVector<T>>(&mut self, alpha: T, x: &V)
+        where T: Copy + Add<T, Output = T> + Mul<T, Output = T>
+    {
+        assert!(self.size() == x.size(), "Vector sizes do not match.");
+        utils::in_place_vec_bin_op(self.as_mut_slice(), x.as_slice(), |y, &x| *y = *y + alpha * x);
+    }
+
+    /// Computes `self *= alpha` in place.
+    fn scal(&mut self, alpha: T)
+        where T: Copy + Mul<T, Output = T>
+    {
+        for x in self.iter_mut() {
+            *x = *x * alpha;
+        }
+    }
+}
+
+impl<T> BaseVector<T> for Vector<T> {
+    fn as_slice(&self) -> &[T] {
+        self.data()
+    }
+}
+
+impl<T> BaseVectorMut<T> for Vector<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.mut_data()
+    }
+}
+
+impl<'a, T> BaseVector<T> for VectorSlice<'a, T> {
+    fn as_slice(&self) -> &[T] {
+        self.data
+    }
+}
+
+impl<'a, T> BaseVector<T> for VectorSliceMut<'a, T> {
+    fn as_slice(&self) -> &[T] {
+        self.data
+    }
+}
+
+impl<'a, T> BaseVectorMut<T> for VectorSliceMut<'a, T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data
+    }
+}
+
+/// Indexes vector slice.
+impl<'a, T> Index<usize> for VectorSlice<'a, T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.data[idx]
+    }
+}
+
+/// Indexes mutable vector slice.
+impl<'a, T> Index<usize> for VectorSliceMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.data[idx]
+    }
+}
+
+/// Indexes mutable vector slice.
+impl<'a, T> IndexMut<usize> for VectorSliceMut<'a, T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        &mut self.data[idx]
+    }
+}
+
+macro_rules! impl_bin_op_vec_slice (
+    ($trt:ident, $op:ident, $lhs:ty, $rhs:ty, $doc:expr) => (
+
+/// Performs elementwise
+#[doc=$doc]
+/// between vectors and slices.
+///
+/// # Panics
+///
+/// - The sizes do not match.
+impl<'a, 'b, 'c, 'd, T> $trt<&'d $rhs> for &'c $lhs
+    where T: Copy + $trt<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn $op(self, v: &$rhs) -> Vector<T> {
+        assert!(self.size() == v.size(), "Vector sizes do not match.");
+        Vector::new(utils::vec_bin_op(self.as_slice(), v.as_slice(), |x, y| x.$op(y)))
+    }
+}
+    );
+);
+
+impl_bin_op_vec_slice!(Add, add, VectorSlice<'a, T>, VectorSlice<'b, T>, "addition");
+impl_bin_op_vec_slice!(Add, add, VectorSlice<'a, T>, VectorSliceMut<'b, T>, "addition");
+impl_bin_op_vec_slice!(Add, add, VectorSliceMut<'a, T>, VectorSlice<'b, T>, "addition");
+impl_bin_op_vec_slice!(Add, add, VectorSliceMut<'a, T>, VectorSliceMut<'b, T>, "addition");
+impl_bin_op_vec_slice!(Add, add, VectorSlice<'a, T>, Vector<T>, "addition");
+impl_bin_op_vec_slice!(Add, add, VectorSliceMut<'a, T>, Vector<T>, "addition");
+impl_bin_op_vec_slice!(Add, add, Vector<T>, VectorSlice<'b, T>, "addition");
+impl_bin_op_vec_slice!(Add, add, Vector<T>, VectorSliceMut<'b, T>, "addition");
+
+impl_bin_op_vec_slice!(Sub, sub, VectorSlice<'a, T>, VectorSlice<'b, T>, "subtraction");
+impl_bin_op_vec_slice!(Sub, sub, VectorSlice<'a, T>, VectorSliceMut<'b, T>, "subtraction");
+impl_bin_op_vec_slice!(Sub, sub, VectorSliceMut<'a, T>, VectorSlice<'b, T>, "subtraction");
+impl_bin_op_vec_slice!(Sub, sub, VectorSliceMut<'a, T>, VectorSliceMut<'b, T>, "subtraction");
+impl_bin_op_vec_slice!(Sub, sub, VectorSlice<'a, T>, Vector<T>, "subtraction");
+impl_bin_op_vec_slice!(Sub, sub, VectorSliceMut<'a, T>, Vector<T>, "subtraction");
+impl_bin_op_vec_slice!(Sub, sub, Vector<T>, VectorSlice<'b, T>, "subtraction");
+impl_bin_op_vec_slice!(Sub, sub, Vector<T>, VectorSliceMut<'b, T>, "subtraction");
+
+macro_rules! impl_op_assign_slice_vec (
+    ($assign_trt:ident, $trt:ident, $op:ident, $op_assign:ident, $rhs:ty, $doc:expr) => (
+
+/// Performs elementwise
+#[doc=$doc]
+/// assignment to a mutable slice.
+///
+/// # Panics
+///
+/// - The sizes do not match.
+impl<'a, 'b, 'c, T> $assign_trt<&'c $rhs> for VectorSliceMut<'a, T>
+    where T: Copy + $trt<T, Output = T>
+{
+    fn $op_assign(&mut self, v: &$rhs) {
+        assert!(self.size() == v.size(), "Vector sizes do not match.");
+        utils::in_place_vec_bin_op(self.data, v.as_slice(), |x, &y| *x = (*x).$op(y));
+    }
+}
+    );
+);
+
+impl_op_assign_slice_vec!(AddAssign, Add, add, add_assign, VectorSlice<'b, T>, "addition");
+impl_op_assign_slice_vec!(AddAssign, Add, add, add_assign, VectorSliceMut<'b, T>, "addition");
+impl_op_assign_slice_vec!(AddAssign, Add, add, add_assign, Vector<T>, "addition");
+impl_op_assign_slice_vec!(SubAssign, Sub, sub, sub_assign, VectorSlice<'b, T>, "subtraction");
+impl_op_assign_slice_vec!(SubAssign, Sub, sub, sub_assign, VectorSliceMut<'b, T>, "subtraction");
+impl_op_assign_slice_vec!(SubAssign, Sub, sub, sub_assign, Vector<T>, "subtraction");
+
+macro_rules! impl_bin_op_scalar_vec_slice (
+    ($trt:ident, $op:ident, $slice:ident, $doc:expr) => (
+
+/// Scalar
+#[doc=$doc]
+/// with vector slice.
+impl<'a, 'b, T> $trt<T> for &'b $slice<'a, T>
+    where T: Copy + $trt<T, Output = T>
+{
+    type Output = Vector<T>;
+
+    fn $op(self, f: T) -> Vector<T> {
+        Vector::new(self.data.iter().map(|&x| x.$op(f)).collect::<Vec<_>>())
+    }
+}
+    );
+);
+
+impl_bin_op_scalar_vec_slice!(Mul, mul, VectorSlice, "multiplication");
+impl_bin_op_scalar_vec_slice!(Mul, mul, VectorSliceMut, "multiplication");
+
+#[cfg(test)]
+mod tests {
+    use vector::Vector;
+    use super::{BaseVector, BaseVectorMut, VectorSlice};
+
+    #[test]
+    fn test_dot_sub_slices() {
+        let a = Vector::new((0..10).map(|x| x as f64).collect::<Vec<_>>());
+        let b = Vector::new((0..10).map(|x| (x % 3) as f64).collect::<Vec<_>>());
+
+        let s = a.slice(2..6);
+        let t = b.slice(5..9);
+        assert_eq!(s.dot(&t), 2.0 * 2.0 + 3.0 * 0.0 + 4.0 * 1.0 + 5.0 * 2.0);
+        assert_eq!(s.dot(&t), s.to_vector().dot(&t.to_vector()));
+
+        // Slices of slices see the same elements.
+        assert_eq!(s.sub_slice(1..3).to_vector(), Vector::new(vec![3.0, 4.0]));
+        assert_eq!(a.sub_slice(2..6).dot(&b.slice(5..9)), s.dot(&t));
+    }
+
+    #[test]
+    fn test_axpy_on_slice() {
+        let mut y = Vector::new(vec![1.0, 1.0, 1.0, 1.0, 1.0]);
+        let x = Vector::new(vec![1.0, 2.0, 3.0]);
+
+        {
+            let mut s = y.slice_mut(1..4);
+            s.axpy(2.0, &x);
+            s.sub_slice_mut(2..3).scal(0.5);
+        }
+        assert_eq!(y, Vector::new(vec![1.0, 3.0, 5.0, 3.5, 1.0]));
+    }
+
+    #[test]
+    fn test_slice_arithmetic() {
+        let a = Vector::new(vec![1, 2, 3, 4, 5, 6]);
+        let s = a.slice(0..3);
+        let t = a.slice(3..6);
+
+        assert_eq!(&s + &t, Vector::new(vec![5, 7, 9]));
+        assert_eq!(&t - &s, Vector::new(vec![3, 3, 3]));
+        assert_eq!(&s + &Vector::new(vec![1, 1, 1]), Vector::new(vec![2, 3, 4]));
+        assert_eq!(&Vector::new(vec![1, 1, 1]) - &t, Vector::new(vec![-3, -4, -5]));
+        assert_eq!(&s * 2, Vector::new(vec![2, 4, 6]));
+
+        let mut b = Vector::new(vec![0; 6]);
+        {
+            let mut u = b.slice_mut(1..4);
+            u += &s;
+            u -= &Vector::new(vec![1, 1, 1]);
+            assert_eq!(u[2], 2);
+        }
+        assert_eq!(b, Vector::new(vec![0, 0, 1, 2, 0, 0]));
+
+        let mut c = a.clone();
+        c.slice_mut(0..3).set_from(&t);
+        assert_eq!(c, Vector::new(vec![4, 5, 6, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_slice_norms() {
+        let a = Vector::new(vec![9.0, 3.0, -4.0, 9.0]);
+        let s = a.slice(1..3);
+
+        assert_eq!(s.norm_1(), 7.0);
+        assert_eq!(s.norm_2(), 5.0);
+        assert_eq!(s.norm_inf(), 4.0);
+    }
+
+    #[test]
+    fn test_empty_slice() {
+        let mut a = Vector::new(vec![1.0, 2.0, 3.0]);
+
+        let s = a.slice(1..1);
+        assert_eq!(s.size(), 0);
+        assert_eq!(s.dot(&a.slice(3..3)), 0.0);
+        assert_eq!(s.to_vector(), Vector::new(vec![]));
+        assert_eq!(s.norm_2(), 0.0);
+
+        a.slice_mut(0..0).scal(2.0);
+        assert_eq!(a, Vector::new(vec![1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let a = Vector::new(vec![1, 2, 3]);
+        let _ = VectorSlice::from_vector(&a, 2..4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_dot_size_mismatch() {
+        let a = Vector::new(vec![1, 2, 3]);
+        let _ = a.slice(0..2).dot(&a.slice(0..3));
+    }
+}